@@ -7,15 +7,15 @@
 use async_trait::async_trait;
 use mcb_domain::entities::CodeChunk;
 use mcb_domain::error::Result;
-use mcb_domain::value_objects::Language;
+use mcb_domain::value_objects::{ChunkSizePolicy, Language};
 use std::path::Path;
 use std::sync::Arc;
 
 /// Options for chunking operations
 #[derive(Debug, Clone, Copy)]
 pub struct ChunkingOptions {
-    /// Maximum size of a single chunk in characters
-    pub max_chunk_size: usize,
+    /// Token bounds of a single chunk
+    pub size_policy: ChunkSizePolicy,
     /// Whether to include surrounding context (imports, class declarations, etc.)
     pub include_context: bool,
     /// Maximum number of chunks per file
@@ -25,7 +25,7 @@ pub struct ChunkingOptions {
 impl Default for ChunkingOptions {
     fn default() -> Self {
         Self {
-            max_chunk_size: 512,
+            size_policy: ChunkSizePolicy::default(),
             include_context: true,
             max_chunks_per_file: 50,
         }
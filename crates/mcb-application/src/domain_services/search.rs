@@ -5,6 +5,6 @@
 
 // Re-export all service interfaces from ports for backward compatibility
 pub use crate::ports::services::{
-    ChunkingOrchestratorInterface, ContextServiceInterface, IndexingResult,
-    IndexingServiceInterface, IndexingStatus, SearchServiceInterface,
+    ChunkingOrchestratorInterface, ContextServiceInterface, EmbeddingCacheStats, IndexingOptions,
+    IndexingResult, IndexingServiceInterface, IndexingStatus, SearchServiceInterface,
 };
@@ -116,6 +116,18 @@ pub struct IndexingOperation {
 pub trait IndexingOperationsInterface: Send + Sync {
     /// Get the map of ongoing indexing operations
     fn get_operations(&self) -> HashMap<String, IndexingOperation>;
+
+    /// Start tracking a new indexing operation, returning its id
+    fn start_operation(&self, collection: &str, total_files: usize) -> String;
+
+    /// Update the total number of files an operation will process
+    fn update_total(&self, operation_id: &str, total_files: usize);
+
+    /// Update progress for an operation
+    fn update_progress(&self, operation_id: &str, current_file: Option<String>, processed: usize);
+
+    /// Complete and stop tracking an operation
+    fn complete_operation(&self, operation_id: &str);
 }
 
 // ============================================================================
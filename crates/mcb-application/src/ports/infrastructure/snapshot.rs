@@ -8,7 +8,7 @@ use async_trait::async_trait;
 use mcb_domain::entities::codebase::{CodebaseSnapshot, SnapshotChanges};
 use mcb_domain::error::Result;
 use mcb_domain::value_objects::config::SyncBatch;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 // ============================================================================
@@ -115,4 +115,34 @@ pub trait SnapshotProvider: Send + Sync {
     /// Convenience method that creates a new snapshot, compares with the previous
     /// one, and returns the list of files that need to be re-indexed.
     async fn get_changed_files(&self, root_path: &Path) -> Result<Vec<String>>;
+
+    /// Create a snapshot of specific files indexed into a collection
+    ///
+    /// Hashes `files` (paths under `root_path`) without walking the codebase
+    /// or saving the result, so callers can persist it with
+    /// [`save_snapshot`](Self::save_snapshot) once the files are indexed.
+    /// Files are keyed by their path relative to `root_path`, using `/`
+    /// separators.
+    async fn snapshot_files(
+        &self,
+        root_path: &Path,
+        collection: &str,
+        files: &[PathBuf],
+    ) -> Result<CodebaseSnapshot>;
+
+    /// Load the snapshot last saved for `collection` at `root_path`
+    async fn load_collection_snapshot(
+        &self,
+        root_path: &Path,
+        collection: &str,
+    ) -> Result<Option<CodebaseSnapshot>>;
+
+    /// Persist a snapshot as the latest state of its collection at `root_path`
+    async fn save_snapshot(&self, root_path: &Path, snapshot: &CodebaseSnapshot) -> Result<()>;
+
+    /// Delete every snapshot saved for `collection`
+    ///
+    /// Called when a collection is cleared so the next indexing run starts
+    /// from scratch.
+    async fn delete_collection_snapshots(&self, collection: &str) -> Result<()>;
 }
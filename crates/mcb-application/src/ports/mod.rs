@@ -33,16 +33,21 @@ pub use infrastructure::{
     AuthServiceInterface, EventBusProvider, LockGuard, LockProvider, SnapshotProvider,
     StateStoreProvider, SyncCoordinator, SystemMetrics, SystemMetricsCollectorInterface,
 };
-pub use providers::{EmbeddingProvider, HybridSearchProvider, VectorStoreProvider};
+pub use providers::{
+    EmbeddingProvider, HybridSearchProvider, RerankerProvider, VectorStoreProvider,
+};
 pub use registry::{
     CacheProviderConfig, CacheProviderEntry, EmbeddingProviderConfig, EmbeddingProviderEntry,
-    LanguageProviderConfig, LanguageProviderEntry, VectorStoreProviderConfig,
-    VectorStoreProviderEntry, list_cache_providers, list_embedding_providers,
-    list_language_providers, list_vector_store_providers, resolve_cache_provider,
-    resolve_embedding_provider, resolve_language_provider, resolve_vector_store_provider,
+    HybridSearchProviderConfig, HybridSearchProviderEntry, LanguageProviderConfig,
+    LanguageProviderEntry, RerankerProviderConfig, RerankerProviderEntry,
+    VectorStoreProviderConfig, VectorStoreProviderEntry, list_cache_providers,
+    list_embedding_providers, list_hybrid_search_providers, list_language_providers,
+    list_reranker_providers, list_vector_store_providers, resolve_cache_provider,
+    resolve_embedding_provider, resolve_hybrid_search_provider, resolve_language_provider,
+    resolve_reranker_provider, resolve_vector_store_provider,
 };
 pub use services::{
     BatchIndexingServiceInterface, ChunkingOrchestratorInterface, ContextServiceInterface,
-    IndexingResult, IndexingServiceInterface, IndexingStats, IndexingStatus,
+    IndexingOptions, IndexingResult, IndexingServiceInterface, IndexingStats, IndexingStatus,
     SearchServiceInterface,
 };
@@ -16,6 +16,7 @@
 //! | [`EmbeddingProvider`] | Text embedding generation services |
 //! | [`VectorStoreProvider`] | Vector storage and similarity search |
 //! | [`HybridSearchProvider`] | Combined semantic and keyword search |
+//! | [`RerankerProvider`] | Second-stage ranking of search results |
 //! | [`LanguageChunkingProvider`] | Language-specific code chunking |
 //! | [`CacheProvider`] | Caching backend services |
 //! | [`CryptoProvider`] | Encryption/decryption services |
@@ -27,6 +28,7 @@ pub use mcb_domain::ports::providers::crypto;
 pub use mcb_domain::ports::providers::embedding;
 pub use mcb_domain::ports::providers::hybrid_search;
 pub use mcb_domain::ports::providers::language_chunking;
+pub use mcb_domain::ports::providers::reranker;
 pub use mcb_domain::ports::providers::vector_store;
 
 // Re-export commonly used traits directly for convenience
@@ -48,6 +50,8 @@ pub use mcb_domain::ports::providers::{
     LanguageChunkingProvider,
     // Config
     ProviderConfigManagerInterface,
+    // Reranker
+    RerankerProvider,
     // Vector Store
     VectorStoreAdmin,
     VectorStoreProvider,
@@ -0,0 +1,172 @@
+//! Hybrid Search Provider Registry
+//!
+//! Auto-registration system for hybrid search providers using linkme distributed slices.
+//! Providers register themselves via `#[linkme::distributed_slice]` and are
+//! discovered at runtime.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use crate::ports::providers::HybridSearchProvider;
+use mcb_domain::value_objects::FusionStrategy;
+
+/// Configuration for hybrid search provider creation
+///
+/// Contains all configuration options that a hybrid search provider might need.
+/// Providers should use what they need and ignore the rest.
+#[derive(Debug, Clone, Default)]
+pub struct HybridSearchProviderConfig {
+    /// Provider name (e.g., "hybrid", "null")
+    pub provider: String,
+    /// Weight of the BM25 score in the fused score (0.0-1.0)
+    pub bm25_weight: Option<f32>,
+    /// Weight of the semantic score in the fused score (0.0-1.0)
+    pub semantic_weight: Option<f32>,
+    /// BM25 k1 parameter (term frequency saturation)
+    pub bm25_k1: Option<f32>,
+    /// BM25 b parameter (document length normalization)
+    pub bm25_b: Option<f32>,
+    /// Default strategy fusing the lexical and semantic rankings
+    pub fusion: Option<FusionStrategy>,
+    /// Rank constant `k` of reciprocal rank fusion
+    pub rrf_k: Option<f32>,
+    /// Directory the lexical indexes are persisted in (in memory when unset)
+    pub index_path: Option<PathBuf>,
+    /// Split identifiers on case changes and underscores
+    pub split_identifiers: Option<bool>,
+    /// Drop language keywords from the lexical index
+    pub keyword_stopwords: Option<bool>,
+    /// Stem the words of comments and queries
+    pub stemming: Option<bool>,
+    /// Length of the character n-grams indexed for partial identifiers
+    pub ngram_size: Option<usize>,
+    /// Additional provider-specific configuration
+    pub extra: HashMap<String, String>,
+}
+
+impl HybridSearchProviderConfig {
+    /// Create a new config with the given provider name
+    pub fn new(provider: impl Into<String>) -> Self {
+        Self {
+            provider: provider.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Set the BM25 and semantic fusion weights
+    pub fn with_weights(mut self, bm25_weight: f32, semantic_weight: f32) -> Self {
+        self.bm25_weight = Some(bm25_weight);
+        self.semantic_weight = Some(semantic_weight);
+        self
+    }
+
+    /// Set the BM25 tuning parameters
+    pub fn with_bm25_params(mut self, k1: f32, b: f32) -> Self {
+        self.bm25_k1 = Some(k1);
+        self.bm25_b = Some(b);
+        self
+    }
+
+    /// Set the default fusion strategy
+    pub fn with_fusion(mut self, fusion: FusionStrategy) -> Self {
+        self.fusion = Some(fusion);
+        self
+    }
+
+    /// Set the rank constant of reciprocal rank fusion
+    pub fn with_rrf_k(mut self, rrf_k: f32) -> Self {
+        self.rrf_k = Some(rrf_k);
+        self
+    }
+
+    /// Persist the lexical indexes under the given directory
+    pub fn with_index_path(mut self, index_path: impl Into<PathBuf>) -> Self {
+        self.index_path = Some(index_path.into());
+        self
+    }
+
+    /// Set the lexical analyzer pipeline
+    pub fn with_analyzer(
+        mut self,
+        split_identifiers: bool,
+        keyword_stopwords: bool,
+        stemming: bool,
+    ) -> Self {
+        self.split_identifiers = Some(split_identifiers);
+        self.keyword_stopwords = Some(keyword_stopwords);
+        self.stemming = Some(stemming);
+        self
+    }
+
+    /// Index character n-grams of the given length
+    pub fn with_ngram_size(mut self, ngram_size: usize) -> Self {
+        self.ngram_size = Some(ngram_size);
+        self
+    }
+
+    /// Add extra configuration
+    pub fn with_extra(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.extra.insert(key.into(), value.into());
+        self
+    }
+}
+
+/// Registry entry for hybrid search providers
+///
+/// Each hybrid search provider implementation registers itself with this entry
+/// using `#[linkme::distributed_slice(HYBRID_SEARCH_PROVIDERS)]`. The entry contains
+/// metadata and a factory function to create provider instances.
+pub struct HybridSearchProviderEntry {
+    /// Unique provider name (e.g., "hybrid", "null")
+    pub name: &'static str,
+    /// Human-readable description
+    pub description: &'static str,
+    /// Factory function to create provider instance
+    pub factory: fn(&HybridSearchProviderConfig) -> Result<Arc<dyn HybridSearchProvider>, String>,
+}
+
+// Auto-collection via linkme distributed slices - providers submit entries at compile time
+#[linkme::distributed_slice]
+pub static HYBRID_SEARCH_PROVIDERS: [HybridSearchProviderEntry] = [..];
+
+/// Resolve hybrid search provider by name from registry
+///
+/// Searches the registry for a provider matching the configured name
+/// and creates an instance using the provider's factory function.
+///
+/// # Arguments
+/// * `config` - Configuration containing provider name and settings
+///
+/// # Returns
+/// * `Ok(Arc<dyn HybridSearchProvider>)` - Created provider instance
+/// * `Err(String)` - Error message if provider not found or creation failed
+pub fn resolve_hybrid_search_provider(
+    config: &HybridSearchProviderConfig,
+) -> Result<Arc<dyn HybridSearchProvider>, String> {
+    let provider_name = &config.provider;
+
+    for entry in HYBRID_SEARCH_PROVIDERS {
+        if entry.name == provider_name {
+            return (entry.factory)(config);
+        }
+    }
+
+    let available: Vec<&str> = HYBRID_SEARCH_PROVIDERS.iter().map(|e| e.name).collect();
+
+    Err(format!(
+        "Unknown hybrid search provider '{}'. Available providers: {:?}",
+        provider_name, available
+    ))
+}
+
+/// List all registered hybrid search providers
+///
+/// Returns a list of (name, description) tuples for all registered
+/// hybrid search providers. Useful for CLI help and admin UI.
+pub fn list_hybrid_search_providers() -> Vec<(&'static str, &'static str)> {
+    HYBRID_SEARCH_PROVIDERS
+        .iter()
+        .map(|e| (e.name, e.description))
+        .collect()
+}
@@ -8,6 +8,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use crate::ports::providers::LanguageChunkingProvider;
+use mcb_domain::value_objects::ChunkSizePolicy;
 
 /// Configuration for language chunking provider creation
 ///
@@ -17,11 +18,11 @@ use crate::ports::providers::LanguageChunkingProvider;
 pub struct LanguageProviderConfig {
     /// Provider name (e.g., "universal", "treesitter", "null")
     pub provider: String,
-    /// Maximum chunk size in characters
+    /// Maximum chunk size in estimated tokens
     pub max_chunk_size: Option<usize>,
-    /// Minimum chunk size in characters
+    /// Chunk size in estimated tokens below which adjacent chunks are merged
     pub min_chunk_size: Option<usize>,
-    /// Chunk overlap in characters
+    /// Overlap between the parts of a split chunk in estimated tokens
     pub overlap: Option<usize>,
     /// Additional provider-specific configuration
     pub extra: HashMap<String, String>,
@@ -54,6 +55,18 @@ impl LanguageProviderConfig {
         self
     }
 
+    /// Chunk size policy described by the size settings, defaults filling the gaps
+    pub fn chunk_size_policy(&self) -> ChunkSizePolicy {
+        ChunkSizePolicy::new(
+            self.max_chunk_size
+                .unwrap_or(ChunkSizePolicy::DEFAULT_MAX_TOKENS),
+            self.min_chunk_size
+                .unwrap_or(ChunkSizePolicy::DEFAULT_MIN_TOKENS),
+            self.overlap
+                .unwrap_or(ChunkSizePolicy::DEFAULT_OVERLAP_TOKENS),
+        )
+    }
+
     /// Add extra configuration
     pub fn with_extra(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
         self.extra.insert(key.into(), value.into());
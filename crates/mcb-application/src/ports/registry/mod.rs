@@ -50,7 +50,9 @@
 
 pub mod cache;
 pub mod embedding;
+pub mod hybrid_search;
 pub mod language;
+pub mod reranker;
 pub mod vector_store;
 
 // Re-export all registry types and functions
@@ -62,10 +64,18 @@ pub use embedding::{
     EMBEDDING_PROVIDERS, EmbeddingProviderConfig, EmbeddingProviderEntry, list_embedding_providers,
     resolve_embedding_provider,
 };
+pub use hybrid_search::{
+    HYBRID_SEARCH_PROVIDERS, HybridSearchProviderConfig, HybridSearchProviderEntry,
+    list_hybrid_search_providers, resolve_hybrid_search_provider,
+};
 pub use language::{
     LANGUAGE_PROVIDERS, LanguageProviderConfig, LanguageProviderEntry, list_language_providers,
     resolve_language_provider,
 };
+pub use reranker::{
+    RERANKER_PROVIDERS, RerankerProviderConfig, RerankerProviderEntry, list_reranker_providers,
+    resolve_reranker_provider,
+};
 pub use vector_store::{
     VECTOR_STORE_PROVIDERS, VectorStoreProviderConfig, VectorStoreProviderEntry,
     list_vector_store_providers, resolve_vector_store_provider,
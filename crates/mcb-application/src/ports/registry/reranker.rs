@@ -0,0 +1,141 @@
+//! Reranker Provider Registry
+//!
+//! Auto-registration system for reranker providers using linkme distributed slices.
+//! Providers register themselves via `#[linkme::distributed_slice]` and are
+//! discovered at runtime.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::ports::providers::RerankerProvider;
+
+/// Configuration for reranker provider creation
+///
+/// Contains all configuration options that a reranker provider might need.
+/// Providers should use what they need and ignore the rest.
+#[derive(Debug, Clone, Default)]
+pub struct RerankerProviderConfig {
+    /// Provider name (e.g., "fastembed", "http", "null")
+    pub provider: String,
+    /// Model name/identifier
+    pub model: Option<String>,
+    /// API key for authentication
+    pub api_key: Option<String>,
+    /// Base URL for the provider API
+    pub base_url: Option<String>,
+    /// Request timeout in seconds
+    pub timeout_secs: Option<u64>,
+    /// Additional provider-specific configuration
+    pub extra: HashMap<String, String>,
+}
+
+impl RerankerProviderConfig {
+    /// Create a new config with the given provider name
+    pub fn new(provider: impl Into<String>) -> Self {
+        Self {
+            provider: provider.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Set the model
+    pub fn with_model(mut self, model: impl Into<String>) -> Self {
+        self.model = Some(model.into());
+        self
+    }
+
+    /// Set the API key
+    pub fn with_api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    /// Set the base URL
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Set the request timeout
+    pub fn with_timeout_secs(mut self, timeout_secs: u64) -> Self {
+        self.timeout_secs = Some(timeout_secs);
+        self
+    }
+
+    /// Add extra configuration
+    pub fn with_extra(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.extra.insert(key.into(), value.into());
+        self
+    }
+}
+
+/// Registry entry for reranker providers
+///
+/// Each reranker provider implementation registers itself with this entry
+/// using `#[linkme::distributed_slice(RERANKER_PROVIDERS)]`. The entry contains
+/// metadata and a factory function to create provider instances.
+pub struct RerankerProviderEntry {
+    /// Unique provider name (e.g., "fastembed", "http", "null")
+    pub name: &'static str,
+    /// Human-readable description
+    pub description: &'static str,
+    /// Factory function to create provider instance
+    pub factory: fn(&RerankerProviderConfig) -> Result<Arc<dyn RerankerProvider>, String>,
+}
+
+// Auto-collection via linkme distributed slices - providers submit entries at compile time
+#[linkme::distributed_slice]
+pub static RERANKER_PROVIDERS: [RerankerProviderEntry] = [..];
+
+/// Resolve reranker provider by name from registry
+///
+/// Searches the registry for a provider matching the configured name
+/// and creates an instance using the provider's factory function.
+///
+/// # Arguments
+/// * `config` - Configuration containing provider name and settings
+///
+/// # Returns
+/// * `Ok(Arc<dyn RerankerProvider>)` - Created provider instance
+/// * `Err(String)` - Error message if provider not found or creation failed
+///
+/// # Example
+///
+/// ```ignore
+/// let config = RerankerProviderConfig::new("http")
+///     .with_base_url("https://api.voyageai.com/v1")
+///     .with_model("rerank-2");
+/// let provider = resolve_reranker_provider(&config)?;
+/// ```
+pub fn resolve_reranker_provider(
+    config: &RerankerProviderConfig,
+) -> Result<Arc<dyn RerankerProvider>, String> {
+    let provider_name = &config.provider;
+
+    for entry in RERANKER_PROVIDERS {
+        if entry.name == provider_name {
+            return (entry.factory)(config);
+        }
+    }
+
+    let available: Vec<&str> = RERANKER_PROVIDERS.iter().map(|e| e.name).collect();
+
+    Err(format!(
+        "Unknown reranker provider '{}'. Available providers: {:?}",
+        provider_name, available
+    ))
+}
+
+/// List all registered reranker providers
+///
+/// Returns a list of (name, description) tuples for all registered
+/// reranker providers. Useful for CLI help and admin UI.
+///
+/// # Returns
+/// Vector of (name, description) tuples for all registered providers
+pub fn list_reranker_providers() -> Vec<(&'static str, &'static str)> {
+    RERANKER_PROVIDERS
+        .iter()
+        .map(|e| (e.name, e.description))
+        .collect()
+}
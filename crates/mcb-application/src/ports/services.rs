@@ -7,9 +7,13 @@
 use async_trait::async_trait;
 use mcb_domain::entities::CodeChunk;
 use mcb_domain::error::Result;
+use mcb_domain::ports::providers::HybridSearchResult;
 use mcb_domain::value_objects::config::SyncBatch;
-use mcb_domain::value_objects::{Embedding, SearchResult};
-use std::path::Path;
+use mcb_domain::value_objects::{
+    ChunkSizePolicy, Embedding, EmbeddingBatchLimits, SearchQuery, SearchResult,
+};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
 
 // ============================================================================
 // Context Service Interface
@@ -23,20 +27,28 @@ pub trait ContextServiceInterface: Send + Sync {
     /// Initialize the service for a collection
     async fn initialize(&self, collection: &str) -> Result<()>;
 
+    /// Check whether the vector store holds `collection`
+    async fn collection_exists(&self, collection: &str) -> Result<bool>;
+
     /// Store code chunks in the repository
     async fn store_chunks(&self, collection: &str, chunks: &[CodeChunk]) -> Result<()>;
 
     /// Search for code similar to the query
+    ///
+    /// The query's filter is evaluated by the vector store and its score
+    /// threshold is applied to the semantic score.
     async fn search_similar(
         &self,
         collection: &str,
-        query: &str,
-        limit: usize,
+        query: &SearchQuery,
     ) -> Result<Vec<SearchResult>>;
 
     /// Get embedding for text
     async fn embed_text(&self, text: &str) -> Result<Embedding>;
 
+    /// Delete every stored chunk extracted from one of `file_paths`
+    async fn delete_file_chunks(&self, collection: &str, file_paths: &[String]) -> Result<()>;
+
     /// Clear/delete a collection
     async fn clear_collection(&self, collection: &str) -> Result<()>;
 
@@ -45,6 +57,43 @@ pub trait ContextServiceInterface: Send + Sync {
 
     /// Get embedding dimensions
     fn embedding_dimensions(&self) -> usize;
+
+    /// Vector space of the embeddings: provider, model and dimensions
+    ///
+    /// Embeddings of different spaces cannot be compared, so indexed state
+    /// recorded under one space is void under another.
+    fn embedding_space(&self) -> String;
+
+    /// Largest batch of chunks worth passing to one `store_chunks` call
+    fn embedding_batch_limits(&self) -> EmbeddingBatchLimits {
+        EmbeddingBatchLimits::default()
+    }
+
+    /// Fit a chunk size policy to the embedding model's input limit and tokenizer
+    fn fit_chunk_size_policy(&self, policy: ChunkSizePolicy) -> ChunkSizePolicy {
+        policy
+    }
+
+    /// Hit and miss counts of the embedding cache
+    fn embedding_cache_stats(&self) -> EmbeddingCacheStats {
+        EmbeddingCacheStats::default()
+    }
+}
+
+/// Embedding cache statistics
+///
+/// Every text to embed is either a hit, served from the cache, or a miss,
+/// embedded by the provider.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EmbeddingCacheStats {
+    /// Whether embeddings are cached
+    pub enabled: bool,
+    /// Texts whose embedding was found in the cache
+    pub hits: u64,
+    /// Texts missing from the cache, embedded by the provider
+    pub misses: u64,
+    /// Share of texts served from the cache (0.0 to 1.0)
+    pub hit_rate: f64,
 }
 
 // ============================================================================
@@ -57,12 +106,15 @@ pub trait ContextServiceInterface: Send + Sync {
 #[async_trait]
 pub trait SearchServiceInterface: Send + Sync {
     /// Search for code similar to the query
+    ///
+    /// Results carry the fused hybrid score along with its BM25 and
+    /// semantic components. Only chunks matching the query's filter are
+    /// returned, and the score threshold applies to the fused score.
     async fn search(
         &self,
         collection: &str,
-        query: &str,
-        limit: usize,
-    ) -> Result<Vec<SearchResult>>;
+        query: &SearchQuery,
+    ) -> Result<Vec<HybridSearchResult>>;
 }
 
 // ============================================================================
@@ -75,15 +127,127 @@ pub trait SearchServiceInterface: Send + Sync {
 #[async_trait]
 pub trait IndexingServiceInterface: Send + Sync {
     /// Index a codebase at the given path
-    async fn index_codebase(&self, path: &Path, collection: &str) -> Result<IndexingResult>;
+    ///
+    /// `options` control which files discovery picks up.
+    async fn index_codebase(
+        &self,
+        path: &Path,
+        collection: &str,
+        options: &IndexingOptions,
+    ) -> Result<IndexingResult>;
+
+    /// Re-index the given files (or directories) of an indexed codebase
+    ///
+    /// Changed files are re-embedded, and files that were deleted or are no
+    /// longer indexable have their chunks removed. The rest of the codebase
+    /// is left untouched. Until the codebase has been fully indexed once,
+    /// nothing is indexed: an interrupted first run is resumed by
+    /// `index_codebase`, not by syncing single files.
+    async fn reindex_files(
+        &self,
+        path: &Path,
+        collection: &str,
+        files: &[PathBuf],
+        options: &IndexingOptions,
+    ) -> Result<IndexingResult>;
 
-    /// Get the current indexing status
+    /// Start indexing a codebase in the background
+    ///
+    /// Returns the id of the indexing operation as soon as it is registered;
+    /// progress is reported through [`get_collection_status`] and indexing
+    /// domain events. Fails if the collection is already being indexed.
+    ///
+    /// [`get_collection_status`]: IndexingServiceInterface::get_collection_status
+    async fn start_indexing(
+        &self,
+        path: &Path,
+        collection: &str,
+        options: &IndexingOptions,
+    ) -> Result<String>;
+
+    /// Cancel a running indexing operation
+    ///
+    /// The operation stops before its next file; files already committed are
+    /// checkpointed, so indexing the collection again resumes where it
+    /// stopped. Fails if no running operation has the given id.
+    async fn cancel_indexing(&self, operation_id: &str) -> Result<()>;
+
+    /// Get the status of the running, or most recent, indexing operation
     fn get_status(&self) -> IndexingStatus;
 
+    /// Get the status of the running, or most recent, indexing operation of a collection
+    fn get_collection_status(&self, collection: &str) -> IndexingStatus;
+
     /// Clear all indexed data from a collection
     async fn clear_collection(&self, collection: &str) -> Result<()>;
 }
 
+/// Options controlling file discovery for an indexing operation
+///
+/// The default indexes every file the chunking provider supports (by
+/// extension, file name or `#!` line), without a size cap and without
+/// following symbolic links. Files excluded by `.gitignore`, `.ignore` or
+/// `.mcp-context-ignore` are always skipped, as are binary and minified files.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IndexingOptions {
+    /// Only index files with these extensions (whatever the chunker supports when `None`)
+    pub extensions: Option<Vec<String>>,
+    /// Additional ignore patterns, using `.gitignore` syntax relative to the root
+    pub ignore_patterns: Vec<String>,
+    /// Skip files larger than this many bytes
+    pub max_file_size: Option<u64>,
+    /// Follow symbolic links (loops are detected and reported)
+    pub follow_symlinks: bool,
+}
+
+impl IndexingOptions {
+    /// Restrict indexing to the given extensions (`rs`, `.rs` and `*.rs` are equivalent)
+    ///
+    /// A list without any non-blank extension adds no restriction.
+    pub fn with_extensions<I, S>(mut self, extensions: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let extensions: Vec<String> = extensions
+            .into_iter()
+            .map(|ext| {
+                ext.as_ref()
+                    .trim()
+                    .trim_start_matches('*')
+                    .trim_start_matches('.')
+                    .to_lowercase()
+            })
+            .filter(|ext| !ext.is_empty())
+            .collect();
+        self.extensions = (!extensions.is_empty()).then_some(extensions);
+        self
+    }
+
+    /// Add ignore patterns
+    pub fn with_ignore_patterns<I, S>(mut self, patterns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.ignore_patterns
+            .extend(patterns.into_iter().map(Into::into));
+        self
+    }
+
+    /// Set the maximum file size in bytes
+    pub fn with_max_file_size(mut self, max_file_size: u64) -> Self {
+        self.max_file_size = Some(max_file_size);
+        self
+    }
+
+    /// Set whether symbolic links are followed
+    pub fn with_follow_symlinks(mut self, follow_symlinks: bool) -> Self {
+        self.follow_symlinks = follow_symlinks;
+        self
+    }
+}
+
 /// Result of an indexing operation
 #[derive(Debug, Clone)]
 pub struct IndexingResult {
@@ -110,6 +274,16 @@ pub struct IndexingStatus {
     pub total_files: usize,
     /// Files processed so far
     pub processed_files: usize,
+    /// Id of the indexing operation
+    pub operation_id: Option<String>,
+    /// Collection being indexed
+    pub collection: Option<String>,
+    /// Estimated seconds until the operation completes
+    pub eta_secs: Option<u64>,
+    /// Whether the operation was cancelled before completing
+    pub cancelled: bool,
+    /// Errors encountered so far (non-fatal ones included)
+    pub errors: Vec<String>,
 }
 
 // ============================================================================
@@ -1,17 +1,35 @@
 //! Context Service Use Case
 //!
 //! Application service for code intelligence and semantic operations.
-//! Orchestrates embeddings, vector storage, and caching for semantic code understanding.
+//! Orchestrates embeddings, vector storage, lexical indexing, and caching for
+//! semantic code understanding.
+//!
+//! With an embedding cache configured, embeddings are cached by content: a
+//! text already embedded by the same provider, model and dimensions is
+//! served from the cache instead of being sent to the provider again.
+//!
+//! Chunks are embedded with a header of their file path, language, enclosing
+//! scope, imports and doc comment (see [`ChunkEnrichment`]), while the vector
+//! store keeps their original content for display.
+//!
+//! With [`QueryExpansion`] enabled, a search embeds the rewrites of its query
+//! along with it, runs one vector search per query in parallel and merges
+//! the rankings, keeping each chunk once with its best score.
 
 use crate::domain_services::search::ContextServiceInterface;
 use crate::ports::providers::cache::CacheEntryConfig;
-use crate::ports::providers::{EmbeddingProvider, VectorStoreProvider};
+use crate::ports::providers::{EmbeddingProvider, HybridSearchProvider, VectorStoreProvider};
+use crate::ports::services::EmbeddingCacheStats;
 use mcb_domain::entities::CodeChunk;
-use mcb_domain::error::Result;
-use mcb_domain::value_objects::{Embedding, SearchResult};
+use mcb_domain::error::{Error, Result};
+use mcb_domain::value_objects::{
+    ChunkSizePolicy, CodeSymbol, Embedding, EmbeddingBatchLimits, QueryExpansion, SearchQuery,
+    SearchResult,
+};
 use serde_json::json;
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 /// Cache key helpers for collection management
 mod cache_keys {
@@ -24,46 +42,306 @@ mod cache_keys {
     pub fn collection_meta(name: &str) -> String {
         format!("collection:{name}:meta")
     }
+
+    /// Content-addressed key of the embedding of `text` in the vector space
+    /// of a provider, model and dimensions
+    pub fn embedding(provider: &str, model: &str, dimensions: usize, text: &str) -> String {
+        use sha2::{Digest, Sha256};
+
+        let mut hasher = Sha256::new();
+        for part in [provider, model, &dimensions.to_string(), text] {
+            // Length prefixes keep the parts from running into each other
+            hasher.update(part.len().to_le_bytes());
+            hasher.update(part.as_bytes());
+        }
+        format!("embedding:{:x}", hasher.finalize())
+    }
 }
 
 /// Build metadata map from a code chunk
+///
+/// Keys double as the fields a `MetadataFilter` can constrain.
 fn build_chunk_metadata(chunk: &CodeChunk) -> HashMap<String, serde_json::Value> {
-    HashMap::from([
+    let mut metadata = HashMap::from([
         ("id".to_string(), json!(chunk.id)),
         ("file_path".to_string(), json!(chunk.file_path)),
         ("content".to_string(), json!(chunk.content)),
         ("start_line".to_string(), json!(chunk.start_line)),
         ("end_line".to_string(), json!(chunk.end_line)),
         ("language".to_string(), json!(chunk.language)),
-    ])
+    ]);
+    if let Some(node_type) = chunk.metadata.get("node_type").and_then(|v| v.as_str()) {
+        metadata.insert("node_type".to_string(), json!(node_type));
+    }
+    if let Some(symbol) = chunk.metadata.get(CodeSymbol::METADATA_KEY) {
+        metadata.insert(CodeSymbol::METADATA_KEY.to_string(), symbol.clone());
+    }
+    metadata
+}
+
+/// Merge the rankings of several queries, keeping each chunk once with its best score
+fn merge_rankings(rankings: Vec<Vec<SearchResult>>) -> Vec<SearchResult> {
+    let mut positions: HashMap<String, usize> = HashMap::new();
+    let mut merged: Vec<SearchResult> = Vec::new();
+    for result in rankings.into_iter().flatten() {
+        match positions.get(&result.id) {
+            Some(&position) => {
+                if result.score > merged[position].score {
+                    merged[position] = result;
+                }
+            }
+            None => {
+                positions.insert(result.id.clone(), merged.len());
+                merged.push(result);
+            }
+        }
+    }
+    merged.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    merged
+}
+
+/// Context embedded along with each chunk
+///
+/// A method named `run` inside `impl Server` of `transport/http.rs` reads
+/// like any other `run` on its own; the header built from these options
+/// tells them apart. Only the embedded text carries the header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChunkEnrichment {
+    /// Include the file path and language
+    pub file_context: bool,
+    /// Include the enclosing scope and the signature of split chunks
+    pub scope: bool,
+    /// Include the file imports the chunk refers to
+    pub imports: bool,
+    /// Include the doc comment of the symbol when the content lacks it
+    pub doc_comments: bool,
+    /// Maximum imports included
+    pub max_imports: usize,
+}
+
+impl Default for ChunkEnrichment {
+    fn default() -> Self {
+        Self {
+            file_context: true,
+            scope: true,
+            imports: true,
+            doc_comments: true,
+            max_imports: 8,
+        }
+    }
+}
+
+impl ChunkEnrichment {
+    /// Embed the chunk content alone
+    pub fn disabled() -> Self {
+        Self {
+            file_context: false,
+            scope: false,
+            imports: false,
+            doc_comments: false,
+            max_imports: 0,
+        }
+    }
+
+    /// Text embedded for `chunk`: the context header, a blank line and the content
+    pub fn embedding_text(&self, chunk: &CodeChunk) -> String {
+        let mut header = Vec::new();
+        if self.file_context {
+            header.push(format!("File: {}", chunk.file_path));
+            header.push(format!("Language: {}", chunk.language));
+        }
+
+        let symbol = chunk.symbol();
+        let flat_content = || {
+            chunk
+                .content
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        if self.scope
+            && let Some(symbol) = &symbol
+        {
+            if let Some(parent) = &symbol.parent {
+                header.push(format!("Scope: {parent}"));
+            }
+            // Parts of a split declaration lose its header
+            if let Some(signature) = &symbol.signature
+                && !flat_content().contains(signature.as_str())
+            {
+                header.push(format!("Definition: {signature}"));
+            }
+        }
+
+        if self.imports && self.max_imports > 0 {
+            let imports = chunk.imports();
+            if !imports.is_empty() {
+                let shown: Vec<&str> = imports
+                    .iter()
+                    .take(self.max_imports)
+                    .map(|import| import.trim_end_matches(';'))
+                    .collect();
+                header.push(format!("Imports: {}", shown.join("; ")));
+            }
+        }
+
+        if self.doc_comments
+            && let Some(doc) = symbol.as_ref().and_then(|symbol| symbol.doc.as_deref())
+            && let Some(first_line) = doc.lines().next()
+            && !chunk.content.contains(first_line)
+        {
+            header.push(format!(
+                "Doc: {}",
+                doc.split_whitespace().collect::<Vec<_>>().join(" ")
+            ));
+        }
+
+        if header.is_empty() {
+            return chunk.content.clone();
+        }
+        format!("{}\n\n{}", header.join("\n"), chunk.content)
+    }
 }
 
 /// Context service implementation - manages embeddings and vector storage
+///
+/// Every chunk stored in the vector store is also fed to the hybrid search
+/// provider, and removed from it along with its file, so the BM25 index
+/// holds the same chunks as the vector store.
 pub struct ContextServiceImpl {
     cache: Arc<dyn crate::ports::providers::cache::CacheProvider>,
     embedding_provider: Arc<dyn EmbeddingProvider>,
     vector_store_provider: Arc<dyn VectorStoreProvider>,
+    hybrid_search: Arc<dyn HybridSearchProvider>,
+    /// Entry configuration of cached embeddings, `None` when not cached
+    embedding_cache: Option<CacheEntryConfig>,
+    enrichment: ChunkEnrichment,
+    expansion: QueryExpansion,
+    embedding_cache_hits: AtomicU64,
+    embedding_cache_misses: AtomicU64,
 }
 
 impl ContextServiceImpl {
     /// Create new context service with injected dependencies
+    ///
+    /// Embeddings are not cached until enabled with
+    /// [`with_embedding_cache`](Self::with_embedding_cache).
     pub fn new(
         cache: Arc<dyn crate::ports::providers::cache::CacheProvider>,
         embedding_provider: Arc<dyn EmbeddingProvider>,
         vector_store_provider: Arc<dyn VectorStoreProvider>,
+        hybrid_search: Arc<dyn HybridSearchProvider>,
     ) -> Self {
         Self {
             cache,
             embedding_provider,
             vector_store_provider,
+            hybrid_search,
+            embedding_cache: None,
+            enrichment: ChunkEnrichment::default(),
+            expansion: QueryExpansion::disabled(),
+            embedding_cache_hits: AtomicU64::new(0),
+            embedding_cache_misses: AtomicU64::new(0),
         }
     }
 
-    /// Check if collection exists in vector store
-    async fn collection_exists(&self, collection: &str) -> Result<bool> {
-        self.vector_store_provider
-            .collection_exists(collection)
-            .await
+    /// Cache embeddings with the given entry configuration, or not at all
+    pub fn with_embedding_cache(mut self, entry: Option<CacheEntryConfig>) -> Self {
+        self.embedding_cache = entry;
+        self
+    }
+
+    /// Configure the context embedded along with each chunk
+    pub fn with_enrichment(mut self, enrichment: ChunkEnrichment) -> Self {
+        self.enrichment = enrichment;
+        self
+    }
+
+    /// Search the embedding rewrites of each query along with it
+    pub fn with_query_expansion(mut self, expansion: QueryExpansion) -> Self {
+        self.expansion = expansion;
+        self
+    }
+
+    /// Embed `texts`, serving the ones embedded before from the cache
+    ///
+    /// Only texts missing from the cache reach the provider, each distinct
+    /// text once. The cache only saves work, so its failures count as misses.
+    async fn embed_texts(&self, texts: &[String]) -> Result<Vec<Embedding>> {
+        let Some(entry) = &self.embedding_cache else {
+            return self.embedding_provider.embed_batch(texts).await;
+        };
+
+        let provider = &self.embedding_provider;
+        let keys: Vec<String> = texts
+            .iter()
+            .map(|text| {
+                cache_keys::embedding(
+                    provider.provider_name(),
+                    provider.model_name(),
+                    provider.dimensions(),
+                    text,
+                )
+            })
+            .collect();
+        let cached: Vec<Option<Embedding>> =
+            futures::future::join_all(keys.iter().map(|key| self.cache.get_json(key)))
+                .await
+                .into_iter()
+                .map(|json| {
+                    json.ok()
+                        .flatten()
+                        .and_then(|json| serde_json::from_str(&json).ok())
+                })
+                .collect();
+
+        // Position in `missing_texts` of every distinct missing text, by key
+        let mut missing: HashMap<&str, usize> = HashMap::new();
+        let mut missing_texts = Vec::new();
+        for ((key, text), embedding) in keys.iter().zip(texts).zip(&cached) {
+            if embedding.is_none() && !missing.contains_key(key.as_str()) {
+                missing.insert(key, missing_texts.len());
+                missing_texts.push(text.clone());
+            }
+        }
+        // Repeats of a missing text are embedded with it, not served from the cache
+        let hits = cached
+            .iter()
+            .filter(|embedding| embedding.is_some())
+            .count() as u64;
+        self.embedding_cache_hits.fetch_add(hits, Ordering::Relaxed);
+        self.embedding_cache_misses
+            .fetch_add(texts.len() as u64 - hits, Ordering::Relaxed);
+
+        let fresh = if missing_texts.is_empty() {
+            Vec::new()
+        } else {
+            self.embedding_provider.embed_batch(&missing_texts).await?
+        };
+        if fresh.len() != missing_texts.len() {
+            return Err(Error::embedding(format!(
+                "Embedding provider returned {} embeddings for {} texts",
+                fresh.len(),
+                missing_texts.len()
+            )));
+        }
+        for (key, &position) in &missing {
+            if let Ok(json) = serde_json::to_string(&fresh[position]) {
+                let _ = self.cache.set_json(key, &json, entry.clone()).await;
+            }
+        }
+
+        Ok(cached
+            .into_iter()
+            .zip(&keys)
+            .map(|(embedding, key)| {
+                embedding.unwrap_or_else(|| fresh[missing[key.as_str()]].clone())
+            })
+            .collect())
     }
 
     /// Set a cache value with default config
@@ -90,19 +368,32 @@ impl ContextServiceInterface for ContextServiceImpl {
             .await
     }
 
+    async fn collection_exists(&self, collection: &str) -> Result<bool> {
+        self.vector_store_provider
+            .collection_exists(collection)
+            .await
+    }
+
     async fn store_chunks(&self, collection: &str, chunks: &[CodeChunk]) -> Result<()> {
-        // Generate embeddings for each chunk
-        let texts: Vec<String> = chunks.iter().map(|c| c.content.clone()).collect();
-        let embeddings = self.embedding_provider.embed_batch(&texts).await?;
+        // Embed each chunk with its context, storing the original content
+        let texts: Vec<String> = chunks
+            .iter()
+            .map(|c| self.enrichment.embedding_text(c))
+            .collect();
+        let embeddings = self.embed_texts(&texts).await?;
 
         // Build metadata for each chunk
         let metadata: Vec<_> = chunks.iter().map(build_chunk_metadata).collect();
 
-        // Insert into vector store
+        // Store under the chunk ids so re-indexed chunks replace their old entries
+        let ids: Vec<String> = chunks.iter().map(|c| c.id.clone()).collect();
         self.vector_store_provider
-            .insert_vectors(collection, &embeddings, metadata)
+            .upsert_vectors(collection, &ids, &embeddings, metadata)
             .await?;
 
+        // Feed the lexical index used for hybrid ranking
+        self.hybrid_search.index_chunks(collection, chunks).await?;
+
         // Update collection metadata in cache
         self.cache_set(
             &cache_keys::collection_meta(collection),
@@ -114,17 +405,48 @@ impl ContextServiceInterface for ContextServiceImpl {
     async fn search_similar(
         &self,
         collection: &str,
-        query: &str,
-        limit: usize,
+        query: &SearchQuery,
     ) -> Result<Vec<SearchResult>> {
-        let query_embedding = self.embedding_provider.embed(query).await?;
-        self.vector_store_provider
-            .search_similar(collection, &query_embedding.vector, limit, None)
-            .await
+        let expanded = self.expansion.expand(&query.text);
+        let embeddings = self.embed_texts(&expanded.embedding_queries).await?;
+        let searches = embeddings.iter().map(|embedding| {
+            self.vector_store_provider.search_similar(
+                collection,
+                &embedding.vector,
+                query.limit,
+                query.filter.as_ref(),
+            )
+        });
+        let rankings = futures::future::try_join_all(searches).await?;
+
+        let mut results = merge_rankings(rankings);
+        results.retain(|r| query.accepts_score(r.score));
+        results.truncate(query.limit);
+        Ok(results)
     }
 
     async fn embed_text(&self, text: &str) -> Result<Embedding> {
-        self.embedding_provider.embed(text).await
+        self.embed_texts(&[text.to_string()])
+            .await?
+            .pop()
+            .ok_or_else(|| Error::embedding("No embedding returned"))
+    }
+
+    async fn delete_file_chunks(&self, collection: &str, file_paths: &[String]) -> Result<()> {
+        if file_paths.is_empty() || !self.collection_exists(collection).await? {
+            return Ok(());
+        }
+
+        for file_path in file_paths {
+            self.vector_store_provider
+                .delete_by_file_path(collection, file_path)
+                .await?;
+        }
+
+        // Keep the lexical index in step with the vector store
+        self.hybrid_search
+            .remove_file_chunks(collection, file_paths)
+            .await
     }
 
     async fn clear_collection(&self, collection: &str) -> Result<()> {
@@ -135,6 +457,9 @@ impl ContextServiceInterface for ContextServiceImpl {
                 .await?;
         }
 
+        // Drop the lexical index for the collection
+        self.hybrid_search.clear_collection(collection).await?;
+
         // Clear cache metadata
         self.cache
             .delete(&cache_keys::collection(collection))
@@ -152,4 +477,41 @@ impl ContextServiceInterface for ContextServiceImpl {
     fn embedding_dimensions(&self) -> usize {
         self.embedding_provider.dimensions()
     }
+
+    fn embedding_space(&self) -> String {
+        let provider = &self.embedding_provider;
+        format!(
+            "{}/{}/{}",
+            provider.provider_name(),
+            provider.model_name(),
+            provider.dimensions()
+        )
+    }
+
+    fn embedding_batch_limits(&self) -> EmbeddingBatchLimits {
+        self.embedding_provider.batch_limits()
+    }
+
+    fn fit_chunk_size_policy(&self, policy: ChunkSizePolicy) -> ChunkSizePolicy {
+        policy.fit_to_model(
+            self.embedding_provider.max_input_tokens(),
+            self.embedding_provider.chars_per_token(),
+        )
+    }
+
+    fn embedding_cache_stats(&self) -> EmbeddingCacheStats {
+        let hits = self.embedding_cache_hits.load(Ordering::Relaxed);
+        let misses = self.embedding_cache_misses.load(Ordering::Relaxed);
+        let total = hits + misses;
+        EmbeddingCacheStats {
+            enabled: self.embedding_cache.is_some(),
+            hits,
+            misses,
+            hit_rate: if total > 0 {
+                hits as f64 / total as f64
+            } else {
+                0.0
+            },
+        }
+    }
 }
@@ -0,0 +1,158 @@
+//! Ignore Rules of a Codebase
+//!
+//! The rules file discovery applies to decide which paths of a codebase are
+//! never indexed: always-skipped directories, the caller's ignore patterns,
+//! `.gitignore`, `.ignore` and `.mcp-context-ignore` files at any depth, and
+//! the global git excludes. Discovery walks the tree and lets the walker read
+//! the ignore files; [`IgnoreRules::is_ignored`] answers for a single path,
+//! for callers that see paths one at a time such as file watchers.
+
+use crate::domain_services::search::IndexingOptions;
+use ignore::Match;
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
+
+/// Directories to skip during indexing
+pub(crate) const SKIP_DIRS: &[&str] = &[".git", "node_modules", "target", "__pycache__"];
+
+/// Project-level ignore file, using `.gitignore` syntax
+pub(crate) const PROJECT_IGNORE_FILE: &str = ".mcp-context-ignore";
+
+/// Ignore files of a directory, highest precedence first
+const IGNORE_FILES: &[&str] = &[PROJECT_IGNORE_FILE, ".ignore", ".gitignore"];
+
+/// Ignore rules of the codebase at a root
+///
+/// Ignore files are read when first needed and cached by directory; call
+/// [`IgnoreRules::reload`] once one of them changes.
+pub struct IgnoreRules {
+    root: PathBuf,
+    /// Caller-supplied patterns, relative to the root
+    patterns: Gitignore,
+    /// Global git excludes (`core.excludesFile`)
+    global: Gitignore,
+    /// Parsed ignore files by directory, highest precedence first
+    directories: Mutex<HashMap<PathBuf, Vec<Gitignore>>>,
+}
+
+impl IgnoreRules {
+    /// Rules of the codebase at `root` indexed with `options`
+    ///
+    /// Invalid patterns are skipped.
+    pub fn new(root: &Path, options: &IndexingOptions) -> Self {
+        let (rules, _errors) = Self::build(root, options);
+        rules
+    }
+
+    /// Build the rules, returning the errors of invalid patterns
+    pub(crate) fn build(root: &Path, options: &IndexingOptions) -> (Self, Vec<ignore::Error>) {
+        let mut errors = Vec::new();
+        let mut builder = GitignoreBuilder::new(root);
+        for pattern in &options.ignore_patterns {
+            if let Err(e) = builder.add_line(None, pattern) {
+                errors.push(e);
+            }
+        }
+        let patterns = builder.build().unwrap_or_else(|e| {
+            errors.push(e);
+            Gitignore::empty()
+        });
+        let (global, _) = GitignoreBuilder::new(root).build_global();
+
+        let rules = Self {
+            root: root.to_path_buf(),
+            patterns,
+            global,
+            directories: Mutex::new(HashMap::new()),
+        };
+        (rules, errors)
+    }
+
+    /// Check whether `path` is excluded regardless of ignore files
+    ///
+    /// Covers the always-skipped directories and the caller's patterns; the
+    /// walker of file discovery reads the ignore files itself.
+    pub(crate) fn excludes(&self, path: &Path, is_dir: bool) -> bool {
+        let Ok(relative) = path.strip_prefix(&self.root) else {
+            return false;
+        };
+        let mut directories: Vec<Component> = relative.components().collect();
+        if !is_dir {
+            directories.pop();
+        }
+        let skipped = directories.iter().any(|component| {
+            matches!(component, Component::Normal(name) if SKIP_DIRS.iter().any(|dir| name == dir))
+        });
+        skipped
+            || self
+                .patterns
+                .matched_path_or_any_parents(path, is_dir)
+                .is_ignore()
+    }
+
+    /// Check whether file discovery would skip `path`
+    ///
+    /// Paths outside the root are never ignored.
+    pub fn is_ignored(&self, path: &Path, is_dir: bool) -> bool {
+        if !path.starts_with(&self.root) || path == self.root {
+            return false;
+        }
+        if self.excludes(path, is_dir) {
+            return true;
+        }
+
+        // The ignore files of deeper directories take precedence
+        let mut directory = path.parent();
+        while let Some(current) = directory.filter(|dir| dir.starts_with(&self.root)) {
+            for rules in self.directory_rules(current) {
+                match rules.matched_path_or_any_parents(path, is_dir) {
+                    Match::Ignore(_) => return true,
+                    Match::Whitelist(_) => return false,
+                    Match::None => {}
+                }
+            }
+            directory = current.parent();
+        }
+        self.global
+            .matched_path_or_any_parents(path, is_dir)
+            .is_ignore()
+    }
+
+    /// Check whether `path` is an ignore file, whose changes call for a reload
+    pub fn is_ignore_file(path: &Path) -> bool {
+        path.file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| IGNORE_FILES.contains(&name))
+    }
+
+    /// Forget the ignore files read so far
+    pub fn reload(&self) {
+        self.lock_directories().clear();
+    }
+
+    /// Parsed ignore files of `directory`, reading them on first use
+    fn directory_rules(&self, directory: &Path) -> Vec<Gitignore> {
+        if let Some(rules) = self.lock_directories().get(directory) {
+            return rules.clone();
+        }
+        // Unreadable ignore files are skipped, as the walker does
+        let rules: Vec<Gitignore> = IGNORE_FILES
+            .iter()
+            .map(|name| directory.join(name))
+            .filter(|file| file.is_file())
+            .map(|file| Gitignore::new(file).0)
+            .filter(|rules| !rules.is_empty())
+            .collect();
+        self.lock_directories()
+            .insert(directory.to_path_buf(), rules.clone());
+        rules
+    }
+
+    fn lock_directories(&self) -> std::sync::MutexGuard<'_, HashMap<PathBuf, Vec<Gitignore>>> {
+        self.directories
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
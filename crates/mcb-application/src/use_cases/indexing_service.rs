@@ -2,21 +2,94 @@
 //!
 //! Application service for code indexing and ingestion operations.
 //! Orchestrates file discovery, chunking, and storage of code embeddings.
+//! Re-indexing is incremental: only files whose content changed since the
+//! last snapshot of the collection are re-embedded. Snapshots are kept per
+//! embedding space and ignored once their collection is gone, so a new
+//! embedder or a lost collection re-embeds every file.
+//!
+//! Every run is tracked as an indexing operation: its progress is exposed
+//! per collection through the service status, mirrored to the indexing
+//! operations tracker and published as indexing domain events. Running
+//! operations can be cancelled, and the files they committed are
+//! checkpointed in the state store so the next run resumes after them.
+//!
+//! Files are read and chunked concurrently on the blocking pool into chunks
+//! fitting the embedding model's input, and their chunks are packed into
+//! batches within the embedding provider's limits, several of which are
+//! stored at the same time.
 
+use super::ignore_rules::{IgnoreRules, PROJECT_IGNORE_FILE};
 use crate::domain_services::search::{
-    ContextServiceInterface, IndexingResult, IndexingServiceInterface,
+    ContextServiceInterface, IndexingOptions, IndexingResult, IndexingServiceInterface,
+    IndexingStatus,
 };
+use crate::ports::admin::IndexingOperationsInterface;
+use crate::ports::infrastructure::{EventBusProvider, SnapshotProvider, StateStoreProvider};
 use crate::ports::providers::LanguageChunkingProvider;
+use futures::{StreamExt, stream};
+use ignore::WalkBuilder;
 use mcb_domain::entities::CodeChunk;
-use mcb_domain::error::Result;
-use std::path::Path;
-use std::sync::Arc;
+use mcb_domain::entities::codebase::CodebaseSnapshot;
+use mcb_domain::error::{Error, Result};
+use mcb_domain::events::DomainEvent;
+use mcb_domain::value_objects::{ChunkSizePolicy, EmbeddingBatchLimits};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Instant;
+use tokio::task::JoinSet;
+
+/// Generated files that are never worth indexing
+const GENERATED_FILES: &[&str] = &[
+    "Cargo.lock",
+    "package-lock.json",
+    "npm-shrinkwrap.json",
+    "yarn.lock",
+    "pnpm-lock.yaml",
+    "bun.lockb",
+    "composer.lock",
+    "Gemfile.lock",
+    "poetry.lock",
+    "Pipfile.lock",
+    "go.sum",
+];
+
+/// Number of leading bytes inspected when detecting binary files
+const BINARY_SNIFF_LEN: usize = 8192;
+
+/// Files shorter than this are never treated as minified
+const MINIFIED_MIN_LEN: usize = 1024;
+
+/// Average line length above which a file is treated as minified
+const MINIFIED_AVG_LINE_LEN: usize = 200;
+
+/// Maximum number of bytes read when probing a file for a `#!` line
+const SHEBANG_MAX_LEN: u64 = 256;
 
-/// Directories to skip during indexing
-const SKIP_DIRS: &[&str] = &[".git", "node_modules", "target", "__pycache__"];
+/// State store key prefix of indexing checkpoints, followed by the collection
+const CHECKPOINT_KEY_PREFIX: &str = "indexing/checkpoint";
 
-/// Supported file extensions for indexing
-const SUPPORTED_EXTENSIONS: &[&str] = &["rs", "py", "js", "ts", "java", "cpp", "c", "go"];
+/// Number of committed files between two checkpoint saves
+const CHECKPOINT_INTERVAL: usize = 50;
+
+/// Concurrency limits of indexing operations
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexingConcurrency {
+    /// Files read and chunked at the same time on the blocking pool
+    pub max_concurrent_reads: usize,
+    /// Chunk batches embedded and stored at the same time
+    pub max_concurrent_embeddings: usize,
+}
+
+impl Default for IndexingConcurrency {
+    fn default() -> Self {
+        Self {
+            max_concurrent_reads: 8,
+            max_concurrent_embeddings: 4,
+        }
+    }
+}
 
 /// Accumulator for indexing progress and errors
 struct IndexingProgress {
@@ -51,10 +124,142 @@ impl IndexingProgress {
     }
 }
 
+/// Files committed by an unfinished indexing operation of a collection
+///
+/// Saved periodically while indexing and when an operation is cancelled, and
+/// deleted once the collection snapshot is saved. The next full run skips the
+/// files whose content still has the committed hash.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct IndexingCheckpoint {
+    /// Operation that committed the files
+    operation_id: String,
+    /// Codebase root the files are relative to
+    root: PathBuf,
+    /// Content hash of every committed file, by path relative to the root
+    committed: HashMap<String, String>,
+}
+
+/// Outcome of reading and chunking a discovered file
+enum ReadOutcome {
+    Chunked(Vec<CodeChunk>),
+    /// Binary or minified content
+    Skipped,
+    Failed(Error),
+}
+
+/// Chunks of consecutive files stored by a single `store_chunks` call
+#[derive(Default)]
+struct ChunkBatch {
+    chunks: Vec<CodeChunk>,
+    /// Position in the embedding pass of every file with chunks in the batch
+    files: Vec<usize>,
+    /// Estimated number of tokens of the chunks
+    tokens: usize,
+}
+
+impl ChunkBatch {
+    /// Check whether a chunk of `tokens` can join the batch within `limits`
+    ///
+    /// A chunk over the token limit still fills an empty batch on its own.
+    fn fits(&self, tokens: usize, limits: &EmbeddingBatchLimits) -> bool {
+        self.chunks.is_empty()
+            || (self.chunks.len() < limits.max_texts
+                && self.tokens.saturating_add(tokens) <= limits.max_tokens)
+    }
+
+    /// Add a chunk of file `file`, returning whether it is the file's first
+    fn push(&mut self, file: usize, chunk: CodeChunk, tokens: usize) -> bool {
+        self.chunks.push(chunk);
+        self.tokens = self.tokens.saturating_add(tokens);
+        let first = self.files.last() != Some(&file);
+        if first {
+            self.files.push(file);
+        }
+        first
+    }
+}
+
+/// Files of a batch and the outcome of storing it
+type StoredBatch = (Vec<usize>, Result<()>);
+
+/// File of an embedding pass
+struct PassFile {
+    path: PathBuf,
+    /// Number of chunks, `None` if the file was skipped or unreadable
+    chunks: Option<usize>,
+    /// Batches with chunks of the file that were not stored yet
+    pending_batches: usize,
+    failed: bool,
+    finished: bool,
+}
+
+/// Mutable state of an operation embedding its files
+struct EmbeddingPass<'a> {
+    operation_id: &'a str,
+    root: &'a Path,
+    collection: &'a str,
+    /// Whether finished files are checkpointed
+    resumable: bool,
+    files: Vec<PassFile>,
+    /// Number of finished files
+    finished: usize,
+    /// Files committed since the checkpoint was last saved
+    unsaved: usize,
+    snapshot: CodebaseSnapshot,
+    checkpoint: IndexingCheckpoint,
+    progress: IndexingProgress,
+}
+
+/// Status of the running, or most recent, indexing operation of a collection
+struct OperationState {
+    status: IndexingStatus,
+    /// When the operation was registered
+    started: Instant,
+    /// When the files to embed were known, the base for the ETA
+    embedding_started: Option<Instant>,
+    /// Whether cancellation was requested while the operation was running
+    cancel_requested: bool,
+}
+
+impl OperationState {
+    /// Status snapshot with progress and ETA derived from the counters
+    fn current_status(&self) -> IndexingStatus {
+        let mut status = self.status.clone();
+        let (processed, total) = (status.processed_files, status.total_files);
+        status.progress = if total > 0 {
+            processed as f64 / total as f64
+        } else if status.is_indexing {
+            0.0
+        } else {
+            1.0
+        };
+        status.eta_secs = match self.embedding_started {
+            Some(started) if status.is_indexing && processed > 0 && total > processed => {
+                let per_file = started.elapsed().as_secs_f64() / processed as f64;
+                Some((per_file * (total - processed) as f64).ceil() as u64)
+            }
+            _ => None,
+        };
+        status
+    }
+}
+
 /// Indexing service implementation - orchestrates file discovery and chunking
+///
+/// Each run hashes the discovered files and compares them with the snapshot
+/// saved by the previous run, so only added and modified files are embedded
+/// and chunks of modified or removed files are deleted. A collection is
+/// indexed by at most one operation at a time.
+#[derive(Clone)]
 pub struct IndexingServiceImpl {
     context_service: Arc<dyn ContextServiceInterface>,
     language_chunker: Arc<dyn LanguageChunkingProvider>,
+    snapshot_provider: Arc<dyn SnapshotProvider>,
+    state_store: Arc<dyn StateStoreProvider>,
+    event_bus: Arc<dyn EventBusProvider>,
+    operations: Arc<dyn IndexingOperationsInterface>,
+    concurrency: IndexingConcurrency,
+    statuses: Arc<Mutex<HashMap<String, OperationState>>>,
 }
 
 impl IndexingServiceImpl {
@@ -62,108 +267,929 @@ impl IndexingServiceImpl {
     pub fn new(
         context_service: Arc<dyn ContextServiceInterface>,
         language_chunker: Arc<dyn LanguageChunkingProvider>,
+        snapshot_provider: Arc<dyn SnapshotProvider>,
+        state_store: Arc<dyn StateStoreProvider>,
+        event_bus: Arc<dyn EventBusProvider>,
+        operations: Arc<dyn IndexingOperationsInterface>,
     ) -> Self {
         Self {
             context_service,
             language_chunker,
+            snapshot_provider,
+            state_store,
+            event_bus,
+            operations,
+            concurrency: IndexingConcurrency::default(),
+            statuses: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
-    /// Discover files recursively from a path
-    async fn discover_files(
+    /// Use the given concurrency limits instead of the defaults
+    pub fn with_concurrency(mut self, concurrency: IndexingConcurrency) -> Self {
+        self.concurrency = concurrency;
+        self
+    }
+
+    /// Register a new indexing operation for `collection`
+    ///
+    /// Fails if an operation is already running for the collection.
+    fn begin_operation(&self, collection: &str) -> Result<String> {
+        let mut statuses = self.lock_statuses();
+        if let Some(state) = statuses.get(collection)
+            && state.status.is_indexing
+        {
+            return Err(Error::invalid_argument(format!(
+                "Collection '{}' is already being indexed (operation {})",
+                collection,
+                state.status.operation_id.as_deref().unwrap_or("unknown")
+            )));
+        }
+
+        let operation_id = self.operations.start_operation(collection, 0);
+        let status = IndexingStatus {
+            is_indexing: true,
+            operation_id: Some(operation_id.clone()),
+            collection: Some(collection.to_string()),
+            ..Default::default()
+        };
+        statuses.insert(
+            collection.to_string(),
+            OperationState {
+                status,
+                started: Instant::now(),
+                embedding_started: None,
+                cancel_requested: false,
+            },
+        );
+        Ok(operation_id)
+    }
+
+    /// Run a registered operation to completion and record its outcome
+    async fn run_operation(
         &self,
-        path: &Path,
+        operation_id: &str,
+        root: &Path,
+        collection: &str,
+        options: &IndexingOptions,
+        scope: Option<&[PathBuf]>,
+    ) -> Result<IndexingResult> {
+        let timer = Instant::now();
+        let outcome = self
+            .sync_codebase(operation_id, root, collection, options, scope)
+            .await;
+        self.operations.complete_operation(operation_id);
+
+        self.update_status(collection, |status| {
+            status.is_indexing = false;
+            status.current_file = None;
+            match &outcome {
+                Ok(result) => status.errors = result.errors.clone(),
+                Err(e) => status.errors.push(e.to_string()),
+            }
+        });
+        if let Ok(result) = &outcome
+            && !self.get_collection_status(collection).cancelled
+        {
+            self.publish(DomainEvent::IndexingCompleted {
+                collection: collection.to_string(),
+                chunks: result.chunks_created,
+                duration_ms: timer.elapsed().as_millis() as u64,
+            })
+            .await;
+        }
+        outcome
+    }
+
+    /// Record the number of files an operation is going to embed
+    async fn report_started(&self, operation_id: &str, collection: &str, total_files: usize) {
+        self.operations.update_total(operation_id, total_files);
+        if let Some(state) = self.lock_statuses().get_mut(collection) {
+            state.status.total_files = total_files;
+            state.embedding_started = Some(Instant::now());
+        }
+        self.publish(DomainEvent::IndexingStarted {
+            collection: collection.to_string(),
+            total_files,
+        })
+        .await;
+    }
+
+    /// Record that an operation finished `current_file`
+    async fn report_progress(
+        &self,
+        operation_id: &str,
+        collection: &str,
+        processed: usize,
+        current_file: &Path,
+        progress: &IndexingProgress,
+    ) {
+        let current_file = current_file.display().to_string();
+        self.operations
+            .update_progress(operation_id, Some(current_file.clone()), processed);
+        let mut total = 0;
+        self.update_status(collection, |status| {
+            status.processed_files = processed;
+            status.current_file = Some(current_file.clone());
+            if status.errors.len() != progress.errors.len() {
+                status.errors = progress.errors.clone();
+            }
+            total = status.total_files;
+        });
+        self.publish(DomainEvent::IndexingProgress {
+            collection: collection.to_string(),
+            processed,
+            total,
+            current_file: Some(current_file),
+        })
+        .await;
+    }
+
+    /// Check whether the operation indexing `collection` should stop
+    fn is_cancel_requested(&self, collection: &str) -> bool {
+        self.lock_statuses()
+            .get(collection)
+            .is_some_and(|state| state.cancel_requested)
+    }
+
+    fn update_status(&self, collection: &str, update: impl FnOnce(&mut IndexingStatus)) {
+        if let Some(state) = self.lock_statuses().get_mut(collection) {
+            update(&mut state.status);
+        }
+    }
+
+    /// Indexing events are informational; a failing event bus never fails indexing
+    async fn publish(&self, event: DomainEvent) {
+        let _ = self.event_bus.publish_event(event).await;
+    }
+
+    fn lock_statuses(&self) -> MutexGuard<'_, HashMap<String, OperationState>> {
+        // Status updates cannot leave the map inconsistent
+        self.statuses.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Index the discovered files of `root` that fall under `scope`
+    ///
+    /// With a scope, only discovered files under one of its paths are compared
+    /// with the previous snapshot and every other file keeps its recorded
+    /// state. Without a previous snapshot the whole codebase is indexed.
+    async fn sync_codebase(
+        &self,
+        operation_id: &str,
+        root: &Path,
+        collection: &str,
+        options: &IndexingOptions,
+        scope: Option<&[PathBuf]>,
+    ) -> Result<IndexingResult> {
+        // Initializing creates a missing collection, whose chunks the saved
+        // snapshot and checkpoint no longer describe
+        let existed = self.context_service.collection_exists(collection).await?;
+        self.context_service.initialize(collection).await?;
+
+        let snapshot_key = self.snapshot_key(collection);
+        let previous = if existed {
+            self.snapshot_provider
+                .load_collection_snapshot(root, &snapshot_key)
+                .await?
+        } else {
+            self.state_store
+                .delete(&Self::checkpoint_key(collection))
+                .await?;
+            None
+        };
+        // Until a full run completes, there is no index to keep in sync: a
+        // scoped run would index the whole codebase
+        if scope.is_some() && previous.is_none() {
+            return Ok(IndexingProgress::new().into_result());
+        }
+
+        // Directory walking is blocking I/O
+        let discovery_root = root.to_path_buf();
+        let discovery_options = options.clone();
+        let chunker = Arc::clone(&self.language_chunker);
+        let (files, progress) = tokio::task::spawn_blocking(move || {
+            let mut progress = IndexingProgress::new();
+            let files = Self::discover_files(
+                &discovery_root,
+                &discovery_options,
+                chunker.as_ref(),
+                &mut progress,
+            );
+            (files, progress)
+        })
+        .await
+        .map_err(|e| Error::internal(format!("File discovery failed: {}", e)))?;
+
+        let files: Vec<PathBuf> = match scope {
+            Some(scope) => files
+                .into_iter()
+                .filter(|f| Self::in_scope(f, scope))
+                .collect(),
+            None => files,
+        };
+
+        let mut snapshot = self
+            .snapshot_provider
+            .snapshot_files(root, &snapshot_key, &files)
+            .await?;
+        if let (Some(scope), Some(previous)) = (scope, &previous) {
+            for (relative, file) in &previous.files {
+                if !Self::in_scope(&root.join(relative), scope) {
+                    snapshot.files.insert(relative.clone(), file.clone());
+                }
+            }
+            Self::update_totals(&mut snapshot);
+        }
+
+        let (mut files, mut stale) = self.plan_changes(root, files, &snapshot, previous).await?;
+        // Scoped runs leave checkpoints of interrupted full runs alone
+        let resumable = scope.is_none();
+        let mut checkpoint = IndexingCheckpoint {
+            operation_id: operation_id.to_string(),
+            root: root.to_path_buf(),
+            committed: HashMap::new(),
+        };
+        if resumable {
+            checkpoint.committed = self
+                .resume_checkpoint(root, collection, &snapshot, &mut files, &mut stale)
+                .await;
+        }
+        self.context_service
+            .delete_file_chunks(collection, &stale)
+            .await?;
+
+        let total_files = files.len();
+        self.report_started(operation_id, collection, total_files)
+            .await;
+        let mut pass = EmbeddingPass {
+            operation_id,
+            root,
+            collection,
+            resumable,
+            files: Vec::with_capacity(total_files),
+            finished: 0,
+            unsaved: 0,
+            snapshot,
+            checkpoint,
+            progress,
+        };
+        let cancelled = self.embed_files(&mut pass, files).await;
+        let EmbeddingPass {
+            finished,
+            snapshot,
+            checkpoint,
+            mut progress,
+            ..
+        } = pass;
+
+        if cancelled {
+            if resumable {
+                self.save_checkpoint(collection, &checkpoint, &mut progress)
+                    .await;
+            }
+            progress.errors.push(format!(
+                "Cancelled after {} of {} files; index the collection again to resume",
+                finished, total_files
+            ));
+            self.update_status(collection, |status| status.cancelled = true);
+            return Ok(progress.into_result());
+        }
+        self.update_status(collection, |status| {
+            status.processed_files = status.total_files;
+        });
+
+        self.snapshot_provider
+            .save_snapshot(root, &snapshot)
+            .await?;
+        if resumable {
+            self.state_store
+                .delete(&Self::checkpoint_key(collection))
+                .await?;
+        }
+        Ok(progress.into_result())
+    }
+
+    /// Read, chunk and store `files`, returning whether the pass was cancelled
+    ///
+    /// Chunks of consecutive files are packed into batches within the limits
+    /// of the embedding provider. Cancellation stops reading and drops the
+    /// batch being filled, but waits for the batches already being stored.
+    /// Chunks stored for files that failed or did not finish are deleted
+    /// again, so the next run embeds those files from scratch.
+    async fn embed_files(&self, pass: &mut EmbeddingPass<'_>, files: Vec<PathBuf>) -> bool {
+        let limits = self.context_service.embedding_batch_limits();
+        let policy = self
+            .context_service
+            .fit_chunk_size_policy(self.language_chunker.chunk_size_policy());
+        let chunker = Arc::clone(&self.language_chunker);
+        // Reads are spawned as soon as they are buffered, so up to the limit
+        // run ahead while batches are stored
+        let mut reads = stream::iter(files)
+            .map(|path| {
+                let chunker = Arc::clone(&chunker);
+                let task_path = path.clone();
+                let task = tokio::task::spawn_blocking(move || {
+                    Self::read_and_chunk(&task_path, chunker.as_ref(), &policy)
+                });
+                async move {
+                    let outcome = task.await.unwrap_or_else(|e| {
+                        ReadOutcome::Failed(Error::internal(format!("Chunking failed: {}", e)))
+                    });
+                    (path, outcome)
+                }
+            })
+            .buffered(self.concurrency.max_concurrent_reads.max(1));
+
+        let mut batch = ChunkBatch::default();
+        let mut in_flight = JoinSet::new();
+        let mut cancelled = false;
+        while let Some((path, outcome)) = reads.next().await {
+            if self.is_cancel_requested(pass.collection) {
+                cancelled = true;
+                break;
+            }
+            let index = pass.files.len();
+            pass.files.push(PassFile {
+                path,
+                chunks: None,
+                pending_batches: 0,
+                failed: false,
+                finished: false,
+            });
+            match outcome {
+                ReadOutcome::Chunked(chunks) => {
+                    pass.files[index].chunks = Some(chunks.len());
+                    for chunk in chunks {
+                        let tokens = policy.estimate_tokens(&chunk.content);
+                        if !batch.fits(tokens, &limits) {
+                            let full = std::mem::take(&mut batch);
+                            if !self.dispatch_batch(pass, &mut in_flight, full).await {
+                                cancelled = true;
+                                break;
+                            }
+                        }
+                        if batch.push(index, chunk, tokens) {
+                            pass.files[index].pending_batches += 1;
+                        }
+                    }
+                    if cancelled {
+                        break;
+                    }
+                    if pass.files[index].pending_batches == 0 {
+                        self.finish_file(pass, index).await;
+                    }
+                }
+                ReadOutcome::Skipped => {
+                    pass.progress.files_skipped += 1;
+                    self.finish_file(pass, index).await;
+                }
+                ReadOutcome::Failed(e) => {
+                    let file = &mut pass.files[index];
+                    file.failed = true;
+                    pass.progress.record_error("Failed to read", &file.path, e);
+                    pass.progress.files_skipped += 1;
+                    self.finish_file(pass, index).await;
+                }
+            }
+            while let Some(stored) = in_flight.try_join_next() {
+                self.finish_batch(pass, stored).await;
+            }
+        }
+
+        if !cancelled && !batch.chunks.is_empty() {
+            cancelled = !self.dispatch_batch(pass, &mut in_flight, batch).await;
+        }
+        while let Some(stored) = in_flight.join_next().await {
+            self.finish_batch(pass, stored).await;
+        }
+
+        let incomplete: Vec<String> = pass
+            .files
+            .iter()
+            .filter(|f| f.chunks.is_some() && (f.failed || !f.finished))
+            .map(|f| f.path.to_string_lossy().into_owned())
+            .collect();
+        if let Err(e) = self
+            .context_service
+            .delete_file_chunks(pass.collection, &incomplete)
+            .await
+        {
+            pass.progress
+                .record_error("Failed to delete incomplete chunks for", pass.root, e);
+        }
+        cancelled
+    }
+
+    /// Start storing `batch` once fewer batches than the limit are in flight
+    ///
+    /// Returns `false`, dropping the batch, if the operation was cancelled.
+    async fn dispatch_batch(
+        &self,
+        pass: &mut EmbeddingPass<'_>,
+        in_flight: &mut JoinSet<StoredBatch>,
+        batch: ChunkBatch,
+    ) -> bool {
+        while in_flight.len() >= self.concurrency.max_concurrent_embeddings.max(1) {
+            if let Some(stored) = in_flight.join_next().await {
+                self.finish_batch(pass, stored).await;
+            }
+        }
+        if self.is_cancel_requested(pass.collection) {
+            return false;
+        }
+
+        let context = Arc::clone(&self.context_service);
+        let collection = pass.collection.to_string();
+        let ChunkBatch { chunks, files, .. } = batch;
+        in_flight.spawn(async move {
+            let stored = context.store_chunks(&collection, &chunks).await;
+            (files, stored)
+        });
+        true
+    }
+
+    /// Record the outcome of a stored batch for each of its files
+    async fn finish_batch(
+        &self,
+        pass: &mut EmbeddingPass<'_>,
+        stored: std::result::Result<StoredBatch, tokio::task::JoinError>,
+    ) {
+        // The files of a panicked batch never finish and are cleaned up
+        let (files, stored) = match stored {
+            Ok(stored) => stored,
+            Err(e) => {
+                pass.progress
+                    .record_error("Failed to store chunks for", pass.root, e);
+                return;
+            }
+        };
+        for index in files {
+            let file = &mut pass.files[index];
+            file.pending_batches -= 1;
+            if let Err(e) = &stored
+                && !file.failed
+            {
+                file.failed = true;
+                pass.progress
+                    .record_error("Failed to store chunks for", &file.path, e);
+            }
+            if file.pending_batches == 0 {
+                self.finish_file(pass, index).await;
+            }
+        }
+    }
+
+    /// Commit a file whose chunks are all stored, or forget a failed one
+    async fn finish_file(&self, pass: &mut EmbeddingPass<'_>, index: usize) {
+        let file = &mut pass.files[index];
+        file.finished = true;
+        if file.failed {
+            Self::forget_file(&mut pass.snapshot, pass.root, &file.path);
+        } else if let Some(chunks) = file.chunks {
+            pass.progress.files_processed += 1;
+            pass.progress.chunks_created += chunks;
+            if pass.resumable {
+                let relative = Self::relative_path(pass.root, &file.path);
+                if let Some(entry) = pass.snapshot.files.get(&relative) {
+                    pass.checkpoint
+                        .committed
+                        .insert(relative, entry.hash.clone());
+                }
+                pass.unsaved += 1;
+                if pass.unsaved == CHECKPOINT_INTERVAL {
+                    self.save_checkpoint(pass.collection, &pass.checkpoint, &mut pass.progress)
+                        .await;
+                    pass.unsaved = 0;
+                }
+            }
+        }
+
+        pass.finished += 1;
+        self.report_progress(
+            pass.operation_id,
+            pass.collection,
+            pass.finished,
+            &pass.files[index].path,
+            &pass.progress,
+        )
+        .await;
+    }
+
+    /// Snapshot key of `collection`
+    ///
+    /// Snapshots record the files embedded in one vector space, so switching
+    /// embedder starts from no snapshot and re-embeds every file.
+    fn snapshot_key(&self, collection: &str) -> String {
+        format!("{}@{}", collection, self.context_service.embedding_space())
+    }
+
+    /// State store key of the checkpoint of `collection`
+    fn checkpoint_key(collection: &str) -> String {
+        format!("{}/{}", CHECKPOINT_KEY_PREFIX, collection)
+    }
+
+    /// Skip the files an unfinished operation already committed
+    ///
+    /// Files whose content still has the checkpointed hash are neither
+    /// re-embedded nor have their chunks deleted. Returns the entries that
+    /// were carried over, to be kept in the checkpoint of this operation.
+    async fn resume_checkpoint(
+        &self,
+        root: &Path,
+        collection: &str,
+        snapshot: &CodebaseSnapshot,
+        files: &mut Vec<PathBuf>,
+        stale: &mut Vec<String>,
+    ) -> HashMap<String, String> {
+        // A checkpoint only saves work; an unreadable one is ignored
+        let checkpoint = match self
+            .state_store
+            .load(&Self::checkpoint_key(collection))
+            .await
+        {
+            Ok(Some(data)) => serde_json::from_slice::<IndexingCheckpoint>(&data).ok(),
+            _ => None,
+        };
+        let Some(checkpoint) = checkpoint.filter(|c| c.root == root) else {
+            return HashMap::new();
+        };
+
+        let committed: HashMap<String, String> = checkpoint
+            .committed
+            .into_iter()
+            .filter(|(relative, hash)| {
+                snapshot
+                    .files
+                    .get(relative)
+                    .is_some_and(|file| &file.hash == hash)
+            })
+            .collect();
+        files.retain(|f| !committed.contains_key(&Self::relative_path(root, f)));
+        stale.retain(|f| !committed.contains_key(&Self::relative_path(root, Path::new(f))));
+        committed
+    }
+
+    /// Persist the files committed so far; failures only cost resumability
+    async fn save_checkpoint(
+        &self,
+        collection: &str,
+        checkpoint: &IndexingCheckpoint,
         progress: &mut IndexingProgress,
-    ) -> Vec<std::path::PathBuf> {
-        use tokio::fs;
+    ) {
+        let saved = match serde_json::to_vec(checkpoint) {
+            Ok(data) => {
+                self.state_store
+                    .save(&Self::checkpoint_key(collection), &data)
+                    .await
+            }
+            Err(e) => Err(e.into()),
+        };
+        if let Err(e) = saved {
+            progress.record_error("Failed to save checkpoint for", &checkpoint.root, e);
+        }
+    }
+
+    /// Select the files to embed and the stored file paths whose chunks are stale
+    ///
+    /// Without a previous snapshot every discovered file is embedded and any
+    /// chunks left for it by an untracked run are replaced.
+    async fn plan_changes(
+        &self,
+        root: &Path,
+        files: Vec<PathBuf>,
+        current: &CodebaseSnapshot,
+        previous: Option<CodebaseSnapshot>,
+    ) -> Result<(Vec<PathBuf>, Vec<String>)> {
+        let Some(previous) = previous else {
+            let stale = files
+                .iter()
+                .map(|f| f.to_string_lossy().into_owned())
+                .collect();
+            return Ok((files, stale));
+        };
+
+        let changes = self
+            .snapshot_provider
+            .compare_snapshots(&previous, current)
+            .await?;
+        let changed: HashSet<&str> = changes
+            .added
+            .iter()
+            .chain(&changes.modified)
+            .map(String::as_str)
+            .collect();
+        // Chunks store the path the file was discovered under
+        let stale = changes
+            .modified
+            .iter()
+            .chain(&changes.removed)
+            .map(|relative| root.join(relative).to_string_lossy().into_owned())
+            .collect();
+        let files = files
+            .into_iter()
+            .filter(|f| changed.contains(Self::relative_path(root, f).as_str()))
+            .collect();
+        Ok((files, stale))
+    }
+
+    /// Snapshot key of a discovered file: its path relative to `root`
+    fn relative_path(root: &Path, path: &Path) -> String {
+        path.strip_prefix(root)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .replace('\\', "/")
+    }
+
+    /// Check if a file is, or is located under, one of the scope paths
+    fn in_scope(path: &Path, scope: &[PathBuf]) -> bool {
+        scope.iter().any(|s| path.starts_with(s))
+    }
+
+    /// Drop a file that failed to index so the next run retries it
+    fn forget_file(snapshot: &mut CodebaseSnapshot, root: &Path, path: &Path) {
+        if snapshot
+            .files
+            .remove(&Self::relative_path(root, path))
+            .is_some()
+        {
+            Self::update_totals(snapshot);
+        }
+    }
+
+    /// Recompute the file count and size of a snapshot
+    fn update_totals(snapshot: &mut CodebaseSnapshot) {
+        snapshot.total_files = snapshot.files.len();
+        snapshot.total_size = snapshot.files.values().map(|f| f.size).sum();
+    }
+
+    /// Discover indexable files under `root` according to `options`
+    ///
+    /// Runs synchronously; callers should move it off the async runtime.
+    fn discover_files(
+        root: &Path,
+        options: &IndexingOptions,
+        chunker: &dyn LanguageChunkingProvider,
+        progress: &mut IndexingProgress,
+    ) -> Vec<PathBuf> {
+        let (ignore, errors) = IgnoreRules::build(root, options);
+        for e in errors {
+            progress.record_error("Invalid ignore pattern for", root, e);
+        }
+        // Honors .gitignore (nested, parent and global excludes), .ignore and
+        // the project ignore file; hidden files are still indexed
+        let walker = WalkBuilder::new(root)
+            .hidden(false)
+            .require_git(false)
+            .add_custom_ignore_filename(PROJECT_IGNORE_FILE)
+            .follow_links(options.follow_symlinks)
+            .filter_entry(move |entry| {
+                if entry.depth() == 0 {
+                    return true;
+                }
+                let is_dir = entry.file_type().is_some_and(|t| t.is_dir());
+                !ignore.excludes(entry.path(), is_dir)
+            })
+            .build();
 
         let mut files = Vec::new();
-        let mut dirs_to_visit = vec![path.to_path_buf()];
+        // The same file can be reached through several links
+        let mut seen = HashSet::new();
 
-        while let Some(dir_path) = dirs_to_visit.pop() {
-            let mut entries = match fs::read_dir(&dir_path).await {
-                Ok(entries) => entries,
+        for entry in walker {
+            let entry = match entry {
+                Ok(entry) => entry,
                 Err(e) => {
-                    progress.record_error("Failed to read directory", &dir_path, e);
+                    // Includes symlink loops when following links
+                    progress.record_error("Failed to walk", root, e);
                     continue;
                 }
             };
+            if !entry.file_type().is_some_and(|t| t.is_file()) {
+                continue;
+            }
 
-            while let Ok(Some(entry)) = entries.next_entry().await {
-                let entry_path = entry.path();
-                if entry_path.is_dir() {
-                    if Self::should_visit_dir(&entry_path) {
-                        dirs_to_visit.push(entry_path);
+            let path = entry.path();
+            if Self::is_generated_file(path)
+                || !Self::is_indexable(path, options.extensions.as_deref(), chunker)
+            {
+                continue;
+            }
+
+            if let Some(max_file_size) = options.max_file_size {
+                match entry.metadata() {
+                    Ok(metadata) if metadata.len() > max_file_size => {
+                        progress.files_skipped += 1;
+                        continue;
+                    }
+                    Ok(_) => {}
+                    Err(e) => {
+                        progress.record_error("Failed to stat", path, e);
+                        continue;
                     }
-                } else if Self::is_supported_file(&entry_path) {
-                    files.push(entry_path);
                 }
             }
+
+            if options.follow_symlinks
+                && let Ok(canonical) = path.canonicalize()
+                && !seen.insert(canonical)
+            {
+                continue;
+            }
+            files.push(path.to_path_buf());
         }
         files
     }
 
-    /// Check if directory should be visited during indexing
-    fn should_visit_dir(path: &Path) -> bool {
+    /// Check if file is a known generated file such as a lockfile
+    fn is_generated_file(path: &Path) -> bool {
         path.file_name()
             .and_then(|name| name.to_str())
-            .map(|name| !SKIP_DIRS.contains(&name))
-            .unwrap_or(true)
+            .is_some_and(|name| GENERATED_FILES.contains(&name))
+    }
+
+    /// Read and chunk a discovered file into chunks sized by `policy`
+    ///
+    /// Runs synchronously; callers should move it off the async runtime.
+    fn read_and_chunk(
+        path: &Path,
+        chunker: &dyn LanguageChunkingProvider,
+        policy: &ChunkSizePolicy,
+    ) -> ReadOutcome {
+        match Self::read_source(path) {
+            Ok(Some(content)) => ReadOutcome::Chunked(chunker.chunk_with_policy(
+                &content,
+                &path.to_string_lossy(),
+                policy,
+            )),
+            Ok(None) => ReadOutcome::Skipped,
+            Err(e) => ReadOutcome::Failed(e.into()),
+        }
+    }
+
+    /// Read a discovered file, returning `None` for binary or minified content
+    fn read_source(path: &Path) -> std::io::Result<Option<String>> {
+        let bytes = std::fs::read(path)?;
+        if Self::is_binary(&bytes) {
+            return Ok(None);
+        }
+        let content = String::from_utf8(bytes)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        if Self::is_minified(path, &content) {
+            return Ok(None);
+        }
+        Ok(Some(content))
+    }
+
+    /// Binary files contain NUL bytes near the start
+    fn is_binary(bytes: &[u8]) -> bool {
+        bytes[..bytes.len().min(BINARY_SNIFF_LEN)].contains(&0)
+    }
+
+    /// Minified files are named `*.min.*` or consist of very long lines
+    fn is_minified(path: &Path, content: &str) -> bool {
+        let named_min = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.contains(".min."));
+        if named_min {
+            return true;
+        }
+        if content.len() < MINIFIED_MIN_LEN {
+            return false;
+        }
+        let lines = content.lines().count().max(1);
+        content.len() / lines > MINIFIED_AVG_LINE_LEN
     }
 
-    /// Check if file has a supported extension
-    fn is_supported_file(path: &Path) -> bool {
-        path.extension()
-            .and_then(|ext| ext.to_str())
-            .map(|ext| SUPPORTED_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
-            .unwrap_or(false)
+    /// Check if a file should be indexed
+    ///
+    /// An explicit extension list wins; otherwise the chunking provider decides
+    /// from the extension, the file name or the `#!` line.
+    fn is_indexable(
+        path: &Path,
+        extensions: Option<&[String]>,
+        chunker: &dyn LanguageChunkingProvider,
+    ) -> bool {
+        if let Some(extensions) = extensions {
+            return path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| extensions.contains(&ext.to_lowercase()));
+        }
+        let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+            return false;
+        };
+        if chunker.supports_file(file_name, None) {
+            return true;
+        }
+        // Only extensionless files are probed for a shebang
+        path.extension().is_none()
+            && Self::read_first_line(path)
+                .is_some_and(|line| chunker.supports_file(file_name, Some(&line)))
     }
 
-    /// Chunk file content using intelligent AST-based chunking
-    fn chunk_file_content(&self, content: &str, path: &Path) -> Vec<CodeChunk> {
-        self.language_chunker
-            .chunk(content, &path.to_string_lossy())
+    /// Read the first line of a file, if it is valid UTF-8
+    fn read_first_line(path: &Path) -> Option<String> {
+        use std::io::{BufRead, BufReader, Read};
+
+        let file = std::fs::File::open(path).ok()?;
+        let mut line = String::new();
+        BufReader::new(file.take(SHEBANG_MAX_LEN))
+            .read_line(&mut line)
+            .ok()?;
+        Some(line)
     }
 }
 
 #[async_trait::async_trait]
 impl IndexingServiceInterface for IndexingServiceImpl {
-    async fn index_codebase(&self, path: &Path, collection: &str) -> Result<IndexingResult> {
-        use tokio::fs;
+    async fn index_codebase(
+        &self,
+        path: &Path,
+        collection: &str,
+        options: &IndexingOptions,
+    ) -> Result<IndexingResult> {
+        let operation_id = self.begin_operation(collection)?;
+        self.run_operation(&operation_id, path, collection, options, None)
+            .await
+    }
 
-        self.context_service.initialize(collection).await?;
-        let mut progress = IndexingProgress::new();
+    async fn reindex_files(
+        &self,
+        path: &Path,
+        collection: &str,
+        files: &[PathBuf],
+        options: &IndexingOptions,
+    ) -> Result<IndexingResult> {
+        let operation_id = self.begin_operation(collection)?;
+        self.run_operation(&operation_id, path, collection, options, Some(files))
+            .await
+    }
 
-        // Discover and process files
-        let files = self.discover_files(path, &mut progress).await;
+    async fn start_indexing(
+        &self,
+        path: &Path,
+        collection: &str,
+        options: &IndexingOptions,
+    ) -> Result<String> {
+        let operation_id = self.begin_operation(collection)?;
 
-        for file_path in files {
-            let content = match fs::read_to_string(&file_path).await {
-                Ok(c) => c,
-                Err(e) => {
-                    progress.record_error("Failed to read", &file_path, e);
-                    progress.files_skipped += 1;
-                    continue;
-                }
-            };
+        let service = self.clone();
+        let job_id = operation_id.clone();
+        let root = path.to_path_buf();
+        let collection = collection.to_string();
+        let options = options.clone();
+        tokio::spawn(async move {
+            // The outcome is reported through the collection status and events
+            let _ = service
+                .run_operation(&job_id, &root, &collection, &options, None)
+                .await;
+        });
+        Ok(operation_id)
+    }
 
-            let chunks = self.chunk_file_content(&content, &file_path);
-            if let Err(e) = self.context_service.store_chunks(collection, &chunks).await {
-                progress.record_error("Failed to store chunks for", &file_path, e);
-                continue;
-            }
-            progress.files_processed += 1;
-            progress.chunks_created += chunks.len();
-        }
+    async fn cancel_indexing(&self, operation_id: &str) -> Result<()> {
+        let mut statuses = self.lock_statuses();
+        let state = statuses
+            .values_mut()
+            .find(|state| {
+                state.status.is_indexing
+                    && state.status.operation_id.as_deref() == Some(operation_id)
+            })
+            .ok_or_else(|| {
+                Error::not_found(format!("Running indexing operation '{}'", operation_id))
+            })?;
+        state.cancel_requested = true;
+        Ok(())
+    }
 
-        Ok(progress.into_result())
+    fn get_status(&self) -> IndexingStatus {
+        self.lock_statuses()
+            .values()
+            .max_by_key(|state| (state.status.is_indexing, state.started))
+            .map(OperationState::current_status)
+            .unwrap_or_default()
     }
 
-    fn get_status(&self) -> crate::domain_services::search::IndexingStatus {
-        crate::domain_services::search::IndexingStatus::default()
+    fn get_collection_status(&self, collection: &str) -> IndexingStatus {
+        self.lock_statuses()
+            .get(collection)
+            .map(OperationState::current_status)
+            .unwrap_or_else(|| IndexingStatus {
+                collection: Some(collection.to_string()),
+                ..Default::default()
+            })
     }
 
     async fn clear_collection(&self, collection: &str) -> Result<()> {
-        self.context_service.clear_collection(collection).await
+        self.context_service.clear_collection(collection).await?;
+        // A running operation keeps reporting to its status
+        self.lock_statuses()
+            .retain(|name, state| name != collection || state.status.is_indexing);
+        self.state_store
+            .delete(&Self::checkpoint_key(collection))
+            .await?;
+        self.snapshot_provider
+            .delete_collection_snapshots(&self.snapshot_key(collection))
+            .await
     }
 }
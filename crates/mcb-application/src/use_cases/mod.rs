@@ -8,6 +8,7 @@
 //! - `context_service`: Code intelligence and semantic operations
 //! - `search_service`: Semantic search operations
 //! - `indexing_service`: Code indexing and ingestion operations
+//! - `ignore_rules`: Paths of a codebase that are never indexed
 //!
 //! ## Dependency Injection
 //!
@@ -15,9 +16,11 @@
 //! They receive their dependencies (ports) through constructor injection.
 
 pub mod context_service;
+pub mod ignore_rules;
 pub mod indexing_service;
 pub mod search_service;
 
 pub use context_service::*;
+pub use ignore_rules::*;
 pub use indexing_service::*;
 pub use search_service::*;
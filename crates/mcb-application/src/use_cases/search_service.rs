@@ -1,22 +1,128 @@
 //! Search Service Use Case
 //!
 //! Application service for semantic search operations.
-//! Orchestrates search functionality using context service for semantic understanding.
+//! Orchestrates hybrid search: semantic candidates from the context service
+//! and lexical candidates from the BM25 index are fused into a single ranking.
+//! When a reranker is configured, the top of that ranking is re-scored by it.
+//! With [`QueryExpansion`] enabled, the lexical side searches the query
+//! extended with its expansion terms.
 
 use crate::domain_services::search::{ContextServiceInterface, SearchServiceInterface};
-use mcb_domain::error::Result;
-use mcb_domain::value_objects::SearchResult;
+use crate::ports::providers::{HybridSearchProvider, HybridSearchResult, RerankerProvider};
+use mcb_domain::constants::{SEARCH_CANDIDATE_MULTIPLIER, SEARCH_CANDIDATES_MAX};
+use mcb_domain::error::{Error, Result};
+use mcb_domain::value_objects::{QueryExpansion, SearchQuery};
 use std::sync::Arc;
 
-/// Search service implementation - delegates to context service
+/// Search service implementation - fuses semantic and BM25 rankings
 pub struct SearchServiceImpl {
     context_service: Arc<dyn ContextServiceInterface>,
+    hybrid_search: Arc<dyn HybridSearchProvider>,
+    reranker: Option<Reranking>,
+    expansion: QueryExpansion,
+}
+
+/// Second ranking stage applied to the fused results
+struct Reranking {
+    provider: Arc<dyn RerankerProvider>,
+    /// Number of top fused results re-scored per query
+    top_n: usize,
 }
 
 impl SearchServiceImpl {
     /// Create new search service with injected dependencies
-    pub fn new(context_service: Arc<dyn ContextServiceInterface>) -> Self {
-        Self { context_service }
+    pub fn new(
+        context_service: Arc<dyn ContextServiceInterface>,
+        hybrid_search: Arc<dyn HybridSearchProvider>,
+    ) -> Self {
+        Self {
+            context_service,
+            hybrid_search,
+            reranker: None,
+            expansion: QueryExpansion::disabled(),
+        }
+    }
+
+    /// Rerank the top `top_n` fused results of every query
+    ///
+    /// Reranking costs a model call per candidate, so only the head of the
+    /// fused ranking is re-scored; results past `top_n` keep their fused
+    /// order after the reranked ones. A `top_n` of zero disables reranking.
+    pub fn with_reranker(mut self, reranker: Arc<dyn RerankerProvider>, top_n: usize) -> Self {
+        self.reranker = (top_n > 0).then_some(Reranking {
+            provider: reranker,
+            top_n,
+        });
+        self
+    }
+
+    /// Extend the lexical query with the expansion terms of each query
+    ///
+    /// Semantic candidates come from the context service, which applies its
+    /// own expansion to the embedded queries.
+    pub fn with_query_expansion(mut self, expansion: QueryExpansion) -> Self {
+        self.expansion = expansion;
+        self
+    }
+
+    /// Number of semantic candidates to fetch for re-ranking
+    ///
+    /// Over-fetches so exact lexical matches ranked just below the semantic
+    /// top-k still get a chance to surface after BM25 fusion.
+    fn candidate_limit(limit: usize) -> usize {
+        limit
+            .saturating_mul(SEARCH_CANDIDATE_MULTIPLIER)
+            .min(SEARCH_CANDIDATES_MAX)
+            .max(limit)
+    }
+
+    /// Number of fused results to request for a query
+    ///
+    /// The whole reranking budget is fused, so results below the caller's
+    /// limit can still be promoted into it.
+    fn fused_limit(&self, limit: usize) -> usize {
+        match &self.reranker {
+            Some(reranking) => limit.max(reranking.top_n),
+            None => limit,
+        }
+    }
+
+    /// Re-score the head of the fused ranking and reorder it
+    async fn rerank(&self, query: &str, results: &mut [HybridSearchResult]) -> Result<()> {
+        let Some(reranking) = &self.reranker else {
+            return Ok(());
+        };
+        let head_len = reranking.top_n.min(results.len());
+        let head = &mut results[..head_len];
+        if head.is_empty() {
+            return Ok(());
+        }
+
+        let documents: Vec<String> = head.iter().map(|r| r.result.content.clone()).collect();
+        let scores = reranking.provider.rerank(query, &documents).await?;
+        if scores.is_empty() {
+            // The provider does not rank; keep the fused order
+            return Ok(());
+        }
+        if scores.len() != head.len() {
+            return Err(Error::internal(format!(
+                "Reranker '{}' returned {} scores for {} documents",
+                reranking.provider.provider_name(),
+                scores.len(),
+                head.len()
+            )));
+        }
+
+        for (result, score) in head.iter_mut().zip(scores) {
+            result.rerank_score = Some(score);
+        }
+        // Stable, so ties keep their fused order
+        head.sort_by(|a, b| {
+            b.rerank_score
+                .partial_cmp(&a.rerank_score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        Ok(())
     }
 }
 
@@ -25,13 +131,38 @@ impl SearchServiceInterface for SearchServiceImpl {
     async fn search(
         &self,
         collection: &str,
-        query: &str,
-        limit: usize,
-    ) -> Result<Vec<SearchResult>> {
-        // Delegate to context service for semantic search
-        // Future: add BM25 scoring and hybrid ranking
-        self.context_service
-            .search_similar(collection, query, limit)
-            .await
+        query: &SearchQuery,
+    ) -> Result<Vec<HybridSearchResult>> {
+        let fused_query = SearchQuery {
+            text: self.expansion.expand(&query.text).lexical_text(),
+            limit: self.fused_limit(query.limit),
+            ..query.clone()
+        };
+        // Filters are applied by the vector store, so every candidate already
+        // matches them. The score threshold is a similarity threshold: fused
+        // scores live on a scale of their own (at most about 0.03 under RRF),
+        // so it is applied to the semantic score, before fusion.
+        let candidate_query = SearchQuery {
+            limit: Self::candidate_limit(fused_query.limit),
+            ..query.clone()
+        };
+        let candidates = self
+            .context_service
+            .search_similar(collection, &candidate_query)
+            .await?;
+
+        let mut results = self
+            .hybrid_search
+            .search_query(collection, &fused_query, candidates)
+            .await?;
+        // Lexical-only hits have no similarity to clear the threshold with
+        if query.min_score.is_some() {
+            results.retain(|r| {
+                r.semantic_score > 0.0 && query.accepts_score(f64::from(r.semantic_score))
+            });
+        }
+        self.rerank(&query.text, &mut results).await?;
+        results.truncate(query.limit);
+        Ok(results)
     }
 }
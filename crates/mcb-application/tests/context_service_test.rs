@@ -0,0 +1,581 @@
+//! Tests for the context service embedding cache, chunk enrichment and
+//! query expansion
+
+use async_trait::async_trait;
+use mcb_application::domain_services::search::ContextServiceInterface;
+use mcb_application::ports::providers::cache::{CacheEntryConfig, CacheProvider, CacheStats};
+use mcb_application::ports::providers::{
+    EmbeddingProvider, HybridSearchProvider, HybridSearchResult, VectorStoreAdmin,
+    VectorStoreProvider,
+};
+use mcb_application::use_cases::{ChunkEnrichment, ContextServiceImpl};
+use mcb_domain::constants::{EMBEDDING_CACHE_NAMESPACE, EMBEDDING_CACHE_TTL_SECS};
+use mcb_domain::entities::CodeChunk;
+use mcb_domain::error::Result;
+use mcb_domain::value_objects::{
+    Embedding, MetadataFilter, QueryExpansion, SearchQuery, SearchResult,
+};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// In-memory cache provider
+#[derive(Debug, Default)]
+struct MemoryCache {
+    entries: Mutex<HashMap<String, String>>,
+}
+
+#[async_trait]
+impl CacheProvider for MemoryCache {
+    async fn get_json(&self, key: &str) -> Result<Option<String>> {
+        Ok(self
+            .entries
+            .lock()
+            .expect("Lock poisoned")
+            .get(key)
+            .cloned())
+    }
+
+    async fn set_json(&self, key: &str, value: &str, _config: CacheEntryConfig) -> Result<()> {
+        self.entries
+            .lock()
+            .expect("Lock poisoned")
+            .insert(key.to_string(), value.to_string());
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<bool> {
+        Ok(self
+            .entries
+            .lock()
+            .expect("Lock poisoned")
+            .remove(key)
+            .is_some())
+    }
+
+    async fn exists(&self, key: &str) -> Result<bool> {
+        Ok(self
+            .entries
+            .lock()
+            .expect("Lock poisoned")
+            .contains_key(key))
+    }
+
+    async fn clear(&self) -> Result<()> {
+        self.entries.lock().expect("Lock poisoned").clear();
+        Ok(())
+    }
+
+    async fn stats(&self) -> Result<CacheStats> {
+        Ok(CacheStats::default())
+    }
+
+    async fn size(&self) -> Result<usize> {
+        Ok(self.entries.lock().expect("Lock poisoned").len())
+    }
+
+    fn provider_name(&self) -> &str {
+        "memory"
+    }
+}
+
+/// Embedding provider recording every text it embeds
+struct RecordingEmbeddingProvider {
+    model: &'static str,
+    embedded: Mutex<Vec<String>>,
+}
+
+impl RecordingEmbeddingProvider {
+    fn new(model: &'static str) -> Self {
+        Self {
+            model,
+            embedded: Mutex::new(Vec::new()),
+        }
+    }
+
+    fn embedded(&self) -> Vec<String> {
+        self.embedded.lock().expect("Lock poisoned").clone()
+    }
+}
+
+#[async_trait]
+impl EmbeddingProvider for RecordingEmbeddingProvider {
+    async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Embedding>> {
+        self.embedded
+            .lock()
+            .expect("Lock poisoned")
+            .extend_from_slice(texts);
+        Ok(texts
+            .iter()
+            .map(|text| Embedding {
+                vector: vec![text.len() as f32, 0.0],
+                model: self.model.to_string(),
+                dimensions: 2,
+            })
+            .collect())
+    }
+
+    fn dimensions(&self) -> usize {
+        2
+    }
+
+    fn provider_name(&self) -> &str {
+        "recording"
+    }
+
+    fn model_name(&self) -> &str {
+        self.model
+    }
+}
+
+/// Vector store recording the vectors and metadata inserted into it
+#[derive(Default)]
+struct RecordingVectorStore {
+    inserted: Mutex<Vec<Embedding>>,
+    metadata: Mutex<Vec<HashMap<String, Value>>>,
+    /// Results of a search, by the length of the embedded query
+    rankings: HashMap<usize, Vec<SearchResult>>,
+}
+
+#[async_trait]
+impl VectorStoreAdmin for RecordingVectorStore {
+    async fn collection_exists(&self, _name: &str) -> Result<bool> {
+        Ok(true)
+    }
+
+    async fn get_stats(&self, _collection: &str) -> Result<HashMap<String, Value>> {
+        Ok(HashMap::new())
+    }
+
+    async fn flush(&self, _collection: &str) -> Result<()> {
+        Ok(())
+    }
+
+    fn provider_name(&self) -> &str {
+        "recording"
+    }
+}
+
+#[async_trait]
+impl VectorStoreProvider for RecordingVectorStore {
+    async fn create_collection(&self, _name: &str, _dimensions: usize) -> Result<()> {
+        Ok(())
+    }
+
+    async fn delete_collection(&self, _name: &str) -> Result<()> {
+        Ok(())
+    }
+
+    async fn insert_vectors(
+        &self,
+        _collection: &str,
+        vectors: &[Embedding],
+        _metadata: Vec<HashMap<String, Value>>,
+    ) -> Result<Vec<String>> {
+        let mut inserted = self.inserted.lock().expect("Lock poisoned");
+        inserted.extend_from_slice(vectors);
+        Ok((0..vectors.len()).map(|i| i.to_string()).collect())
+    }
+
+    async fn upsert_vectors(
+        &self,
+        _collection: &str,
+        _ids: &[String],
+        vectors: &[Embedding],
+        metadata: Vec<HashMap<String, Value>>,
+    ) -> Result<()> {
+        let mut inserted = self.inserted.lock().expect("Lock poisoned");
+        inserted.extend_from_slice(vectors);
+        self.metadata
+            .lock()
+            .expect("Lock poisoned")
+            .extend(metadata);
+        Ok(())
+    }
+
+    async fn search_similar(
+        &self,
+        _collection: &str,
+        query_vector: &[f32],
+        _limit: usize,
+        _filter: Option<&MetadataFilter>,
+    ) -> Result<Vec<SearchResult>> {
+        let query_length = query_vector[0] as usize;
+        Ok(self
+            .rankings
+            .get(&query_length)
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn delete_vectors(&self, _collection: &str, _ids: &[String]) -> Result<()> {
+        Ok(())
+    }
+
+    async fn delete_by_file_path(&self, _collection: &str, _file_path: &str) -> Result<()> {
+        Ok(())
+    }
+
+    async fn list_by_file_path(
+        &self,
+        _collection: &str,
+        _file_path: &str,
+    ) -> Result<Vec<SearchResult>> {
+        Ok(Vec::new())
+    }
+
+    async fn get_vectors_by_ids(
+        &self,
+        _collection: &str,
+        _ids: &[String],
+    ) -> Result<Vec<SearchResult>> {
+        Ok(Vec::new())
+    }
+
+    async fn list_vectors(&self, _collection: &str, _limit: usize) -> Result<Vec<SearchResult>> {
+        Ok(Vec::new())
+    }
+}
+
+/// Hybrid search provider recording the files removed from its index
+#[derive(Default)]
+struct RecordingHybridSearch {
+    removed_files: Mutex<Vec<String>>,
+}
+
+#[async_trait]
+impl HybridSearchProvider for RecordingHybridSearch {
+    async fn index_chunks(&self, _collection: &str, _chunks: &[CodeChunk]) -> Result<()> {
+        Ok(())
+    }
+
+    async fn remove_chunks(&self, _collection: &str, _chunk_ids: &[String]) -> Result<()> {
+        Ok(())
+    }
+
+    async fn remove_file_chunks(&self, _collection: &str, file_paths: &[String]) -> Result<()> {
+        self.removed_files
+            .lock()
+            .expect("Lock poisoned")
+            .extend_from_slice(file_paths);
+        Ok(())
+    }
+
+    async fn search(
+        &self,
+        _collection: &str,
+        _query: &str,
+        semantic_results: Vec<SearchResult>,
+        _limit: usize,
+    ) -> Result<Vec<HybridSearchResult>> {
+        Ok(semantic_results
+            .into_iter()
+            .map(HybridSearchResult::semantic_only)
+            .collect())
+    }
+
+    async fn clear_collection(&self, _collection: &str) -> Result<()> {
+        Ok(())
+    }
+
+    async fn get_stats(&self) -> HashMap<String, Value> {
+        HashMap::new()
+    }
+}
+
+fn context_service(
+    cache: Arc<MemoryCache>,
+    embedding: Arc<RecordingEmbeddingProvider>,
+    vector_store: Arc<RecordingVectorStore>,
+) -> ContextServiceImpl {
+    ContextServiceImpl::new(
+        cache,
+        embedding,
+        vector_store,
+        Arc::new(RecordingHybridSearch::default()),
+    )
+    .with_enrichment(ChunkEnrichment::disabled())
+    .with_embedding_cache(Some(
+        CacheEntryConfig::new()
+            .with_ttl_secs(EMBEDDING_CACHE_TTL_SECS)
+            .with_namespace(EMBEDDING_CACHE_NAMESPACE),
+    ))
+}
+
+fn search_result(id: &str, score: f64) -> SearchResult {
+    SearchResult {
+        id: id.to_string(),
+        file_path: format!("src/{id}.rs"),
+        start_line: 1,
+        content: format!("fn {id}() {{}}"),
+        score,
+        language: "rust".to_string(),
+        symbol: None,
+    }
+}
+
+fn chunk(file_path: &str, content: &str) -> CodeChunk {
+    CodeChunk {
+        id: format!("{}:1", file_path),
+        content: content.to_string(),
+        file_path: file_path.to_string(),
+        start_line: 1,
+        end_line: 1,
+        language: "rust".to_string(),
+        metadata: serde_json::json!({}),
+    }
+}
+
+#[tokio::test]
+async fn test_unchanged_chunks_are_embedded_once() {
+    let embedding = Arc::new(RecordingEmbeddingProvider::new("model-a"));
+    let vector_store = Arc::new(RecordingVectorStore::default());
+    let service = context_service(
+        Arc::new(MemoryCache::default()),
+        embedding.clone(),
+        vector_store.clone(),
+    );
+    let chunks = vec![
+        chunk("src/a.rs", "fn a() {}"),
+        chunk("src/b.rs", "fn b() {}"),
+        chunk("src/copy_of_a.rs", "fn a() {}"),
+    ];
+
+    service.store_chunks("main", &chunks).await.unwrap();
+    // A branch of the same codebase shares the content
+    service.store_chunks("branch", &chunks).await.unwrap();
+
+    assert_eq!(
+        embedding.embedded(),
+        vec!["fn a() {}".to_string(), "fn b() {}".to_string()]
+    );
+    let inserted = vector_store.inserted.lock().expect("Lock poisoned").clone();
+    assert_eq!(inserted.len(), 6);
+    assert_eq!(inserted[0], inserted[2]);
+    assert_eq!(inserted[3..], inserted[..3]);
+
+    // The copy of `a` is embedded with it on the first call, not served
+    // from the cache
+    let stats = service.embedding_cache_stats();
+    assert!(stats.enabled);
+    assert_eq!((stats.hits, stats.misses), (3, 3));
+    assert!((stats.hit_rate - 0.5).abs() < 1e-9);
+}
+
+#[tokio::test]
+async fn test_cached_embeddings_are_scoped_to_the_model() {
+    let cache = Arc::new(MemoryCache::default());
+    let first = Arc::new(RecordingEmbeddingProvider::new("model-a"));
+    let second = Arc::new(RecordingEmbeddingProvider::new("model-b"));
+    let vector_store = Arc::new(RecordingVectorStore::default());
+
+    context_service(cache.clone(), first.clone(), vector_store.clone())
+        .embed_text("query")
+        .await
+        .unwrap();
+    let embedded = context_service(cache.clone(), second.clone(), vector_store)
+        .embed_text("query")
+        .await
+        .unwrap();
+
+    assert_eq!(first.embedded().len(), 1);
+    assert_eq!(second.embedded().len(), 1);
+    assert_eq!(embedded.model, "model-b");
+}
+
+#[tokio::test]
+async fn test_embedding_cache_is_off_by_default() {
+    let embedding = Arc::new(RecordingEmbeddingProvider::new("model-a"));
+    let service = ContextServiceImpl::new(
+        Arc::new(MemoryCache::default()),
+        embedding.clone(),
+        Arc::new(RecordingVectorStore::default()),
+        Arc::new(RecordingHybridSearch::default()),
+    );
+
+    service.embed_text("query").await.unwrap();
+    service.embed_text("query").await.unwrap();
+
+    assert_eq!(embedding.embedded().len(), 2);
+    assert!(!service.embedding_cache_stats().enabled);
+}
+
+#[tokio::test]
+async fn test_disabled_embedding_cache_always_embeds() {
+    let embedding = Arc::new(RecordingEmbeddingProvider::new("model-a"));
+    let service = context_service(
+        Arc::new(MemoryCache::default()),
+        embedding.clone(),
+        Arc::new(RecordingVectorStore::default()),
+    )
+    .with_embedding_cache(None);
+    let chunks = vec![chunk("src/a.rs", "fn a() {}")];
+
+    service.store_chunks("main", &chunks).await.unwrap();
+    service.store_chunks("main", &chunks).await.unwrap();
+
+    assert_eq!(embedding.embedded().len(), 2);
+    let stats = service.embedding_cache_stats();
+    assert!(!stats.enabled);
+    assert_eq!((stats.hits, stats.misses), (0, 0));
+}
+
+#[tokio::test]
+async fn test_chunks_are_embedded_with_their_context() {
+    let embedding = Arc::new(RecordingEmbeddingProvider::new("model-a"));
+    let vector_store = Arc::new(RecordingVectorStore::default());
+    let service = context_service(
+        Arc::new(MemoryCache::default()),
+        embedding.clone(),
+        vector_store.clone(),
+    )
+    .with_enrichment(ChunkEnrichment::default());
+    let mut method = chunk(
+        "src/transport/http.rs",
+        "pub fn run(&self) {\n    serve(self.listener);\n}",
+    );
+    method.metadata = serde_json::json!({
+        "symbol": {
+            "name": "run",
+            "kind": "fn",
+            "signature": "pub fn run(&self)",
+            "parent": "impl Server",
+            "doc": "Serve requests until shutdown"
+        },
+        "imports": ["use crate::net::serve;", "use std::net::TcpListener;"]
+    });
+
+    service
+        .store_chunks("main", &[method.clone()])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        embedding.embedded(),
+        vec![
+            "File: src/transport/http.rs\n\
+             Language: rust\n\
+             Scope: impl Server\n\
+             Imports: use crate::net::serve; use std::net::TcpListener\n\
+             Doc: Serve requests until shutdown\n\n\
+             pub fn run(&self) {\n    serve(self.listener);\n}"
+                .to_string()
+        ]
+    );
+    let metadata = vector_store.metadata.lock().expect("Lock poisoned").clone();
+    assert_eq!(metadata[0]["content"], serde_json::json!(method.content));
+}
+
+#[tokio::test]
+async fn test_deleted_file_chunks_leave_the_lexical_index() {
+    let hybrid_search = Arc::new(RecordingHybridSearch::default());
+    let service = ContextServiceImpl::new(
+        Arc::new(MemoryCache::default()),
+        Arc::new(RecordingEmbeddingProvider::new("model-a")),
+        Arc::new(RecordingVectorStore::default()),
+        hybrid_search.clone(),
+    );
+    let stale = vec!["src/a.rs".to_string(), "src/b.rs".to_string()];
+
+    service.delete_file_chunks("main", &stale).await.unwrap();
+
+    let removed = hybrid_search
+        .removed_files
+        .lock()
+        .expect("Lock poisoned")
+        .clone();
+    assert_eq!(removed, stale);
+}
+
+#[test]
+fn test_split_chunks_are_embedded_with_their_definition() {
+    let mut part = chunk("src/sum.rs", "    total += values[7];\n    total");
+    part.metadata = serde_json::json!({
+        "symbol": {"name": "sum", "kind": "fn", "signature": "fn sum(values: &[u64]) -> u64"}
+    });
+    let enrichment = ChunkEnrichment {
+        file_context: false,
+        ..ChunkEnrichment::default()
+    };
+
+    assert_eq!(
+        enrichment.embedding_text(&part),
+        "Definition: fn sum(values: &[u64]) -> u64\n\n    total += values[7];\n    total"
+    );
+    assert_eq!(
+        ChunkEnrichment::disabled().embedding_text(&part),
+        part.content
+    );
+}
+
+#[tokio::test]
+async fn test_expanded_queries_are_searched_and_merged() {
+    let embedding = Arc::new(RecordingEmbeddingProvider::new("model-a"));
+    let vector_store = Arc::new(RecordingVectorStore {
+        rankings: HashMap::from([
+            // "retry calls"
+            (11, vec![search_result("a", 0.5), search_result("b", 0.4)]),
+            // "retry calls backoff"
+            (19, vec![search_result("c", 0.9), search_result("a", 0.7)]),
+        ]),
+        ..Default::default()
+    });
+    let service = context_service(
+        Arc::new(MemoryCache::default()),
+        embedding.clone(),
+        vector_store,
+    )
+    .with_query_expansion(
+        QueryExpansion {
+            max_terms: 4,
+            ..QueryExpansion::disabled()
+        }
+        .with_glossary([("retry", ["backoff"])])
+        .with_max_queries(2),
+    );
+
+    let results = service
+        .search_similar("main", &SearchQuery::new("retry calls", 10))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        embedding.embedded(),
+        vec!["retry calls".to_string(), "retry calls backoff".to_string()]
+    );
+    // Each chunk once, with the best score any query gave it
+    let ranked: Vec<(&str, f64)> = results.iter().map(|r| (r.id.as_str(), r.score)).collect();
+    assert_eq!(ranked, vec![("c", 0.9), ("a", 0.7), ("b", 0.4)]);
+
+    let results = service
+        .search_similar(
+            "main",
+            &SearchQuery::new("retry calls", 2).with_min_score(0.8),
+        )
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].id, "c");
+}
+
+#[tokio::test]
+async fn test_disabled_expansion_embeds_the_query_only() {
+    let embedding = Arc::new(RecordingEmbeddingProvider::new("model-a"));
+    let vector_store = Arc::new(RecordingVectorStore {
+        rankings: HashMap::from([(11, vec![search_result("a", 0.5)])]),
+        ..Default::default()
+    });
+    let service = context_service(
+        Arc::new(MemoryCache::default()),
+        embedding.clone(),
+        vector_store,
+    );
+
+    let results = service
+        .search_similar("main", &SearchQuery::new("retry calls", 10))
+        .await
+        .unwrap();
+
+    assert_eq!(embedding.embedded(), vec!["retry calls".to_string()]);
+    assert_eq!(results.len(), 1);
+}
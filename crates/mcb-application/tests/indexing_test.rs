@@ -0,0 +1,1104 @@
+//! Tests for indexing service file discovery, incremental re-indexing,
+//! chunk batching, indexing operation reporting and cancellation
+
+use mcb_application::domain_services::search::{
+    ContextServiceInterface, IndexingOptions, IndexingServiceInterface, IndexingStatus,
+};
+use mcb_application::ports::admin::{IndexingOperation, IndexingOperationsInterface};
+use mcb_application::ports::infrastructure::{
+    DomainEventStream, EventBusProvider, SnapshotProvider, StateStoreProvider,
+};
+use mcb_application::ports::providers::LanguageChunkingProvider;
+use mcb_application::use_cases::{IgnoreRules, IndexingConcurrency, IndexingServiceImpl};
+use mcb_domain::entities::CodeChunk;
+use mcb_domain::entities::codebase::{CodebaseSnapshot, FileSnapshot, SnapshotChanges};
+use mcb_domain::error::Result;
+use mcb_domain::events::DomainEvent;
+use mcb_domain::value_objects::{
+    Embedding, EmbeddingBatchLimits, Language, SearchQuery, SearchResult,
+};
+use std::collections::{HashMap, HashSet};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::Semaphore;
+
+/// Context service recording the file path of every stored and deleted chunk
+#[derive(Default)]
+struct RecordingContextService {
+    stored_files: Mutex<Vec<String>>,
+    deleted_files: Mutex<Vec<String>>,
+    /// Number of chunks of every `store_chunks` call
+    batch_sizes: Mutex<Vec<usize>>,
+    /// `store_chunks` calls running now, and the most seen at once
+    active_stores: AtomicUsize,
+    max_active_stores: AtomicUsize,
+    batch_limits: EmbeddingBatchLimits,
+    /// When set, every store waits for a permit
+    gate: Option<Arc<Semaphore>>,
+    /// When set, every store takes this long
+    delay: Option<Duration>,
+    /// Collections created by `initialize` and not cleared since
+    collections: Mutex<HashSet<String>>,
+    /// Embedding model, part of the embedding space
+    model: Mutex<String>,
+}
+
+impl RecordingContextService {
+    /// Stored file paths relative to `root`, sorted
+    fn stored_relative(&self, root: &Path) -> Vec<String> {
+        Self::relative(&self.stored_files, root)
+    }
+
+    /// Deleted file paths relative to `root`, sorted
+    fn deleted_relative(&self, root: &Path) -> Vec<String> {
+        Self::relative(&self.deleted_files, root)
+    }
+
+    fn relative(files: &Mutex<Vec<String>>, root: &Path) -> Vec<String> {
+        let mut files: Vec<String> = files
+            .lock()
+            .expect("Lock poisoned")
+            .iter()
+            .map(|f| {
+                Path::new(f)
+                    .strip_prefix(root)
+                    .map(|p| p.to_string_lossy().replace('\\', "/"))
+                    .unwrap_or_else(|_| f.clone())
+            })
+            .collect();
+        files.sort();
+        files
+    }
+
+    fn batch_sizes(&self) -> Vec<usize> {
+        self.batch_sizes.lock().expect("Lock poisoned").clone()
+    }
+
+    fn reset(&self) {
+        self.stored_files.lock().expect("Lock poisoned").clear();
+        self.deleted_files.lock().expect("Lock poisoned").clear();
+    }
+
+    /// Lose a collection behind the indexing service's back
+    fn drop_collection(&self, collection: &str) {
+        self.collections
+            .lock()
+            .expect("Lock poisoned")
+            .remove(collection);
+    }
+
+    fn set_model(&self, model: &str) {
+        *self.model.lock().expect("Lock poisoned") = model.to_string();
+    }
+}
+
+#[async_trait::async_trait]
+impl ContextServiceInterface for RecordingContextService {
+    async fn initialize(&self, collection: &str) -> Result<()> {
+        self.collections
+            .lock()
+            .expect("Lock poisoned")
+            .insert(collection.to_string());
+        Ok(())
+    }
+
+    async fn collection_exists(&self, collection: &str) -> Result<bool> {
+        Ok(self
+            .collections
+            .lock()
+            .expect("Lock poisoned")
+            .contains(collection))
+    }
+
+    async fn store_chunks(&self, _collection: &str, chunks: &[CodeChunk]) -> Result<()> {
+        let active = self.active_stores.fetch_add(1, Ordering::SeqCst) + 1;
+        self.max_active_stores.fetch_max(active, Ordering::SeqCst);
+        if let Some(gate) = &self.gate {
+            gate.acquire().await.expect("gate open").forget();
+        }
+        if let Some(delay) = self.delay {
+            tokio::time::sleep(delay).await;
+        }
+        self.active_stores.fetch_sub(1, Ordering::SeqCst);
+        self.batch_sizes
+            .lock()
+            .expect("Lock poisoned")
+            .push(chunks.len());
+        let mut stored = self.stored_files.lock().expect("Lock poisoned");
+        stored.extend(chunks.iter().map(|c| c.file_path.clone()));
+        Ok(())
+    }
+
+    async fn search_similar(
+        &self,
+        _collection: &str,
+        _query: &SearchQuery,
+    ) -> Result<Vec<SearchResult>> {
+        Ok(Vec::new())
+    }
+
+    async fn embed_text(&self, _text: &str) -> Result<Embedding> {
+        Ok(Embedding {
+            vector: vec![0.0; 4],
+            model: "mock-model".to_string(),
+            dimensions: 4,
+        })
+    }
+
+    async fn delete_file_chunks(&self, _collection: &str, file_paths: &[String]) -> Result<()> {
+        let mut deleted = self.deleted_files.lock().expect("Lock poisoned");
+        deleted.extend(file_paths.iter().cloned());
+        Ok(())
+    }
+
+    async fn clear_collection(&self, collection: &str) -> Result<()> {
+        self.drop_collection(collection);
+        Ok(())
+    }
+
+    async fn get_stats(&self) -> Result<(i64, i64)> {
+        Ok((0, 0))
+    }
+
+    fn embedding_dimensions(&self) -> usize {
+        4
+    }
+
+    fn embedding_space(&self) -> String {
+        format!("mock/{}/4", self.model.lock().expect("Lock poisoned"))
+    }
+
+    fn embedding_batch_limits(&self) -> EmbeddingBatchLimits {
+        self.batch_limits
+    }
+}
+
+/// Chunker producing a single chunk per file
+struct WholeFileChunker;
+
+impl LanguageChunkingProvider for WholeFileChunker {
+    fn language(&self) -> Language {
+        "text".to_string()
+    }
+
+    fn extensions(&self) -> &[&'static str] {
+        &["rs", "py"]
+    }
+
+    fn file_names(&self) -> &[&'static str] {
+        &["Makefile"]
+    }
+
+    fn interpreters(&self) -> &[&'static str] {
+        &["python"]
+    }
+
+    fn chunk(&self, content: &str, file_path: &str) -> Vec<CodeChunk> {
+        vec![CodeChunk {
+            id: file_path.to_string(),
+            content: content.to_string(),
+            file_path: file_path.to_string(),
+            start_line: 1,
+            end_line: content.lines().count().max(1) as u32,
+            language: "text".to_string(),
+            metadata: serde_json::json!({}),
+        }]
+    }
+
+    fn provider_name(&self) -> &str {
+        "whole-file"
+    }
+}
+
+/// Snapshot provider hashing file contents and keeping snapshots in memory
+#[derive(Default)]
+struct MemorySnapshotProvider {
+    saved: Mutex<HashMap<(PathBuf, String), CodebaseSnapshot>>,
+}
+
+#[async_trait::async_trait]
+impl SnapshotProvider for MemorySnapshotProvider {
+    async fn create_snapshot(&self, root_path: &Path) -> Result<CodebaseSnapshot> {
+        self.snapshot_files(root_path, &root_path.to_string_lossy(), &[])
+            .await
+    }
+
+    async fn load_snapshot(&self, root_path: &Path) -> Result<Option<CodebaseSnapshot>> {
+        self.load_collection_snapshot(root_path, &root_path.to_string_lossy())
+            .await
+    }
+
+    async fn compare_snapshots(
+        &self,
+        old_snapshot: &CodebaseSnapshot,
+        new_snapshot: &CodebaseSnapshot,
+    ) -> Result<SnapshotChanges> {
+        let mut changes = SnapshotChanges {
+            added: Vec::new(),
+            modified: Vec::new(),
+            removed: Vec::new(),
+        };
+        for (path, file) in &new_snapshot.files {
+            match old_snapshot.files.get(path) {
+                None => changes.added.push(path.clone()),
+                Some(old) if old.hash != file.hash => changes.modified.push(path.clone()),
+                Some(_) => {}
+            }
+        }
+        for path in old_snapshot.files.keys() {
+            if !new_snapshot.files.contains_key(path) {
+                changes.removed.push(path.clone());
+            }
+        }
+        Ok(changes)
+    }
+
+    async fn get_changed_files(&self, _root_path: &Path) -> Result<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn snapshot_files(
+        &self,
+        root_path: &Path,
+        collection: &str,
+        files: &[PathBuf],
+    ) -> Result<CodebaseSnapshot> {
+        let mut snapshot_files = HashMap::new();
+        for file in files {
+            let content = std::fs::read(file)?;
+            let mut hasher = DefaultHasher::new();
+            content.hash(&mut hasher);
+            let path = file
+                .strip_prefix(root_path)
+                .expect("file under root")
+                .to_string_lossy()
+                .replace('\\', "/");
+            snapshot_files.insert(
+                path.clone(),
+                FileSnapshot {
+                    path,
+                    modified_at: 0,
+                    size: content.len() as u64,
+                    hash: hasher.finish().to_string(),
+                    language: "text".to_string(),
+                },
+            );
+        }
+        Ok(CodebaseSnapshot {
+            id: "memory".to_string(),
+            created_at: 0,
+            collection: collection.to_string(),
+            total_files: snapshot_files.len(),
+            total_size: snapshot_files.values().map(|f| f.size).sum(),
+            files: snapshot_files,
+        })
+    }
+
+    async fn load_collection_snapshot(
+        &self,
+        root_path: &Path,
+        collection: &str,
+    ) -> Result<Option<CodebaseSnapshot>> {
+        let saved = self.saved.lock().expect("Lock poisoned");
+        Ok(saved
+            .get(&(root_path.to_path_buf(), collection.to_string()))
+            .cloned())
+    }
+
+    async fn save_snapshot(&self, root_path: &Path, snapshot: &CodebaseSnapshot) -> Result<()> {
+        let mut saved = self.saved.lock().expect("Lock poisoned");
+        saved.insert(
+            (root_path.to_path_buf(), snapshot.collection.clone()),
+            snapshot.clone(),
+        );
+        Ok(())
+    }
+
+    async fn delete_collection_snapshots(&self, collection: &str) -> Result<()> {
+        let mut saved = self.saved.lock().expect("Lock poisoned");
+        saved.retain(|(_, c), _| c != collection);
+        Ok(())
+    }
+}
+
+/// Event bus recording every published event
+#[derive(Default)]
+struct RecordingEventBus {
+    events: Mutex<Vec<DomainEvent>>,
+}
+
+impl RecordingEventBus {
+    fn events(&self) -> Vec<DomainEvent> {
+        self.events.lock().expect("Lock poisoned").clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl EventBusProvider for RecordingEventBus {
+    async fn publish_event(&self, event: DomainEvent) -> Result<()> {
+        self.events.lock().expect("Lock poisoned").push(event);
+        Ok(())
+    }
+
+    async fn subscribe_events(&self) -> Result<DomainEventStream> {
+        Ok(Box::pin(futures::stream::empty()))
+    }
+
+    fn has_subscribers(&self) -> bool {
+        false
+    }
+
+    async fn publish(&self, _topic: &str, _payload: &[u8]) -> Result<()> {
+        Ok(())
+    }
+
+    async fn subscribe(&self, topic: &str) -> Result<String> {
+        Ok(topic.to_string())
+    }
+}
+
+/// Operations tracker keeping running operations in memory
+#[derive(Default)]
+struct MemoryOperations {
+    operations: Mutex<HashMap<String, IndexingOperation>>,
+    started: Mutex<usize>,
+}
+
+impl IndexingOperationsInterface for MemoryOperations {
+    fn get_operations(&self) -> HashMap<String, IndexingOperation> {
+        self.operations.lock().expect("Lock poisoned").clone()
+    }
+
+    fn start_operation(&self, collection: &str, total_files: usize) -> String {
+        let mut started = self.started.lock().expect("Lock poisoned");
+        *started += 1;
+        let id = format!("op-{}", started);
+        let operation = IndexingOperation {
+            id: id.clone(),
+            collection: collection.to_string(),
+            current_file: None,
+            total_files,
+            processed_files: 0,
+            start_timestamp: 0,
+        };
+        self.operations
+            .lock()
+            .expect("Lock poisoned")
+            .insert(id.clone(), operation);
+        id
+    }
+
+    fn update_total(&self, operation_id: &str, total_files: usize) {
+        if let Some(op) = self
+            .operations
+            .lock()
+            .expect("Lock poisoned")
+            .get_mut(operation_id)
+        {
+            op.total_files = total_files;
+        }
+    }
+
+    fn update_progress(&self, operation_id: &str, current_file: Option<String>, processed: usize) {
+        if let Some(op) = self
+            .operations
+            .lock()
+            .expect("Lock poisoned")
+            .get_mut(operation_id)
+        {
+            op.current_file = current_file;
+            op.processed_files = processed;
+        }
+    }
+
+    fn complete_operation(&self, operation_id: &str) {
+        self.operations
+            .lock()
+            .expect("Lock poisoned")
+            .remove(operation_id);
+    }
+}
+
+/// State store keeping values in memory
+#[derive(Default)]
+struct MemoryStateStore {
+    values: Mutex<HashMap<String, Vec<u8>>>,
+}
+
+#[async_trait::async_trait]
+impl StateStoreProvider for MemoryStateStore {
+    async fn save(&self, key: &str, data: &[u8]) -> Result<()> {
+        self.values
+            .lock()
+            .expect("Lock poisoned")
+            .insert(key.to_string(), data.to_vec());
+        Ok(())
+    }
+
+    async fn load(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        Ok(self.values.lock().expect("Lock poisoned").get(key).cloned())
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        self.values.lock().expect("Lock poisoned").remove(key);
+        Ok(())
+    }
+}
+
+fn indexing_service(context: Arc<RecordingContextService>) -> IndexingServiceImpl {
+    IndexingServiceImpl::new(
+        context,
+        Arc::new(WholeFileChunker),
+        Arc::new(MemorySnapshotProvider::default()),
+        Arc::new(MemoryStateStore::default()),
+        Arc::new(RecordingEventBus::default()),
+        Arc::new(MemoryOperations::default()),
+    )
+}
+
+fn write_file(root: &Path, relative: &str, content: &str) {
+    let path = root.join(relative);
+    std::fs::create_dir_all(path.parent().expect("file has a parent")).expect("create dirs");
+    std::fs::write(path, content).expect("write file");
+}
+
+fn create_codebase() -> tempfile::TempDir {
+    let dir = tempfile::tempdir().expect("create temp dir");
+    let root = dir.path();
+    write_file(root, "src/main.rs", "fn main() {}");
+    write_file(root, "src/lib.py", "def f(): pass");
+    write_file(root, "src/api.generated.rs", "fn generated() {}");
+    write_file(root, "vendor/dep/lib.rs", "fn vendored() {}");
+    write_file(root, "node_modules/pkg/index.js", "module.exports = {}");
+    write_file(root, "README.md", "# readme");
+    dir
+}
+
+async fn index(root: &Path, options: &IndexingOptions) -> (Vec<String>, usize, Vec<String>) {
+    let context = Arc::new(RecordingContextService::default());
+    let service = indexing_service(context.clone());
+    let result = service
+        .index_codebase(root, "test", options)
+        .await
+        .expect("indexing should succeed");
+    (
+        context.stored_relative(root),
+        result.files_skipped,
+        result.errors,
+    )
+}
+
+#[tokio::test]
+async fn test_default_options_index_supported_files() {
+    let dir = create_codebase();
+
+    let (files, _, errors) = index(dir.path(), &IndexingOptions::default()).await;
+
+    assert!(errors.is_empty(), "unexpected errors: {errors:?}");
+    assert_eq!(
+        files,
+        vec![
+            "src/api.generated.rs",
+            "src/lib.py",
+            "src/main.rs",
+            "vendor/dep/lib.rs",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_extensions_restrict_discovery() {
+    let dir = create_codebase();
+    let options = IndexingOptions::default().with_extensions([".PY", "md"]);
+
+    let (files, _, _) = index(dir.path(), &options).await;
+
+    assert_eq!(files, vec!["README.md", "src/lib.py"]);
+}
+
+#[tokio::test]
+async fn test_blank_extensions_do_not_restrict_discovery() {
+    let dir = create_codebase();
+    let options = IndexingOptions::default().with_extensions([" ", "*.", ""]);
+    assert!(options.extensions.is_none());
+
+    let (files, _, _) = index(dir.path(), &options).await;
+    let (all_files, _, _) = index(dir.path(), &IndexingOptions::default()).await;
+
+    assert!(!files.is_empty());
+    assert_eq!(files, all_files);
+}
+
+#[tokio::test]
+async fn test_ignore_patterns_exclude_directories_and_files() {
+    let dir = create_codebase();
+    let options = IndexingOptions::default().with_ignore_patterns(["vendor/", "*.generated.rs"]);
+
+    let (files, _, _) = index(dir.path(), &options).await;
+
+    assert_eq!(files, vec!["src/lib.py", "src/main.rs"]);
+}
+
+#[tokio::test]
+async fn test_max_file_size_skips_large_files() {
+    let dir = create_codebase();
+    write_file(dir.path(), "src/big.rs", &"// padding\n".repeat(100));
+    let options = IndexingOptions::default()
+        .with_extensions(["rs"])
+        .with_max_file_size(64);
+
+    let (files, skipped, _) = index(dir.path(), &options).await;
+
+    assert!(!files.contains(&"src/big.rs".to_string()));
+    assert!(files.contains(&"src/main.rs".to_string()));
+    assert_eq!(skipped, 1);
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_symlinks_followed_only_when_enabled() {
+    let dir = create_codebase();
+    let root = dir.path();
+    let external = tempfile::tempdir().expect("create temp dir");
+    write_file(external.path(), "shared.rs", "fn shared() {}");
+    std::os::unix::fs::symlink(external.path(), root.join("linked")).expect("symlink");
+    // A link back to the root would recurse forever without loop detection
+    std::os::unix::fs::symlink(root, root.join("src/loop")).expect("symlink");
+
+    let (files, _, _) = index(root, &IndexingOptions::default()).await;
+    assert!(!files.contains(&"linked/shared.rs".to_string()));
+
+    let options = IndexingOptions::default().with_follow_symlinks(true);
+    let (files, _, errors) = index(root, &options).await;
+    assert!(files.contains(&"linked/shared.rs".to_string()));
+    assert_eq!(
+        files.iter().filter(|f| f.ends_with("main.rs")).count(),
+        1,
+        "files reachable through the loop are indexed once"
+    );
+    assert!(!errors.is_empty(), "the symlink loop is reported");
+}
+
+#[tokio::test]
+async fn test_ignore_files_are_honored() {
+    let dir = create_codebase();
+    let root = dir.path();
+    write_file(root, ".gitignore", "vendor/\n");
+    write_file(root, "src/.gitignore", "*.generated.rs\n");
+    write_file(root, ".ignore", "src/lib.py\n");
+    write_file(root, "dist/bundle.js", "console.log('bundle');");
+    write_file(root, ".mcp-context-ignore", "dist/\n");
+
+    let (files, _, _) = index(root, &IndexingOptions::default()).await;
+
+    assert_eq!(files, vec!["src/main.rs"]);
+}
+
+#[test]
+fn test_ignore_rules_match_discovery_path_by_path() {
+    let dir = create_codebase();
+    let root = dir.path();
+    write_file(root, ".gitignore", "vendor/\n");
+    write_file(
+        root,
+        "src/.gitignore",
+        "*.generated.rs\n!kept.generated.rs\n",
+    );
+    write_file(root, ".mcp-context-ignore", "dist/\n");
+    let options = IndexingOptions::default().with_ignore_patterns(["*.py"]);
+    let rules = IgnoreRules::new(root, &options);
+
+    for ignored in [
+        "vendor/dep/lib.rs",
+        "node_modules/pkg/index.js",
+        "src/api.generated.rs",
+        "src/lib.py",
+        "dist/bundle.js",
+        ".git/HEAD",
+    ] {
+        assert!(rules.is_ignored(&root.join(ignored), false), "{ignored}");
+    }
+    for kept in ["src/main.rs", "src/kept.generated.rs", "README.md"] {
+        assert!(!rules.is_ignored(&root.join(kept), false), "{kept}");
+    }
+
+    // Ignore files are read once, until reloaded
+    write_file(root, ".gitignore", "vendor/\nREADME.md\n");
+    assert!(!rules.is_ignored(&root.join("README.md"), false));
+    rules.reload();
+    assert!(rules.is_ignored(&root.join("README.md"), false));
+    assert!(IgnoreRules::is_ignore_file(&root.join("src/.gitignore")));
+}
+
+#[tokio::test]
+async fn test_binary_minified_and_lock_files_are_skipped() {
+    let dir = create_codebase();
+    let root = dir.path();
+    std::fs::write(root.join("src/blob.rs"), b"fn x() {}\0\x01\x02").expect("write file");
+    write_file(root, "src/app.min.js", "var a=1;");
+    write_file(root, "src/bundle.js", &"var a=1;".repeat(500));
+    write_file(root, "package-lock.json", "{}");
+    let options = IndexingOptions::default().with_extensions(["rs", "js", "json"]);
+
+    let (files, skipped, errors) = index(root, &options).await;
+
+    assert!(errors.is_empty(), "unexpected errors: {errors:?}");
+    assert_eq!(
+        files,
+        vec!["src/api.generated.rs", "src/main.rs", "vendor/dep/lib.rs"]
+    );
+    assert_eq!(skipped, 3);
+}
+
+#[tokio::test]
+async fn test_chunker_decides_by_file_name_and_shebang() {
+    let dir = create_codebase();
+    let root = dir.path();
+    write_file(root, "Makefile", "all:\n\tcargo build\n");
+    write_file(
+        root,
+        "bin/deploy",
+        "#!/usr/bin/env python3\nprint('deploy')\n",
+    );
+    write_file(root, "bin/setup", "#!/bin/sh\necho setup\n");
+    write_file(root, "notes", "plain text without a shebang");
+    let options = IndexingOptions::default().with_ignore_patterns(["src/", "vendor/"]);
+
+    let (files, _, _) = index(root, &options).await;
+
+    assert_eq!(files, vec!["Makefile", "bin/deploy"]);
+}
+
+#[tokio::test]
+async fn test_reindex_embeds_only_changed_files() {
+    let dir = create_codebase();
+    let root = dir.path();
+    let context = Arc::new(RecordingContextService::default());
+    let service = indexing_service(context.clone());
+    let options = IndexingOptions::default();
+    service
+        .index_codebase(root, "test", &options)
+        .await
+        .expect("initial indexing should succeed");
+    assert_eq!(context.stored_relative(root).len(), 4);
+
+    write_file(root, "src/main.rs", "fn main() { run() }");
+    write_file(root, "src/new.rs", "fn new() {}");
+    std::fs::remove_file(root.join("src/lib.py")).expect("remove file");
+    context.reset();
+
+    let result = service
+        .index_codebase(root, "test", &options)
+        .await
+        .expect("re-indexing should succeed");
+
+    assert_eq!(result.files_processed, 2);
+    assert_eq!(
+        context.stored_relative(root),
+        vec!["src/main.rs", "src/new.rs"]
+    );
+    assert_eq!(
+        context.deleted_relative(root),
+        vec!["src/lib.py", "src/main.rs"]
+    );
+
+    context.reset();
+    let result = service
+        .index_codebase(root, "test", &options)
+        .await
+        .expect("re-indexing should succeed");
+    assert_eq!(result.files_processed, 0, "nothing changed");
+    assert!(context.deleted_relative(root).is_empty());
+}
+
+#[tokio::test]
+async fn test_clear_collection_forces_full_reindex() {
+    let dir = create_codebase();
+    let root = dir.path();
+    let context = Arc::new(RecordingContextService::default());
+    let service = indexing_service(context.clone());
+    let options = IndexingOptions::default();
+    service
+        .index_codebase(root, "test", &options)
+        .await
+        .expect("initial indexing should succeed");
+
+    service
+        .clear_collection("test")
+        .await
+        .expect("clear should succeed");
+    context.reset();
+    service
+        .index_codebase(root, "test", &options)
+        .await
+        .expect("re-indexing should succeed");
+
+    assert_eq!(context.stored_relative(root).len(), 4);
+}
+
+#[tokio::test]
+async fn test_missing_collection_forces_full_reindex() {
+    let dir = create_codebase();
+    let root = dir.path();
+    let context = Arc::new(RecordingContextService::default());
+    let service = indexing_service(context.clone());
+    let options = IndexingOptions::default();
+    service
+        .index_codebase(root, "test", &options)
+        .await
+        .expect("initial indexing should succeed");
+
+    // An in-memory vector store loses its collections on restart
+    context.drop_collection("test");
+    context.reset();
+    let result = service
+        .index_codebase(root, "test", &options)
+        .await
+        .expect("re-indexing should succeed");
+
+    assert_eq!(result.files_processed, 4);
+    assert_eq!(context.stored_relative(root).len(), 4);
+}
+
+#[tokio::test]
+async fn test_switching_embedder_forces_full_reindex() {
+    let dir = create_codebase();
+    let root = dir.path();
+    let context = Arc::new(RecordingContextService::default());
+    let service = indexing_service(context.clone());
+    let options = IndexingOptions::default();
+    service
+        .index_codebase(root, "test", &options)
+        .await
+        .expect("initial indexing should succeed");
+
+    context.set_model("other-model");
+    context.reset();
+    service
+        .index_codebase(root, "test", &options)
+        .await
+        .expect("re-indexing should succeed");
+
+    assert_eq!(context.stored_relative(root).len(), 4);
+    // Chunks embedded by the previous model are replaced
+    assert_eq!(context.deleted_relative(root).len(), 4);
+}
+
+#[tokio::test]
+async fn test_reindex_files_only_touches_given_paths() {
+    let dir = create_codebase();
+    let root = dir.path();
+    let context = Arc::new(RecordingContextService::default());
+    let service = indexing_service(context.clone());
+    let options = IndexingOptions::default();
+    service
+        .index_codebase(root, "test", &options)
+        .await
+        .expect("initial indexing should succeed");
+
+    write_file(root, "src/main.rs", "fn main() { run() }");
+    write_file(root, "src/lib.py", "def g(): pass");
+    std::fs::remove_file(root.join("src/api.generated.rs")).expect("remove file");
+    context.reset();
+
+    let files = [root.join("src/main.rs"), root.join("src/api.generated.rs")];
+    let result = service
+        .reindex_files(root, "test", &files, &options)
+        .await
+        .expect("re-indexing files should succeed");
+
+    assert_eq!(result.files_processed, 1);
+    assert_eq!(context.stored_relative(root), vec!["src/main.rs"]);
+    assert_eq!(
+        context.deleted_relative(root),
+        vec!["src/api.generated.rs", "src/main.rs"]
+    );
+
+    // Changes outside the given paths are picked up by the next full pass
+    context.reset();
+    service
+        .index_codebase(root, "test", &options)
+        .await
+        .expect("re-indexing should succeed");
+    assert_eq!(context.stored_relative(root), vec!["src/lib.py"]);
+}
+
+#[tokio::test]
+async fn test_reindex_files_waits_for_a_first_full_index() {
+    let dir = create_codebase();
+    let root = dir.path();
+    let context = Arc::new(RecordingContextService::default());
+    let service = indexing_service(context.clone());
+    let options = IndexingOptions::default();
+
+    // As after a cancelled first run: no snapshot to sync against
+    let files = [root.join("src/main.rs")];
+    let result = service
+        .reindex_files(root, "test", &files, &options)
+        .await
+        .expect("re-indexing files should succeed");
+
+    assert_eq!(result.files_processed, 0);
+    assert!(context.stored_relative(root).is_empty());
+    assert!(context.deleted_relative(root).is_empty());
+
+    service
+        .index_codebase(root, "test", &options)
+        .await
+        .expect("initial indexing should succeed");
+    assert_eq!(context.stored_relative(root).len(), 4);
+}
+
+/// Wait until a background operation of the collection is no longer running
+async fn wait_until_idle(service: &IndexingServiceImpl, collection: &str) -> IndexingStatus {
+    tokio::time::timeout(Duration::from_secs(10), async {
+        loop {
+            let status = service.get_collection_status(collection);
+            if !status.is_indexing {
+                return status;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("indexing should stop")
+}
+
+#[tokio::test]
+async fn test_start_indexing_runs_in_background_and_reports_progress() {
+    let dir = create_codebase();
+    let root = dir.path();
+    let context = Arc::new(RecordingContextService::default());
+    let event_bus = Arc::new(RecordingEventBus::default());
+    let operations = Arc::new(MemoryOperations::default());
+    let service = IndexingServiceImpl::new(
+        context.clone(),
+        Arc::new(WholeFileChunker),
+        Arc::new(MemorySnapshotProvider::default()),
+        Arc::new(MemoryStateStore::default()),
+        event_bus.clone(),
+        operations.clone(),
+    );
+    let options = IndexingOptions::default();
+
+    let operation_id = service
+        .start_indexing(root, "test", &options)
+        .await
+        .expect("indexing should start");
+    let status = service.get_collection_status("test");
+    assert!(status.is_indexing);
+    assert_eq!(status.operation_id.as_deref(), Some(operation_id.as_str()));
+    assert!(operations.get_operations().contains_key(&operation_id));
+    assert!(
+        service
+            .start_indexing(root, "test", &options)
+            .await
+            .is_err(),
+        "a collection is indexed by one operation at a time"
+    );
+
+    let status = wait_until_idle(&service, "test").await;
+
+    assert_eq!(status.total_files, 4);
+    assert_eq!(status.processed_files, 4);
+    assert_eq!(status.progress, 1.0);
+    assert!(status.errors.is_empty());
+    assert_eq!(service.get_status().operation_id, status.operation_id);
+    assert!(operations.get_operations().is_empty());
+    assert_eq!(context.stored_relative(root).len(), 4);
+
+    let events = event_bus.events();
+    assert!(matches!(
+        events.first(),
+        Some(DomainEvent::IndexingStarted { total_files: 4, .. })
+    ));
+    let progress = events
+        .iter()
+        .filter(|e| matches!(e, DomainEvent::IndexingProgress { total: 4, .. }))
+        .count();
+    assert_eq!(progress, 4);
+    assert!(matches!(
+        events.last(),
+        Some(DomainEvent::IndexingCompleted { chunks: 4, .. })
+    ));
+}
+
+#[tokio::test]
+async fn test_batches_pack_chunks_of_several_files() {
+    let dir = create_codebase();
+    let root = dir.path();
+    let context = Arc::new(RecordingContextService {
+        batch_limits: EmbeddingBatchLimits::new(3, usize::MAX),
+        ..Default::default()
+    });
+    let service = indexing_service(context.clone());
+
+    let result = service
+        .index_codebase(root, "test", &IndexingOptions::default())
+        .await
+        .expect("indexing should succeed");
+
+    assert_eq!(result.files_processed, 4);
+    assert_eq!(result.chunks_created, 4);
+    assert_eq!(context.batch_sizes(), vec![3, 1]);
+    assert_eq!(context.stored_relative(root).len(), 4);
+}
+
+#[tokio::test]
+async fn test_batches_respect_token_budget() {
+    let dir = tempfile::tempdir().expect("create temp dir");
+    let root = dir.path();
+    // 40 characters estimate to 10 tokens each
+    for name in ["a.rs", "b.rs", "c.rs"] {
+        write_file(root, name, &"x".repeat(40));
+    }
+    let context = Arc::new(RecordingContextService {
+        batch_limits: EmbeddingBatchLimits::new(10, 25),
+        ..Default::default()
+    });
+    let service = indexing_service(context.clone());
+
+    service
+        .index_codebase(root, "test", &IndexingOptions::default())
+        .await
+        .expect("indexing should succeed");
+
+    assert_eq!(context.batch_sizes(), vec![2, 1]);
+}
+
+#[tokio::test]
+async fn test_concurrent_embeddings_are_bounded() {
+    let dir = tempfile::tempdir().expect("create temp dir");
+    let root = dir.path();
+    for i in 0..8 {
+        write_file(root, &format!("file{}.rs", i), &format!("fn f{}() {{}}", i));
+    }
+    let context = Arc::new(RecordingContextService {
+        batch_limits: EmbeddingBatchLimits::new(1, usize::MAX),
+        delay: Some(Duration::from_millis(20)),
+        ..Default::default()
+    });
+    let service = indexing_service(context.clone()).with_concurrency(IndexingConcurrency {
+        max_concurrent_reads: 4,
+        max_concurrent_embeddings: 2,
+    });
+
+    let result = service
+        .index_codebase(root, "test", &IndexingOptions::default())
+        .await
+        .expect("indexing should succeed");
+
+    assert_eq!(result.files_processed, 8);
+    assert_eq!(context.batch_sizes(), vec![1; 8]);
+    assert_eq!(context.max_active_stores.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_collection_status_defaults_to_idle() {
+    let service = indexing_service(Arc::new(RecordingContextService::default()));
+
+    let status = service.get_collection_status("unknown");
+
+    assert!(!status.is_indexing);
+    assert!(status.operation_id.is_none());
+    assert_eq!(status.collection.as_deref(), Some("unknown"));
+}
+
+#[tokio::test]
+async fn test_cancelled_indexing_resumes_from_checkpoint() {
+    let dir = create_codebase();
+    let root = dir.path();
+    let gate = Arc::new(Semaphore::new(2));
+    // One file per batch and one batch at a time, stalling on the third file
+    let context = Arc::new(RecordingContextService {
+        batch_limits: EmbeddingBatchLimits::new(1, usize::MAX),
+        gate: Some(gate.clone()),
+        ..Default::default()
+    });
+    let snapshots = Arc::new(MemorySnapshotProvider::default());
+    let state_store = Arc::new(MemoryStateStore::default());
+    let service = IndexingServiceImpl::new(
+        context.clone(),
+        Arc::new(WholeFileChunker),
+        snapshots.clone(),
+        state_store.clone(),
+        Arc::new(RecordingEventBus::default()),
+        Arc::new(MemoryOperations::default()),
+    )
+    .with_concurrency(IndexingConcurrency {
+        max_concurrent_reads: 1,
+        max_concurrent_embeddings: 1,
+    });
+    let options = IndexingOptions::default();
+
+    let operation_id = service
+        .start_indexing(root, "test", &options)
+        .await
+        .expect("indexing should start");
+    tokio::time::timeout(Duration::from_secs(10), async {
+        while context.stored_relative(root).len() < 2 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("two files should be stored");
+    service
+        .cancel_indexing(&operation_id)
+        .await
+        .expect("running operation should be cancellable");
+    gate.add_permits(Semaphore::MAX_PERMITS / 2);
+
+    let status = wait_until_idle(&service, "test").await;
+    assert!(status.cancelled);
+    let committed = context.stored_relative(root);
+    assert!(committed.len() < 4, "cancelled before the last file");
+    assert!(status.errors.iter().any(|e| e.contains("Cancelled")));
+    assert!(
+        service.cancel_indexing(&operation_id).await.is_err(),
+        "a finished operation cannot be cancelled"
+    );
+
+    // A restarted service resumes from the persisted checkpoint
+    context.reset();
+    let restarted = IndexingServiceImpl::new(
+        context.clone(),
+        Arc::new(WholeFileChunker),
+        snapshots,
+        state_store.clone(),
+        Arc::new(RecordingEventBus::default()),
+        Arc::new(MemoryOperations::default()),
+    );
+    let result = restarted
+        .index_codebase(root, "test", &options)
+        .await
+        .expect("resumed indexing should succeed");
+
+    let resumed = context.stored_relative(root);
+    assert_eq!(result.files_processed, 4 - committed.len());
+    assert!(resumed.iter().all(|f| !committed.contains(f)));
+    assert!(
+        context
+            .deleted_relative(root)
+            .iter()
+            .all(|f| !committed.contains(f))
+    );
+    assert!(!restarted.get_collection_status("test").cancelled);
+    assert!(
+        state_store
+            .load("indexing/checkpoint/test")
+            .await
+            .expect("load checkpoint")
+            .is_none(),
+        "checkpoint is dropped once the snapshot is saved"
+    );
+}
+
+#[tokio::test]
+async fn test_cancel_unknown_operation_fails() {
+    let service = indexing_service(Arc::new(RecordingContextService::default()));
+
+    assert!(service.cancel_indexing("missing").await.is_err());
+}
@@ -1,6 +1,7 @@
 //! Tests for provider registries
 //!
-//! Tests the auto-registration system for embedding, vector store, cache, and language providers.
+//! Tests the auto-registration system for embedding, vector store, cache, language,
+//! hybrid search and reranker providers.
 
 #[cfg(test)]
 mod embedding_registry_tests {
@@ -89,3 +90,63 @@ mod language_registry_tests {
         assert_eq!(config.overlap, Some(50));
     }
 }
+
+#[cfg(test)]
+mod hybrid_search_registry_tests {
+    use mcb_application::ports::registry::hybrid_search::*;
+
+    #[test]
+    fn test_config_builder() {
+        let config = HybridSearchProviderConfig::new("hybrid")
+            .with_weights(0.3, 0.7)
+            .with_bm25_params(1.5, 0.6)
+            .with_extra("custom", "value");
+
+        assert_eq!(config.provider, "hybrid");
+        assert_eq!(config.bm25_weight, Some(0.3));
+        assert_eq!(config.semantic_weight, Some(0.7));
+        assert_eq!(config.bm25_k1, Some(1.5));
+        assert_eq!(config.bm25_b, Some(0.6));
+        assert_eq!(config.extra.get("custom"), Some(&"value".to_string()));
+    }
+
+    #[test]
+    fn test_resolve_unknown_provider_lists_available() {
+        let config = HybridSearchProviderConfig::new("does-not-exist");
+        let err = resolve_hybrid_search_provider(&config)
+            .err()
+            .expect("unknown provider should fail");
+        assert!(err.contains("does-not-exist"));
+    }
+}
+
+#[cfg(test)]
+mod reranker_registry_tests {
+    use mcb_application::ports::registry::reranker::*;
+
+    #[test]
+    fn test_config_builder() {
+        let config = RerankerProviderConfig::new("http")
+            .with_model("rerank-2")
+            .with_api_key("secret")
+            .with_base_url("http://localhost:8080")
+            .with_timeout_secs(5)
+            .with_extra("custom", "value");
+
+        assert_eq!(config.provider, "http");
+        assert_eq!(config.model, Some("rerank-2".to_string()));
+        assert_eq!(config.api_key, Some("secret".to_string()));
+        assert_eq!(config.base_url, Some("http://localhost:8080".to_string()));
+        assert_eq!(config.timeout_secs, Some(5));
+        assert_eq!(config.extra.get("custom"), Some(&"value".to_string()));
+    }
+
+    #[test]
+    fn test_resolve_unknown_provider_lists_available() {
+        let config = RerankerProviderConfig::new("does-not-exist");
+        let err = resolve_reranker_provider(&config)
+            .err()
+            .expect("unknown provider should fail");
+        assert!(err.contains("does-not-exist"));
+    }
+}
@@ -1,8 +1,13 @@
 //! Tests for search domain services
 
 use mcb_application::domain_services::search::SearchServiceInterface;
+use mcb_application::ports::providers::{
+    HybridSearchProvider, HybridSearchResult, RerankerProvider,
+};
 use mcb_application::use_cases::SearchServiceImpl;
-use std::sync::Arc;
+use mcb_domain::value_objects::{FusionStrategy, QueryExpansion, SearchQuery, SearchResult};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 
 // Mock implementation for testing
 #[derive(Clone)]
@@ -14,6 +19,10 @@ impl mcb_application::ports::services::ContextServiceInterface for MockContextSe
         Ok(())
     }
 
+    async fn collection_exists(&self, _collection: &str) -> mcb_domain::error::Result<bool> {
+        Ok(true)
+    }
+
     async fn store_chunks(
         &self,
         _collection: &str,
@@ -25,8 +34,7 @@ impl mcb_application::ports::services::ContextServiceInterface for MockContextSe
     async fn search_similar(
         &self,
         _collection: &str,
-        _query: &str,
-        _limit: usize,
+        _query: &SearchQuery,
     ) -> mcb_domain::error::Result<Vec<mcb_domain::value_objects::SearchResult>> {
         Ok(Vec::new())
     }
@@ -42,6 +50,14 @@ impl mcb_application::ports::services::ContextServiceInterface for MockContextSe
         })
     }
 
+    async fn delete_file_chunks(
+        &self,
+        _collection: &str,
+        _file_paths: &[String],
+    ) -> mcb_domain::error::Result<()> {
+        Ok(())
+    }
+
     async fn clear_collection(&self, _collection: &str) -> mcb_domain::error::Result<()> {
         Ok(())
     }
@@ -53,6 +69,339 @@ impl mcb_application::ports::services::ContextServiceInterface for MockContextSe
     fn embedding_dimensions(&self) -> usize {
         384
     }
+
+    fn embedding_space(&self) -> String {
+        "mock/mock-model/384".to_string()
+    }
+}
+
+/// Context service returning a fixed candidate list and recording the requested query
+#[derive(Default)]
+struct CandidateContextService {
+    requested: Mutex<Option<SearchQuery>>,
+}
+
+impl CandidateContextService {
+    fn requested_query(&self) -> SearchQuery {
+        self.requested
+            .lock()
+            .expect("Lock poisoned")
+            .clone()
+            .expect("search_similar should have been called")
+    }
+}
+
+#[async_trait::async_trait]
+impl mcb_application::ports::services::ContextServiceInterface for CandidateContextService {
+    async fn initialize(&self, _collection: &str) -> mcb_domain::error::Result<()> {
+        Ok(())
+    }
+
+    async fn collection_exists(&self, _collection: &str) -> mcb_domain::error::Result<bool> {
+        Ok(true)
+    }
+
+    async fn store_chunks(
+        &self,
+        _collection: &str,
+        _chunks: &[mcb_domain::entities::CodeChunk],
+    ) -> mcb_domain::error::Result<()> {
+        Ok(())
+    }
+
+    async fn search_similar(
+        &self,
+        _collection: &str,
+        query: &SearchQuery,
+    ) -> mcb_domain::error::Result<Vec<SearchResult>> {
+        *self.requested.lock().expect("Lock poisoned") = Some(query.clone());
+        Ok((0..query.limit)
+            .map(|i| SearchResult {
+                id: format!("chunk-{i}"),
+                file_path: format!("src/file_{i}.rs"),
+                start_line: 1,
+                content: "fn candidate() {}".to_string(),
+                score: 0.9 - i as f64 * 0.01,
+                language: "rust".to_string(),
+                symbol: None,
+            })
+            .collect())
+    }
+
+    async fn embed_text(
+        &self,
+        _text: &str,
+    ) -> mcb_domain::error::Result<mcb_domain::value_objects::Embedding> {
+        Ok(mcb_domain::value_objects::Embedding {
+            vector: vec![0.0; 384],
+            model: "mock-model".to_string(),
+            dimensions: 384,
+        })
+    }
+
+    async fn delete_file_chunks(
+        &self,
+        _collection: &str,
+        _file_paths: &[String],
+    ) -> mcb_domain::error::Result<()> {
+        Ok(())
+    }
+
+    async fn clear_collection(&self, _collection: &str) -> mcb_domain::error::Result<()> {
+        Ok(())
+    }
+
+    async fn get_stats(&self) -> mcb_domain::error::Result<(i64, i64)> {
+        Ok((0, 0))
+    }
+
+    fn embedding_dimensions(&self) -> usize {
+        384
+    }
+
+    fn embedding_space(&self) -> String {
+        "mock/mock-model/384".to_string()
+    }
+}
+
+/// Hybrid provider that reverses candidate order and assigns a fixed BM25 score
+struct ReversingHybridSearch;
+
+#[async_trait::async_trait]
+impl HybridSearchProvider for ReversingHybridSearch {
+    async fn index_chunks(
+        &self,
+        _collection: &str,
+        _chunks: &[mcb_domain::entities::CodeChunk],
+    ) -> mcb_domain::error::Result<()> {
+        Ok(())
+    }
+
+    async fn remove_chunks(
+        &self,
+        _collection: &str,
+        _chunk_ids: &[String],
+    ) -> mcb_domain::error::Result<()> {
+        Ok(())
+    }
+
+    async fn remove_file_chunks(
+        &self,
+        _collection: &str,
+        _file_paths: &[String],
+    ) -> mcb_domain::error::Result<()> {
+        Ok(())
+    }
+
+    async fn search(
+        &self,
+        _collection: &str,
+        _query: &str,
+        semantic_results: Vec<SearchResult>,
+        limit: usize,
+    ) -> mcb_domain::error::Result<Vec<HybridSearchResult>> {
+        Ok(semantic_results
+            .into_iter()
+            .rev()
+            .take(limit)
+            .map(|result| HybridSearchResult {
+                semantic_score: result.score as f32,
+                bm25_score: 1.0,
+                hybrid_score: 1.0 - result.score as f32,
+                rerank_score: None,
+                result,
+            })
+            .collect())
+    }
+
+    async fn clear_collection(&self, _collection: &str) -> mcb_domain::error::Result<()> {
+        Ok(())
+    }
+
+    async fn get_stats(&self) -> HashMap<String, serde_json::Value> {
+        HashMap::new()
+    }
+}
+
+/// Hybrid provider scoring by rank like RRF, ahead of a lexical-only hit
+struct RankFusingHybridSearch;
+
+#[async_trait::async_trait]
+impl HybridSearchProvider for RankFusingHybridSearch {
+    async fn index_chunks(
+        &self,
+        _collection: &str,
+        _chunks: &[mcb_domain::entities::CodeChunk],
+    ) -> mcb_domain::error::Result<()> {
+        Ok(())
+    }
+
+    async fn remove_chunks(
+        &self,
+        _collection: &str,
+        _chunk_ids: &[String],
+    ) -> mcb_domain::error::Result<()> {
+        Ok(())
+    }
+
+    async fn remove_file_chunks(
+        &self,
+        _collection: &str,
+        _file_paths: &[String],
+    ) -> mcb_domain::error::Result<()> {
+        Ok(())
+    }
+
+    async fn search(
+        &self,
+        _collection: &str,
+        _query: &str,
+        semantic_results: Vec<SearchResult>,
+        limit: usize,
+    ) -> mcb_domain::error::Result<Vec<HybridSearchResult>> {
+        let lexical_only = SearchResult {
+            id: "lexical-only".to_string(),
+            file_path: "src/lexical.rs".to_string(),
+            start_line: 1,
+            content: "fn query() {}".to_string(),
+            score: 0.0,
+            language: "rust".to_string(),
+            symbol: None,
+        };
+        Ok(std::iter::once(lexical_only)
+            .chain(semantic_results)
+            .take(limit)
+            .enumerate()
+            .map(|(rank, result)| HybridSearchResult {
+                semantic_score: result.score as f32,
+                bm25_score: 1.0,
+                hybrid_score: 1.0 / (60.0 + rank as f32 + 1.0),
+                rerank_score: None,
+                result,
+            })
+            .collect())
+    }
+
+    async fn clear_collection(&self, _collection: &str) -> mcb_domain::error::Result<()> {
+        Ok(())
+    }
+
+    async fn get_stats(&self) -> HashMap<String, serde_json::Value> {
+        HashMap::new()
+    }
+}
+
+/// Hybrid provider recording the full query it was asked to fuse for
+#[derive(Default)]
+struct RecordingHybridSearch {
+    query: Mutex<Option<SearchQuery>>,
+}
+
+#[async_trait::async_trait]
+impl HybridSearchProvider for RecordingHybridSearch {
+    async fn index_chunks(
+        &self,
+        _collection: &str,
+        _chunks: &[mcb_domain::entities::CodeChunk],
+    ) -> mcb_domain::error::Result<()> {
+        Ok(())
+    }
+
+    async fn remove_chunks(
+        &self,
+        _collection: &str,
+        _chunk_ids: &[String],
+    ) -> mcb_domain::error::Result<()> {
+        Ok(())
+    }
+
+    async fn remove_file_chunks(
+        &self,
+        _collection: &str,
+        _file_paths: &[String],
+    ) -> mcb_domain::error::Result<()> {
+        Ok(())
+    }
+
+    async fn search(
+        &self,
+        _collection: &str,
+        _query: &str,
+        semantic_results: Vec<SearchResult>,
+        limit: usize,
+    ) -> mcb_domain::error::Result<Vec<HybridSearchResult>> {
+        Ok(semantic_results
+            .into_iter()
+            .take(limit)
+            .map(HybridSearchResult::semantic_only)
+            .collect())
+    }
+
+    async fn search_query(
+        &self,
+        collection: &str,
+        query: &SearchQuery,
+        semantic_results: Vec<SearchResult>,
+    ) -> mcb_domain::error::Result<Vec<HybridSearchResult>> {
+        *self.query.lock().expect("Lock poisoned") = Some(query.clone());
+        self.search(collection, &query.text, semantic_results, query.limit)
+            .await
+    }
+
+    async fn clear_collection(&self, _collection: &str) -> mcb_domain::error::Result<()> {
+        Ok(())
+    }
+
+    async fn get_stats(&self) -> HashMap<String, serde_json::Value> {
+        HashMap::new()
+    }
+}
+
+/// Reranker scoring documents with a fixed rule and recording how many it saw
+struct StubReranker {
+    scores: fn(usize) -> Vec<f32>,
+    documents: Mutex<Option<usize>>,
+}
+
+impl StubReranker {
+    /// Scores rising with position, reversing the order it is given
+    fn reversing() -> Self {
+        Self::new(|n| (0..n).map(|i| i as f32).collect())
+    }
+
+    fn new(scores: fn(usize) -> Vec<f32>) -> Self {
+        Self {
+            scores,
+            documents: Mutex::new(None),
+        }
+    }
+
+    fn documents(&self) -> usize {
+        self.documents
+            .lock()
+            .expect("Lock poisoned")
+            .expect("rerank should have been called")
+    }
+}
+
+#[async_trait::async_trait]
+impl RerankerProvider for StubReranker {
+    async fn rerank(
+        &self,
+        _query: &str,
+        documents: &[String],
+    ) -> mcb_domain::error::Result<Vec<f32>> {
+        *self.documents.lock().expect("Lock poisoned") = Some(documents.len());
+        Ok((self.scores)(documents.len()))
+    }
+
+    fn provider_name(&self) -> &str {
+        "stub"
+    }
+
+    fn model_name(&self) -> &str {
+        "stub-model"
+    }
 }
 
 #[test]
@@ -62,7 +411,7 @@ fn test_search_service_creation() {
         dyn mcb_application::domain_services::search::ContextServiceInterface,
     > = Arc::new(MockContextService);
 
-    let search_service = SearchServiceImpl::new(context_service);
+    let search_service = SearchServiceImpl::new(context_service, Arc::new(ReversingHybridSearch));
 
     // Test that service can be created without panicking
     let _service: Box<dyn SearchServiceInterface> = Box::new(search_service);
@@ -75,12 +424,12 @@ fn test_search_service_search() {
         dyn mcb_application::domain_services::search::ContextServiceInterface,
     > = Arc::new(MockContextService);
 
-    let search_service = SearchServiceImpl::new(context_service);
+    let search_service = SearchServiceImpl::new(context_service, Arc::new(ReversingHybridSearch));
 
     // Test search functionality (will return empty results with mock)
     let result = tokio::runtime::Runtime::new().unwrap().block_on(async {
         search_service
-            .search("test-collection", "test-query", 10)
+            .search("test-collection", &SearchQuery::new("test-query", 10))
             .await
     });
 
@@ -88,3 +437,252 @@ fn test_search_service_search() {
     assert!(result.is_ok());
     assert!(result.unwrap().is_empty());
 }
+
+#[tokio::test]
+async fn test_search_service_overfetches_and_fuses() {
+    let context_service = Arc::new(CandidateContextService::default());
+    let search_service =
+        SearchServiceImpl::new(context_service.clone(), Arc::new(ReversingHybridSearch));
+
+    let results = search_service
+        .search("test-collection", &SearchQuery::new("ConfigLoader", 5))
+        .await
+        .expect("search should succeed");
+
+    // Semantic candidates are over-fetched so BM25 can promote lexical matches
+    let requested = context_service.requested_query().limit;
+    assert_eq!(requested, 5 * mcb_domain::SEARCH_CANDIDATE_MULTIPLIER);
+
+    // Ranking comes from the hybrid provider, trimmed to the caller's limit
+    assert_eq!(results.len(), 5);
+    assert_eq!(
+        results[0].result.file_path,
+        format!("src/file_{}.rs", requested - 1)
+    );
+    assert!((results[0].bm25_score - 1.0).abs() < f32::EPSILON);
+}
+
+#[tokio::test]
+async fn test_search_service_caps_candidate_pool() {
+    let context_service = Arc::new(CandidateContextService::default());
+    let search_service =
+        SearchServiceImpl::new(context_service.clone(), Arc::new(ReversingHybridSearch));
+
+    search_service
+        .search("test-collection", &SearchQuery::new("query", 500))
+        .await
+        .expect("search should succeed");
+
+    // Never fetch fewer candidates than the caller asked for
+    assert_eq!(context_service.requested_query().limit, 500);
+}
+
+#[tokio::test]
+async fn test_search_service_forwards_filter_and_min_score() {
+    let context_service = Arc::new(CandidateContextService::default());
+    let search_service =
+        SearchServiceImpl::new(context_service.clone(), Arc::new(ReversingHybridSearch));
+    let query = SearchQuery::new("query", 10)
+        .with_languages(["rust"])
+        .with_min_score(0.345);
+
+    search_service
+        .search("test-collection", &query)
+        .await
+        .expect("search should succeed");
+
+    // Filter and threshold both reach the vector store
+    let requested = context_service.requested_query();
+    assert_eq!(requested.filter, query.filter);
+    assert_eq!(requested.min_score, Some(0.345));
+}
+
+#[tokio::test]
+async fn test_search_service_applies_min_score_to_semantic_score() {
+    let search_service = SearchServiceImpl::new(
+        Arc::new(CandidateContextService::default()),
+        Arc::new(ReversingHybridSearch),
+    );
+    let query = SearchQuery::new("query", 10).with_min_score(0.655);
+
+    let results = search_service
+        .search("test-collection", &query)
+        .await
+        .expect("search should succeed");
+
+    // Of the ten fused results (similarity 0.70 down to 0.61), the five
+    // from 0.70 to 0.66 clear the threshold, whatever their fused score
+    assert_eq!(results.len(), 5);
+    assert!(results.iter().all(|r| r.semantic_score >= 0.655));
+    assert!(results.iter().all(|r| r.hybrid_score < 0.655));
+}
+
+#[tokio::test]
+async fn test_search_service_min_score_keeps_rrf_results() {
+    let search_service = SearchServiceImpl::new(
+        Arc::new(CandidateContextService::default()),
+        Arc::new(RankFusingHybridSearch),
+    );
+    let query = SearchQuery::new("query", 5)
+        .with_fusion(FusionStrategy::ReciprocalRank)
+        .with_min_score(0.5);
+
+    let results = search_service
+        .search("test-collection", &query)
+        .await
+        .expect("search should succeed");
+
+    // RRF scores never reach 0.5, the similarity of the candidates does;
+    // the lexical-only hit has no similarity to clear the threshold with
+    assert_eq!(results.len(), 4);
+    assert!(results.iter().all(|r| r.hybrid_score < 0.05));
+    assert!(results.iter().all(|r| r.result.id != "lexical-only"));
+}
+
+#[tokio::test]
+async fn test_search_service_passes_query_to_hybrid_search() {
+    let hybrid_search = Arc::new(RecordingHybridSearch::default());
+    let search_service = SearchServiceImpl::new(
+        Arc::new(CandidateContextService::default()),
+        hybrid_search.clone(),
+    );
+    let query = SearchQuery::new("ConfigLoader", 5)
+        .with_languages(["rust"])
+        .with_fusion(FusionStrategy::ReciprocalRank);
+
+    search_service
+        .search("test-collection", &query)
+        .await
+        .expect("search should succeed");
+
+    // Lexical retrieval needs the filter, and the fusion strategy is per query
+    let fused = hybrid_search
+        .query
+        .lock()
+        .expect("Lock poisoned")
+        .clone()
+        .expect("search_query should have been called");
+    assert_eq!(fused, query);
+}
+
+#[tokio::test]
+async fn test_search_service_expands_lexical_query() {
+    let context_service = Arc::new(CandidateContextService::default());
+    let hybrid_search = Arc::new(RecordingHybridSearch::default());
+    let search_service = SearchServiceImpl::new(context_service.clone(), hybrid_search.clone())
+        .with_query_expansion(
+            QueryExpansion {
+                max_terms: 4,
+                ..QueryExpansion::disabled()
+            }
+            .with_glossary([("retry", ["backoff", "attempts"])]),
+        );
+
+    search_service
+        .search("test-collection", &SearchQuery::new("retry http calls", 5))
+        .await
+        .expect("search should succeed");
+
+    let fused = hybrid_search
+        .query
+        .lock()
+        .expect("Lock poisoned")
+        .clone()
+        .expect("search_query should have been called");
+    assert_eq!(fused.text, "retry http calls backoff attempts");
+    // The context service expands the embedded queries itself
+    assert_eq!(context_service.requested_query().text, "retry http calls");
+}
+
+#[tokio::test]
+async fn test_search_service_reranks_whole_budget() {
+    let context_service = Arc::new(CandidateContextService::default());
+    let reranker = Arc::new(StubReranker::reversing());
+    let search_service =
+        SearchServiceImpl::new(context_service.clone(), Arc::new(ReversingHybridSearch))
+            .with_reranker(reranker.clone(), 10);
+
+    let results = search_service
+        .search("test-collection", &SearchQuery::new("ConfigLoader", 3))
+        .await
+        .expect("search should succeed");
+
+    // The budget is fused and reranked even though only three are returned
+    assert_eq!(
+        context_service.requested_query().limit,
+        10 * mcb_domain::SEARCH_CANDIDATE_MULTIPLIER
+    );
+    assert_eq!(reranker.documents(), 10);
+
+    // The tenth fused result is the reranker's favourite
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].result.file_path, "src/file_20.rs");
+    assert_eq!(results[0].rerank_score, Some(9.0));
+    assert_eq!(results[1].result.file_path, "src/file_21.rs");
+}
+
+#[tokio::test]
+async fn test_search_service_reranks_only_top_n() {
+    let context_service = Arc::new(CandidateContextService::default());
+    let reranker = Arc::new(StubReranker::reversing());
+    let search_service =
+        SearchServiceImpl::new(context_service.clone(), Arc::new(ReversingHybridSearch))
+            .with_reranker(reranker.clone(), 3);
+
+    let results = search_service
+        .search("test-collection", &SearchQuery::new("ConfigLoader", 10))
+        .await
+        .expect("search should succeed");
+
+    // Fused order is file_29, file_28, ...; only the first three are re-scored
+    assert_eq!(reranker.documents(), 3);
+    assert_eq!(results.len(), 10);
+    let paths: Vec<&str> = results
+        .iter()
+        .take(4)
+        .map(|r| r.result.file_path.as_str())
+        .collect();
+    assert_eq!(
+        paths,
+        [
+            "src/file_27.rs",
+            "src/file_28.rs",
+            "src/file_29.rs",
+            "src/file_26.rs"
+        ]
+    );
+    assert_eq!(results[3].rerank_score, None);
+}
+
+#[tokio::test]
+async fn test_search_service_keeps_order_without_rerank_scores() {
+    let search_service = SearchServiceImpl::new(
+        Arc::new(CandidateContextService::default()),
+        Arc::new(ReversingHybridSearch),
+    )
+    .with_reranker(Arc::new(StubReranker::new(|_| Vec::new())), 10);
+
+    let results = search_service
+        .search("test-collection", &SearchQuery::new("ConfigLoader", 5))
+        .await
+        .expect("search should succeed");
+
+    assert_eq!(results.len(), 5);
+    assert_eq!(results[0].result.file_path, "src/file_29.rs");
+    assert!(results.iter().all(|r| r.rerank_score.is_none()));
+}
+
+#[tokio::test]
+async fn test_search_service_rejects_partial_rerank_scores() {
+    let search_service = SearchServiceImpl::new(
+        Arc::new(CandidateContextService::default()),
+        Arc::new(ReversingHybridSearch),
+    )
+    .with_reranker(Arc::new(StubReranker::new(|n| vec![1.0; n - 1])), 10);
+
+    let result = search_service
+        .search("test-collection", &SearchQuery::new("ConfigLoader", 5))
+        .await;
+
+    assert!(result.is_err());
+}
@@ -19,3 +19,66 @@ pub const INDEXING_CHUNK_MIN_LINES: usize = 2;
 
 /// Maximum number of chunks extracted from a single file
 pub const INDEXING_CHUNKS_MAX_PER_FILE: usize = 50;
+
+// ============================================================================
+// EMBEDDING CACHE DOMAIN CONSTANTS
+// ============================================================================
+
+/// TTL of cached embeddings in seconds (7 days)
+pub const EMBEDDING_CACHE_TTL_SECS: u64 = 7 * 24 * 60 * 60;
+
+/// Cache namespace of embeddings
+pub const EMBEDDING_CACHE_NAMESPACE: &str = "embeddings";
+
+// ============================================================================
+// SEARCH DOMAIN CONSTANTS
+// ============================================================================
+
+/// Over-fetch factor for semantic candidates re-ranked by hybrid search
+pub const SEARCH_CANDIDATE_MULTIPLIER: usize = 3;
+
+/// Upper bound on semantic candidates fetched for a single search
+pub const SEARCH_CANDIDATES_MAX: usize = 100;
+
+/// Maximum terms query expansion adds to the lexical query
+pub const QUERY_EXPANSION_MAX_TERMS: usize = 12;
+
+/// Maximum queries embedded per search with query expansion, the original included
+pub const QUERY_EXPANSION_MAX_QUERIES: usize = 3;
+
+/// Words of a question never joined into identifiers by query expansion
+pub const QUERY_EXPANSION_STOPWORDS: &[&str] = &[
+    "a", "an", "and", "are", "at", "be", "by", "can", "code", "do", "does", "find", "for", "from",
+    "how", "i", "in", "is", "it", "me", "my", "of", "on", "or", "our", "show", "that", "the",
+    "there", "this", "to", "we", "what", "when", "where", "which", "who", "why", "with",
+];
+
+/// Built-in glossary of query expansion: code concepts and the words code
+/// implementing them tends to use
+pub const QUERY_EXPANSION_GLOSSARY: &[(&str, &[&str])] = &[
+    (
+        "auth",
+        &["authentication", "authorize", "credentials", "token"],
+    ),
+    ("cache", &["memoize", "ttl", "evict"]),
+    ("config", &["configuration", "settings", "options"]),
+    ("database", &["db", "sql", "repository"]),
+    ("delete", &["remove", "drop", "purge"]),
+    ("encrypt", &["cipher", "crypto", "decrypt"]),
+    ("error", &["err", "failure", "exception"]),
+    ("hash", &["digest", "checksum"]),
+    ("http", &["request", "client", "fetch"]),
+    ("limit", &["throttle", "quota", "rate"]),
+    ("listen", &["subscribe", "watch", "handler"]),
+    ("log", &["logger", "tracing", "warn"]),
+    ("login", &["authenticate", "session", "password"]),
+    ("parse", &["decode", "deserialize", "parser"]),
+    ("retry", &["backoff", "attempts", "retries"]),
+    ("send", &["publish", "emit", "dispatch"]),
+    ("serialize", &["encode", "json", "serde"]),
+    ("shutdown", &["stop", "cancel", "graceful"]),
+    ("start", &["init", "bootstrap", "spawn"]),
+    ("test", &["mock", "assert", "fixture"]),
+    ("timeout", &["deadline", "elapsed", "duration"]),
+    ("validate", &["check", "verify", "sanitize"]),
+];
@@ -4,7 +4,7 @@
 //! of source code. Code chunks are the fundamental units of semantic indexing
 //! and search in the system.
 
-use crate::value_objects::Language;
+use crate::value_objects::{CodeSymbol, Language};
 use serde::{Deserialize, Serialize};
 
 /// Core Entity: Semantically Meaningful Code Segment
@@ -53,3 +53,56 @@ pub struct CodeChunk {
     /// Additional metadata as JSON (context, AST info, etc.)
     pub metadata: serde_json::Value,
 }
+
+impl CodeChunk {
+    /// Metadata key of the file imports the chunk refers to
+    pub const IMPORTS_METADATA_KEY: &'static str = "imports";
+
+    /// Declaration this chunk covers, read from its metadata
+    pub fn symbol(&self) -> Option<CodeSymbol> {
+        CodeSymbol::from_metadata(self.metadata.get(CodeSymbol::METADATA_KEY))
+    }
+
+    /// Import statements of the file that this chunk refers to
+    pub fn imports(&self) -> Vec<String> {
+        self.metadata
+            .get(Self::IMPORTS_METADATA_KEY)
+            .and_then(|imports| imports.as_array())
+            .map(|imports| {
+                imports
+                    .iter()
+                    .filter_map(|import| import.as_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Deterministic identifier of a chunk
+    ///
+    /// Derived from the file path, the symbol the chunk covers, its start
+    /// line and a hash of its content, so re-chunking unchanged code yields
+    /// the same id and storing the chunk again replaces the previous entry.
+    /// The start line keeps identical bodies of one file apart, such as
+    /// repeated fallback or merged chunks.
+    ///
+    /// ```rust
+    /// use mcb_domain::entities::CodeChunk;
+    ///
+    /// let id = CodeChunk::stable_id("src/auth.rs", "authenticate", 10, "fn authenticate() {}");
+    /// assert_eq!(id, CodeChunk::stable_id("src/auth.rs", "authenticate", 10, "fn authenticate() {}"));
+    /// assert_ne!(id, CodeChunk::stable_id("src/auth.rs", "authenticate", 10, "fn authenticate() { }"));
+    /// assert_ne!(id, CodeChunk::stable_id("src/auth.rs", "authenticate", 20, "fn authenticate() {}"));
+    /// ```
+    pub fn stable_id(file_path: &str, symbol: &str, start_line: u32, content: &str) -> String {
+        use sha2::{Digest, Sha256};
+
+        let start_line = start_line.to_string();
+        let mut hasher = Sha256::new();
+        for part in [file_path, symbol, &start_line, content] {
+            // Length prefixes keep the parts from running into each other
+            hasher.update(part.len().to_le_bytes());
+            hasher.update(part.as_bytes());
+        }
+        format!("{:x}", hasher.finalize())
+    }
+}
@@ -1,5 +1,5 @@
 use crate::error::Result;
-use crate::value_objects::Embedding;
+use crate::value_objects::{ChunkSizePolicy, Embedding, EmbeddingBatchLimits};
 use async_trait::async_trait;
 
 /// AI Semantic Understanding Interface
@@ -46,6 +46,30 @@ pub trait EmbeddingProvider: Send + Sync {
     /// Get embeddings for multiple texts (must be implemented by provider)
     async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Embedding>>;
 
+    /// Largest batch accepted by a single `embed_batch()` request
+    ///
+    /// Defaults to [`EmbeddingBatchLimits::default`]; providers with
+    /// documented request limits override it.
+    fn batch_limits(&self) -> EmbeddingBatchLimits {
+        EmbeddingBatchLimits::default()
+    }
+
+    /// Largest input, in tokens, embedded without truncation
+    ///
+    /// Chunks are sized to fit this limit; defaults to
+    /// [`ChunkSizePolicy::DEFAULT_MAX_TOKENS`].
+    fn max_input_tokens(&self) -> usize {
+        ChunkSizePolicy::DEFAULT_MAX_TOKENS
+    }
+
+    /// Estimated characters per token of the model's tokenizer
+    ///
+    /// Used to estimate token counts from text length; providers whose
+    /// tokenizer packs code differently override it.
+    fn chars_per_token(&self) -> usize {
+        ChunkSizePolicy::DEFAULT_CHARS_PER_TOKEN
+    }
+
     /// Get the dimensionality of embeddings produced by this provider
     ///
     /// # Returns
@@ -58,6 +82,12 @@ pub trait EmbeddingProvider: Send + Sync {
     /// A string identifier for the provider (e.g., "openai", "ollama", "anthropic")
     fn provider_name(&self) -> &str;
 
+    /// Get the name of the model producing the embeddings
+    ///
+    /// Together with the provider name and dimensions it identifies the
+    /// vector space, e.g. when caching embeddings.
+    fn model_name(&self) -> &str;
+
     /// Health check for the provider (default implementation provided)
     async fn health_check(&self) -> Result<()> {
         // Default implementation - try a simple embed operation
@@ -5,27 +5,51 @@
 
 use crate::entities::CodeChunk;
 use crate::error::Result;
-use crate::value_objects::SearchResult;
+use crate::value_objects::{SearchQuery, SearchResult};
 use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Result of a hybrid search operation
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct HybridSearchResult {
     /// The underlying search result with code chunk and metadata
     pub result: SearchResult,
-    /// BM25 lexical matching score (0.0 to 1.0)
+    /// BM25 lexical matching score, relative to the best lexical hit (0.0 to 1.0)
     pub bm25_score: f32,
     /// Semantic similarity score from vector search (0.0 to 1.0)
     pub semantic_score: f32,
-    /// Combined hybrid score from both BM25 and semantic components
+    /// Combined hybrid score from both BM25 and semantic components, on the
+    /// scale of the fusion strategy that produced it
     pub hybrid_score: f32,
+    /// Relevance score from the reranking stage, on the reranker's own
+    /// scale; `None` when the result was not reranked
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rerank_score: Option<f32>,
+}
+
+impl HybridSearchResult {
+    /// Wrap a semantic-only result (no lexical component)
+    ///
+    /// Used when no BM25 index exists for the collection, so the hybrid
+    /// score is the semantic score unchanged.
+    pub fn semantic_only(result: SearchResult) -> Self {
+        let semantic_score = result.score as f32;
+        Self {
+            result,
+            bm25_score: 0.0,
+            semantic_score,
+            hybrid_score: semantic_score,
+            rerank_score: None,
+        }
+    }
 }
 
 /// Port for hybrid search operations
 ///
 /// Combines lexical (BM25) and semantic (vector) search for improved relevance.
-/// BM25 excels at exact keyword matching while semantic search understands meaning.
+/// BM25 excels at exact keyword matching while semantic search understands meaning,
+/// so each retrieves its own candidates and the two rankings are fused.
 ///
 /// # Example
 ///
@@ -40,23 +64,53 @@ pub struct HybridSearchResult {
 /// let results = provider.search("project", "async fn", semantic_results, 10).await?;
 ///
 /// // Results are ranked by combined BM25 + semantic scores
-/// for result in results {
-///     println!("{}: {}", result.file_path, result.score);
+/// for hit in results {
+///     println!(
+///         "{}: {} (bm25 {}, semantic {})",
+///         hit.result.file_path, hit.hybrid_score, hit.bm25_score, hit.semantic_score
+///     );
 /// }
 /// ```
 #[async_trait]
 pub trait HybridSearchProvider: Send + Sync {
     /// Index code chunks for hybrid search
+    ///
+    /// Chunks already indexed under the same id are replaced.
     async fn index_chunks(&self, collection: &str, chunks: &[CodeChunk]) -> Result<()>;
 
+    /// Remove chunks from the index by id
+    async fn remove_chunks(&self, collection: &str, chunk_ids: &[String]) -> Result<()>;
+
+    /// Remove every chunk extracted from the given files
+    async fn remove_file_chunks(&self, collection: &str, file_paths: &[String]) -> Result<()>;
+
     /// Perform hybrid search
+    ///
+    /// Retrieves lexical candidates from the BM25 index, fuses them with the
+    /// semantic candidates and returns the top `limit` results ordered by
+    /// fused score, with each component score preserved.
     async fn search(
         &self,
         collection: &str,
         query: &str,
         semantic_results: Vec<SearchResult>,
         limit: usize,
-    ) -> Result<Vec<SearchResult>>;
+    ) -> Result<Vec<HybridSearchResult>>;
+
+    /// Perform hybrid search for a full query
+    ///
+    /// Lexical candidates are restricted by the query's filter, and its
+    /// fusion strategy, when set, replaces the provider's default. Providers
+    /// without a lexical index of their own only see the text and limit.
+    async fn search_query(
+        &self,
+        collection: &str,
+        query: &SearchQuery,
+        semantic_results: Vec<SearchResult>,
+    ) -> Result<Vec<HybridSearchResult>> {
+        self.search(collection, &query.text, semantic_results, query.limit)
+            .await
+    }
 
     /// Clear indexed data for a collection
     async fn clear_collection(&self, collection: &str) -> Result<()>;
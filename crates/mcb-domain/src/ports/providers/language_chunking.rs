@@ -11,7 +11,7 @@
 //! factory creation, and feature-flag based compilation.
 
 use crate::entities::CodeChunk;
-use crate::value_objects::Language;
+use crate::value_objects::{ChunkSizePolicy, Language};
 
 /// Language-Specific Code Chunking Provider
 ///
@@ -25,12 +25,13 @@ use crate::value_objects::Language;
 /// - Rust, Python, JavaScript, TypeScript
 /// - Go, Java, C, C++, C#
 /// - Ruby, PHP, Swift, Kotlin
+/// - Scala, Elixir, Lua, Bash, Haskell, Zig
 ///
 /// # Example
 ///
 /// ```ignore
 /// use mcb_domain::ports::providers::LanguageChunkingProvider;
-/// use crate::value_objects::Language;
+/// use crate::value_objects::{ChunkSizePolicy, Language};
 ///
 /// // Get provider from registry
 /// let provider: Arc<dyn LanguageChunkingProvider> = registry.get_by_extension("rs")?;
@@ -83,14 +84,86 @@ pub trait LanguageChunkingProvider: Send + Sync {
             .any(|e| e.eq_ignore_ascii_case(ext))
     }
 
-    /// Get the maximum chunk size for this language
+    /// Get exact file names this provider handles regardless of extension
     ///
-    /// Different languages may have different optimal chunk sizes
-    /// based on their syntax structure.
+    /// # Returns
+    /// Slice of file names (e.g., ["Dockerfile", "Makefile"]); empty by default
+    fn file_names(&self) -> &[&'static str] {
+        &[]
+    }
+
+    /// Get the interpreters whose `#!` scripts this provider handles
     ///
     /// # Returns
-    /// Maximum number of lines for a single chunk (default: 50)
-    fn max_chunk_size(&self) -> usize {
-        50
+    /// Slice of interpreter names without version suffix (e.g., ["python", "bash"]);
+    /// empty by default
+    fn interpreters(&self) -> &[&'static str] {
+        &[]
     }
+
+    /// Check if this provider can chunk a file
+    ///
+    /// Matches the extension first, then the exact file name, then the
+    /// interpreter named on a `#!` first line.
+    ///
+    /// # Arguments
+    /// * `file_name` - File name without directories (e.g., "main.rs", "Makefile")
+    /// * `first_line` - First line of the file, when available
+    fn supports_file(&self, file_name: &str, first_line: Option<&str>) -> bool {
+        if let Some((_, ext)) = file_name.rsplit_once('.')
+            && self.supports_extension(ext)
+        {
+            return true;
+        }
+        if self.file_names().contains(&file_name) {
+            return true;
+        }
+        first_line
+            .and_then(shebang_interpreter)
+            .is_some_and(|interpreter| self.interpreters().contains(&interpreter))
+    }
+
+    /// Extract code chunks sized by `policy`
+    ///
+    /// Providers able to split oversized declarations and merge tiny ones
+    /// override this; the default ignores the policy and calls `chunk()`.
+    ///
+    /// # Arguments
+    /// * `content` - The source code content to parse
+    /// * `file_path` - The path to the source file (for metadata)
+    /// * `_policy` - Token bounds of the chunks
+    fn chunk_with_policy(
+        &self,
+        content: &str,
+        file_path: &str,
+        _policy: &ChunkSizePolicy,
+    ) -> Vec<CodeChunk> {
+        self.chunk(content, file_path)
+    }
+
+    /// Get the chunk size policy `chunk()` applies
+    ///
+    /// # Returns
+    /// Token bounds of the chunks (default: [`ChunkSizePolicy::default`])
+    fn chunk_size_policy(&self) -> ChunkSizePolicy {
+        ChunkSizePolicy::default()
+    }
+}
+
+/// Extract the interpreter name from a `#!` line
+///
+/// Resolves `/usr/bin/env` indirection and strips version suffixes, so
+/// `#!/usr/bin/env -S python3.11 -u` yields `python`.
+pub fn shebang_interpreter(line: &str) -> Option<&str> {
+    let mut parts = line.strip_prefix("#!")?.split_whitespace();
+    let mut program = base_name(parts.next()?);
+    if program == "env" {
+        program = base_name(parts.find(|arg| !arg.starts_with('-') && !arg.contains('='))?);
+    }
+    let name = program.trim_end_matches(|c: char| c.is_ascii_digit() || c == '.');
+    (!name.is_empty()).then_some(name)
+}
+
+fn base_name(path: &str) -> &str {
+    path.rsplit('/').next().unwrap_or(path)
 }
@@ -11,6 +11,7 @@
 //! | [`EmbeddingProvider`] | Text embedding generation services |
 //! | [`VectorStoreProvider`] | Vector storage and similarity search |
 //! | [`HybridSearchProvider`] | Combined semantic and keyword search |
+//! | [`RerankerProvider`] | Second-stage ranking of search results |
 //! | [`LanguageChunkingProvider`] | Language-specific code chunking |
 //! | [`CacheProvider`] | Caching backend services |
 //! | [`CryptoProvider`] | Encryption/decryption services |
@@ -27,6 +28,8 @@ pub mod embedding;
 pub mod hybrid_search;
 /// Language chunking provider port
 pub mod language_chunking;
+/// Reranker provider port
+pub mod reranker;
 /// Vector store provider port
 pub mod vector_store;
 
@@ -36,5 +39,6 @@ pub use config::ProviderConfigManagerInterface;
 pub use crypto::{CryptoProvider, EncryptedData};
 pub use embedding::EmbeddingProvider;
 pub use hybrid_search::{HybridSearchProvider, HybridSearchResult};
-pub use language_chunking::LanguageChunkingProvider;
+pub use language_chunking::{LanguageChunkingProvider, shebang_interpreter};
+pub use reranker::RerankerProvider;
 pub use vector_store::{VectorStoreAdmin, VectorStoreProvider};
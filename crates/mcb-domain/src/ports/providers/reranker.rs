@@ -0,0 +1,48 @@
+//! Reranker Port
+//!
+//! Defines the interface for second-stage ranking of search results.
+
+use crate::error::Result;
+use async_trait::async_trait;
+
+/// Port for reranking search candidates
+///
+/// A reranker reads the query and each candidate together (a cross-encoder
+/// or a hosted reranking API) instead of comparing precomputed embeddings.
+/// It is too slow to rank a whole collection, but far more precise on the
+/// few candidates first-stage retrieval returns.
+///
+/// # Example
+///
+/// ```ignore
+/// use mcb_domain::ports::providers::RerankerProvider;
+///
+/// let documents = vec!["fn parse_config() {}".into(), "fn render() {}".into()];
+/// let scores = reranker.rerank("where is the config parsed", &documents).await?;
+///
+/// // One score per document, higher is more relevant
+/// assert_eq!(scores.len(), documents.len());
+/// ```
+#[async_trait]
+pub trait RerankerProvider: Send + Sync {
+    /// Score documents against a query
+    ///
+    /// Returns one relevance score per document, in the order the documents
+    /// were given; higher is more relevant. Scores are only comparable
+    /// within one call. Providers that do not rank return no scores, and
+    /// the candidates keep their order.
+    async fn rerank(&self, query: &str, documents: &[String]) -> Result<Vec<f32>>;
+
+    /// Get the name/identifier of this provider implementation
+    fn provider_name(&self) -> &str;
+
+    /// Get the name of the model scoring the documents
+    fn model_name(&self) -> &str;
+
+    /// Health check for the provider (default implementation provided)
+    async fn health_check(&self) -> Result<()> {
+        self.rerank("health check", &["health check".to_string()])
+            .await?;
+        Ok(())
+    }
+}
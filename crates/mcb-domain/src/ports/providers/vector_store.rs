@@ -1,5 +1,5 @@
 use crate::error::Result;
-use crate::value_objects::{Embedding, SearchResult};
+use crate::value_objects::{Embedding, MetadataFilter, SearchResult};
 use async_trait::async_trait;
 use serde_json::Value;
 use std::collections::HashMap;
@@ -128,22 +128,45 @@ pub trait VectorStoreProvider: VectorStoreAdmin + Send + Sync {
         metadata: Vec<HashMap<String, Value>>,
     ) -> Result<Vec<String>>;
 
+    /// Insert vectors under caller-provided IDs, replacing existing entries
+    ///
+    /// A vector stored under an ID already present in the collection replaces
+    /// the previous vector and metadata instead of being added next to them.
+    /// Chunks are stored under their `CodeChunk::stable_id`, so re-indexing
+    /// unchanged code never duplicates it.
+    ///
+    /// # Arguments
+    /// * `collection` - Name of the collection to write to
+    /// * `ids` - IDs of the vectors, one per vector
+    /// * `vectors` - Slice of embedding vectors to store
+    /// * `metadata` - Vector of metadata maps, one per vector
+    ///
+    /// # Returns
+    /// Ok(()) if all vectors were stored, Error if lengths differ or storage failed
+    async fn upsert_vectors(
+        &self,
+        collection: &str,
+        ids: &[String],
+        vectors: &[Embedding],
+        metadata: Vec<HashMap<String, Value>>,
+    ) -> Result<()>;
+
     /// Search for vectors similar to a query vector
     ///
     /// # Arguments
     /// * `collection` - Name of the collection to search in
     /// * `query_vector` - The query vector to find similar vectors for
     /// * `limit` - Maximum number of results to return
-    /// * `filter` - Optional filter expression to restrict search scope
+    /// * `filter` - Optional metadata filter; only matching vectors are candidates
     ///
     /// # Returns
-    /// Ok(vector_of_results) containing the search results ordered by similarity
+    /// Ok(vector_of_results) containing up to `limit` matching results ordered by similarity
     async fn search_similar(
         &self,
         collection: &str,
         query_vector: &[f32],
         limit: usize,
-        filter: Option<&str>,
+        filter: Option<&MetadataFilter>,
     ) -> Result<Vec<SearchResult>>;
 
     /// Delete vectors by their IDs
@@ -156,6 +179,32 @@ pub trait VectorStoreProvider: VectorStoreAdmin + Send + Sync {
     /// Ok(()) if all vectors were deleted successfully, Error if deletion failed
     async fn delete_vectors(&self, collection: &str, ids: &[String]) -> Result<()>;
 
+    /// Delete every vector stored for a file
+    ///
+    /// Matches the `file_path` metadata of the vectors exactly.
+    ///
+    /// # Arguments
+    /// * `collection` - Name of the collection to delete from
+    /// * `file_path` - Path of the file whose vectors are deleted
+    ///
+    /// # Returns
+    /// Ok(()) if the vectors were deleted or none existed, Error if deletion failed
+    async fn delete_by_file_path(&self, collection: &str, file_path: &str) -> Result<()>;
+
+    /// List every vector stored for a file
+    ///
+    /// # Arguments
+    /// * `collection` - Name of the collection to list vectors from
+    /// * `file_path` - Path of the file whose vectors are listed
+    ///
+    /// # Returns
+    /// Ok(vector_of_results) containing the vectors of the file
+    async fn list_by_file_path(
+        &self,
+        collection: &str,
+        file_path: &str,
+    ) -> Result<Vec<SearchResult>>;
+
     /// Retrieve vectors by their IDs
     ///
     /// # Arguments
@@ -5,6 +5,7 @@
 
 use crate::entities::CodeChunk;
 use crate::error::Result;
+use crate::value_objects::filter::MetadataFilter;
 use crate::value_objects::search::SearchResult;
 use async_trait::async_trait;
 
@@ -48,7 +49,7 @@ pub trait SearchRepository: Send + Sync {
         collection: &str,
         query_vector: &[f32],
         limit: usize,
-        filter: Option<&str>,
+        filter: Option<&MetadataFilter>,
     ) -> Result<Vec<SearchResult>>;
 
     /// Index chunks for keyword/BM25 search
@@ -0,0 +1,88 @@
+//! Chunk Sizing Value Objects
+//!
+//! Value objects describing how large code chunks may grow before they
+//! are split, measured in estimated embedding model tokens.
+
+/// Value Object: Chunk Size Policy
+///
+/// Bounds the size of code chunks in estimated tokens of the embedding
+/// model. Chunks larger than `max_tokens` are split at statement boundaries,
+/// each part repeating up to `overlap_tokens` of the previous one; adjacent
+/// chunks smaller than `min_tokens` are merged. Token counts are estimated
+/// from the text length with the model's `chars_per_token` ratio.
+///
+/// ## Example
+///
+/// ```rust
+/// use mcb_domain::value_objects::ChunkSizePolicy;
+///
+/// let policy = ChunkSizePolicy::new(256, 16, 32).fit_to_model(8192, 3);
+/// assert_eq!(policy.max_tokens, 256);
+/// assert_eq!(policy.estimate_tokens("fn main() {}"), 4);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChunkSizePolicy {
+    /// Maximum estimated tokens of a chunk
+    pub max_tokens: usize,
+    /// Chunks below this estimate are merged with adjacent small chunks
+    pub min_tokens: usize,
+    /// Estimated tokens repeated from the previous part of a split chunk
+    pub overlap_tokens: usize,
+    /// Characters per token of the embedding model's tokenizer
+    pub chars_per_token: usize,
+}
+
+impl ChunkSizePolicy {
+    /// Default maximum tokens of a chunk
+    pub const DEFAULT_MAX_TOKENS: usize = 512;
+
+    /// Default size below which adjacent chunks are merged
+    pub const DEFAULT_MIN_TOKENS: usize = 16;
+
+    /// Default overlap between the parts of a split chunk
+    pub const DEFAULT_OVERLAP_TOKENS: usize = 32;
+
+    /// Default characters per token, typical of BPE tokenizers on code
+    pub const DEFAULT_CHARS_PER_TOKEN: usize = 4;
+
+    /// Create a policy, keeping the bounds consistent
+    ///
+    /// `max_tokens` is at least one, `min_tokens` at most `max_tokens` and
+    /// `overlap_tokens` below half of `max_tokens` so split parts advance.
+    pub fn new(max_tokens: usize, min_tokens: usize, overlap_tokens: usize) -> Self {
+        let max_tokens = max_tokens.max(1);
+        Self {
+            max_tokens,
+            min_tokens: min_tokens.min(max_tokens),
+            overlap_tokens: overlap_tokens.min(max_tokens / 2),
+            chars_per_token: Self::DEFAULT_CHARS_PER_TOKEN,
+        }
+    }
+
+    /// Fit the policy to an embedding model's input limit and tokenizer
+    pub fn fit_to_model(self, max_input_tokens: usize, chars_per_token: usize) -> Self {
+        Self {
+            chars_per_token: chars_per_token.max(1),
+            ..Self::new(
+                self.max_tokens.min(max_input_tokens),
+                self.min_tokens,
+                self.overlap_tokens,
+            )
+        }
+    }
+
+    /// Estimated number of tokens of `text`
+    pub fn estimate_tokens(&self, text: &str) -> usize {
+        text.len().div_ceil(self.chars_per_token.max(1))
+    }
+}
+
+impl Default for ChunkSizePolicy {
+    fn default() -> Self {
+        Self::new(
+            Self::DEFAULT_MAX_TOKENS,
+            Self::DEFAULT_MIN_TOKENS,
+            Self::DEFAULT_OVERLAP_TOKENS,
+        )
+    }
+}
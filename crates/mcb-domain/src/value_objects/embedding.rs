@@ -37,3 +37,48 @@ pub struct Embedding {
     /// Dimensionality of the embedding vector
     pub dimensions: usize,
 }
+
+/// Value Object: Embedding Request Limits
+///
+/// Largest batch an embedding provider accepts in a single request, as a
+/// number of texts and an estimated number of tokens. Callers packing texts
+/// into batches stay within both limits; a single text over the token limit
+/// is sent on its own.
+///
+/// ## Example
+///
+/// ```rust
+/// use mcb_domain::value_objects::EmbeddingBatchLimits;
+///
+/// let limits = EmbeddingBatchLimits::new(128, 100_000);
+/// assert_eq!(limits.max_texts, 128);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmbeddingBatchLimits {
+    /// Maximum number of texts per request
+    pub max_texts: usize,
+    /// Maximum estimated number of tokens per request
+    pub max_tokens: usize,
+}
+
+impl EmbeddingBatchLimits {
+    /// Default number of texts per request for providers without a documented limit
+    pub const DEFAULT_MAX_TEXTS: usize = 64;
+
+    /// Default token budget per request for providers without a documented limit
+    pub const DEFAULT_MAX_TOKENS: usize = 16_384;
+
+    /// Create limits, treating zero as one
+    pub fn new(max_texts: usize, max_tokens: usize) -> Self {
+        Self {
+            max_texts: max_texts.max(1),
+            max_tokens: max_tokens.max(1),
+        }
+    }
+}
+
+impl Default for EmbeddingBatchLimits {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_MAX_TEXTS, Self::DEFAULT_MAX_TOKENS)
+    }
+}
@@ -0,0 +1,269 @@
+//! Metadata Filter Value Objects
+//!
+//! Typed filter expressions used to restrict vector similarity searches
+//! to a subset of the indexed chunks (a subdirectory, a language, a line
+//! range, ...). Vector store providers evaluate these natively instead of
+//! receiving an opaque filter string.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// Chunk metadata field that a [`MetadataFilter`] can constrain
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FilterField {
+    /// Path of the source file the chunk was extracted from
+    FilePath,
+    /// Programming language of the chunk
+    Language,
+    /// AST node type the chunk was extracted from (e.g. `function_item`)
+    NodeType,
+}
+
+impl FilterField {
+    /// Metadata key under which vector stores persist this field
+    pub fn key(&self) -> &'static str {
+        match self {
+            Self::FilePath => "file_path",
+            Self::Language => "language",
+            Self::NodeType => "node_type",
+        }
+    }
+}
+
+/// Value Object: Structured Metadata Filter
+///
+/// A boolean expression over chunk metadata. Leaf predicates compare a
+/// [`FilterField`] against literal values; `And`, `Or` and `Not` combine them.
+///
+/// ## Example
+///
+/// ```rust
+/// use mcb_domain::value_objects::{FilterField, MetadataFilter};
+///
+/// // Rust code under crates/, excluding tests
+/// let filter = MetadataFilter::prefix(FilterField::FilePath, "crates/")
+///     .and(MetadataFilter::eq(FilterField::Language, "rust"))
+///     .and(MetadataFilter::glob(FilterField::FilePath, "**/tests/**").negate());
+///
+/// let metadata = std::collections::HashMap::from([
+///     ("file_path".to_string(), serde_json::json!("crates/core/src/lib.rs")),
+///     ("language".to_string(), serde_json::json!("rust")),
+/// ]);
+/// assert!(filter.matches(&metadata));
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "op", content = "args", rename_all = "snake_case")]
+pub enum MetadataFilter {
+    /// Field equals the value
+    Eq {
+        /// Field to compare
+        field: FilterField,
+        /// Expected value
+        value: String,
+    },
+    /// Field equals one of the values
+    In {
+        /// Field to compare
+        field: FilterField,
+        /// Accepted values
+        values: Vec<String>,
+    },
+    /// Field starts with the prefix
+    Prefix {
+        /// Field to compare
+        field: FilterField,
+        /// Required prefix
+        prefix: String,
+    },
+    /// Field matches a glob pattern (`*`, `?` and `**` for any directories)
+    Glob {
+        /// Field to compare
+        field: FilterField,
+        /// Glob pattern
+        pattern: String,
+    },
+    /// Chunk line span overlaps the given (inclusive) range
+    LineRange {
+        /// First line of the range, unbounded when `None`
+        start: Option<u32>,
+        /// Last line of the range, unbounded when `None`
+        end: Option<u32>,
+    },
+    /// All sub-filters match (an empty list matches everything)
+    And(Vec<MetadataFilter>),
+    /// At least one sub-filter matches (an empty list matches nothing)
+    Or(Vec<MetadataFilter>),
+    /// The sub-filter does not match
+    Not(Box<MetadataFilter>),
+}
+
+impl MetadataFilter {
+    /// Field equals `value`
+    pub fn eq(field: FilterField, value: impl Into<String>) -> Self {
+        Self::Eq {
+            field,
+            value: value.into(),
+        }
+    }
+
+    /// Field equals one of `values`
+    pub fn one_of<I, S>(field: FilterField, values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::In {
+            field,
+            values: values.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Field starts with `prefix`
+    pub fn prefix(field: FilterField, prefix: impl Into<String>) -> Self {
+        Self::Prefix {
+            field,
+            prefix: prefix.into(),
+        }
+    }
+
+    /// Field matches the glob `pattern`
+    pub fn glob(field: FilterField, pattern: impl Into<String>) -> Self {
+        Self::Glob {
+            field,
+            pattern: pattern.into(),
+        }
+    }
+
+    /// Chunk overlaps lines `start..=end`
+    pub fn line_range(start: Option<u32>, end: Option<u32>) -> Self {
+        Self::LineRange { start, end }
+    }
+
+    /// Combine with another filter using logical AND
+    pub fn and(self, other: MetadataFilter) -> Self {
+        match self {
+            Self::And(mut filters) => {
+                filters.push(other);
+                Self::And(filters)
+            }
+            first => Self::And(vec![first, other]),
+        }
+    }
+
+    /// Combine with another filter using logical OR
+    pub fn or(self, other: MetadataFilter) -> Self {
+        match self {
+            Self::Or(mut filters) => {
+                filters.push(other);
+                Self::Or(filters)
+            }
+            first => Self::Or(vec![first, other]),
+        }
+    }
+
+    /// Logical negation of this filter
+    pub fn negate(self) -> Self {
+        Self::Not(Box::new(self))
+    }
+
+    /// Evaluate the filter against a chunk's stored metadata
+    ///
+    /// Missing fields never satisfy a leaf predicate.
+    pub fn matches<S: FilterSubject + ?Sized>(&self, subject: &S) -> bool {
+        match self {
+            Self::Eq { field, value } => string_field(subject, *field).is_some_and(|v| v == value),
+            Self::In { field, values } => {
+                string_field(subject, *field).is_some_and(|v| values.iter().any(|x| x == v))
+            }
+            Self::Prefix { field, prefix } => {
+                string_field(subject, *field).is_some_and(|v| v.starts_with(prefix.as_str()))
+            }
+            Self::Glob { field, pattern } => {
+                string_field(subject, *field).is_some_and(|v| glob_matches(pattern, v))
+            }
+            Self::LineRange { start, end } => {
+                let Some(chunk_start) = line_field(subject, "start_line")
+                    .or_else(|| line_field(subject, "line_number"))
+                else {
+                    return false;
+                };
+                let chunk_end = line_field(subject, "end_line").unwrap_or(chunk_start);
+                start.is_none_or(|s| chunk_end >= u64::from(s))
+                    && end.is_none_or(|e| chunk_start <= u64::from(e))
+            }
+            Self::And(filters) => filters.iter().all(|f| f.matches(subject)),
+            Self::Or(filters) => filters.iter().any(|f| f.matches(subject)),
+            Self::Not(filter) => !filter.matches(subject),
+        }
+    }
+}
+
+/// Metadata container a [`MetadataFilter`] can be evaluated against
+pub trait FilterSubject {
+    /// Look up a metadata value by key
+    fn field_value(&self, key: &str) -> Option<&Value>;
+}
+
+impl FilterSubject for HashMap<String, Value> {
+    fn field_value(&self, key: &str) -> Option<&Value> {
+        self.get(key)
+    }
+}
+
+impl FilterSubject for Map<String, Value> {
+    fn field_value(&self, key: &str) -> Option<&Value> {
+        self.get(key)
+    }
+}
+
+fn string_field<S: FilterSubject + ?Sized>(subject: &S, field: FilterField) -> Option<&str> {
+    subject.field_value(field.key()).and_then(Value::as_str)
+}
+
+fn line_field<S: FilterSubject + ?Sized>(subject: &S, key: &str) -> Option<u64> {
+    subject.field_value(key).and_then(Value::as_u64)
+}
+
+/// Match `text` against a glob `pattern`
+///
+/// `*` and `?` do not cross `/`; `**` matches any number of path segments
+/// (including none when followed by `/`).
+pub fn glob_matches(pattern: &str, text: &str) -> bool {
+    glob_match_bytes(pattern.as_bytes(), text.as_bytes())
+}
+
+fn glob_match_bytes(pattern: &[u8], text: &[u8]) -> bool {
+    match pattern {
+        [] => text.is_empty(),
+        [b'*', b'*', rest @ ..] => {
+            // `**/` may also match zero directories
+            if let Some(after) = rest.strip_prefix(b"/")
+                && glob_match_bytes(after, text)
+            {
+                return true;
+            }
+            (0..=text.len()).any(|i| glob_match_bytes(rest, &text[i..]))
+        }
+        [b'*', rest @ ..] => {
+            for i in 0..=text.len() {
+                if glob_match_bytes(rest, &text[i..]) {
+                    return true;
+                }
+                if text.get(i) == Some(&b'/') {
+                    break;
+                }
+            }
+            false
+        }
+        [b'?', rest @ ..] => match text {
+            [c, text_rest @ ..] if *c != b'/' => glob_match_bytes(rest, text_rest),
+            _ => false,
+        },
+        [p, rest @ ..] => match text {
+            [c, text_rest @ ..] if c == p => glob_match_bytes(rest, text_rest),
+            _ => false,
+        },
+    }
+}
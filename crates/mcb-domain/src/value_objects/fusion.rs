@@ -0,0 +1,81 @@
+//! Score Fusion Value Objects
+//!
+//! Value objects describing how hybrid search merges its lexical and
+//! semantic rankings into one.
+
+use crate::error::Error;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Value Object: Score Fusion Strategy
+///
+/// Hybrid search retrieves two rankings for a query, one from the BM25
+/// index and one from the vector store, and fuses them with one of these
+/// strategies. Weights and the RRF constant are engine settings; the
+/// strategy itself can be chosen per query.
+///
+/// ## Example
+///
+/// ```rust
+/// use mcb_domain::value_objects::FusionStrategy;
+///
+/// let strategy: FusionStrategy = "rrf".parse().unwrap();
+///
+/// assert_eq!(strategy, FusionStrategy::ReciprocalRank);
+/// assert_eq!(strategy.to_string(), "reciprocal_rank");
+/// assert!("best".parse::<FusionStrategy>().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FusionStrategy {
+    /// Weighted sum of the semantic score and the BM25 score scaled to the
+    /// best lexical hit
+    #[default]
+    Linear,
+    /// Reciprocal Rank Fusion: each ranking adds `1 / (k + rank)`, so only
+    /// positions matter and score scales never have to be reconciled
+    #[serde(alias = "rrf")]
+    ReciprocalRank,
+    /// Weighted sum of scores normalized by their ranking's distribution,
+    /// mapping `mean - 3σ..mean + 3σ` onto `0..1`
+    #[serde(alias = "distribution")]
+    DistributionBased,
+}
+
+impl FusionStrategy {
+    /// Every strategy, in declaration order
+    pub const ALL: [Self; 3] = [Self::Linear, Self::ReciprocalRank, Self::DistributionBased];
+
+    /// Canonical name of the strategy
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Linear => "linear",
+            Self::ReciprocalRank => "reciprocal_rank",
+            Self::DistributionBased => "distribution_based",
+        }
+    }
+}
+
+impl fmt::Display for FusionStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for FusionStrategy {
+    type Err = Error;
+
+    /// Parse a strategy name; `rrf` and `distribution` are accepted as
+    /// short forms
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name.trim().to_lowercase().replace('-', "_").as_str() {
+            "linear" => Ok(Self::Linear),
+            "reciprocal_rank" | "rrf" => Ok(Self::ReciprocalRank),
+            "distribution_based" | "distribution" => Ok(Self::DistributionBased),
+            other => Err(Error::invalid_argument(format!(
+                "Unknown fusion strategy '{other}'. Expected one of: linear, rrf, distribution"
+            ))),
+        }
+    }
+}
@@ -9,23 +9,45 @@
 //! | Value Object | Description |
 //! |--------------|-------------|
 //! | [`Embedding`] | Vector representation of text for semantic search |
+//! | [`EmbeddingBatchLimits`] | Request size limits of an embedding provider |
+//! | [`ChunkSizePolicy`] | Token bounds of code chunks |
 //! | [`SearchResult`] | Ranked result from semantic search operation |
+//! | [`SearchQuery`] | Query text, limit and filters for a search |
+//! | [`FusionStrategy`] | How hybrid search merges lexical and semantic rankings |
+//! | [`QueryExpansion`] | Lexical variants and extra embedding queries of a query |
+//! | [`MetadataFilter`] | Structured filter restricting a vector search |
+//! | [`CodeSymbol`] | Declaration a code chunk covers |
 //! | [`Language`] | Programming language identifier |
 //! | [`OperationType`] | Operation type for metrics and rate limiting |
 
+/// Chunk sizing value objects
+pub mod chunking;
 /// Configuration value objects
 pub mod config;
 /// Semantic embedding value objects
 pub mod embedding;
+/// Metadata filter value objects
+pub mod filter;
+/// Score fusion value objects
+pub mod fusion;
+/// Query expansion value objects
+pub mod query_expansion;
 /// Search-related value objects
 pub mod search;
+/// Code symbol value objects
+pub mod symbol;
 /// Type definitions for dynamic domain concepts
 pub mod types;
 
 // Re-export commonly used value objects
+pub use chunking::ChunkSizePolicy;
 pub use config::{CacheConfig, EmbeddingConfig, VectorStoreConfig};
-pub use embedding::Embedding;
-pub use search::SearchResult;
+pub use embedding::{Embedding, EmbeddingBatchLimits};
+pub use filter::{FilterField, FilterSubject, MetadataFilter};
+pub use fusion::FusionStrategy;
+pub use query_expansion::{ExpandedQuery, QueryExpansion, split_identifier};
+pub use search::{SearchQuery, SearchResult};
+pub use symbol::CodeSymbol;
 pub use types::{
     CacheProviderKind, EmbeddingProviderKind, Language, OperationType, VectorStoreProviderKind,
 };
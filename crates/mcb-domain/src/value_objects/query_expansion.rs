@@ -0,0 +1,301 @@
+//! Query Expansion Value Objects
+//!
+//! Questions are asked in English while code is written in identifiers:
+//! "where do we retry http calls" shares no word with a loop counting
+//! `attempts` under a `backoff` policy. Query expansion rewrites a query
+//! into the variants searched along with it:
+//!
+//! - **Identifier variants**: identifiers of the query are split into
+//!   their words (`getUserById` gives "get user by id") and adjacent words
+//!   are joined into the identifiers they may name ("user id" gives
+//!   `user_id` and `userid`)
+//! - **Glossary synonyms**: words of the query found in a code glossary
+//!   bring their synonyms (`retry` brings `backoff` and `attempts`)
+//!
+//! The added terms extend the lexical query, and the rewrites are embedded
+//! as extra semantic queries whose results are merged by chunk.
+
+use crate::constants::{
+    QUERY_EXPANSION_GLOSSARY, QUERY_EXPANSION_MAX_QUERIES, QUERY_EXPANSION_MAX_TERMS,
+    QUERY_EXPANSION_STOPWORDS,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+
+/// Value Object: Query Expansion Settings
+///
+/// Expansion is a pure function of the query text; [`QueryExpansion::expand`]
+/// returns what to search, the search services decide how.
+///
+/// ## Example
+///
+/// ```rust
+/// use mcb_domain::value_objects::QueryExpansion;
+///
+/// let expansion = QueryExpansion::default().with_glossary([("queue", ["channel"])]);
+/// let expanded = expansion.expand("where do we retry http calls");
+///
+/// assert!(expanded.terms.contains(&"backoff".to_string()));
+/// assert!(expanded.terms.contains(&"retry_http".to_string()));
+/// assert_eq!(expanded.embedding_queries[0], "where do we retry http calls");
+///
+/// let plain = QueryExpansion::disabled().expand("where do we retry http calls");
+/// assert!(plain.terms.is_empty());
+/// assert_eq!(plain.embedding_queries.len(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QueryExpansion {
+    /// Split the identifiers of the query and join its adjacent words
+    pub identifier_variants: bool,
+    /// Synonyms of code terms, keyed by lowercase word
+    pub glossary: BTreeMap<String, Vec<String>>,
+    /// Maximum terms added to the lexical query
+    pub max_terms: usize,
+    /// Maximum queries embedded, the original included
+    pub max_queries: usize,
+}
+
+impl Default for QueryExpansion {
+    fn default() -> Self {
+        Self {
+            identifier_variants: true,
+            glossary: Self::default_glossary(),
+            max_terms: QUERY_EXPANSION_MAX_TERMS,
+            max_queries: QUERY_EXPANSION_MAX_QUERIES,
+        }
+    }
+}
+
+impl QueryExpansion {
+    /// Search the query as written
+    pub fn disabled() -> Self {
+        Self {
+            identifier_variants: false,
+            glossary: BTreeMap::new(),
+            max_terms: 0,
+            max_queries: 1,
+        }
+    }
+
+    /// Built-in glossary of common code concepts
+    pub fn default_glossary() -> BTreeMap<String, Vec<String>> {
+        QUERY_EXPANSION_GLOSSARY
+            .iter()
+            .map(|(term, synonyms)| {
+                (
+                    term.to_string(),
+                    synonyms.iter().map(|s| s.to_string()).collect(),
+                )
+            })
+            .collect()
+    }
+
+    /// Add glossary entries, extending the synonyms of terms already present
+    pub fn with_glossary<I, K, V, S>(mut self, entries: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        for (term, synonyms) in entries {
+            let term = term.as_ref().trim().to_lowercase();
+            if term.is_empty() {
+                continue;
+            }
+            let entry = self.glossary.entry(term).or_default();
+            for synonym in synonyms {
+                let synonym = synonym.as_ref().trim();
+                if !synonym.is_empty() && !entry.iter().any(|s| s == synonym) {
+                    entry.push(synonym.to_string());
+                }
+            }
+        }
+        self
+    }
+
+    /// Embed at most `max_queries` queries per search, the original included
+    pub fn with_max_queries(mut self, max_queries: usize) -> Self {
+        self.max_queries = max_queries;
+        self
+    }
+
+    /// Whether expansion may change what is searched
+    pub fn is_enabled(&self) -> bool {
+        self.max_terms > 0 || self.max_queries > 1
+    }
+
+    /// Expand a query into its lexical terms and embedding queries
+    pub fn expand(&self, text: &str) -> ExpandedQuery {
+        let mut expanded = ExpandedQuery {
+            text: text.to_string(),
+            identifiers: Vec::new(),
+            terms: Vec::new(),
+            embedding_queries: vec![text.to_string()],
+        };
+        if !self.is_enabled() {
+            return expanded;
+        }
+
+        let tokens: Vec<&str> = text
+            .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .filter(|token| token.chars().any(char::is_alphanumeric))
+            .collect();
+        let words: Vec<String> = tokens.iter().flat_map(|t| split_identifier(t)).collect();
+        // Terms the query already contains, in any of its forms
+        let mut seen: HashSet<String> = tokens
+            .iter()
+            .map(|token| token.to_lowercase())
+            .chain(words.iter().cloned())
+            .collect();
+
+        let mut synonyms = Vec::new();
+        for word in &words {
+            for synonym in self.synonyms(word) {
+                if seen.insert(synonym.to_lowercase()) {
+                    synonyms.push(synonym.clone());
+                }
+            }
+        }
+
+        let mut joined = Vec::new();
+        if self.identifier_variants {
+            for token in &tokens {
+                if split_identifier(token).len() > 1
+                    && !expanded.identifiers.iter().any(|id| id == token)
+                {
+                    expanded.identifiers.push(token.to_string());
+                }
+            }
+            for pair in tokens.windows(2) {
+                let (Some(first), Some(second)) = (plain_word(pair[0]), plain_word(pair[1])) else {
+                    continue;
+                };
+                for variant in [format!("{first}_{second}"), format!("{first}{second}")] {
+                    if seen.insert(variant.clone()) {
+                        joined.push(variant);
+                    }
+                }
+            }
+        }
+
+        // Synonyms first: they find code the query words cannot
+        let kept_synonyms = synonyms.len().min(self.max_terms);
+        expanded.terms = synonyms.into_iter().chain(joined).collect();
+        expanded.terms.truncate(self.max_terms);
+
+        if !expanded.identifiers.is_empty() {
+            expanded.embedding_queries.push(split_identifiers_in(text));
+        }
+        if kept_synonyms > 0 {
+            let synonyms = expanded.terms[..kept_synonyms].join(" ");
+            expanded
+                .embedding_queries
+                .push(format!("{text} {synonyms}"));
+        }
+        let mut unique = HashSet::new();
+        expanded
+            .embedding_queries
+            .retain(|query| unique.insert(query.clone()));
+        expanded.embedding_queries.truncate(self.max_queries.max(1));
+        expanded
+    }
+
+    /// Glossary synonyms of a lowercase word, trying its singular form too
+    fn synonyms(&self, word: &str) -> &[String] {
+        let singular = word
+            .strip_suffix("ies")
+            .map(|stem| format!("{stem}y"))
+            .or_else(|| word.strip_suffix('s').map(str::to_string));
+        self.glossary
+            .get(word)
+            .or_else(|| singular.and_then(|singular| self.glossary.get(&singular)))
+            .map_or(&[], Vec::as_slice)
+    }
+}
+
+/// Text with every identifier token replaced by its words
+///
+/// Works token by token, so an identifier containing another one
+/// (`getUser` and `get`) is never rewritten through the shorter one.
+fn split_identifiers_in(text: &str) -> String {
+    let is_token_char = |c: char| c.is_alphanumeric() || c == '_';
+    let mut split = String::with_capacity(text.len());
+    let mut rest = text;
+    while !rest.is_empty() {
+        let token_len = rest.find(|c: char| !is_token_char(c)).unwrap_or(rest.len());
+        if token_len == 0 {
+            let gap_len = rest.find(is_token_char).unwrap_or(rest.len());
+            split.push_str(&rest[..gap_len]);
+            rest = &rest[gap_len..];
+            continue;
+        }
+        let token = &rest[..token_len];
+        let words = split_identifier(token);
+        if words.len() > 1 {
+            split.push_str(&words.join(" "));
+        } else {
+            split.push_str(token);
+        }
+        rest = &rest[token_len..];
+    }
+    split
+}
+
+/// Lowercase form of a token that is a plain word worth joining
+fn plain_word(token: &str) -> Option<String> {
+    let word = token.to_lowercase();
+    let plain = token.chars().all(|c| c.is_alphabetic())
+        && split_identifier(token).len() == 1
+        && !QUERY_EXPANSION_STOPWORDS.contains(&word.as_str());
+    plain.then_some(word)
+}
+
+/// Value Object: Expanded Query
+///
+/// What a search runs for one query after [`QueryExpansion`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExpandedQuery {
+    /// Query as written
+    pub text: String,
+    /// Identifiers found in the query
+    pub identifiers: Vec<String>,
+    /// Terms added to the lexical query
+    pub terms: Vec<String>,
+    /// Queries to embed, the original first
+    pub embedding_queries: Vec<String>,
+}
+
+impl ExpandedQuery {
+    /// Text searched in the lexical index: the query and the added terms
+    pub fn lexical_text(&self) -> String {
+        if self.terms.is_empty() {
+            return self.text.clone();
+        }
+        format!("{} {}", self.text, self.terms.join(" "))
+    }
+}
+
+/// Split an identifier on underscores and case changes, lowercasing each word
+///
+/// `HTTPServerError` gives `http`, `server`, `error`; digits stay with the
+/// word before them (`utf8Decoder` gives `utf8`, `decoder`).
+pub fn split_identifier(identifier: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    for segment in identifier.split('_').filter(|s| !s.is_empty()) {
+        let chars: Vec<char> = segment.chars().collect();
+        let mut start = 0;
+        for i in 1..chars.len() {
+            let (prev, current) = (chars[i - 1], chars[i]);
+            let next_is_lower = chars.get(i + 1).is_some_and(|c| c.is_lowercase());
+            let boundary = (prev.is_lowercase() || prev.is_numeric()) && current.is_uppercase()
+                || prev.is_uppercase() && current.is_uppercase() && next_is_lower;
+            if boundary {
+                words.push(chars[start..i].iter().collect::<String>().to_lowercase());
+                start = i;
+            }
+        }
+        words.push(chars[start..].iter().collect::<String>().to_lowercase());
+    }
+    words
+}
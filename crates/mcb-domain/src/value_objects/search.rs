@@ -4,6 +4,9 @@
 //! for semantic search operations.
 
 use crate::value_objects::Language;
+use crate::value_objects::filter::{FilterField, MetadataFilter};
+use crate::value_objects::fusion::FusionStrategy;
+use crate::value_objects::symbol::CodeSymbol;
 use serde::{Deserialize, Serialize};
 
 /// Value Object: Ranked Search Result
@@ -30,6 +33,7 @@ use serde::{Deserialize, Serialize};
 ///     content: "pub fn authenticate(token: &str) -> Result<User> { ... }".to_string(),
 ///     score: 0.92,
 ///     language: "rust".to_string(),
+///     symbol: None,
 /// };
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -46,4 +50,168 @@ pub struct SearchResult {
     pub score: f64,
     /// Programming language of the matched code
     pub language: Language,
+    /// Declaration the matched chunk covers, when the chunker identified one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<CodeSymbol>,
+}
+
+/// Value Object: Search Query
+///
+/// Everything a search needs besides the collection: the query text, the
+/// number of results wanted, an optional metadata filter, an optional
+/// score threshold and an optional fusion strategy overriding the hybrid
+/// search default. The filter is evaluated by the vector store and the
+/// lexical index alike, so `limit` counts results *after* filtering.
+///
+/// ## Example
+///
+/// ```rust
+/// use mcb_domain::value_objects::{FusionStrategy, SearchQuery};
+///
+/// let query = SearchQuery::new("token validation", 10)
+///     .with_extensions(["rs", ".toml"])
+///     .with_languages(["Rust"])
+///     .excluding(["tests/", "*.generated.rs"])
+///     .with_min_score(0.5)
+///     .with_fusion(FusionStrategy::ReciprocalRank);
+///
+/// assert!(query.filter.is_some());
+/// assert!(!query.accepts_score(0.4));
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SearchQuery {
+    /// Natural language or code query text
+    pub text: String,
+    /// Maximum number of results to return
+    pub limit: usize,
+    /// Restricts which chunks may appear in the results
+    pub filter: Option<MetadataFilter>,
+    /// Results whose semantic similarity is below this threshold are dropped
+    pub min_score: Option<f64>,
+    /// Fusion strategy for this query instead of the configured one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fusion: Option<FusionStrategy>,
+}
+
+impl SearchQuery {
+    /// Create an unfiltered query
+    pub fn new(text: impl Into<String>, limit: usize) -> Self {
+        Self {
+            text: text.into(),
+            limit,
+            filter: None,
+            min_score: None,
+            fusion: None,
+        }
+    }
+
+    /// Replace the result limit
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Add a filter, AND-ed with any filter already present
+    pub fn with_filter(mut self, filter: MetadataFilter) -> Self {
+        self.filter = Some(match self.filter.take() {
+            Some(existing) => existing.and(filter),
+            None => filter,
+        });
+        self
+    }
+
+    /// Only match files with one of these extensions
+    ///
+    /// Accepts `rs`, `.rs` or `*.rs`; an empty list adds no constraint.
+    pub fn with_extensions<I, S>(self, extensions: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut globs: Vec<MetadataFilter> = extensions
+            .into_iter()
+            .filter_map(|ext| {
+                let ext = ext.as_ref().trim();
+                let ext = ext.trim_start_matches('*').trim_start_matches('.');
+                (!ext.is_empty())
+                    .then(|| MetadataFilter::glob(FilterField::FilePath, format!("**/*.{ext}")))
+            })
+            .collect();
+        match globs.len() {
+            0 => self,
+            1 => self.with_filter(globs.remove(0)),
+            _ => self.with_filter(MetadataFilter::Or(globs)),
+        }
+    }
+
+    /// Only match chunks in one of these languages (case-insensitive)
+    pub fn with_languages<I, S>(self, languages: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let languages: Vec<String> = languages
+            .into_iter()
+            .map(|lang| lang.as_ref().trim().to_lowercase())
+            .filter(|lang| !lang.is_empty())
+            .collect();
+        if languages.is_empty() {
+            return self;
+        }
+        self.with_filter(MetadataFilter::one_of(FilterField::Language, languages))
+    }
+
+    /// Exclude files matching any of these glob patterns
+    ///
+    /// Patterns are unanchored: `tests/` excludes every `tests` directory and
+    /// `*.min.js` matches at any depth.
+    pub fn excluding<I, S>(self, patterns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        patterns
+            .into_iter()
+            .filter_map(|pattern| unanchored_glob(pattern.as_ref()))
+            .fold(self, |query, pattern| {
+                query.with_filter(MetadataFilter::glob(FilterField::FilePath, pattern).negate())
+            })
+    }
+
+    /// Drop results whose semantic similarity is below `min_score`
+    pub fn with_min_score(mut self, min_score: f64) -> Self {
+        self.min_score = Some(min_score);
+        self
+    }
+
+    /// Fuse the lexical and semantic rankings with `fusion`
+    pub fn with_fusion(mut self, fusion: FusionStrategy) -> Self {
+        self.fusion = Some(fusion);
+        self
+    }
+
+    /// Whether a result with this score passes the threshold
+    pub fn accepts_score(&self, score: f64) -> bool {
+        self.min_score.is_none_or(|min| score >= min)
+    }
+}
+
+/// Turn a user exclude pattern into a glob that matches at any depth
+fn unanchored_glob(pattern: &str) -> Option<String> {
+    let pattern = pattern
+        .trim()
+        .trim_start_matches("./")
+        .trim_start_matches('/');
+    if pattern.is_empty() {
+        return None;
+    }
+    let mut glob = if pattern.starts_with("**/") {
+        pattern.to_string()
+    } else {
+        format!("**/{pattern}")
+    };
+    if glob.ends_with('/') {
+        glob.push_str("**");
+    }
+    Some(glob)
 }
@@ -0,0 +1,65 @@
+//! Code Symbol Value Objects
+//!
+//! Value objects describing the declaration a code chunk covers.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Value Object: Declared Code Symbol
+///
+/// The declaration a chunk covers: its name and kind, its signature, the
+/// scope enclosing it and its doc comment. Chunkers store it under the
+/// [`CodeSymbol::METADATA_KEY`] key of the chunk metadata, and search results
+/// carry it so a hit can be described without reading the file again.
+///
+/// ## Example
+///
+/// ```rust
+/// use mcb_domain::value_objects::CodeSymbol;
+///
+/// let symbol = CodeSymbol {
+///     name: "load_config".to_string(),
+///     kind: "fn".to_string(),
+///     signature: Some("pub fn load_config(path: &Path) -> Result<Config>".to_string()),
+///     parent: Some("impl ConfigLoader".to_string()),
+///     doc: Some("Load the configuration file".to_string()),
+/// };
+///
+/// assert_eq!(symbol.to_string(), "fn `load_config` in `impl ConfigLoader`");
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CodeSymbol {
+    /// Declared name (e.g. `load_config`, `ConfigLoader`)
+    pub name: String,
+    /// Kind of declaration (e.g. `fn`, `class`, `impl`)
+    pub kind: String,
+    /// Declaration header without its body
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+    /// Header of the enclosing declaration (e.g. `impl ConfigLoader`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
+    /// Doc comment attached to the declaration, without comment markers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+}
+
+impl CodeSymbol {
+    /// Metadata key the symbol is stored under in chunk and vector metadata
+    pub const METADATA_KEY: &'static str = "symbol";
+
+    /// Read the symbol stored in a metadata value, if any
+    pub fn from_metadata(value: Option<&serde_json::Value>) -> Option<Self> {
+        value.and_then(|value| serde_json::from_value(value.clone()).ok())
+    }
+}
+
+impl fmt::Display for CodeSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} `{}`", self.kind, self.name)?;
+        if let Some(parent) = &self.parent {
+            write!(f, " in `{}`", parent)?;
+        }
+        Ok(())
+    }
+}
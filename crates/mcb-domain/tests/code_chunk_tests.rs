@@ -76,4 +76,37 @@ mod tests {
         assert_eq!(chunk.metadata["complexity"], 2);
         assert!(chunk.metadata["methods"].is_array());
     }
+
+    #[test]
+    fn test_stable_id_is_deterministic() {
+        let id = CodeChunk::stable_id("src/auth.rs", "authenticate", 10, "fn authenticate() {}");
+
+        assert_eq!(
+            id,
+            CodeChunk::stable_id("src/auth.rs", "authenticate", 10, "fn authenticate() {}")
+        );
+        assert_eq!(id.len(), 64);
+        assert_ne!(
+            id,
+            CodeChunk::stable_id("src/other.rs", "authenticate", 10, "fn authenticate() {}")
+        );
+        assert_ne!(
+            id,
+            CodeChunk::stable_id("src/auth.rs", "authorize", 10, "fn authenticate() {}")
+        );
+        assert_ne!(
+            id,
+            CodeChunk::stable_id("src/auth.rs", "authenticate", 10, "fn authenticate() { }")
+        );
+        // Identical bodies of one file are told apart by where they start
+        assert_ne!(
+            CodeChunk::stable_id("src/auth.rs", "fallback", 1, "fn authenticate() {}"),
+            CodeChunk::stable_id("src/auth.rs", "fallback", 40, "fn authenticate() {}")
+        );
+        // Part boundaries matter
+        assert_ne!(
+            CodeChunk::stable_id("ab", "c", 1, "x"),
+            CodeChunk::stable_id("a", "bc", 1, "x")
+        );
+    }
 }
@@ -2,7 +2,7 @@
 
 use mcb_domain::{
     INDEXING_BATCH_SIZE, INDEXING_CHUNK_MIN_LENGTH, INDEXING_CHUNK_MIN_LINES,
-    INDEXING_CHUNKS_MAX_PER_FILE,
+    INDEXING_CHUNKS_MAX_PER_FILE, SEARCH_CANDIDATE_MULTIPLIER, SEARCH_CANDIDATES_MAX,
 };
 
 #[test]
@@ -49,3 +49,11 @@ fn test_constants_are_compile_time() {
     assert!(INDEXING_BATCH_SIZE * 2 == 20);
     assert!(INDEXING_CHUNK_MIN_LENGTH + 5 == 30);
 }
+
+#[test]
+#[allow(clippy::assertions_on_constants)]
+fn test_search_candidate_constants() {
+    // Over-fetching must widen the candidate pool without exceeding the cap
+    assert!(SEARCH_CANDIDATE_MULTIPLIER > 1);
+    assert!(SEARCH_CANDIDATES_MAX >= SEARCH_CANDIDATE_MULTIPLIER * 10);
+}
@@ -1,6 +1,7 @@
 //! Unit tests for Embedding value object
 
 use mcb_domain::Embedding;
+use mcb_domain::value_objects::EmbeddingBatchLimits;
 
 #[test]
 fn test_embedding_creation() {
@@ -66,3 +67,17 @@ fn test_embedding_large_vector() {
     // Use approximate comparison for floating-point due to precision
     assert!((embedding.vector[1535] - 1.535).abs() < 0.0001);
 }
+
+#[test]
+fn test_embedding_batch_limits_are_at_least_one() {
+    let limits = EmbeddingBatchLimits::new(0, 0);
+    assert_eq!(limits.max_texts, 1);
+    assert_eq!(limits.max_tokens, 1);
+
+    let defaults = EmbeddingBatchLimits::default();
+    assert_eq!(defaults.max_texts, EmbeddingBatchLimits::DEFAULT_MAX_TEXTS);
+    assert_eq!(
+        defaults.max_tokens,
+        EmbeddingBatchLimits::DEFAULT_MAX_TOKENS
+    );
+}
@@ -57,6 +57,7 @@ fn test_search_result_from_code_chunk() {
         content: chunk.content.clone(),
         score: 0.95,
         language: chunk.language.clone(),
+        symbol: None,
     };
 
     // Integration test: SearchResult preserves CodeChunk information
@@ -148,6 +149,7 @@ fn test_search_result_ranking() {
             content: "fn exact_match_function() {}".to_string(),
             score: 1.0,
             language: "rust".to_string(),
+            symbol: None,
         },
         SearchResult {
             id: "high-match".to_string(),
@@ -156,6 +158,7 @@ fn test_search_result_ranking() {
             content: "fn similar_function() {}".to_string(),
             score: 0.85,
             language: "rust".to_string(),
+            symbol: None,
         },
         SearchResult {
             id: "medium-match".to_string(),
@@ -164,6 +167,7 @@ fn test_search_result_ranking() {
             content: "fn somewhat_related() {}".to_string(),
             score: 0.65,
             language: "rust".to_string(),
+            symbol: None,
         },
         SearchResult {
             id: "low-match".to_string(),
@@ -172,6 +176,7 @@ fn test_search_result_ranking() {
             content: "fn barely_related() {}".to_string(),
             score: 0.25,
             language: "rust".to_string(),
+            symbol: None,
         },
     ];
 
@@ -0,0 +1,100 @@
+//! Unit tests for MetadataFilter value object
+
+#[cfg(test)]
+mod tests {
+    use mcb_domain::value_objects::filter::glob_matches;
+    use mcb_domain::value_objects::{FilterField, MetadataFilter};
+    use serde_json::{Value, json};
+    use std::collections::HashMap;
+
+    fn chunk_metadata(
+        file_path: &str,
+        language: &str,
+        start: u32,
+        end: u32,
+    ) -> HashMap<String, Value> {
+        HashMap::from([
+            ("file_path".to_string(), json!(file_path)),
+            ("language".to_string(), json!(language)),
+            ("node_type".to_string(), json!("function_item")),
+            ("start_line".to_string(), json!(start)),
+            ("end_line".to_string(), json!(end)),
+        ])
+    }
+
+    #[test]
+    fn test_eq_and_in_filters() {
+        let meta = chunk_metadata("src/lib.rs", "rust", 1, 10);
+
+        assert!(MetadataFilter::eq(FilterField::Language, "rust").matches(&meta));
+        assert!(!MetadataFilter::eq(FilterField::Language, "python").matches(&meta));
+        assert!(MetadataFilter::one_of(FilterField::Language, ["python", "rust"]).matches(&meta));
+        assert!(MetadataFilter::eq(FilterField::NodeType, "function_item").matches(&meta));
+    }
+
+    #[test]
+    fn test_prefix_filter_scopes_subdirectory() {
+        let filter = MetadataFilter::prefix(FilterField::FilePath, "crates/core/");
+
+        assert!(filter.matches(&chunk_metadata("crates/core/src/lib.rs", "rust", 1, 2)));
+        assert!(!filter.matches(&chunk_metadata("crates/cli/src/main.rs", "rust", 1, 2)));
+    }
+
+    #[test]
+    fn test_glob_matching() {
+        assert!(glob_matches("*.rs", "lib.rs"));
+        assert!(!glob_matches("*.rs", "src/lib.rs"));
+        assert!(glob_matches("**/*.rs", "src/lib.rs"));
+        assert!(glob_matches("**/*.rs", "lib.rs"));
+        assert!(glob_matches("src/**", "src/a/b/c.py"));
+        assert!(glob_matches("src/?.rs", "src/a.rs"));
+        assert!(!glob_matches("src/?.rs", "src/ab.rs"));
+        assert!(glob_matches("**/tests/**", "crates/x/tests/it.rs"));
+    }
+
+    #[test]
+    fn test_line_range_overlap() {
+        let meta = chunk_metadata("src/lib.rs", "rust", 20, 40);
+
+        assert!(MetadataFilter::line_range(Some(30), Some(50)).matches(&meta));
+        assert!(MetadataFilter::line_range(None, Some(20)).matches(&meta));
+        assert!(!MetadataFilter::line_range(Some(41), None).matches(&meta));
+        assert!(!MetadataFilter::line_range(Some(1), Some(19)).matches(&meta));
+    }
+
+    #[test]
+    fn test_boolean_combinators() {
+        let meta = chunk_metadata("crates/core/tests/it.rs", "rust", 1, 5);
+
+        let rust_not_tests = MetadataFilter::eq(FilterField::Language, "rust")
+            .and(MetadataFilter::glob(FilterField::FilePath, "**/tests/**").negate());
+        assert!(!rust_not_tests.matches(&meta));
+
+        let either = MetadataFilter::eq(FilterField::Language, "go")
+            .or(MetadataFilter::prefix(FilterField::FilePath, "crates/"));
+        assert!(either.matches(&meta));
+
+        assert!(MetadataFilter::And(vec![]).matches(&meta));
+        assert!(!MetadataFilter::Or(vec![]).matches(&meta));
+    }
+
+    #[test]
+    fn test_missing_field_does_not_match() {
+        let meta = HashMap::from([("file_path".to_string(), json!("src/lib.rs"))]);
+
+        assert!(!MetadataFilter::eq(FilterField::Language, "rust").matches(&meta));
+        assert!(!MetadataFilter::line_range(Some(1), None).matches(&meta));
+    }
+
+    #[test]
+    fn test_filter_serialization_roundtrip() {
+        let filter = MetadataFilter::prefix(FilterField::FilePath, "src/")
+            .and(MetadataFilter::line_range(Some(1), None));
+
+        let json = serde_json::to_value(&filter).expect("serialize filter");
+        assert_eq!(json["op"], "and");
+
+        let back: MetadataFilter = serde_json::from_value(json).expect("deserialize filter");
+        assert_eq!(back, filter);
+    }
+}
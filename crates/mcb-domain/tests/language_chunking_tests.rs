@@ -0,0 +1,91 @@
+//! Unit tests for language chunking provider file detection
+
+#[cfg(test)]
+mod tests {
+    use mcb_domain::entities::CodeChunk;
+    use mcb_domain::ports::providers::{LanguageChunkingProvider, shebang_interpreter};
+    use mcb_domain::value_objects::{ChunkSizePolicy, Language};
+
+    struct ScriptProvider;
+
+    impl LanguageChunkingProvider for ScriptProvider {
+        fn language(&self) -> Language {
+            "python".to_string()
+        }
+
+        fn extensions(&self) -> &[&'static str] {
+            &["py"]
+        }
+
+        fn chunk(&self, _content: &str, _file_path: &str) -> Vec<CodeChunk> {
+            Vec::new()
+        }
+
+        fn provider_name(&self) -> &str {
+            "script"
+        }
+
+        fn file_names(&self) -> &[&'static str] {
+            &["SConstruct"]
+        }
+
+        fn interpreters(&self) -> &[&'static str] {
+            &["python"]
+        }
+    }
+
+    #[test]
+    fn test_shebang_interpreter() {
+        assert_eq!(shebang_interpreter("#!/bin/bash"), Some("bash"));
+        assert_eq!(
+            shebang_interpreter("#!/usr/bin/env python3"),
+            Some("python")
+        );
+        assert_eq!(
+            shebang_interpreter("#!/usr/bin/env -S LC_ALL=C python3.11 -u"),
+            Some("python")
+        );
+        assert_eq!(shebang_interpreter("#! /usr/local/bin/node"), Some("node"));
+        assert_eq!(shebang_interpreter("# comment"), None);
+        assert_eq!(shebang_interpreter("#!"), None);
+    }
+
+    #[test]
+    fn test_supports_file_by_extension_name_and_shebang() {
+        let provider = ScriptProvider;
+
+        assert!(provider.supports_file("tool.PY", None));
+        assert!(provider.supports_file("SConstruct", None));
+        assert!(provider.supports_file("deploy", Some("#!/usr/bin/env python3")));
+        assert!(!provider.supports_file("deploy", Some("#!/bin/sh")));
+        assert!(!provider.supports_file("deploy", None));
+        assert!(!provider.supports_file("main.rs", None));
+    }
+
+    #[test]
+    fn test_chunk_size_policy_keeps_bounds_consistent() {
+        let policy = ChunkSizePolicy::new(0, 50, 10);
+        assert_eq!(policy.max_tokens, 1);
+        assert_eq!(policy.min_tokens, 1);
+        assert_eq!(policy.overlap_tokens, 0);
+
+        let policy = ChunkSizePolicy::new(512, 16, 400);
+        assert_eq!(policy.overlap_tokens, 256);
+    }
+
+    #[test]
+    fn test_chunk_size_policy_fits_model_limits() {
+        let policy = ChunkSizePolicy::default().fit_to_model(128, 2);
+        assert_eq!(policy.max_tokens, 128);
+        assert_eq!(policy.min_tokens, ChunkSizePolicy::DEFAULT_MIN_TOKENS);
+        assert_eq!(
+            policy.overlap_tokens,
+            ChunkSizePolicy::DEFAULT_OVERLAP_TOKENS
+        );
+        assert_eq!(policy.estimate_tokens("abcde"), 3);
+
+        let policy = ChunkSizePolicy::default().fit_to_model(8192, 0);
+        assert_eq!(policy.max_tokens, ChunkSizePolicy::DEFAULT_MAX_TOKENS);
+        assert_eq!(policy.chars_per_token, 1);
+    }
+}
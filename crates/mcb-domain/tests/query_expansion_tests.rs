@@ -0,0 +1,112 @@
+//! Unit tests for QueryExpansion value object
+
+#[cfg(test)]
+mod tests {
+    use mcb_domain::value_objects::QueryExpansion;
+
+    #[test]
+    fn test_glossary_synonyms_reach_code_vocabulary() {
+        let expanded = QueryExpansion::default().expand("where do we retry http calls");
+
+        for term in ["backoff", "attempts", "request"] {
+            assert!(
+                expanded.terms.iter().any(|t| t == term),
+                "missing {term} in {:?}",
+                expanded.terms
+            );
+        }
+        // Synonyms are embedded along with the original question
+        assert_eq!(
+            expanded.embedding_queries[0],
+            "where do we retry http calls"
+        );
+        assert!(
+            expanded
+                .embedding_queries
+                .iter()
+                .any(|q| q.contains("backoff"))
+        );
+        assert!(
+            expanded
+                .lexical_text()
+                .starts_with("where do we retry http calls ")
+        );
+    }
+
+    #[test]
+    fn test_plural_words_match_glossary_terms() {
+        let expansion = QueryExpansion {
+            max_terms: 4,
+            ..QueryExpansion::disabled()
+        }
+        .with_glossary([("retry", ["backoff"])]);
+
+        let expanded = expansion.expand("failed retries");
+
+        assert_eq!(expanded.terms, vec!["backoff"]);
+    }
+
+    #[test]
+    fn test_identifiers_are_split_and_words_joined() {
+        let expansion = QueryExpansion {
+            glossary: Default::default(),
+            ..QueryExpansion::default()
+        };
+
+        let expanded = expansion.expand("who calls getUserById");
+        assert_eq!(expanded.identifiers, vec!["getUserById"]);
+        assert!(
+            expanded
+                .embedding_queries
+                .contains(&"who calls get user by id".to_string())
+        );
+
+        // Identifiers are rewritten as tokens, never inside a longer one
+        let expanded = expansion.expand("getUser vs getUserById: get_user");
+        assert!(
+            expanded
+                .embedding_queries
+                .contains(&"get user vs get user by id: get user".to_string())
+        );
+
+        // Stopwords are never joined
+        let expanded = expansion.expand("where is the user id stored");
+        assert!(expanded.terms.contains(&"user_id".to_string()));
+        assert!(expanded.terms.contains(&"userid".to_string()));
+        assert!(!expanded.terms.iter().any(|t| t.starts_with("the")));
+    }
+
+    #[test]
+    fn test_expansion_respects_budgets() {
+        let expansion = QueryExpansion {
+            max_terms: 2,
+            max_queries: 2,
+            ..QueryExpansion::default()
+        };
+
+        let expanded = expansion.expand("retry http auth config");
+
+        assert_eq!(expanded.terms.len(), 2);
+        assert_eq!(expanded.embedding_queries.len(), 2);
+    }
+
+    #[test]
+    fn test_disabled_expansion_keeps_query() {
+        let expanded = QueryExpansion::disabled().expand("retry http calls");
+
+        assert!(expanded.terms.is_empty());
+        assert_eq!(expanded.embedding_queries, vec!["retry http calls"]);
+        assert_eq!(expanded.lexical_text(), "retry http calls");
+    }
+
+    #[test]
+    fn test_custom_glossary_extends_defaults() {
+        let expansion =
+            QueryExpansion::default().with_glossary([("Retry", vec!["resilience", " backoff "])]);
+
+        let synonyms = &expansion.glossary["retry"];
+        assert!(synonyms.contains(&"resilience".to_string()));
+        // Already present synonyms are not repeated
+        assert_eq!(synonyms.iter().filter(|s| *s == "backoff").count(), 1);
+    }
+}
@@ -1,8 +1,18 @@
-//! Unit tests for SearchResult value object
+//! Unit tests for SearchResult and SearchQuery value objects
 
 #[cfg(test)]
 mod tests {
     use mcb_domain::SearchResult;
+    use mcb_domain::value_objects::{FusionStrategy, SearchQuery};
+    use serde_json::{Value, json};
+    use std::collections::HashMap;
+
+    fn chunk_metadata(file_path: &str, language: &str) -> HashMap<String, Value> {
+        HashMap::from([
+            ("file_path".to_string(), json!(file_path)),
+            ("language".to_string(), json!(language)),
+        ])
+    }
 
     #[test]
     fn test_search_result_creation() {
@@ -13,6 +23,7 @@ mod tests {
             content: "impl SearchService for DefaultSearch { ... }".to_string(),
             score: 0.87,
             language: "rust".to_string(),
+            symbol: None,
         };
 
         assert_eq!(result.id, "chunk-123");
@@ -35,6 +46,7 @@ mod tests {
             content: "fn search_perfect_match() {}".to_string(),
             score: 0.99,
             language: "rust".to_string(),
+            symbol: None,
         };
 
         assert!(result.score > 0.95);
@@ -50,6 +62,7 @@ mod tests {
             content: "fn unrelated_function() {}".to_string(),
             score: 0.12,
             language: "rust".to_string(),
+            symbol: None,
         };
 
         assert!(result.score < 0.2);
@@ -65,6 +78,7 @@ mod tests {
             content: "pub fn process_data(data: &str) -> Result<String> { ... }".to_string(),
             score: 0.85,
             language: "rust".to_string(),
+            symbol: None,
         };
 
         let python_result = SearchResult {
@@ -74,6 +88,7 @@ mod tests {
             content: "def process_data(data: str) -> str:\n    return data.upper()".to_string(),
             score: 0.82,
             language: "python".to_string(),
+            symbol: None,
         };
 
         assert_eq!(rust_result.language, "rust");
@@ -90,6 +105,7 @@ mod tests {
             content: "unrelated content".to_string(),
             score: 0.0,
             language: "rust".to_string(),
+            symbol: None,
         };
 
         assert_eq!(result.score, 0.0);
@@ -104,8 +120,68 @@ mod tests {
             content: "exact match content".to_string(),
             score: 1.0,
             language: "rust".to_string(),
+            symbol: None,
         };
 
         assert_eq!(result.score, 1.0);
     }
+
+    #[test]
+    fn test_search_query_without_filters() {
+        let query = SearchQuery::new("auth", 10)
+            .with_extensions(Vec::<String>::new())
+            .with_languages([" "])
+            .excluding([""]);
+
+        assert_eq!(query.limit, 10);
+        assert!(query.filter.is_none());
+        assert!(query.accepts_score(0.0));
+    }
+
+    #[test]
+    fn test_search_query_extension_filter() {
+        let query = SearchQuery::new("auth", 10).with_extensions(["rs", ".py", "*.go"]);
+        let filter = query.filter.expect("extension filter");
+
+        assert!(filter.matches(&chunk_metadata("src/lib.rs", "rust")));
+        assert!(filter.matches(&chunk_metadata("/abs/repo/tool.py", "python")));
+        assert!(filter.matches(&chunk_metadata("main.go", "go")));
+        assert!(!filter.matches(&chunk_metadata("src/index.ts", "typescript")));
+    }
+
+    #[test]
+    fn test_search_query_language_and_exclude_filters() {
+        let query = SearchQuery::new("auth", 10)
+            .with_languages(["Rust"])
+            .excluding(["tests/", "*.generated.rs"]);
+        let filter = query.filter.expect("combined filter");
+
+        assert!(filter.matches(&chunk_metadata("/repo/src/auth.rs", "rust")));
+        assert!(!filter.matches(&chunk_metadata("/repo/tests/auth.rs", "rust")));
+        assert!(!filter.matches(&chunk_metadata("/repo/src/api.generated.rs", "rust")));
+        assert!(!filter.matches(&chunk_metadata("/repo/src/auth.py", "python")));
+    }
+
+    #[test]
+    fn test_search_query_min_score() {
+        let query = SearchQuery::new("auth", 5).with_min_score(0.5);
+
+        assert!(query.accepts_score(0.5));
+        assert!(!query.accepts_score(0.49));
+    }
+
+    #[test]
+    fn test_search_query_fusion_strategy() {
+        let query = SearchQuery::new("auth", 5);
+        assert_eq!(query.fusion, None);
+
+        let query = query.with_fusion("distribution".parse().expect("known strategy"));
+        assert_eq!(query.fusion, Some(FusionStrategy::DistributionBased));
+
+        // Configs and clients may use the short names
+        let parsed: FusionStrategy = serde_json::from_str("\"rrf\"").expect("short name");
+        assert_eq!(parsed, FusionStrategy::ReciprocalRank);
+        let json = serde_json::to_value(&query).expect("serializable query");
+        assert_eq!(json["fusion"], "distribution_based");
+    }
 }
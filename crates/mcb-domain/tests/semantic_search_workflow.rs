@@ -169,6 +169,7 @@ fn perform_semantic_search(
             // Scale score to always be in [0.0, 1.0] range
             score: 1.0 - (i as f64 / len.max(1.0)),
             language: chunk.language.clone(),
+            symbol: None,
         })
         .collect()
 }
@@ -10,21 +10,26 @@ pub use super::{
     backup::BackupConfig,
     cache::{CacheConfig, CacheProvider},
     daemon::DaemonConfig,
+    embedding_cache::EmbeddingCacheConfig,
     event_bus::{EventBusConfig, EventBusProvider},
+    hybrid_search::{HybridSearchConfig, QueryExpansionConfig},
+    indexing::{ChunkEnrichmentConfig, IndexingConfig},
     limits::LimitsConfig,
     logging::LoggingConfig,
     metrics::MetricsConfig,
     operations::OperationsConfig,
+    reranker::RerankerConfig,
     resilience::ResilienceConfig,
     server::{
         ServerConfig, ServerCorsConfig, ServerNetworkConfig, ServerSslConfig, ServerTimeoutConfig,
         TransportMode,
     },
     snapshot::SnapshotConfig,
+    state_store::StateStoreConfig,
     sync::SyncConfig,
 };
 
-/// Provider configurations (embedding and vector store)
+/// Provider configurations (embedding, vector store, hybrid search and reranker)
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ProvidersConfig {
     /// Embedding provider configurations
@@ -32,14 +37,23 @@ pub struct ProvidersConfig {
 
     /// Vector store provider configurations
     pub vector_store: HashMap<String, VectorStoreConfig>,
+
+    /// Hybrid search (BM25 + semantic fusion) configuration
+    pub hybrid_search: HybridSearchConfig,
+
+    /// Reranker (second ranking stage) configuration
+    pub reranker: RerankerConfig,
 }
 
-/// Infrastructure configurations (cache, event_bus, metrics, resilience, limits)
+/// Infrastructure configurations (cache, embedding cache, event_bus, metrics, resilience, limits)
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct InfrastructureConfig {
     /// Cache configuration
     pub cache: CacheConfig,
 
+    /// Embedding cache configuration
+    pub embedding_cache: EmbeddingCacheConfig,
+
     /// EventBus configuration
     pub event_bus: EventBusConfig,
 
@@ -53,7 +67,7 @@ pub struct InfrastructureConfig {
     pub limits: LimitsConfig,
 }
 
-/// Data management configurations (snapshot, sync, backup)
+/// Data management configurations (snapshot, sync, backup, state, indexing)
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct DataConfig {
     /// Snapshot configuration
@@ -64,6 +78,12 @@ pub struct DataConfig {
 
     /// Backup configuration
     pub backup: BackupConfig,
+
+    /// State store configuration
+    pub state: StateStoreConfig,
+
+    /// Indexing configuration
+    pub indexing: IndexingConfig,
 }
 
 /// System infrastructure and data configurations
@@ -0,0 +1,26 @@
+//! Embedding cache configuration types
+
+use crate::constants::*;
+use serde::{Deserialize, Serialize};
+
+/// Embedding cache configuration
+///
+/// Embeddings are stored in the configured cache provider, keyed by a hash
+/// of the embedding provider, model, dimensions and embedded text.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmbeddingCacheConfig {
+    /// Embedding cache enabled
+    pub enabled: bool,
+
+    /// TTL of cached embeddings in seconds
+    pub ttl_secs: u64,
+}
+
+impl Default for EmbeddingCacheConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            ttl_secs: EMBEDDING_CACHE_TTL_SECS,
+        }
+    }
+}
@@ -0,0 +1,113 @@
+//! Hybrid search configuration types
+
+use crate::constants::*;
+use mcb_domain::value_objects::FusionStrategy;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// Hybrid search configuration (BM25 + semantic score fusion)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HybridSearchConfig {
+    /// Hybrid search enabled (falls back to semantic-only ranking when disabled)
+    pub enabled: bool,
+
+    /// Weight of the BM25 score in the fused score (0.0-1.0)
+    pub bm25_weight: f64,
+
+    /// Weight of the semantic score in the fused score (0.0-1.0)
+    pub semantic_weight: f64,
+
+    /// BM25 k1 parameter (term frequency saturation)
+    pub bm25_k1: f64,
+
+    /// BM25 b parameter (document length normalization)
+    pub bm25_b: f64,
+
+    /// Default fusion strategy (`linear`, `rrf` or `distribution`);
+    /// queries may override it
+    pub fusion: FusionStrategy,
+
+    /// Rank constant `k` of reciprocal rank fusion
+    pub rrf_k: f64,
+
+    /// Directory the BM25 indexes are persisted in, so they survive
+    /// restarts (kept in memory only when unset)
+    pub index_path: Option<PathBuf>,
+
+    /// Split identifiers such as `IndexingServiceImpl` into their words,
+    /// keeping the full identifier as well
+    pub split_identifiers: bool,
+
+    /// Drop the keywords of each chunk's language from the BM25 index
+    pub keyword_stopwords: bool,
+
+    /// Stem the words of comments, docs and queries
+    pub stemming: bool,
+
+    /// Length of the character n-grams indexed for partial identifier
+    /// matches (disabled when unset)
+    pub ngram_size: Option<usize>,
+
+    /// Lexical variants and extra embedding queries of each search query
+    pub query_expansion: QueryExpansionConfig,
+}
+
+impl Default for HybridSearchConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            bm25_weight: HYBRID_SEARCH_BM25_WEIGHT,
+            semantic_weight: HYBRID_SEARCH_SEMANTIC_WEIGHT,
+            bm25_k1: HYBRID_SEARCH_BM25_K1,
+            bm25_b: HYBRID_SEARCH_BM25_B,
+            fusion: FusionStrategy::default(),
+            rrf_k: HYBRID_SEARCH_RRF_K,
+            index_path: None,
+            split_identifiers: true,
+            keyword_stopwords: true,
+            stemming: false,
+            ngram_size: None,
+            query_expansion: QueryExpansionConfig::default(),
+        }
+    }
+}
+
+/// Query expansion configuration
+///
+/// Expanded terms extend the BM25 query; rewrites of the query are embedded
+/// and searched along with it, their results merged by chunk.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueryExpansionConfig {
+    /// Query expansion enabled
+    pub enabled: bool,
+
+    /// Split the identifiers of the query and join its adjacent words
+    pub identifier_variants: bool,
+
+    /// Start from the built-in glossary of common code concepts
+    pub default_glossary: bool,
+
+    /// Synonyms of code terms, added to the built-in glossary
+    pub glossary: HashMap<String, Vec<String>>,
+
+    /// Maximum terms added to the BM25 query
+    pub max_terms: usize,
+
+    /// Maximum queries embedded per search, the original included
+    /// (1 embeds the original only)
+    pub max_queries: usize,
+}
+
+impl Default for QueryExpansionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            identifier_variants: true,
+            default_glossary: true,
+            glossary: HashMap::new(),
+            max_terms: QUERY_EXPANSION_MAX_TERMS,
+            max_queries: QUERY_EXPANSION_MAX_QUERIES,
+        }
+    }
+}
@@ -0,0 +1,77 @@
+//! Indexing configuration types
+
+use crate::constants::*;
+use serde::{Deserialize, Serialize};
+
+/// Indexing configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexingConfig {
+    /// Maximum files read and chunked concurrently
+    pub max_concurrent_reads: usize,
+
+    /// Maximum embedding batches stored concurrently
+    pub max_concurrent_embeddings: usize,
+
+    /// Maximum chunk size in estimated tokens, capped by the embedding model
+    pub max_chunk_tokens: usize,
+
+    /// Chunk size in estimated tokens below which adjacent chunks are merged
+    pub min_chunk_tokens: usize,
+
+    /// Estimated tokens repeated between the parts of a split chunk
+    pub chunk_overlap_tokens: usize,
+
+    /// Context embedded along with each chunk
+    pub enrichment: ChunkEnrichmentConfig,
+}
+
+impl Default for IndexingConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrent_reads: INDEXING_MAX_CONCURRENT_READS,
+            max_concurrent_embeddings: INDEXING_MAX_CONCURRENT_EMBEDDINGS,
+            max_chunk_tokens: INDEXING_MAX_CHUNK_TOKENS,
+            min_chunk_tokens: INDEXING_MIN_CHUNK_TOKENS,
+            chunk_overlap_tokens: INDEXING_CHUNK_OVERLAP_TOKENS,
+            enrichment: ChunkEnrichmentConfig::default(),
+        }
+    }
+}
+
+/// Chunk enrichment configuration
+///
+/// Chunks are embedded with a header of the selected context; the vector
+/// store keeps their original content.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChunkEnrichmentConfig {
+    /// Chunk enrichment enabled
+    pub enabled: bool,
+
+    /// Include the file path and language
+    pub file_context: bool,
+
+    /// Include the enclosing scope of the chunk's symbol
+    pub scope: bool,
+
+    /// Include the file imports the chunk refers to
+    pub imports: bool,
+
+    /// Include the doc comment of the chunk's symbol
+    pub doc_comments: bool,
+
+    /// Maximum imports included
+    pub max_imports: usize,
+}
+
+impl Default for ChunkEnrichmentConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            file_context: true,
+            scope: true,
+            imports: true,
+            doc_comments: true,
+            max_imports: INDEXING_ENRICHMENT_MAX_IMPORTS,
+        }
+    }
+}
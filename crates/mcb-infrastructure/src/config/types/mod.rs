@@ -5,14 +5,19 @@ pub mod auth;
 pub mod backup;
 pub mod cache;
 pub mod daemon;
+pub mod embedding_cache;
 pub mod event_bus;
+pub mod hybrid_search;
+pub mod indexing;
 pub mod limits;
 pub mod logging;
 pub mod metrics;
 pub mod operations;
+pub mod reranker;
 pub mod resilience;
 pub mod server;
 pub mod snapshot;
+pub mod state_store;
 pub mod sync;
 
 // Re-export main types
@@ -0,0 +1,43 @@
+//! Reranker configuration types
+
+use crate::constants::*;
+use serde::{Deserialize, Serialize};
+
+/// Reranker configuration (second ranking stage of search results)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RerankerConfig {
+    /// Reranking enabled (results keep their fused order when disabled)
+    pub enabled: bool,
+
+    /// Reranker provider (`fastembed`, `http` or `null`)
+    pub provider: String,
+
+    /// Model name (provider default when unset)
+    pub model: Option<String>,
+
+    /// Base URL of the HTTP reranking API
+    pub base_url: Option<String>,
+
+    /// API key of the HTTP reranking API
+    pub api_key: Option<String>,
+
+    /// Number of top fused results reranked per query
+    pub top_n: usize,
+
+    /// Request timeout in seconds (HTTP provider)
+    pub timeout_secs: u64,
+}
+
+impl Default for RerankerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            provider: RERANKER_PROVIDER_DEFAULT.to_string(),
+            model: None,
+            base_url: None,
+            api_key: None,
+            top_n: RERANKER_TOP_N,
+            timeout_secs: RERANKER_TIMEOUT_SECS,
+        }
+    }
+}
@@ -0,0 +1,23 @@
+//! State store configuration types
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// State store configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StateStoreConfig {
+    /// State store enabled
+    pub enabled: bool,
+
+    /// State store directory
+    pub directory: PathBuf,
+}
+
+impl Default for StateStoreConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            directory: PathBuf::from("./state"),
+        }
+    }
+}
@@ -254,6 +254,28 @@ pub const SYNC_DEBOUNCE_DELAY_MS: u64 = 500;
 /// Sync timeout in seconds
 pub const SYNC_TIMEOUT_SECS: u64 = 300;
 
+// ============================================================================
+// INDEXING CONSTANTS
+// ============================================================================
+
+/// Maximum files read and chunked concurrently while indexing
+pub const INDEXING_MAX_CONCURRENT_READS: usize = 8;
+
+/// Maximum embedding batches stored concurrently while indexing
+pub const INDEXING_MAX_CONCURRENT_EMBEDDINGS: usize = 4;
+
+/// Maximum chunk size in estimated tokens
+pub const INDEXING_MAX_CHUNK_TOKENS: usize = 512;
+
+/// Chunk size in estimated tokens below which adjacent chunks are merged
+pub const INDEXING_MIN_CHUNK_TOKENS: usize = 16;
+
+/// Estimated tokens repeated between the parts of a split chunk
+pub const INDEXING_CHUNK_OVERLAP_TOKENS: usize = 32;
+
+/// Maximum imports embedded along with a chunk
+pub const INDEXING_ENRICHMENT_MAX_IMPORTS: usize = 8;
+
 // ============================================================================
 // LIMITS CONSTANTS
 // ============================================================================
@@ -477,9 +499,25 @@ pub const HYBRID_SEARCH_BM25_WEIGHT: f64 = 0.4;
 /// Default semantic weight in hybrid search (0.6 = 60% embedding-based)
 pub const HYBRID_SEARCH_SEMANTIC_WEIGHT: f64 = 0.6;
 
+/// Rank constant of reciprocal rank fusion (60 as in the original RRF paper)
+pub const HYBRID_SEARCH_RRF_K: f64 = 60.0;
+
 /// Maximum number of results to retrieve from each search method before fusion
 pub const HYBRID_SEARCH_MAX_CANDIDATES: usize = 100;
 
+// ============================================================================
+// RERANKER CONSTANTS
+// ============================================================================
+
+/// Default reranker provider (local cross-encoder, no API key needed)
+pub const RERANKER_PROVIDER_DEFAULT: &str = "fastembed";
+
+/// Number of top fused results reranked per query
+pub const RERANKER_TOP_N: usize = 20;
+
+/// Reranking request timeout in seconds
+pub const RERANKER_TIMEOUT_SECS: u64 = 30;
+
 // ============================================================================
 // TREE-SITTER NODE TYPE CONSTANTS
 // ============================================================================
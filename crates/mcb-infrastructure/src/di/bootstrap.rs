@@ -5,7 +5,7 @@
 //!
 //! ## Architecture
 //!
-//! External providers (embedding, vector_store, cache, language) are resolved
+//! External providers (embedding, vector_store, cache, language, hybrid_search, reranker) are resolved
 //! via the linkme-based registry system. Provider Handles allow runtime switching
 //! via admin API. Infrastructure services are stored directly in AppContext.
 //!
@@ -40,29 +40,32 @@ use crate::di::admin::{
     CacheAdminService, EmbeddingAdminService, LanguageAdminService, VectorStoreAdminService,
 };
 use crate::di::handles::{
-    CacheProviderHandle, EmbeddingProviderHandle, LanguageProviderHandle, VectorStoreProviderHandle,
+    CacheProviderHandle, EmbeddingProviderHandle, HybridSearchProviderHandle,
+    LanguageProviderHandle, RerankerProviderHandle, VectorStoreProviderHandle,
 };
 use crate::di::provider_resolvers::{
-    CacheProviderResolver, EmbeddingProviderResolver, LanguageProviderResolver,
-    VectorStoreProviderResolver,
+    CacheProviderResolver, EmbeddingProviderResolver, HybridSearchProviderResolver,
+    LanguageProviderResolver, RerankerProviderResolver, VectorStoreProviderResolver,
 };
 use crate::infrastructure::{
-    admin::{NullIndexingOperations, NullPerformanceMetrics},
+    admin::NullPerformanceMetrics,
     auth::NullAuthService,
     events::TokioBroadcastEventBus,
     lifecycle::DefaultShutdownCoordinator,
     metrics::NullSystemMetricsCollector,
-    snapshot::NullSnapshotProvider,
+    snapshot::{FileHashSnapshotProvider, NullSnapshotProvider},
+    state_store::{FileStateStore, NullStateStore},
     sync::NullSyncProvider,
 };
 use mcb_application::ports::admin::{
     IndexingOperationsInterface, PerformanceMetricsInterface, ShutdownCoordinator,
 };
 use mcb_application::ports::infrastructure::{
-    AuthServiceInterface, EventBusProvider, SnapshotProvider, SyncProvider,
+    AuthServiceInterface, EventBusProvider, SnapshotProvider, StateStoreProvider, SyncProvider,
     SystemMetricsCollectorInterface,
 };
 use mcb_domain::error::Result;
+use mcb_providers::admin::DefaultIndexingOperations;
 use std::sync::Arc;
 use tracing::info;
 
@@ -84,6 +87,8 @@ pub struct AppContext {
     vector_store_handle: Arc<VectorStoreProviderHandle>,
     cache_handle: Arc<CacheProviderHandle>,
     language_handle: Arc<LanguageProviderHandle>,
+    hybrid_search_handle: Arc<HybridSearchProviderHandle>,
+    reranker_handle: Arc<RerankerProviderHandle>,
 
     // ========================================================================
     // Provider Resolvers (linkme registry access)
@@ -96,6 +101,10 @@ pub struct AppContext {
     cache_resolver: Arc<CacheProviderResolver>,
     #[allow(dead_code)]
     language_resolver: Arc<LanguageProviderResolver>,
+    #[allow(dead_code)]
+    hybrid_search_resolver: Arc<HybridSearchProviderResolver>,
+    #[allow(dead_code)]
+    reranker_resolver: Arc<RerankerProviderResolver>,
 
     // ========================================================================
     // Admin Services (switch providers via API)
@@ -113,6 +122,7 @@ pub struct AppContext {
     metrics_collector: Arc<dyn SystemMetricsCollectorInterface>,
     sync_provider: Arc<dyn SyncProvider>,
     snapshot_provider: Arc<dyn SnapshotProvider>,
+    state_store: Arc<dyn StateStoreProvider>,
     shutdown_coordinator: Arc<dyn ShutdownCoordinator>,
     performance_metrics: Arc<dyn PerformanceMetricsInterface>,
     indexing_operations: Arc<dyn IndexingOperationsInterface>,
@@ -143,6 +153,16 @@ impl AppContext {
         self.language_handle.clone()
     }
 
+    /// Get hybrid search provider handle
+    pub fn hybrid_search_handle(&self) -> Arc<HybridSearchProviderHandle> {
+        self.hybrid_search_handle.clone()
+    }
+
+    /// Get reranker provider handle
+    pub fn reranker_handle(&self) -> Arc<RerankerProviderHandle> {
+        self.reranker_handle.clone()
+    }
+
     // ========================================================================
     // Admin Services (switch providers via API)
     // ========================================================================
@@ -200,6 +220,11 @@ impl AppContext {
         self.snapshot_provider.clone()
     }
 
+    /// Get state store
+    pub fn state_store(&self) -> Arc<dyn StateStoreProvider> {
+        self.state_store.clone()
+    }
+
     /// Get shutdown coordinator
     pub fn shutdown(&self) -> Arc<dyn ShutdownCoordinator> {
         self.shutdown_coordinator.clone()
@@ -223,6 +248,8 @@ impl std::fmt::Debug for AppContext {
             .field("vector_store", &self.vector_store_handle)
             .field("cache", &self.cache_handle)
             .field("language", &self.language_handle)
+            .field("hybrid_search", &self.hybrid_search_handle)
+            .field("reranker", &self.reranker_handle)
             .finish_non_exhaustive()
     }
 }
@@ -247,6 +274,8 @@ pub async fn init_app(config: AppConfig) -> Result<AppContext> {
     let vector_store_resolver = Arc::new(VectorStoreProviderResolver::new(config.clone()));
     let cache_resolver = Arc::new(CacheProviderResolver::new(config.clone()));
     let language_resolver = Arc::new(LanguageProviderResolver::new(config.clone()));
+    let hybrid_search_resolver = Arc::new(HybridSearchProviderResolver::new(config.clone()));
+    let reranker_resolver = Arc::new(RerankerProviderResolver::new(config.clone()));
 
     info!("Created provider resolvers");
 
@@ -270,12 +299,21 @@ pub async fn init_app(config: AppConfig) -> Result<AppContext> {
         .resolve_from_config()
         .map_err(|e| mcb_domain::error::Error::configuration(format!("Language: {e}")))?;
 
+    let hybrid_search_provider = hybrid_search_resolver
+        .resolve_from_config()
+        .map_err(|e| mcb_domain::error::Error::configuration(format!("HybridSearch: {e}")))?;
+
+    let reranker_provider = reranker_resolver
+        .resolve_from_config()
+        .map_err(|e| mcb_domain::error::Error::configuration(format!("Reranker: {e}")))?;
+
     info!(
-        "Resolved providers: embedding={}, vector_store={}, cache={}, language={}",
+        "Resolved providers: embedding={}, vector_store={}, cache={}, language={}, reranker={}",
         embedding_provider.provider_name(),
         vector_store_provider.provider_name(),
         cache_provider.provider_name(),
-        language_provider.provider_name()
+        language_provider.provider_name(),
+        reranker_provider.provider_name()
     );
 
     // ========================================================================
@@ -286,6 +324,8 @@ pub async fn init_app(config: AppConfig) -> Result<AppContext> {
     let vector_store_handle = Arc::new(VectorStoreProviderHandle::new(vector_store_provider));
     let cache_handle = Arc::new(CacheProviderHandle::new(cache_provider));
     let language_handle = Arc::new(LanguageProviderHandle::new(language_provider));
+    let hybrid_search_handle = Arc::new(HybridSearchProviderHandle::new(hybrid_search_provider));
+    let reranker_handle = Arc::new(RerankerProviderHandle::new(reranker_provider));
 
     info!("Created provider handles");
 
@@ -321,13 +361,26 @@ pub async fn init_app(config: AppConfig) -> Result<AppContext> {
     let metrics_collector: Arc<dyn SystemMetricsCollectorInterface> =
         Arc::new(NullSystemMetricsCollector::new());
     let sync_provider: Arc<dyn SyncProvider> = Arc::new(NullSyncProvider::new());
-    let snapshot_provider: Arc<dyn SnapshotProvider> = Arc::new(NullSnapshotProvider::new());
+    let snapshot_config = &config.system.data.snapshot;
+    let snapshot_provider: Arc<dyn SnapshotProvider> =
+        if snapshot_config.enabled && snapshot_config.change_detection_enabled {
+            Arc::new(FileHashSnapshotProvider::new(snapshot_config))
+        } else {
+            Arc::new(NullSnapshotProvider::new())
+        };
+    let state_config = &config.system.data.state;
+    let state_store: Arc<dyn StateStoreProvider> = if state_config.enabled {
+        Arc::new(FileStateStore::new(state_config))
+    } else {
+        Arc::new(NullStateStore::new())
+    };
     let shutdown_coordinator: Arc<dyn ShutdownCoordinator> =
         Arc::new(DefaultShutdownCoordinator::new());
     let performance_metrics: Arc<dyn PerformanceMetricsInterface> =
         Arc::new(NullPerformanceMetrics);
+    // Tracks the operations of the indexing service for the admin API
     let indexing_operations: Arc<dyn IndexingOperationsInterface> =
-        Arc::new(NullIndexingOperations);
+        Arc::new(DefaultIndexingOperations::new());
 
     info!("Created infrastructure services");
 
@@ -337,10 +390,14 @@ pub async fn init_app(config: AppConfig) -> Result<AppContext> {
         vector_store_handle,
         cache_handle,
         language_handle,
+        hybrid_search_handle,
+        reranker_handle,
         embedding_resolver,
         vector_store_resolver,
         cache_resolver,
         language_resolver,
+        hybrid_search_resolver,
+        reranker_resolver,
         embedding_admin,
         vector_store_admin,
         cache_admin,
@@ -350,6 +407,7 @@ pub async fn init_app(config: AppConfig) -> Result<AppContext> {
         metrics_collector,
         sync_provider,
         snapshot_provider,
+        state_store,
         shutdown_coordinator,
         performance_metrics,
         indexing_operations,
@@ -14,7 +14,8 @@
 // dill macros removed - they conflict with the need for manual constructors
 // that accept initial provider instances. Use add_value pattern in bootstrap.rs instead.
 use mcb_application::ports::providers::{
-    CacheProvider, EmbeddingProvider, LanguageChunkingProvider, VectorStoreProvider,
+    CacheProvider, EmbeddingProvider, HybridSearchProvider, LanguageChunkingProvider,
+    RerankerProvider, VectorStoreProvider,
 };
 use std::sync::{Arc, RwLock};
 
@@ -202,3 +203,87 @@ impl std::fmt::Debug for LanguageProviderHandle {
         f.debug_struct("LanguageProviderHandle").finish()
     }
 }
+
+// ============================================================================
+// Hybrid Search Provider Handle
+// ============================================================================
+
+/// Handle for runtime-swappable hybrid search provider
+// Note: dill #[component] removed - requires manual constructor with initial provider
+// Use add_value pattern in bootstrap.rs instead
+pub struct HybridSearchProviderHandle {
+    inner: RwLock<Arc<dyn HybridSearchProvider>>,
+}
+
+impl HybridSearchProviderHandle {
+    /// Create a new handle with an initial provider
+    pub fn new(provider: Arc<dyn HybridSearchProvider>) -> Self {
+        Self {
+            inner: RwLock::new(provider),
+        }
+    }
+
+    /// Get the current provider
+    pub fn get(&self) -> Arc<dyn HybridSearchProvider> {
+        self.inner
+            .read()
+            .expect("HybridSearchProviderHandle lock poisoned") // mcb-validate-ignore: lock_poisoning_recovery
+            .clone()
+    }
+
+    /// Set a new provider (used by admin service)
+    pub fn set(&self, new_provider: Arc<dyn HybridSearchProvider>) {
+        *self
+            .inner
+            .write()
+            .expect("HybridSearchProviderHandle lock poisoned") = new_provider; // mcb-validate-ignore: lock_poisoning_recovery
+    }
+}
+
+impl std::fmt::Debug for HybridSearchProviderHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HybridSearchProviderHandle").finish()
+    }
+}
+
+// ============================================================================
+// Reranker Provider Handle
+// ============================================================================
+
+/// Handle for runtime-swappable reranker provider
+// Note: dill #[component] removed - requires manual constructor with initial provider
+// Use add_value pattern in bootstrap.rs instead
+pub struct RerankerProviderHandle {
+    inner: RwLock<Arc<dyn RerankerProvider>>,
+}
+
+impl RerankerProviderHandle {
+    /// Create a new handle with an initial provider
+    pub fn new(provider: Arc<dyn RerankerProvider>) -> Self {
+        Self {
+            inner: RwLock::new(provider),
+        }
+    }
+
+    /// Get the current provider
+    pub fn get(&self) -> Arc<dyn RerankerProvider> {
+        self.inner
+            .read()
+            .expect("RerankerProviderHandle lock poisoned") // mcb-validate-ignore: lock_poisoning_recovery
+            .clone()
+    }
+
+    /// Set a new provider (used by admin service)
+    pub fn set(&self, new_provider: Arc<dyn RerankerProvider>) {
+        *self
+            .inner
+            .write()
+            .expect("RerankerProviderHandle lock poisoned") = new_provider; // mcb-validate-ignore: lock_poisoning_recovery
+    }
+}
+
+impl std::fmt::Debug for RerankerProviderHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RerankerProviderHandle").finish()
+    }
+}
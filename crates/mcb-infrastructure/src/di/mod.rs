@@ -39,11 +39,12 @@ pub use admin::{
 pub use bootstrap::*;
 pub use dispatch::*;
 pub use handles::{
-    CacheProviderHandle, EmbeddingProviderHandle, LanguageProviderHandle, VectorStoreProviderHandle,
+    CacheProviderHandle, EmbeddingProviderHandle, HybridSearchProviderHandle,
+    LanguageProviderHandle, RerankerProviderHandle, VectorStoreProviderHandle,
 };
 pub use modules::{DomainServicesContainer, DomainServicesFactory, ServiceDependencies};
 pub use provider_resolvers::{
-    CacheProviderResolver, EmbeddingProviderResolver, LanguageProviderResolver,
-    VectorStoreProviderResolver,
+    CacheProviderResolver, EmbeddingProviderResolver, HybridSearchProviderResolver,
+    LanguageProviderResolver, RerankerProviderResolver, VectorStoreProviderResolver,
 };
 pub use resolver::{ResolvedProviders, resolve_providers};
@@ -10,16 +10,31 @@
 //! (embedding provider, vector store, cache).
 
 use crate::cache::provider::SharedCacheProvider;
-use crate::config::AppConfig;
+use crate::config::{
+    AppConfig, ChunkEnrichmentConfig, EmbeddingCacheConfig, IndexingConfig, QueryExpansionConfig,
+    RerankerConfig, SyncConfig,
+};
+use crate::constants::EMBEDDING_CACHE_NAMESPACE;
 use crate::crypto::CryptoService;
+use crate::infrastructure::file_watcher::{FileWatcherService, WatchingIndexingService};
 use mcb_application::domain_services::search::{
     ContextServiceInterface, IndexingServiceInterface, SearchServiceInterface,
 };
+use mcb_application::ports::admin::{IndexingOperationsInterface, LifecycleManaged};
+use mcb_application::ports::infrastructure::{
+    EventBusProvider, SnapshotProvider, StateStoreProvider,
+};
+use mcb_application::ports::providers::cache::CacheEntryConfig;
 use mcb_application::ports::providers::{
-    EmbeddingProvider, LanguageChunkingProvider, VectorStoreProvider,
+    EmbeddingProvider, HybridSearchProvider, LanguageChunkingProvider, RerankerProvider,
+    VectorStoreProvider,
+};
+use mcb_application::use_cases::{
+    ChunkEnrichment, ContextServiceImpl, IndexingConcurrency, IndexingServiceImpl,
+    SearchServiceImpl,
 };
-use mcb_application::use_cases::{ContextServiceImpl, IndexingServiceImpl, SearchServiceImpl};
 use mcb_domain::error::Result;
+use mcb_domain::value_objects::QueryExpansion;
 use std::sync::Arc;
 
 use super::super::bootstrap::AppContext;
@@ -30,6 +45,8 @@ pub struct DomainServicesContainer {
     pub context_service: Arc<dyn ContextServiceInterface>,
     pub search_service: Arc<dyn SearchServiceInterface>,
     pub indexing_service: Arc<dyn IndexingServiceInterface>,
+    /// Background file watcher, present when sync is enabled
+    pub file_watcher: Option<Arc<dyn LifecycleManaged>>,
 }
 
 /// Dependencies for creating domain services
@@ -49,6 +66,18 @@ pub struct ServiceDependencies {
     pub vector_store_provider: Arc<dyn VectorStoreProvider>,
     /// Language chunker for code processing
     pub language_chunker: Arc<dyn LanguageChunkingProvider>,
+    /// Hybrid search provider for BM25 + semantic fusion
+    pub hybrid_search_provider: Arc<dyn HybridSearchProvider>,
+    /// Reranker re-scoring the top search results
+    pub reranker_provider: Arc<dyn RerankerProvider>,
+    /// Snapshot provider for incremental re-indexing
+    pub snapshot_provider: Arc<dyn SnapshotProvider>,
+    /// State store for indexing checkpoints
+    pub state_store: Arc<dyn StateStoreProvider>,
+    /// Event bus for indexing and sync notifications
+    pub event_bus: Arc<dyn EventBusProvider>,
+    /// Tracker of running indexing operations
+    pub indexing_operations: Arc<dyn IndexingOperationsInterface>,
 }
 
 /// Domain services factory - creates services with runtime dependencies
@@ -58,42 +87,149 @@ impl DomainServicesFactory {
     /// Create domain services using infrastructure components
     pub async fn create_services(deps: ServiceDependencies) -> Result<DomainServicesContainer> {
         // Create context service with dependencies
-        let context_service: Arc<dyn ContextServiceInterface> = Arc::new(ContextServiceImpl::new(
-            deps.cache.into(),
-            deps.embedding_provider,
-            deps.vector_store_provider,
-        ));
+        let context_service: Arc<dyn ContextServiceInterface> = Arc::new(
+            ContextServiceImpl::new(
+                deps.cache.into(),
+                deps.embedding_provider,
+                deps.vector_store_provider,
+                Arc::clone(&deps.hybrid_search_provider),
+            )
+            .with_embedding_cache(Self::embedding_cache_entry(
+                &deps.config.system.infrastructure.embedding_cache,
+            ))
+            .with_enrichment(Self::chunk_enrichment(
+                &deps.config.system.data.indexing.enrichment,
+            ))
+            .with_query_expansion(Self::query_expansion(
+                &deps.config.providers.hybrid_search.query_expansion,
+            )),
+        );
 
-        // Create search service with context service dependency
-        let search_service: Arc<dyn SearchServiceInterface> =
-            Arc::new(SearchServiceImpl::new(Arc::clone(&context_service)));
+        // Create search service with context service, hybrid search and reranker dependencies
+        let search_service: Arc<dyn SearchServiceInterface> = Arc::new(
+            SearchServiceImpl::new(Arc::clone(&context_service), deps.hybrid_search_provider)
+                .with_reranker(
+                    deps.reranker_provider,
+                    Self::rerank_budget(&deps.config.providers.reranker),
+                )
+                .with_query_expansion(Self::query_expansion(
+                    &deps.config.providers.hybrid_search.query_expansion,
+                )),
+        );
 
-        // Create indexing service with context service and language chunker dependency
+        // Create indexing service with context service, language chunker, snapshot,
+        // checkpoint and operation reporting dependencies
         let indexing_service: Arc<dyn IndexingServiceInterface> = Arc::new(
-            IndexingServiceImpl::new(Arc::clone(&context_service), deps.language_chunker),
+            IndexingServiceImpl::new(
+                Arc::clone(&context_service),
+                deps.language_chunker,
+                deps.snapshot_provider,
+                Arc::clone(&deps.state_store),
+                Arc::clone(&deps.event_bus),
+                deps.indexing_operations,
+            )
+            .with_concurrency(Self::indexing_concurrency(
+                &deps.config.system.data.indexing,
+            )),
+        );
+
+        // Keep indexed codebases in sync when enabled
+        let (indexing_service, file_watcher) = Self::watch_indexed_codebases(
+            indexing_service,
+            &deps.config.system.data.sync,
+            deps.event_bus,
+            deps.state_store,
         );
 
         Ok(DomainServicesContainer {
             context_service,
             search_service,
             indexing_service,
+            file_watcher,
         })
     }
 
     /// Create indexing service from app context
+    ///
+    /// Returns the service along with the file watcher keeping indexed
+    /// codebases in sync, present when sync is enabled. The caller starts
+    /// and stops the watcher.
     pub async fn create_indexing_service(
         app_context: &AppContext,
-    ) -> Result<Arc<dyn IndexingServiceInterface>> {
+    ) -> Result<(
+        Arc<dyn IndexingServiceInterface>,
+        Option<Arc<dyn LifecycleManaged>>,
+    )> {
         // Get providers from handles (runtime-swappable)
         let language_chunker = app_context.language_handle().get();
 
         // Create context service first (dependency)
         let context_service = Self::create_context_service(app_context).await?;
 
-        Ok(Arc::new(IndexingServiceImpl::new(
-            context_service,
-            language_chunker,
-        )))
+        let indexing_service: Arc<dyn IndexingServiceInterface> = Arc::new(
+            IndexingServiceImpl::new(
+                context_service,
+                language_chunker,
+                app_context.snapshot(),
+                app_context.state_store(),
+                app_context.event_bus(),
+                app_context.indexing(),
+            )
+            .with_concurrency(Self::indexing_concurrency(
+                &app_context.config.system.data.indexing,
+            )),
+        );
+
+        // Keep indexed codebases in sync when enabled
+        Ok(Self::watch_indexed_codebases(
+            indexing_service,
+            &app_context.config.system.data.sync,
+            app_context.event_bus(),
+            app_context.state_store(),
+        ))
+    }
+
+    /// Register the codebases `indexing_service` indexes with a file watcher
+    /// when sync is enabled
+    ///
+    /// Returns the wrapped service and the watcher, or the service unchanged
+    /// when sync is disabled.
+    fn watch_indexed_codebases(
+        indexing_service: Arc<dyn IndexingServiceInterface>,
+        config: &SyncConfig,
+        event_bus: Arc<dyn EventBusProvider>,
+        state_store: Arc<dyn StateStoreProvider>,
+    ) -> (
+        Arc<dyn IndexingServiceInterface>,
+        Option<Arc<dyn LifecycleManaged>>,
+    ) {
+        if !config.enabled {
+            return (indexing_service, None);
+        }
+        let watcher = Arc::new(FileWatcherService::new(
+            config,
+            Arc::clone(&indexing_service),
+            event_bus,
+            state_store,
+        ));
+        let indexing_service: Arc<dyn IndexingServiceInterface> = Arc::new(
+            WatchingIndexingService::new(indexing_service, Arc::clone(&watcher)),
+        );
+        let file_watcher: Arc<dyn LifecycleManaged> = watcher;
+        (indexing_service, Some(file_watcher))
+    }
+
+    /// Number of top results reranked per query, zero when reranking is off
+    fn rerank_budget(config: &RerankerConfig) -> usize {
+        if config.enabled { config.top_n } else { 0 }
+    }
+
+    /// Concurrency limits of indexing operations from configuration
+    fn indexing_concurrency(config: &IndexingConfig) -> IndexingConcurrency {
+        IndexingConcurrency {
+            max_concurrent_reads: config.max_concurrent_reads,
+            max_concurrent_embeddings: config.max_concurrent_embeddings,
+        }
     }
 
     /// Create context service from app context
@@ -104,12 +240,67 @@ impl DomainServicesFactory {
         let cache_provider = app_context.cache_handle().get();
         let embedding_provider = app_context.embedding_handle().get();
         let vector_store_provider = app_context.vector_store_handle().get();
+        let hybrid_search_provider = app_context.hybrid_search_handle().get();
+
+        Ok(Arc::new(
+            ContextServiceImpl::new(
+                cache_provider,
+                embedding_provider,
+                vector_store_provider,
+                hybrid_search_provider,
+            )
+            .with_embedding_cache(Self::embedding_cache_entry(
+                &app_context.config.system.infrastructure.embedding_cache,
+            ))
+            .with_enrichment(Self::chunk_enrichment(
+                &app_context.config.system.data.indexing.enrichment,
+            ))
+            .with_query_expansion(Self::query_expansion(
+                &app_context.config.providers.hybrid_search.query_expansion,
+            )),
+        ))
+    }
+
+    /// Cache entry configuration of embeddings, `None` when not cached
+    fn embedding_cache_entry(config: &EmbeddingCacheConfig) -> Option<CacheEntryConfig> {
+        config.enabled.then(|| {
+            CacheEntryConfig::new()
+                .with_ttl_secs(config.ttl_secs)
+                .with_namespace(EMBEDDING_CACHE_NAMESPACE)
+        })
+    }
+
+    /// Context embedded along with each chunk from configuration
+    fn chunk_enrichment(config: &ChunkEnrichmentConfig) -> ChunkEnrichment {
+        if !config.enabled {
+            return ChunkEnrichment::disabled();
+        }
+        ChunkEnrichment {
+            file_context: config.file_context,
+            scope: config.scope,
+            imports: config.imports,
+            doc_comments: config.doc_comments,
+            max_imports: config.max_imports,
+        }
+    }
 
-        Ok(Arc::new(ContextServiceImpl::new(
-            cache_provider,
-            embedding_provider,
-            vector_store_provider,
-        )))
+    /// Query expansion of searches from configuration
+    fn query_expansion(config: &QueryExpansionConfig) -> QueryExpansion {
+        if !config.enabled {
+            return QueryExpansion::disabled();
+        }
+        let glossary = if config.default_glossary {
+            QueryExpansion::default_glossary()
+        } else {
+            Default::default()
+        };
+        QueryExpansion {
+            identifier_variants: config.identifier_variants,
+            glossary,
+            max_terms: config.max_terms,
+            max_queries: config.max_queries,
+        }
+        .with_glossary(&config.glossary)
     }
 
     /// Create search service from app context
@@ -118,7 +309,18 @@ impl DomainServicesFactory {
     ) -> Result<Arc<dyn SearchServiceInterface>> {
         // Create context service first (dependency)
         let context_service = Self::create_context_service(app_context).await?;
+        let hybrid_search_provider = app_context.hybrid_search_handle().get();
+        let reranker_provider = app_context.reranker_handle().get();
 
-        Ok(Arc::new(SearchServiceImpl::new(context_service)))
+        Ok(Arc::new(
+            SearchServiceImpl::new(context_service, hybrid_search_provider)
+                .with_reranker(
+                    reranker_provider,
+                    Self::rerank_budget(&app_context.config.providers.reranker),
+                )
+                .with_query_expansion(Self::query_expansion(
+                    &app_context.config.providers.hybrid_search.query_expansion,
+                )),
+        ))
     }
 }
@@ -9,15 +9,17 @@
 //! AppConfig (injected) → Resolver → linkme registry → Arc<dyn Provider>
 //! ```
 
-use crate::config::AppConfig;
+use crate::config::{AppConfig, IndexingConfig};
 // dill macros removed - they conflict with manual new() methods
 use mcb_application::ports::providers::{
-    CacheProvider, EmbeddingProvider, LanguageChunkingProvider, VectorStoreProvider,
+    CacheProvider, EmbeddingProvider, HybridSearchProvider, LanguageChunkingProvider,
+    RerankerProvider, VectorStoreProvider,
 };
 use mcb_application::ports::registry::{
-    CacheProviderConfig, EmbeddingProviderConfig, LanguageProviderConfig,
-    VectorStoreProviderConfig, resolve_cache_provider, resolve_embedding_provider,
-    resolve_language_provider, resolve_vector_store_provider,
+    CacheProviderConfig, EmbeddingProviderConfig, HybridSearchProviderConfig,
+    LanguageProviderConfig, RerankerProviderConfig, VectorStoreProviderConfig,
+    resolve_cache_provider, resolve_embedding_provider, resolve_hybrid_search_provider,
+    resolve_language_provider, resolve_reranker_provider, resolve_vector_store_provider,
 };
 use mcb_domain::value_objects::{EmbeddingConfig, VectorStoreConfig};
 use std::sync::Arc;
@@ -191,7 +193,6 @@ impl std::fmt::Debug for CacheProviderResolver {
 // Note: dill #[component] removed - conflicts with manual new() method
 // Use add_value pattern in bootstrap.rs instead
 pub struct LanguageProviderResolver {
-    #[allow(dead_code)]
     config: Arc<AppConfig>,
 }
 
@@ -204,7 +205,7 @@ impl LanguageProviderResolver {
     /// Resolve provider from current application config
     pub fn resolve_from_config(&self) -> Result<Arc<dyn LanguageChunkingProvider>, String> {
         // Language provider is always "universal" for now
-        let registry_config = LanguageProviderConfig::new("universal");
+        let registry_config = language_config_to_registry(&self.config.system.data.indexing);
         resolve_language_provider(&registry_config)
     }
 
@@ -228,6 +229,140 @@ impl std::fmt::Debug for LanguageProviderResolver {
     }
 }
 
+// ============================================================================
+// Hybrid Search Provider Resolver
+// ============================================================================
+
+/// Resolver component for hybrid search providers
+///
+/// Resolves the BM25 + semantic fusion engine when hybrid search is enabled,
+/// and the pass-through null provider otherwise.
+// Note: dill #[component] removed - conflicts with manual new() method
+// Use add_value pattern in bootstrap.rs instead
+pub struct HybridSearchProviderResolver {
+    config: Arc<AppConfig>,
+}
+
+impl HybridSearchProviderResolver {
+    /// Create a new resolver with config
+    pub fn new(config: Arc<AppConfig>) -> Self {
+        Self { config }
+    }
+
+    /// Resolve provider from current application config
+    pub fn resolve_from_config(&self) -> Result<Arc<dyn HybridSearchProvider>, String> {
+        let hybrid = &self.config.providers.hybrid_search;
+
+        let registry_config = if hybrid.enabled {
+            let config = HybridSearchProviderConfig::new("hybrid")
+                .with_weights(hybrid.bm25_weight as f32, hybrid.semantic_weight as f32)
+                .with_bm25_params(hybrid.bm25_k1 as f32, hybrid.bm25_b as f32)
+                .with_fusion(hybrid.fusion)
+                .with_rrf_k(hybrid.rrf_k as f32)
+                .with_analyzer(
+                    hybrid.split_identifiers,
+                    hybrid.keyword_stopwords,
+                    hybrid.stemming,
+                );
+            let config = match hybrid.ngram_size {
+                Some(size) => config.with_ngram_size(size),
+                None => config,
+            };
+            match &hybrid.index_path {
+                Some(path) => config.with_index_path(path),
+                None => config,
+            }
+        } else {
+            HybridSearchProviderConfig::new("null")
+        };
+
+        resolve_hybrid_search_provider(&registry_config)
+    }
+
+    /// Resolve provider from override config (for admin API)
+    pub fn resolve_from_override(
+        &self,
+        override_config: &HybridSearchProviderConfig,
+    ) -> Result<Arc<dyn HybridSearchProvider>, String> {
+        resolve_hybrid_search_provider(override_config)
+    }
+
+    /// List available hybrid search providers
+    pub fn list_available(&self) -> Vec<(&'static str, &'static str)> {
+        mcb_application::ports::registry::list_hybrid_search_providers()
+    }
+}
+
+impl std::fmt::Debug for HybridSearchProviderResolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HybridSearchProviderResolver").finish()
+    }
+}
+
+// ============================================================================
+// Reranker Provider Resolver
+// ============================================================================
+
+/// Resolver component for reranker providers
+///
+/// Resolves the configured reranker when reranking is enabled and the null
+/// provider otherwise.
+// Note: dill #[component] removed - conflicts with manual new() method
+// Use add_value pattern in bootstrap.rs instead
+pub struct RerankerProviderResolver {
+    config: Arc<AppConfig>,
+}
+
+impl RerankerProviderResolver {
+    /// Create a new resolver with config
+    pub fn new(config: Arc<AppConfig>) -> Self {
+        Self { config }
+    }
+
+    /// Resolve provider from current application config
+    pub fn resolve_from_config(&self) -> Result<Arc<dyn RerankerProvider>, String> {
+        let reranker = &self.config.providers.reranker;
+
+        let registry_config = if reranker.enabled {
+            let mut config = RerankerProviderConfig::new(&reranker.provider)
+                .with_timeout_secs(reranker.timeout_secs);
+            if let Some(model) = &reranker.model {
+                config = config.with_model(model);
+            }
+            if let Some(base_url) = &reranker.base_url {
+                config = config.with_base_url(base_url);
+            }
+            if let Some(api_key) = &reranker.api_key {
+                config = config.with_api_key(api_key);
+            }
+            config
+        } else {
+            RerankerProviderConfig::new("null")
+        };
+
+        resolve_reranker_provider(&registry_config)
+    }
+
+    /// Resolve provider from override config (for admin API)
+    pub fn resolve_from_override(
+        &self,
+        override_config: &RerankerProviderConfig,
+    ) -> Result<Arc<dyn RerankerProvider>, String> {
+        resolve_reranker_provider(override_config)
+    }
+
+    /// List available reranker providers
+    pub fn list_available(&self) -> Vec<(&'static str, &'static str)> {
+        mcb_application::ports::registry::list_reranker_providers()
+    }
+}
+
+impl std::fmt::Debug for RerankerProviderResolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RerankerProviderResolver").finish()
+    }
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
@@ -257,3 +392,11 @@ fn vector_store_config_to_registry(config: &VectorStoreConfig) -> VectorStorePro
         extra: Default::default(),
     }
 }
+
+/// Convert indexing chunk size settings to a universal LanguageProviderConfig
+fn language_config_to_registry(config: &IndexingConfig) -> LanguageProviderConfig {
+    LanguageProviderConfig::new("universal")
+        .with_max_chunk_size(config.max_chunk_tokens)
+        .with_min_chunk_size(config.min_chunk_tokens)
+        .with_overlap(config.chunk_overlap_tokens)
+}
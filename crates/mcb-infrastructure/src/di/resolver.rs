@@ -55,7 +55,7 @@ use mcb_application::ports::registry::{
 use mcb_domain::error::{Error, Result};
 use mcb_domain::value_objects::{EmbeddingConfig, VectorStoreConfig};
 
-use crate::config::AppConfig;
+use crate::config::{AppConfig, IndexingConfig};
 
 /// Resolved providers from configuration
 ///
@@ -129,8 +129,8 @@ pub fn resolve_providers(config: &AppConfig) -> Result<ResolvedProviders> {
         extra: Default::default(),
     };
 
-    // Language config - use "universal" as default
-    let language_config = LanguageProviderConfig::new("universal");
+    // Language config - "universal" chunker sized by the indexing settings
+    let language_config = language_config_to_registry(&config.system.data.indexing);
 
     // Resolve each provider from registry
     let embedding = resolve_embedding_provider(&embedding_config).map_err(|e| {
@@ -181,6 +181,14 @@ fn vector_store_config_to_registry(config: &VectorStoreConfig) -> VectorStorePro
     }
 }
 
+/// Convert indexing chunk size settings to a universal LanguageProviderConfig
+fn language_config_to_registry(config: &IndexingConfig) -> LanguageProviderConfig {
+    LanguageProviderConfig::new("universal")
+        .with_max_chunk_size(config.max_chunk_tokens)
+        .with_min_chunk_size(config.min_chunk_tokens)
+        .with_overlap(config.chunk_overlap_tokens)
+}
+
 /// Default embedding config for testing
 fn default_embedding_config() -> EmbeddingProviderConfig {
     EmbeddingProviderConfig::new("null")
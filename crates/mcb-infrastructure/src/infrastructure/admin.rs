@@ -3,11 +3,7 @@
 //! Null implementations of admin port traits for testing.
 //! Real implementations are in mcb-providers and created at runtime.
 
-use mcb_application::ports::admin::{
-    IndexingOperation, IndexingOperationsInterface, PerformanceMetricsData,
-    PerformanceMetricsInterface,
-};
-use std::collections::HashMap;
+use mcb_application::ports::admin::{PerformanceMetricsData, PerformanceMetricsInterface};
 
 /// Null implementation of PerformanceMetricsInterface for testing
 #[derive(Default)]
@@ -34,13 +30,3 @@ impl PerformanceMetricsInterface for NullPerformanceMetrics {
         }
     }
 }
-
-/// Null implementation of IndexingOperationsInterface for testing
-#[derive(Default)]
-pub struct NullIndexingOperations;
-
-impl IndexingOperationsInterface for NullIndexingOperations {
-    fn get_operations(&self) -> HashMap<String, IndexingOperation> {
-        HashMap::new()
-    }
-}
@@ -0,0 +1,672 @@
+//! File Watcher Service
+//!
+//! Keeps indexed collections in sync with their codebases. Filesystem events
+//! are debounced per collection, split into [`SyncBatch`]es and pushed through
+//! incremental re-indexing, publishing `FileChangesDetected` and
+//! `SyncCompleted` domain events along the way.
+//!
+//! Codebases are registered by [`WatchingIndexingService`], which wraps the
+//! indexing service so every successfully indexed root is watched and every
+//! cleared collection stops being watched. Registrations are persisted in the
+//! state store and restored when the service starts.
+
+use crate::config::SyncConfig;
+use async_trait::async_trait;
+use dashmap::DashMap;
+use mcb_application::ports::admin::{
+    DependencyHealth, DependencyHealthCheck, LifecycleManaged, ServiceState,
+};
+use mcb_application::ports::infrastructure::{EventBusProvider, StateStoreProvider};
+use mcb_application::ports::services::{
+    IndexingOptions, IndexingResult, IndexingServiceInterface, IndexingStatus,
+};
+use mcb_application::use_cases::IgnoreRules;
+use mcb_domain::error::{Error, Result};
+use mcb_domain::events::DomainEvent;
+use mcb_domain::value_objects::config::SyncBatch;
+use notify::event::{ModifyKind, RenameMode};
+use notify::{Config, Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+use tokio::sync::{Semaphore, mpsc};
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+use tracing::{debug, info, warn};
+
+/// Name under which the watcher registers with the service manager
+pub const FILE_WATCHER_SERVICE_NAME: &str = "file_watcher";
+
+/// State store key of the persisted watch registrations
+const WATCH_TARGETS_KEY: &str = "file_watcher/targets";
+
+/// Interval at which a sync checks whether a running indexing operation finished
+const INDEXING_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Attempts at syncing a batch rejected because an operation was running
+const SYNC_BATCH_MAX_ATTEMPTS: usize = 5;
+
+/// Kind of change recorded for a path
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ChangeKind {
+    Added,
+    Modified,
+    Removed,
+}
+
+impl ChangeKind {
+    /// Classify a watcher event, ignoring events that do not change content
+    fn from_event(kind: &EventKind, path_index: usize) -> Option<Self> {
+        match kind {
+            EventKind::Create(_) => Some(Self::Added),
+            EventKind::Remove(_) => Some(Self::Removed),
+            EventKind::Modify(ModifyKind::Name(RenameMode::From)) => Some(Self::Removed),
+            EventKind::Modify(ModifyKind::Name(RenameMode::To)) => Some(Self::Added),
+            // Rename events carrying both paths list the source first
+            EventKind::Modify(ModifyKind::Name(RenameMode::Both)) if path_index == 0 => {
+                Some(Self::Removed)
+            }
+            EventKind::Modify(ModifyKind::Name(RenameMode::Both)) => Some(Self::Added),
+            EventKind::Modify(ModifyKind::Metadata(_)) | EventKind::Access(_) => None,
+            EventKind::Modify(_) | EventKind::Any | EventKind::Other => Some(Self::Modified),
+        }
+    }
+
+    /// Combine with a later change of the same path
+    fn then(self, later: Self) -> Self {
+        match (self, later) {
+            (Self::Added, Self::Modified) => Self::Added,
+            (_, later) => later,
+        }
+    }
+}
+
+/// Codebase kept in sync for a collection
+#[derive(Clone)]
+struct WatchTarget {
+    /// Root as passed to the indexing service
+    root: PathBuf,
+    /// Canonical root, matched against event paths
+    canonical_root: PathBuf,
+    /// Options the codebase was indexed with
+    options: IndexingOptions,
+    /// Ignore rules of file discovery, matched against event paths
+    ignore: Arc<IgnoreRules>,
+    /// Serializes re-indexing of the collection
+    sync_lock: Arc<tokio::sync::Mutex<()>>,
+}
+
+/// Watch registration as persisted in the state store
+#[derive(Serialize, Deserialize)]
+struct PersistedWatch {
+    collection: String,
+    root: PathBuf,
+    options: IndexingOptions,
+}
+
+/// Changes accumulated for a collection while its debounce window is open
+struct PendingChanges {
+    files: HashMap<PathBuf, ChangeKind>,
+    last_event: Instant,
+}
+
+impl PendingChanges {
+    fn count(&self, kind: ChangeKind) -> usize {
+        self.files.values().filter(|k| **k == kind).count()
+    }
+}
+
+/// State shared between the service and its event loop
+struct WatcherShared {
+    indexing_service: Arc<dyn IndexingServiceInterface>,
+    event_bus: Arc<dyn EventBusProvider>,
+    state_store: Arc<dyn StateStoreProvider>,
+    /// Serializes writes of the persisted registrations
+    persist_lock: tokio::sync::Mutex<()>,
+    debounce: Duration,
+    batch_size: usize,
+    sync_permits: Arc<Semaphore>,
+    targets: DashMap<String, WatchTarget>,
+}
+
+/// Watcher and event loop of a running service
+struct RunningWatcher {
+    watcher: RecommendedWatcher,
+    event_loop: JoinHandle<()>,
+}
+
+/// File watcher service
+///
+/// Watches the roots of indexed collections and re-indexes changed files
+/// once no further change has been seen for `SyncConfig::debounce_delay_ms`.
+/// At most `SyncConfig::max_concurrent` collections are re-indexed at once,
+/// each in batches of up to `SyncConfig::batch_size` paths.
+pub struct FileWatcherService {
+    shared: Arc<WatcherShared>,
+    running: Mutex<Option<RunningWatcher>>,
+    state: RwLock<ServiceState>,
+}
+
+impl FileWatcherService {
+    /// Create a stopped watcher re-indexing through `indexing_service`
+    ///
+    /// Watch registrations are kept in `state_store` across restarts.
+    pub fn new(
+        config: &SyncConfig,
+        indexing_service: Arc<dyn IndexingServiceInterface>,
+        event_bus: Arc<dyn EventBusProvider>,
+        state_store: Arc<dyn StateStoreProvider>,
+    ) -> Self {
+        Self {
+            shared: Arc::new(WatcherShared {
+                indexing_service,
+                event_bus,
+                state_store,
+                persist_lock: tokio::sync::Mutex::new(()),
+                debounce: Duration::from_millis(config.debounce_delay_ms),
+                batch_size: config.batch_size.max(1),
+                sync_permits: Arc::new(Semaphore::new(config.max_concurrent.max(1))),
+                targets: DashMap::new(),
+            }),
+            running: Mutex::new(None),
+            state: RwLock::new(ServiceState::Stopped),
+        }
+    }
+
+    /// Keep `collection` in sync with the codebase at `root`
+    ///
+    /// Replaces any codebase previously watched for the collection.
+    pub async fn watch(
+        &self,
+        root: &Path,
+        collection: &str,
+        options: &IndexingOptions,
+    ) -> Result<()> {
+        self.register(root, collection, options)?;
+        self.persist_targets().await;
+        info!(collection = %collection, root = %root.display(), "Watching codebase");
+        Ok(())
+    }
+
+    /// Stop keeping `collection` in sync
+    pub async fn unwatch(&self, collection: &str) -> Result<()> {
+        let Some((_, target)) = self.shared.targets.remove(collection) else {
+            return Ok(());
+        };
+        if let Some(running) = self.lock_running()?.as_mut() {
+            self.release_root(running, &target.canonical_root);
+        }
+        self.persist_targets().await;
+        info!(collection = %collection, "Stopped watching codebase");
+        Ok(())
+    }
+
+    /// Add the watch target of a collection, watching its root when running
+    fn register(&self, root: &Path, collection: &str, options: &IndexingOptions) -> Result<()> {
+        let canonical_root = root.canonicalize()?;
+        let target = WatchTarget {
+            root: root.to_path_buf(),
+            canonical_root: canonical_root.clone(),
+            options: options.clone(),
+            ignore: Arc::new(IgnoreRules::new(&canonical_root, options)),
+            sync_lock: Arc::new(tokio::sync::Mutex::new(())),
+        };
+        let previous = self.shared.targets.insert(collection.to_string(), target);
+
+        let mut running = self.lock_running()?;
+        if let Some(running) = running.as_mut() {
+            if let Some(previous) = previous
+                && previous.canonical_root != canonical_root
+            {
+                self.release_root(running, &previous.canonical_root);
+            }
+            running
+                .watcher
+                .watch(&canonical_root, RecursiveMode::Recursive)
+                .map_err(|e| {
+                    Error::infrastructure(format!("Failed to watch {}: {}", root.display(), e))
+                })?;
+        }
+        Ok(())
+    }
+
+    /// Save the watch targets; failures only cost watching across restarts
+    async fn persist_targets(&self) {
+        let _guard = self.shared.persist_lock.lock().await;
+        let mut watches: Vec<PersistedWatch> = self
+            .shared
+            .targets
+            .iter()
+            .map(|entry| PersistedWatch {
+                collection: entry.key().clone(),
+                root: entry.root.clone(),
+                options: entry.options.clone(),
+            })
+            .collect();
+        watches.sort_by(|a, b| a.collection.cmp(&b.collection));
+        let saved = match serde_json::to_vec(&watches) {
+            Ok(data) => self.shared.state_store.save(WATCH_TARGETS_KEY, &data).await,
+            Err(e) => Err(e.into()),
+        };
+        if let Err(e) = saved {
+            warn!("Failed to persist watched codebases: {}", e);
+        }
+    }
+
+    /// Register the persisted watch targets of collections not watched yet
+    async fn restore_targets(&self) {
+        let watches = match self.shared.state_store.load(WATCH_TARGETS_KEY).await {
+            Ok(Some(data)) => serde_json::from_slice::<Vec<PersistedWatch>>(&data),
+            Ok(None) => return,
+            Err(e) => {
+                warn!("Failed to load watched codebases: {}", e);
+                return;
+            }
+        };
+        let watches = match watches {
+            Ok(watches) => watches,
+            Err(e) => {
+                warn!("Ignoring unreadable watched codebases: {}", e);
+                return;
+            }
+        };
+        for watch in watches {
+            if self.shared.targets.contains_key(&watch.collection) {
+                continue;
+            }
+            // Roots that are gone are kept persisted, in case they come back
+            if let Err(e) = self.register(&watch.root, &watch.collection, &watch.options) {
+                warn!(
+                    collection = %watch.collection,
+                    root = %watch.root.display(),
+                    "Failed to restore watched codebase: {}", e
+                );
+            }
+        }
+    }
+
+    /// Collections currently kept in sync
+    pub fn watched_collections(&self) -> Vec<String> {
+        let mut collections: Vec<String> = self
+            .shared
+            .targets
+            .iter()
+            .map(|entry| entry.key().clone())
+            .collect();
+        collections.sort();
+        collections
+    }
+
+    /// Stop watching a root unless another collection still uses it
+    fn release_root(&self, running: &mut RunningWatcher, canonical_root: &Path) {
+        let still_used = self
+            .shared
+            .targets
+            .iter()
+            .any(|entry| entry.canonical_root == canonical_root);
+        if !still_used && let Err(e) = running.watcher.unwatch(canonical_root) {
+            debug!(root = %canonical_root.display(), "Failed to unwatch root: {}", e);
+        }
+    }
+
+    fn lock_running(&self) -> Result<std::sync::MutexGuard<'_, Option<RunningWatcher>>> {
+        self.running
+            .lock()
+            .map_err(|e| Error::internal(format!("Lock poisoned: {}", e)))
+    }
+
+    fn set_state(&self, state: ServiceState) {
+        if let Ok(mut current) = self.state.write() {
+            *current = state;
+        }
+    }
+}
+
+impl WatcherShared {
+    /// Debounce watcher events and dispatch the changes of quiet collections
+    async fn run_event_loop(
+        self: Arc<Self>,
+        mut events: mpsc::UnboundedReceiver<notify::Result<Event>>,
+    ) {
+        let mut pending: HashMap<String, PendingChanges> = HashMap::new();
+        loop {
+            let deadline = pending
+                .values()
+                .map(|changes| changes.last_event + self.debounce)
+                .min();
+            tokio::select! {
+                event = events.recv() => match event {
+                    Some(Ok(event)) => self.record_event(&mut pending, event),
+                    Some(Err(e)) => warn!("File watch error: {}", e),
+                    None => break,
+                },
+                _ = sleep_until(deadline) => {
+                    let now = Instant::now();
+                    let ready: Vec<String> = pending
+                        .iter()
+                        .filter(|(_, changes)| changes.last_event + self.debounce <= now)
+                        .map(|(collection, _)| collection.clone())
+                        .collect();
+                    for collection in ready {
+                        if let Some(changes) = pending.remove(&collection) {
+                            Arc::clone(&self).dispatch(collection, changes).await;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Record the paths of an event against every collection watching them
+    fn record_event(&self, pending: &mut HashMap<String, PendingChanges>, event: Event) {
+        let now = Instant::now();
+        for (index, path) in event.paths.iter().enumerate() {
+            let Some(kind) = ChangeKind::from_event(&event.kind, index) else {
+                continue;
+            };
+            for entry in self.targets.iter() {
+                let Ok(relative) = path.strip_prefix(&entry.canonical_root) else {
+                    continue;
+                };
+                if IgnoreRules::is_ignore_file(path) {
+                    entry.ignore.reload();
+                }
+                // Paths discovery would skip are never indexed
+                if entry.ignore.is_ignored(path, path.is_dir()) {
+                    continue;
+                }
+                let changes =
+                    pending
+                        .entry(entry.key().clone())
+                        .or_insert_with(|| PendingChanges {
+                            files: HashMap::new(),
+                            last_event: now,
+                        });
+                // Re-index through the root the collection was indexed with
+                let file = entry.root.join(relative);
+                let kind = changes
+                    .files
+                    .get(&file)
+                    .map_or(kind, |previous| previous.then(kind));
+                changes.files.insert(file, kind);
+                changes.last_event = now;
+            }
+        }
+    }
+
+    /// Announce the changes of a collection and re-index them in the background
+    async fn dispatch(self: Arc<Self>, collection: String, changes: PendingChanges) {
+        let Some(target) = self.targets.get(&collection).map(|entry| entry.clone()) else {
+            return;
+        };
+
+        let event = DomainEvent::FileChangesDetected {
+            root_path: target.root.display().to_string(),
+            added: changes.count(ChangeKind::Added),
+            modified: changes.count(ChangeKind::Modified),
+            removed: changes.count(ChangeKind::Removed),
+        };
+        if let Err(e) = self.event_bus.publish_event(event).await {
+            warn!("Failed to publish file changes event: {}", e);
+        }
+
+        let mut files: Vec<String> = changes
+            .files
+            .into_keys()
+            .map(|path| path.to_string_lossy().into_owned())
+            .collect();
+        files.sort();
+        let batches: Vec<SyncBatch> = files
+            .chunks(self.batch_size)
+            .map(|chunk| SyncBatch {
+                id: uuid::Uuid::new_v4().to_string(),
+                collection: collection.clone(),
+                files: chunk.to_vec(),
+                priority: 0,
+                created_at: chrono::Utc::now().timestamp(),
+            })
+            .collect();
+
+        tokio::spawn(async move {
+            let Ok(_permit) = self.sync_permits.acquire().await else {
+                return;
+            };
+            let _guard = target.sync_lock.lock().await;
+            self.wait_until_idle(&collection).await;
+            for batch in batches {
+                let mut attempt = 1;
+                // An operation started since the collection was idle rejects
+                // the batch; it is retried once that operation finishes
+                while let Err(e) = self.sync_batch(&target, &batch).await {
+                    let running = self
+                        .indexing_service
+                        .get_collection_status(&collection)
+                        .is_indexing;
+                    if !running || attempt >= SYNC_BATCH_MAX_ATTEMPTS {
+                        warn!(collection = %collection, batch = %batch.id, "Sync failed: {}", e);
+                        break;
+                    }
+                    debug!(collection = %collection, batch = %batch.id, "Sync deferred: {}", e);
+                    attempt += 1;
+                    self.wait_until_idle(&collection).await;
+                }
+            }
+        });
+    }
+
+    /// Wait until no indexing operation runs on `collection`
+    ///
+    /// A collection is indexed by one operation at a time.
+    async fn wait_until_idle(&self, collection: &str) {
+        while self
+            .indexing_service
+            .get_collection_status(collection)
+            .is_indexing
+        {
+            tokio::time::sleep(INDEXING_POLL_INTERVAL).await;
+        }
+    }
+
+    /// Push a batch through incremental re-indexing
+    async fn sync_batch(&self, target: &WatchTarget, batch: &SyncBatch) -> Result<()> {
+        let files: Vec<PathBuf> = batch.files.iter().map(PathBuf::from).collect();
+        let result = self
+            .indexing_service
+            .reindex_files(&target.root, &batch.collection, &files, &target.options)
+            .await?;
+        for error in &result.errors {
+            warn!(collection = %batch.collection, "Sync error: {}", error);
+        }
+        debug!(
+            collection = %batch.collection,
+            batch = %batch.id,
+            files = batch.files.len(),
+            reindexed = result.files_processed,
+            "Synced batch"
+        );
+        let event = DomainEvent::SyncCompleted {
+            path: target.root.display().to_string(),
+            files_changed: batch.files.len() as i32,
+        };
+        if let Err(e) = self.event_bus.publish_event(event).await {
+            warn!("Failed to publish sync completed event: {}", e);
+        }
+        Ok(())
+    }
+}
+
+/// Sleep until `deadline`, or forever without one
+async fn sleep_until(deadline: Option<Instant>) {
+    match deadline {
+        Some(deadline) => tokio::time::sleep_until(deadline).await,
+        None => std::future::pending().await,
+    }
+}
+
+#[async_trait]
+impl LifecycleManaged for FileWatcherService {
+    fn name(&self) -> &str {
+        FILE_WATCHER_SERVICE_NAME
+    }
+
+    fn state(&self) -> ServiceState {
+        self.state
+            .read()
+            .map(|state| *state)
+            .unwrap_or(ServiceState::Stopped)
+    }
+
+    async fn start(&self) -> Result<()> {
+        // Codebases watched before a restart; their roots are watched below
+        self.restore_targets().await;
+
+        let mut running = self.lock_running()?;
+        if running.is_some() {
+            return Ok(());
+        }
+        self.set_state(ServiceState::Starting);
+
+        // The notify callback runs on its own thread
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let mut watcher = RecommendedWatcher::new(
+            move |event: notify::Result<Event>| {
+                let _ = sender.send(event);
+            },
+            Config::default(),
+        )
+        .map_err(|e| {
+            self.set_state(ServiceState::Stopped);
+            Error::infrastructure(format!("Failed to create file watcher: {}", e))
+        })?;
+
+        for entry in self.shared.targets.iter() {
+            if let Err(e) = watcher.watch(&entry.canonical_root, RecursiveMode::Recursive) {
+                warn!(root = %entry.root.display(), "Failed to watch codebase: {}", e);
+            }
+        }
+
+        let event_loop = tokio::spawn(Arc::clone(&self.shared).run_event_loop(receiver));
+        *running = Some(RunningWatcher {
+            watcher,
+            event_loop,
+        });
+        self.set_state(ServiceState::Running);
+        info!(
+            codebases = self.shared.targets.len(),
+            "File watcher started"
+        );
+        Ok(())
+    }
+
+    async fn stop(&self) -> Result<()> {
+        let Some(running) = self.lock_running()?.take() else {
+            return Ok(());
+        };
+        self.set_state(ServiceState::Stopping);
+        // Dropping the watcher closes the event channel
+        drop(running.watcher);
+        running.event_loop.abort();
+        self.set_state(ServiceState::Stopped);
+        info!("File watcher stopped");
+        Ok(())
+    }
+
+    async fn health_check(&self) -> DependencyHealthCheck {
+        let state = self.state();
+        DependencyHealthCheck {
+            name: FILE_WATCHER_SERVICE_NAME.to_string(),
+            status: if state == ServiceState::Running {
+                DependencyHealth::Healthy
+            } else {
+                DependencyHealth::Unknown
+            },
+            message: Some(format!(
+                "{:?}, watching {} codebases: {}",
+                state,
+                self.shared.targets.len(),
+                self.watched_collections().join(", ")
+            )),
+            latency_ms: None,
+            last_check: chrono::Utc::now().timestamp() as u64,
+        }
+    }
+}
+
+/// Indexing service that keeps every indexed codebase watched
+///
+/// Successful `index_codebase` calls register the root with the
+/// [`FileWatcherService`]; clearing a collection unregisters it.
+pub struct WatchingIndexingService {
+    inner: Arc<dyn IndexingServiceInterface>,
+    watcher: Arc<FileWatcherService>,
+}
+
+impl WatchingIndexingService {
+    /// Wrap `inner`, registering indexed codebases with `watcher`
+    pub fn new(inner: Arc<dyn IndexingServiceInterface>, watcher: Arc<FileWatcherService>) -> Self {
+        Self { inner, watcher }
+    }
+}
+
+#[async_trait]
+impl IndexingServiceInterface for WatchingIndexingService {
+    async fn index_codebase(
+        &self,
+        path: &Path,
+        collection: &str,
+        options: &IndexingOptions,
+    ) -> Result<IndexingResult> {
+        let result = self.inner.index_codebase(path, collection, options).await?;
+        // Watching is best effort; the index itself is up to date
+        if let Err(e) = self.watcher.watch(path, collection, options).await {
+            warn!(collection = %collection, "Failed to watch codebase: {}", e);
+        }
+        Ok(result)
+    }
+
+    async fn reindex_files(
+        &self,
+        path: &Path,
+        collection: &str,
+        files: &[PathBuf],
+        options: &IndexingOptions,
+    ) -> Result<IndexingResult> {
+        self.inner
+            .reindex_files(path, collection, files, options)
+            .await
+    }
+
+    async fn start_indexing(
+        &self,
+        path: &Path,
+        collection: &str,
+        options: &IndexingOptions,
+    ) -> Result<String> {
+        let operation_id = self.inner.start_indexing(path, collection, options).await?;
+        // Changes made while the operation runs are synced once it completes;
+        // until a first run completes, syncs leave the collection alone
+        if let Err(e) = self.watcher.watch(path, collection, options).await {
+            warn!(collection = %collection, "Failed to watch codebase: {}", e);
+        }
+        Ok(operation_id)
+    }
+
+    async fn cancel_indexing(&self, operation_id: &str) -> Result<()> {
+        self.inner.cancel_indexing(operation_id).await
+    }
+
+    fn get_status(&self) -> IndexingStatus {
+        self.inner.get_status()
+    }
+
+    fn get_collection_status(&self, collection: &str) -> IndexingStatus {
+        self.inner.get_collection_status(collection)
+    }
+
+    async fn clear_collection(&self, collection: &str) -> Result<()> {
+        self.watcher.unwatch(collection).await?;
+        self.inner.clear_collection(collection).await
+    }
+}
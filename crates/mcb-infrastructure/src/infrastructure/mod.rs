@@ -14,9 +14,11 @@
 pub(crate) mod admin;
 pub(crate) mod auth;
 pub(crate) mod events;
+pub(crate) mod file_watcher;
 pub(crate) mod lifecycle;
 pub(crate) mod metrics;
 pub(crate) mod snapshot;
+pub(crate) mod state_store;
 pub(crate) mod sync;
 
 // Public data types (NOT implementations) - these are pure DTOs
@@ -1,13 +1,313 @@
-//! Snapshot Provider Adapter
+//! Snapshot Provider Adapters
 //!
-//! Null implementation of snapshot port for testing.
+//! File-hash implementation of the snapshot port, persisting snapshots under
+//! the configured snapshot directory, plus a null implementation for testing.
 
+use crate::config::SnapshotConfig;
 use async_trait::async_trait;
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use ignore::WalkBuilder;
 use mcb_application::ports::infrastructure::SnapshotProvider;
-use mcb_domain::entities::codebase::{CodebaseSnapshot, SnapshotChanges};
-use mcb_domain::error::Result;
+use mcb_domain::entities::codebase::{CodebaseSnapshot, FileSnapshot, SnapshotChanges};
+use mcb_domain::error::{Error, Result};
+use rayon::prelude::*;
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
-use std::path::Path;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+/// Leading bytes of a gzip stream
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Extension of persisted snapshot files
+const SNAPSHOT_FILE_EXTENSION: &str = "snapshot";
+
+/// File-hash snapshot provider
+///
+/// Hashes file contents with SHA-256 and keeps one snapshot per collection
+/// and codebase root under the configured directory. Files larger than the
+/// configured maximum are tracked by size and modification time instead of
+/// being read.
+pub struct FileHashSnapshotProvider {
+    directory: PathBuf,
+    max_file_size: u64,
+    compression_enabled: bool,
+}
+
+impl FileHashSnapshotProvider {
+    /// Create a provider storing snapshots as configured
+    pub fn new(config: &SnapshotConfig) -> Self {
+        Self {
+            directory: config.directory.clone(),
+            max_file_size: config.max_file_size as u64,
+            compression_enabled: config.compression_enabled,
+        }
+    }
+
+    /// Directory holding every snapshot of `collection`
+    fn collection_dir(&self, collection: &str) -> PathBuf {
+        self.directory.join(short_hash(collection))
+    }
+
+    /// File holding the snapshot of `collection` at `root_path`
+    fn snapshot_path(&self, root_path: &Path, collection: &str) -> PathBuf {
+        let root = root_path
+            .canonicalize()
+            .unwrap_or_else(|_| root_path.to_path_buf());
+        self.collection_dir(collection).join(format!(
+            "{}.{}",
+            short_hash(&root.to_string_lossy()),
+            SNAPSHOT_FILE_EXTENSION
+        ))
+    }
+
+    /// Collection used by the root-only methods of the port
+    fn root_collection(root_path: &Path) -> String {
+        root_path.to_string_lossy().into_owned()
+    }
+
+    /// Hash `files` into a snapshot, skipping files that vanished meanwhile
+    fn build_snapshot(
+        root_path: &Path,
+        collection: &str,
+        files: &[PathBuf],
+        max_file_size: u64,
+    ) -> CodebaseSnapshot {
+        let files: HashMap<String, FileSnapshot> = files
+            .par_iter()
+            .filter_map(|path| Self::snapshot_file(root_path, path, max_file_size).ok())
+            .map(|file| (file.path.clone(), file))
+            .collect();
+
+        CodebaseSnapshot {
+            id: uuid::Uuid::new_v4().to_string(),
+            created_at: chrono::Utc::now().timestamp(),
+            collection: collection.to_string(),
+            total_files: files.len(),
+            total_size: files.values().map(|f| f.size).sum(),
+            files,
+        }
+    }
+
+    /// Capture the state of a single file
+    fn snapshot_file(root_path: &Path, path: &Path, max_file_size: u64) -> Result<FileSnapshot> {
+        let metadata = std::fs::metadata(path)?;
+        let modified_at = metadata
+            .modified()
+            .ok()
+            .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+            .map_or(0, |elapsed| elapsed.as_secs() as i64);
+
+        let hash = if metadata.len() > max_file_size {
+            format!("size:{}:mtime:{}", metadata.len(), modified_at)
+        } else {
+            let mut hasher = Sha256::new();
+            std::io::copy(&mut std::fs::File::open(path)?, &mut hasher)?;
+            hex::encode(hasher.finalize())
+        };
+
+        Ok(FileSnapshot {
+            path: relative_path(root_path, path),
+            modified_at,
+            size: metadata.len(),
+            hash,
+            language: "unknown".to_string(),
+        })
+    }
+
+    /// Every file under `root_path`, honoring ignore files
+    fn walk_files(root_path: &Path) -> Vec<PathBuf> {
+        WalkBuilder::new(root_path)
+            .hidden(false)
+            .require_git(false)
+            .filter_entry(|entry| entry.file_name() != ".git")
+            .build()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_type().is_some_and(|t| t.is_file()))
+            .map(|entry| entry.into_path())
+            .collect()
+    }
+
+    fn read_snapshot(path: &Path) -> Result<Option<CodebaseSnapshot>> {
+        let bytes = match std::fs::read(path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        // Snapshots written with compression disabled are plain JSON
+        let json = if bytes.starts_with(&GZIP_MAGIC) {
+            let mut json = Vec::new();
+            GzDecoder::new(bytes.as_slice()).read_to_end(&mut json)?;
+            json
+        } else {
+            bytes
+        };
+        Ok(Some(serde_json::from_slice(&json)?))
+    }
+
+    fn write_snapshot(path: &Path, snapshot: &CodebaseSnapshot, compress: bool) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_vec(snapshot)?;
+        let bytes = if compress {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(&json)?;
+            encoder.finish()?
+        } else {
+            json
+        };
+        // Write then rename so a crash never leaves a truncated snapshot
+        let temp_path = path.with_extension("tmp");
+        std::fs::write(&temp_path, bytes)?;
+        std::fs::rename(&temp_path, path)?;
+        Ok(())
+    }
+}
+
+/// Snapshot key of a file: its path relative to `root_path` with `/` separators
+fn relative_path(root_path: &Path, path: &Path) -> String {
+    path.strip_prefix(root_path)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+/// Short, filesystem-safe digest of a name
+fn short_hash(value: &str) -> String {
+    hex::encode(&Sha256::digest(value.as_bytes())[..16])
+}
+
+/// Added, modified and removed files between two snapshots, sorted by path
+fn diff_snapshots(old: &CodebaseSnapshot, new: &CodebaseSnapshot) -> SnapshotChanges {
+    let mut added = Vec::new();
+    let mut modified = Vec::new();
+    for (path, file) in &new.files {
+        match old.files.get(path) {
+            None => added.push(path.clone()),
+            Some(previous) if previous.hash != file.hash => modified.push(path.clone()),
+            Some(_) => {}
+        }
+    }
+    let mut removed: Vec<String> = old
+        .files
+        .keys()
+        .filter(|path| !new.files.contains_key(*path))
+        .cloned()
+        .collect();
+
+    added.sort();
+    modified.sort();
+    removed.sort();
+    SnapshotChanges {
+        added,
+        modified,
+        removed,
+    }
+}
+
+/// Run blocking snapshot I/O off the async runtime
+async fn run_blocking<T, F>(task: F) -> Result<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T> + Send + 'static,
+{
+    tokio::task::spawn_blocking(task)
+        .await
+        .map_err(|e| Error::internal(format!("Snapshot task failed: {}", e)))?
+}
+
+#[async_trait]
+impl SnapshotProvider for FileHashSnapshotProvider {
+    async fn create_snapshot(&self, root_path: &Path) -> Result<CodebaseSnapshot> {
+        let root = root_path.to_path_buf();
+        let max_file_size = self.max_file_size;
+        let snapshot = run_blocking(move || {
+            let files = Self::walk_files(&root);
+            Ok(Self::build_snapshot(
+                &root,
+                &Self::root_collection(&root),
+                &files,
+                max_file_size,
+            ))
+        })
+        .await?;
+        self.save_snapshot(root_path, &snapshot).await?;
+        Ok(snapshot)
+    }
+
+    async fn load_snapshot(&self, root_path: &Path) -> Result<Option<CodebaseSnapshot>> {
+        self.load_collection_snapshot(root_path, &Self::root_collection(root_path))
+            .await
+    }
+
+    async fn compare_snapshots(
+        &self,
+        old_snapshot: &CodebaseSnapshot,
+        new_snapshot: &CodebaseSnapshot,
+    ) -> Result<SnapshotChanges> {
+        Ok(diff_snapshots(old_snapshot, new_snapshot))
+    }
+
+    async fn get_changed_files(&self, root_path: &Path) -> Result<Vec<String>> {
+        let previous = self.load_snapshot(root_path).await?;
+        let current = self.create_snapshot(root_path).await?;
+        let Some(previous) = previous else {
+            let mut files: Vec<String> = current.files.into_keys().collect();
+            files.sort();
+            return Ok(files);
+        };
+        let changes = diff_snapshots(&previous, &current);
+        Ok(changes.added.into_iter().chain(changes.modified).collect())
+    }
+
+    async fn snapshot_files(
+        &self,
+        root_path: &Path,
+        collection: &str,
+        files: &[PathBuf],
+    ) -> Result<CodebaseSnapshot> {
+        let root = root_path.to_path_buf();
+        let collection = collection.to_string();
+        let files = files.to_vec();
+        let max_file_size = self.max_file_size;
+        run_blocking(move || {
+            Ok(Self::build_snapshot(
+                &root,
+                &collection,
+                &files,
+                max_file_size,
+            ))
+        })
+        .await
+    }
+
+    async fn load_collection_snapshot(
+        &self,
+        root_path: &Path,
+        collection: &str,
+    ) -> Result<Option<CodebaseSnapshot>> {
+        let path = self.snapshot_path(root_path, collection);
+        run_blocking(move || Self::read_snapshot(&path)).await
+    }
+
+    async fn save_snapshot(&self, root_path: &Path, snapshot: &CodebaseSnapshot) -> Result<()> {
+        let path = self.snapshot_path(root_path, &snapshot.collection);
+        let snapshot = snapshot.clone();
+        let compress = self.compression_enabled;
+        run_blocking(move || Self::write_snapshot(&path, &snapshot, compress)).await
+    }
+
+    async fn delete_collection_snapshots(&self, collection: &str) -> Result<()> {
+        match tokio::fs::remove_dir_all(self.collection_dir(collection)).await {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+}
 
 /// Null snapshot provider for testing
 ///
@@ -58,4 +358,36 @@ impl SnapshotProvider for NullSnapshotProvider {
     async fn get_changed_files(&self, _root_path: &Path) -> Result<Vec<String>> {
         Ok(Vec::new())
     }
+
+    async fn snapshot_files(
+        &self,
+        _root_path: &Path,
+        collection: &str,
+        _files: &[PathBuf],
+    ) -> Result<CodebaseSnapshot> {
+        Ok(CodebaseSnapshot {
+            id: "null-snapshot".to_string(),
+            created_at: 0,
+            collection: collection.to_string(),
+            files: HashMap::new(),
+            total_files: 0,
+            total_size: 0,
+        })
+    }
+
+    async fn load_collection_snapshot(
+        &self,
+        _root_path: &Path,
+        _collection: &str,
+    ) -> Result<Option<CodebaseSnapshot>> {
+        Ok(None)
+    }
+
+    async fn save_snapshot(&self, _root_path: &Path, _snapshot: &CodebaseSnapshot) -> Result<()> {
+        Ok(())
+    }
+
+    async fn delete_collection_snapshots(&self, _collection: &str) -> Result<()> {
+        Ok(())
+    }
 }
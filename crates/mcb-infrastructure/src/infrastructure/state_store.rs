@@ -0,0 +1,125 @@
+//! State Store Adapters
+//!
+//! File-backed implementation of the state store port, keeping one file per
+//! key under the configured state directory, plus a null implementation for
+//! testing.
+
+use crate::config::StateStoreConfig;
+use async_trait::async_trait;
+use mcb_application::ports::infrastructure::StateStoreProvider;
+use mcb_domain::error::{Error, Result};
+use std::path::PathBuf;
+
+/// Extension of persisted state files
+const STATE_FILE_EXTENSION: &str = "state";
+
+/// File-backed state store
+///
+/// Keys are `/`-separated paths such as `indexing/checkpoint/project`; each
+/// segment becomes a directory below the configured directory and the last
+/// one names the file holding the value.
+pub struct FileStateStore {
+    directory: PathBuf,
+}
+
+impl FileStateStore {
+    /// Create a store persisting state as configured
+    pub fn new(config: &StateStoreConfig) -> Self {
+        Self {
+            directory: config.directory.clone(),
+        }
+    }
+
+    /// File holding the value of `key`
+    fn key_path(&self, key: &str) -> Result<PathBuf> {
+        let mut path = self.directory.clone();
+        for segment in key.split('/') {
+            if segment.is_empty() || segment == "." || segment == ".." {
+                return Err(Error::invalid_argument(format!(
+                    "Invalid state key '{}'",
+                    key
+                )));
+            }
+            path.push(sanitize_segment(segment));
+        }
+        path.set_extension(STATE_FILE_EXTENSION);
+        Ok(path)
+    }
+}
+
+/// Replace characters that are not safe in file names
+fn sanitize_segment(segment: &str) -> String {
+    segment
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+#[async_trait]
+impl StateStoreProvider for FileStateStore {
+    async fn save(&self, key: &str, data: &[u8]) -> Result<()> {
+        let path = self.key_path(key)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        // Write then rename so a crash never leaves a truncated value
+        let temp_path = path.with_extension("tmp");
+        tokio::fs::write(&temp_path, data).await?;
+        tokio::fs::rename(&temp_path, &path).await?;
+        Ok(())
+    }
+
+    async fn load(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        match tokio::fs::read(self.key_path(key)?).await {
+            Ok(data) => Ok(Some(data)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        match tokio::fs::remove_file(self.key_path(key)?).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+/// Null state store for testing
+///
+/// Stores nothing; every key loads as missing.
+pub struct NullStateStore;
+
+impl NullStateStore {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for NullStateStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl StateStoreProvider for NullStateStore {
+    async fn save(&self, _key: &str, _data: &[u8]) -> Result<()> {
+        Ok(())
+    }
+
+    async fn load(&self, _key: &str) -> Result<Option<Vec<u8>>> {
+        Ok(None)
+    }
+
+    async fn delete(&self, _key: &str) -> Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,281 @@
+//! Tests for the background file watcher
+
+use crate::config::{StateStoreConfig, SyncConfig};
+use crate::infrastructure::events::TokioBroadcastEventBus;
+use crate::infrastructure::file_watcher::{FileWatcherService, WatchingIndexingService};
+use crate::infrastructure::state_store::{FileStateStore, NullStateStore};
+use async_trait::async_trait;
+use futures::StreamExt;
+use mcb_application::ports::admin::{LifecycleManaged, ServiceState};
+use mcb_application::ports::infrastructure::{EventBusProvider, StateStoreProvider};
+use mcb_application::ports::services::{
+    IndexingOptions, IndexingResult, IndexingServiceInterface, IndexingStatus,
+};
+use mcb_domain::error::{Error, Result};
+use mcb_domain::events::DomainEvent;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Indexing service recording the files it was asked to re-index
+#[derive(Default)]
+struct RecordingIndexingService {
+    reindexed: Mutex<Vec<PathBuf>>,
+    /// Reject the first re-index as if an operation had just started
+    busy_once: bool,
+    attempts: AtomicUsize,
+    /// Reported by the status until polled once
+    indexing: AtomicBool,
+}
+
+fn indexing_result(files_processed: usize) -> IndexingResult {
+    IndexingResult {
+        files_processed,
+        chunks_created: 0,
+        files_skipped: 0,
+        errors: Vec::new(),
+    }
+}
+
+impl RecordingIndexingService {
+    fn reindexed(&self) -> Vec<PathBuf> {
+        self.reindexed.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl IndexingServiceInterface for RecordingIndexingService {
+    async fn index_codebase(
+        &self,
+        _path: &Path,
+        _collection: &str,
+        _options: &IndexingOptions,
+    ) -> Result<IndexingResult> {
+        Ok(indexing_result(0))
+    }
+
+    async fn reindex_files(
+        &self,
+        _path: &Path,
+        _collection: &str,
+        files: &[PathBuf],
+        _options: &IndexingOptions,
+    ) -> Result<IndexingResult> {
+        if self.attempts.fetch_add(1, Ordering::SeqCst) == 0 && self.busy_once {
+            self.indexing.store(true, Ordering::SeqCst);
+            return Err(Error::invalid_argument(
+                "Collection is already being indexed",
+            ));
+        }
+        self.reindexed.lock().unwrap().extend_from_slice(files);
+        Ok(indexing_result(files.len()))
+    }
+
+    async fn start_indexing(
+        &self,
+        _path: &Path,
+        _collection: &str,
+        _options: &IndexingOptions,
+    ) -> Result<String> {
+        Ok("operation".to_string())
+    }
+
+    async fn cancel_indexing(&self, _operation_id: &str) -> Result<()> {
+        Ok(())
+    }
+
+    fn get_status(&self) -> IndexingStatus {
+        IndexingStatus::default()
+    }
+
+    fn get_collection_status(&self, _collection: &str) -> IndexingStatus {
+        IndexingStatus {
+            is_indexing: self.indexing.swap(false, Ordering::SeqCst),
+            ..Default::default()
+        }
+    }
+
+    async fn clear_collection(&self, _collection: &str) -> Result<()> {
+        Ok(())
+    }
+}
+
+fn sync_config() -> SyncConfig {
+    SyncConfig {
+        debounce_delay_ms: 50,
+        ..Default::default()
+    }
+}
+
+fn watcher_for(
+    indexing: Arc<RecordingIndexingService>,
+    event_bus: Arc<TokioBroadcastEventBus>,
+) -> Arc<FileWatcherService> {
+    Arc::new(FileWatcherService::new(
+        &sync_config(),
+        indexing,
+        event_bus,
+        Arc::new(NullStateStore::new()),
+    ))
+}
+
+#[tokio::test]
+async fn test_watcher_lifecycle() {
+    let watcher = watcher_for(
+        Arc::new(RecordingIndexingService::default()),
+        Arc::new(TokioBroadcastEventBus::new()),
+    );
+    assert_eq!(watcher.name(), "file_watcher");
+    assert_eq!(watcher.state(), ServiceState::Stopped);
+
+    watcher.start().await.unwrap();
+    assert_eq!(watcher.state(), ServiceState::Running);
+
+    watcher.stop().await.unwrap();
+    assert_eq!(watcher.state(), ServiceState::Stopped);
+}
+
+#[tokio::test]
+async fn test_watching_indexing_service_tracks_collections() {
+    let dir = tempfile::tempdir().unwrap();
+    let watcher = watcher_for(
+        Arc::new(RecordingIndexingService::default()),
+        Arc::new(TokioBroadcastEventBus::new()),
+    );
+    let service = WatchingIndexingService::new(
+        Arc::new(RecordingIndexingService::default()),
+        Arc::clone(&watcher),
+    );
+    let options = IndexingOptions::default();
+
+    service
+        .index_codebase(dir.path(), "project", &options)
+        .await
+        .unwrap();
+    assert_eq!(watcher.watched_collections(), vec!["project".to_string()]);
+
+    service.clear_collection("project").await.unwrap();
+    assert!(watcher.watched_collections().is_empty());
+}
+
+#[tokio::test]
+async fn test_watched_codebases_are_restored_on_start() {
+    let dir = tempfile::tempdir().unwrap();
+    let state_dir = tempfile::tempdir().unwrap();
+    let state_store: Arc<dyn StateStoreProvider> =
+        Arc::new(FileStateStore::new(&StateStoreConfig {
+            enabled: true,
+            directory: state_dir.path().to_path_buf(),
+        }));
+    let watcher_with_store = || {
+        FileWatcherService::new(
+            &sync_config(),
+            Arc::new(RecordingIndexingService::default()),
+            Arc::new(TokioBroadcastEventBus::new()),
+            Arc::clone(&state_store),
+        )
+    };
+    let options = IndexingOptions::default().with_extensions(["rs"]);
+
+    let watcher = watcher_with_store();
+    watcher
+        .watch(dir.path(), "project", &options)
+        .await
+        .unwrap();
+    watcher
+        .watch(dir.path(), "scratch", &options)
+        .await
+        .unwrap();
+    watcher.unwatch("scratch").await.unwrap();
+
+    // A new process starts with nothing registered
+    let restarted = watcher_with_store();
+    assert!(restarted.watched_collections().is_empty());
+    restarted.start().await.unwrap();
+    assert_eq!(restarted.watched_collections(), vec!["project".to_string()]);
+    restarted.stop().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_changes_are_debounced_and_reindexed() {
+    let dir = tempfile::tempdir().unwrap();
+    let indexing = Arc::new(RecordingIndexingService::default());
+    let event_bus = Arc::new(TokioBroadcastEventBus::new());
+    let mut events = event_bus.subscribe_events().await.unwrap();
+    let watcher = watcher_for(Arc::clone(&indexing), Arc::clone(&event_bus));
+    std::fs::write(dir.path().join(".gitignore"), "generated/\n").unwrap();
+
+    watcher
+        .watch(dir.path(), "project", &IndexingOptions::default())
+        .await
+        .unwrap();
+    watcher.start().await.unwrap();
+
+    std::fs::write(dir.path().join("lib.rs"), "fn a() {}").unwrap();
+    std::fs::write(dir.path().join("lib.rs"), "fn a() {}\nfn b() {}").unwrap();
+    std::fs::create_dir(dir.path().join(".git")).unwrap();
+    std::fs::write(dir.path().join(".git").join("HEAD"), "ref").unwrap();
+    std::fs::create_dir(dir.path().join("generated")).unwrap();
+    std::fs::write(dir.path().join("generated").join("out.rs"), "fn g() {}").unwrap();
+
+    let completed = tokio::time::timeout(Duration::from_secs(10), async {
+        while let Some(event) = events.next().await {
+            if let DomainEvent::SyncCompleted { files_changed, .. } = event {
+                return files_changed;
+            }
+        }
+        0
+    })
+    .await
+    .unwrap();
+
+    watcher.stop().await.unwrap();
+
+    assert!(completed >= 1);
+    let reindexed = indexing.reindexed();
+    assert!(reindexed.contains(&dir.path().join("lib.rs")));
+    // Paths file discovery skips are never re-indexed
+    for skipped in [".git", "generated"] {
+        assert!(
+            !reindexed
+                .iter()
+                .any(|path| path.starts_with(dir.path().join(skipped))),
+            "{skipped} was re-indexed"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_batch_rejected_by_a_running_operation_is_retried() {
+    let dir = tempfile::tempdir().unwrap();
+    let indexing = Arc::new(RecordingIndexingService {
+        busy_once: true,
+        ..Default::default()
+    });
+    let event_bus = Arc::new(TokioBroadcastEventBus::new());
+    let mut events = event_bus.subscribe_events().await.unwrap();
+    let watcher = watcher_for(Arc::clone(&indexing), Arc::clone(&event_bus));
+
+    watcher
+        .watch(dir.path(), "project", &IndexingOptions::default())
+        .await
+        .unwrap();
+    watcher.start().await.unwrap();
+    std::fs::write(dir.path().join("lib.rs"), "fn a() {}").unwrap();
+
+    tokio::time::timeout(Duration::from_secs(10), async {
+        while let Some(event) = events.next().await {
+            if matches!(event, DomainEvent::SyncCompleted { .. }) {
+                return;
+            }
+        }
+    })
+    .await
+    .unwrap();
+    watcher.stop().await.unwrap();
+
+    // Rejected once, then synced
+    assert!(indexing.attempts.load(Ordering::SeqCst) >= 2);
+    assert!(indexing.reindexed().contains(&dir.path().join("lib.rs")));
+}
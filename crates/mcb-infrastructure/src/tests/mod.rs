@@ -3,5 +3,7 @@
 //! These tests are inside the crate to access pub(crate) modules.
 
 mod auth_tests;
+mod file_watcher_tests;
 mod snapshot_tests;
+mod state_store_tests;
 mod sync_tests;
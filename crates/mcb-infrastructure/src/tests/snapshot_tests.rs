@@ -1,8 +1,9 @@
 //! Tests for snapshot infrastructure
 
-use crate::infrastructure::snapshot::NullSnapshotProvider;
+use crate::config::SnapshotConfig;
+use crate::infrastructure::snapshot::{FileHashSnapshotProvider, NullSnapshotProvider};
 use mcb_application::ports::infrastructure::SnapshotProvider;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 #[test]
 fn test_null_snapshot_provider_creation() {
@@ -35,3 +36,126 @@ async fn test_null_snapshot_provider_get_changed_files() {
     assert!(result.is_ok());
     assert!(result.unwrap().is_empty());
 }
+
+fn file_hash_provider(directory: &Path, compression_enabled: bool) -> FileHashSnapshotProvider {
+    FileHashSnapshotProvider::new(&SnapshotConfig {
+        directory: directory.to_path_buf(),
+        compression_enabled,
+        ..SnapshotConfig::default()
+    })
+}
+
+fn write_file(root: &Path, relative: &str, content: &str) -> PathBuf {
+    let path = root.join(relative);
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(&path, content).unwrap();
+    path
+}
+
+#[tokio::test]
+async fn test_file_hash_snapshot_roundtrip() {
+    for compression_enabled in [true, false] {
+        let store = tempfile::tempdir().unwrap();
+        let codebase = tempfile::tempdir().unwrap();
+        let root = codebase.path();
+        let files = vec![
+            write_file(root, "src/main.rs", "fn main() {}"),
+            write_file(root, "src/lib.rs", "pub fn lib() {}"),
+        ];
+        let provider = file_hash_provider(store.path(), compression_enabled);
+
+        let snapshot = provider.snapshot_files(root, "code", &files).await.unwrap();
+        assert_eq!(snapshot.total_files, 2);
+        assert!(snapshot.files.contains_key("src/main.rs"));
+        assert!(
+            provider
+                .load_collection_snapshot(root, "code")
+                .await
+                .unwrap()
+                .is_none(),
+            "snapshot_files does not persist"
+        );
+
+        provider.save_snapshot(root, &snapshot).await.unwrap();
+        let loaded = provider
+            .load_collection_snapshot(root, "code")
+            .await
+            .unwrap();
+        assert_eq!(loaded, Some(snapshot));
+        assert!(
+            provider
+                .load_collection_snapshot(root, "other")
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_file_hash_snapshot_detects_changes() {
+    let store = tempfile::tempdir().unwrap();
+    let codebase = tempfile::tempdir().unwrap();
+    let root = codebase.path();
+    let main = write_file(root, "src/main.rs", "fn main() {}");
+    let lib = write_file(root, "src/lib.rs", "pub fn lib() {}");
+    let old_file = write_file(root, "src/old.rs", "fn old() {}");
+    let provider = file_hash_provider(store.path(), true);
+    let old = provider
+        .snapshot_files(root, "code", &[main.clone(), lib.clone(), old_file.clone()])
+        .await
+        .unwrap();
+
+    std::fs::write(&lib, "pub fn lib() { changed() }").unwrap();
+    std::fs::remove_file(&old_file).unwrap();
+    let new_file = write_file(root, "src/new.rs", "fn new() {}");
+    let new = provider
+        .snapshot_files(root, "code", &[main, lib, new_file])
+        .await
+        .unwrap();
+
+    let changes = provider.compare_snapshots(&old, &new).await.unwrap();
+    assert_eq!(changes.added, vec!["src/new.rs"]);
+    assert_eq!(changes.modified, vec!["src/lib.rs"]);
+    assert_eq!(changes.removed, vec!["src/old.rs"]);
+}
+
+#[tokio::test]
+async fn test_file_hash_snapshot_changed_files_since_last_snapshot() {
+    let store = tempfile::tempdir().unwrap();
+    let codebase = tempfile::tempdir().unwrap();
+    let root = codebase.path();
+    write_file(root, "src/main.rs", "fn main() {}");
+    write_file(root, "src/lib.rs", "pub fn lib() {}");
+    let provider = file_hash_provider(store.path(), true);
+
+    let changed = provider.get_changed_files(root).await.unwrap();
+    assert_eq!(changed, vec!["src/lib.rs", "src/main.rs"]);
+    assert!(provider.load_snapshot(root).await.unwrap().is_some());
+
+    write_file(root, "src/main.rs", "fn main() { run() }");
+    let changed = provider.get_changed_files(root).await.unwrap();
+    assert_eq!(changed, vec!["src/main.rs"]);
+}
+
+#[tokio::test]
+async fn test_file_hash_snapshot_delete_collection() {
+    let store = tempfile::tempdir().unwrap();
+    let codebase = tempfile::tempdir().unwrap();
+    let root = codebase.path();
+    let files = vec![write_file(root, "src/main.rs", "fn main() {}")];
+    let provider = file_hash_provider(store.path(), false);
+    let snapshot = provider.snapshot_files(root, "code", &files).await.unwrap();
+    provider.save_snapshot(root, &snapshot).await.unwrap();
+
+    provider.delete_collection_snapshots("code").await.unwrap();
+    assert!(
+        provider
+            .load_collection_snapshot(root, "code")
+            .await
+            .unwrap()
+            .is_none()
+    );
+    // Deleting a collection without snapshots is not an error
+    provider.delete_collection_snapshots("code").await.unwrap();
+}
@@ -0,0 +1,66 @@
+//! Tests for state store infrastructure
+
+use crate::config::StateStoreConfig;
+use crate::infrastructure::state_store::{FileStateStore, NullStateStore};
+use mcb_application::ports::infrastructure::StateStoreProvider;
+
+fn file_store(directory: &std::path::Path) -> FileStateStore {
+    FileStateStore::new(&StateStoreConfig {
+        enabled: true,
+        directory: directory.to_path_buf(),
+    })
+}
+
+#[tokio::test]
+async fn test_null_state_store_keeps_nothing() {
+    let store = NullStateStore::new();
+    store
+        .save("indexing/checkpoint/project", b"data")
+        .await
+        .unwrap();
+    assert!(
+        store
+            .load("indexing/checkpoint/project")
+            .await
+            .unwrap()
+            .is_none()
+    );
+    store.delete("indexing/checkpoint/project").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_file_state_store_roundtrip() {
+    let dir = tempfile::tempdir().unwrap();
+    let store = file_store(dir.path());
+    let key = "indexing/checkpoint/project";
+
+    assert!(store.load(key).await.unwrap().is_none());
+
+    store.save(key, b"first").await.unwrap();
+    store.save(key, b"second").await.unwrap();
+    assert_eq!(
+        store.load(key).await.unwrap().as_deref(),
+        Some(&b"second"[..])
+    );
+
+    store.delete(key).await.unwrap();
+    assert!(store.load(key).await.unwrap().is_none());
+    // Deleting a missing key is not an error
+    store.delete(key).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_file_state_store_stays_in_directory() {
+    let dir = tempfile::tempdir().unwrap();
+    let store = file_store(&dir.path().join("state"));
+
+    assert!(store.save("../escape", b"data").await.is_err());
+    assert!(store.save("indexing//project", b"data").await.is_err());
+
+    store.save("indexing/a b:c", b"data").await.unwrap();
+    assert_eq!(
+        store.load("indexing/a b:c").await.unwrap().as_deref(),
+        Some(&b"data"[..])
+    );
+    assert!(!dir.path().join("escape.state").exists());
+}
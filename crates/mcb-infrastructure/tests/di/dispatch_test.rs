@@ -175,3 +175,89 @@ async fn test_infrastructure_services_from_catalog() {
     // All should be non-null (they're Arc<dyn Trait>)
     // If any were missing from the catalog, get() would panic
 }
+
+/// Default configuration with the null embedding provider
+fn null_embedding_config() -> AppConfig {
+    let mut config = AppConfig::default();
+    config.providers.embedding.insert(
+        "default".to_string(),
+        EmbeddingConfig {
+            provider: "null".to_string(),
+            model: "test".to_string(),
+            api_key: None,
+            base_url: None,
+            dimensions: Some(384),
+            max_tokens: Some(1000),
+        },
+    );
+    config
+}
+
+#[tokio::test]
+async fn test_indexing_operations_are_tracked_for_the_admin_api() {
+    use mcb_application::domain_services::search::IndexingOptions;
+    use mcb_infrastructure::di::modules::DomainServicesFactory;
+
+    let app_context = init_app(null_embedding_config())
+        .await
+        .expect("Should initialize successfully");
+    let (indexing_service, file_watcher) =
+        DomainServicesFactory::create_indexing_service(&app_context)
+            .await
+            .expect("Should create the indexing service");
+    let file_watcher = file_watcher.expect("sync is enabled by default");
+
+    let dir = tempfile::tempdir().expect("create temp dir");
+    std::fs::write(dir.path().join("main.rs"), "fn main() {}").expect("write file");
+    let operation_id = indexing_service
+        .start_indexing(dir.path(), "tracked", &IndexingOptions::default())
+        .await
+        .expect("indexing should start");
+
+    // The operation runs on this test's runtime, so it has not finished yet
+    let operations = app_context.indexing().get_operations();
+    let operation = operations
+        .get(&operation_id)
+        .expect("started operation should be tracked");
+    assert_eq!(operation.collection, "tracked");
+
+    tokio::time::timeout(std::time::Duration::from_secs(10), async {
+        while indexing_service
+            .get_collection_status("tracked")
+            .is_indexing
+        {
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("indexing should finish");
+    assert!(
+        !app_context
+            .indexing()
+            .get_operations()
+            .contains_key(&operation_id),
+        "finished operations are no longer listed"
+    );
+    // The indexed codebase is kept in sync
+    let health = file_watcher.health_check().await;
+    assert!(
+        health.message.unwrap_or_default().contains("tracked"),
+        "indexed codebases should be watched"
+    );
+}
+
+#[tokio::test]
+async fn test_indexing_service_is_not_watched_with_sync_disabled() {
+    use mcb_infrastructure::di::modules::DomainServicesFactory;
+
+    let mut config = null_embedding_config();
+    config.system.data.sync.enabled = false;
+    let app_context = init_app(config)
+        .await
+        .expect("Should initialize successfully");
+    let (_, file_watcher) = DomainServicesFactory::create_indexing_service(&app_context)
+        .await
+        .expect("Should create the indexing service");
+
+    assert!(file_watcher.is_none());
+}
@@ -31,39 +31,6 @@ impl DefaultIndexingOperations {
         Arc::new(Self::new())
     }
 
-    /// Start tracking a new indexing operation
-    pub fn start_operation(&self, collection: &str, total_files: usize) -> String {
-        let id = Uuid::new_v4().to_string();
-        let operation = IndexingOperation {
-            id: id.clone(),
-            collection: collection.to_string(),
-            current_file: None,
-            total_files,
-            processed_files: 0,
-            start_timestamp: current_timestamp(),
-        };
-        self.operations.insert(id.clone(), operation);
-        id
-    }
-
-    /// Update progress for an operation
-    pub fn update_progress(
-        &self,
-        operation_id: &str,
-        current_file: Option<String>,
-        processed: usize,
-    ) {
-        if let Some(mut op) = self.operations.get_mut(operation_id) {
-            op.current_file = current_file;
-            op.processed_files = processed;
-        }
-    }
-
-    /// Complete and remove an operation
-    pub fn complete_operation(&self, operation_id: &str) {
-        self.operations.remove(operation_id);
-    }
-
     /// Check if any operations are in progress
     pub fn has_active_operations(&self) -> bool {
         !self.operations.is_empty()
@@ -88,6 +55,37 @@ impl IndexingOperationsInterface for DefaultIndexingOperations {
             .map(|entry| (entry.key().clone(), entry.value().clone()))
             .collect()
     }
+
+    fn start_operation(&self, collection: &str, total_files: usize) -> String {
+        let id = Uuid::new_v4().to_string();
+        let operation = IndexingOperation {
+            id: id.clone(),
+            collection: collection.to_string(),
+            current_file: None,
+            total_files,
+            processed_files: 0,
+            start_timestamp: current_timestamp(),
+        };
+        self.operations.insert(id.clone(), operation);
+        id
+    }
+
+    fn update_total(&self, operation_id: &str, total_files: usize) {
+        if let Some(mut op) = self.operations.get_mut(operation_id) {
+            op.total_files = total_files;
+        }
+    }
+
+    fn update_progress(&self, operation_id: &str, current_file: Option<String>, processed: usize) {
+        if let Some(mut op) = self.operations.get_mut(operation_id) {
+            op.current_file = current_file;
+            op.processed_files = processed;
+        }
+    }
+
+    fn complete_operation(&self, operation_id: &str) {
+        self.operations.remove(operation_id);
+    }
 }
 
 /// Get current Unix timestamp
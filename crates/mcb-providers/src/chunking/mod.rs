@@ -14,6 +14,9 @@
 //! - Rust, Python, JavaScript, TypeScript
 //! - Go, Java, C, C++, C#
 //! - Ruby, PHP, Swift, Kotlin
+//! - Scala, Elixir, Lua, Bash, Haskell, Zig
+//! - Markdown, reStructuredText, AsciiDoc and plain text documentation
+//! - TOML, YAML, JSON and SQL files
 //!
 //! ## Architecture
 //!
@@ -25,10 +28,13 @@ pub use crate::language::{
     // Base types
     BaseProcessor,
     // Language processors
+    BashProcessor,
     CProcessor,
     CSharpProcessor,
     CppProcessor,
+    ElixirProcessor,
     GoProcessor,
+    HaskellProcessor,
     // Engine
     IntelligentChunker,
     JavaProcessor,
@@ -36,12 +42,15 @@ pub use crate::language::{
     KotlinProcessor,
     LanguageConfig,
     LanguageProcessor,
+    LuaProcessor,
     NodeExtractionRule,
     PhpProcessor,
     PythonProcessor,
     RubyProcessor,
     RustProcessor,
+    ScalaProcessor,
     SwiftProcessor,
+    ZigProcessor,
     // Helpers
     get_chunk_size,
     is_language_supported,
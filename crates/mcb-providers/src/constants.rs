@@ -62,9 +62,46 @@ pub const VOYAGEAI_MAX_OUTPUT_TOKENS: usize = 16000;
 /// OpenAI max tokens per request
 pub const OPENAI_MAX_TOKENS_PER_REQUEST: usize = 8191;
 
+/// OpenAI max inputs per embedding request
+pub const OPENAI_MAX_INPUTS_PER_REQUEST: usize = 2048;
+
+/// OpenAI token budget per embedding request (below the 300k API limit, as
+/// token counts are estimated)
+pub const OPENAI_MAX_BATCH_TOKENS: usize = 200_000;
+
+/// VoyageAI max inputs per embedding request
+pub const VOYAGEAI_MAX_INPUTS_PER_REQUEST: usize = 128;
+
+/// VoyageAI token budget per embedding request (below the 120k API limit)
+pub const VOYAGEAI_MAX_BATCH_TOKENS: usize = 100_000;
+
+/// Texts per batch for providers sending one request per text (Ollama, Gemini)
+pub const SINGLE_TEXT_API_BATCH_SIZE: usize = 16;
+
+/// Texts per batch for the local FastEmbed model
+pub const FASTEMBED_BATCH_SIZE: usize = 256;
+
+/// Characters per token of the FastEmbed models (WordPiece tokenizers split
+/// code into more tokens than BPE tokenizers)
+pub const FASTEMBED_CHARS_PER_TOKEN: usize = 3;
+
 /// Ollama server default port
 pub const OLLAMA_DEFAULT_PORT: u16 = 11434;
 
+// ============================================================================
+// RERANKER PROVIDER CONSTANTS
+// ============================================================================
+
+/// Default base URL of the HTTP reranker (VoyageAI; Cohere-compatible APIs
+/// take the same request)
+pub const RERANKER_HTTP_DEFAULT_BASE_URL: &str = "https://api.voyageai.com/v1";
+
+/// Default model of the HTTP reranker
+pub const RERANKER_HTTP_DEFAULT_MODEL: &str = "rerank-2";
+
+/// Query/document pairs scored per FastEmbed cross-encoder batch
+pub const FASTEMBED_RERANK_BATCH_SIZE: usize = 32;
+
 // ============================================================================
 // CACHE PROVIDER CONSTANTS
 // ============================================================================
@@ -210,6 +247,21 @@ pub const LANG_SWIFT: &str = "swift";
 /// Kotlin language identifier
 pub const LANG_KOTLIN: &str = "kotlin";
 
+/// Scala language identifier
+pub const LANG_SCALA: &str = "scala";
+
+/// Elixir language identifier
+pub const LANG_ELIXIR: &str = "elixir";
+
+/// Lua language identifier
+pub const LANG_LUA: &str = "lua";
+
+/// Haskell language identifier
+pub const LANG_HASKELL: &str = "haskell";
+
+/// Zig language identifier
+pub const LANG_ZIG: &str = "zig";
+
 // ============================================================================
 // EDGEVEC VECTOR STORE CONSTANTS
 // ============================================================================
@@ -229,6 +281,9 @@ pub const EDGEVEC_HNSW_EF_SEARCH: u32 = 64;
 /// EdgeVec default dimensions (for OpenAI embeddings)
 pub const EDGEVEC_DEFAULT_DIMENSIONS: usize = 1536;
 
+/// Candidate multiplier for filtered EdgeVec searches (HNSW cannot pre-filter)
+pub const EDGEVEC_FILTER_OVERFETCH_FACTOR: usize = 10;
+
 // ============================================================================
 // FILESYSTEM VECTOR STORE CONSTANTS
 // ============================================================================
@@ -252,6 +307,12 @@ pub const MILVUS_FIELD_VARCHAR_MAX_LENGTH: i32 = 512;
 /// Milvus metadata varchar max length
 pub const MILVUS_METADATA_VARCHAR_MAX_LENGTH: i32 = 65535;
 
+/// Candidate multiplier for filtered Milvus searches (globs are re-checked locally)
+pub const MILVUS_FILTER_OVERFETCH_FACTOR: usize = 4;
+
+/// Maximum number of entities a single Milvus query may return
+pub const MILVUS_QUERY_MAX_LIMIT: usize = 16_384;
+
 /// Milvus IvfFlat nlist parameter
 pub const MILVUS_IVFFLAT_NLIST: u32 = 128;
 
@@ -273,3 +334,230 @@ pub const HYBRID_SEARCH_BM25_B: f32 = 0.75;
 
 /// BM25 token minimum length filter (filter very short tokens)
 pub const BM25_TOKEN_MIN_LENGTH: usize = 2;
+
+/// Rank constant `k` of reciprocal rank fusion (value from the original RRF paper)
+pub const HYBRID_SEARCH_RRF_K: f32 = 60.0;
+
+/// Standard deviations on each side of the mean mapped onto 0-1 by
+/// distribution-based score fusion
+pub const HYBRID_SEARCH_DISTRIBUTION_SIGMAS: f32 = 3.0;
+
+/// Journal entries a persisted BM25 index accumulates before it is
+/// compacted into a snapshot (at least; larger indexes wait for as many
+/// entries as they hold chunks)
+pub const HYBRID_SEARCH_JOURNAL_COMPACT_MIN: usize = 1_000;
+
+/// Prefix of character n-gram terms, keeping them apart from whole words
+pub const BM25_NGRAM_PREFIX: &str = "~";
+
+/// Prefixes marking a line as a comment, whose words the BM25 analyzer may stem
+pub const BM25_COMMENT_PREFIXES: &[&str] = &["//", "/*", "*", "# ", "--", "\"\"\"", "'''", ";;"];
+
+/// Suffix rewrites of the light English stemmer, tried in order
+pub const BM25_STEM_SUFFIXES: &[(&str, &str)] = &[
+    ("ational", "ate"),
+    ("ization", "ize"),
+    ("fulness", "ful"),
+    ("iveness", "ive"),
+    ("sses", "ss"),
+    ("ies", "y"),
+    ("ing", ""),
+    ("ed", ""),
+    ("ly", ""),
+    ("s", ""),
+];
+
+// Language keywords dropped from indexed code by the BM25 analyzer. Only
+// keywords longer than BM25_TOKEN_MIN_LENGTH are listed; shorter tokens are
+// never indexed.
+
+/// Rust keywords
+pub const BM25_KEYWORDS_RUST: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "for", "impl", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return",
+    "self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use", "where",
+    "while",
+];
+
+/// Python keywords
+pub const BM25_KEYWORDS_PYTHON: &[&str] = &[
+    "and", "assert", "async", "await", "break", "class", "continue", "def", "del", "elif", "else",
+    "except", "false", "finally", "for", "from", "global", "import", "lambda", "none", "nonlocal",
+    "not", "pass", "raise", "return", "self", "true", "try", "while", "with", "yield",
+];
+
+/// JavaScript and TypeScript keywords
+pub const BM25_KEYWORDS_JAVASCRIPT: &[&str] = &[
+    "async",
+    "await",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "default",
+    "delete",
+    "else",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "from",
+    "function",
+    "import",
+    "interface",
+    "let",
+    "new",
+    "null",
+    "return",
+    "static",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "type",
+    "typeof",
+    "undefined",
+    "var",
+    "void",
+    "while",
+    "yield",
+];
+
+/// Java, Kotlin and Scala keywords
+pub const BM25_KEYWORDS_JVM: &[&str] = &[
+    "abstract",
+    "boolean",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "continue",
+    "def",
+    "default",
+    "else",
+    "enum",
+    "extends",
+    "false",
+    "final",
+    "finally",
+    "for",
+    "fun",
+    "implements",
+    "import",
+    "instanceof",
+    "int",
+    "interface",
+    "new",
+    "null",
+    "object",
+    "override",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "static",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "throws",
+    "true",
+    "try",
+    "val",
+    "var",
+    "void",
+    "when",
+    "while",
+];
+
+/// Go keywords
+pub const BM25_KEYWORDS_GO: &[&str] = &[
+    "break",
+    "case",
+    "chan",
+    "const",
+    "continue",
+    "default",
+    "defer",
+    "else",
+    "false",
+    "for",
+    "func",
+    "go",
+    "goto",
+    "import",
+    "interface",
+    "map",
+    "nil",
+    "package",
+    "range",
+    "return",
+    "select",
+    "struct",
+    "switch",
+    "true",
+    "type",
+    "var",
+];
+
+/// C, C++ and C# keywords
+pub const BM25_KEYWORDS_C_FAMILY: &[&str] = &[
+    "auto",
+    "bool",
+    "break",
+    "case",
+    "char",
+    "class",
+    "const",
+    "continue",
+    "default",
+    "define",
+    "delete",
+    "double",
+    "else",
+    "enum",
+    "extern",
+    "false",
+    "float",
+    "for",
+    "include",
+    "int",
+    "long",
+    "namespace",
+    "new",
+    "null",
+    "nullptr",
+    "private",
+    "public",
+    "return",
+    "short",
+    "signed",
+    "sizeof",
+    "static",
+    "struct",
+    "switch",
+    "template",
+    "this",
+    "true",
+    "typedef",
+    "unsigned",
+    "using",
+    "var",
+    "virtual",
+    "void",
+    "while",
+];
+
+/// Ruby, PHP and Lua keywords
+pub const BM25_KEYWORDS_SCRIPTING: &[&str] = &[
+    "and", "array", "begin", "break", "case", "class", "def", "echo", "else", "elseif", "elsif",
+    "end", "ensure", "false", "for", "function", "local", "module", "new", "nil", "not", "null",
+    "public", "private", "require", "rescue", "return", "self", "then", "this", "true", "unless",
+    "until", "when", "while", "yield",
+];
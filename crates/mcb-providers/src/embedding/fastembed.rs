@@ -9,9 +9,11 @@ use tokio::sync::{mpsc, oneshot};
 
 use mcb_domain::error::{Error, Result};
 use mcb_domain::ports::providers::EmbeddingProvider;
-use mcb_domain::value_objects::Embedding;
+use mcb_domain::value_objects::{Embedding, EmbeddingBatchLimits};
 
-use crate::constants::EMBEDDING_DIMENSION_FASTEMBED_DEFAULT;
+use crate::constants::{
+    EMBEDDING_DIMENSION_FASTEMBED_DEFAULT, FASTEMBED_BATCH_SIZE, FASTEMBED_CHARS_PER_TOKEN,
+};
 
 /// Messages for the FastEmbed actor
 enum FastEmbedMessage {
@@ -99,6 +101,19 @@ impl EmbeddingProvider for FastEmbedProvider {
             .unwrap_or_else(|_| Err(Error::embedding("FastEmbed actor closed")))
     }
 
+    fn batch_limits(&self) -> EmbeddingBatchLimits {
+        // The model truncates long inputs itself
+        EmbeddingBatchLimits::new(FASTEMBED_BATCH_SIZE, usize::MAX)
+    }
+
+    fn max_input_tokens(&self) -> usize {
+        self.max_tokens()
+    }
+
+    fn chars_per_token(&self) -> usize {
+        FASTEMBED_CHARS_PER_TOKEN
+    }
+
     fn dimensions(&self) -> usize {
         // AllMiniLML6V2 has 384 dimensions
         EMBEDDING_DIMENSION_FASTEMBED_DEFAULT
@@ -108,6 +123,10 @@ impl EmbeddingProvider for FastEmbedProvider {
         "fastembed"
     }
 
+    fn model_name(&self) -> &str {
+        self.model()
+    }
+
     async fn health_check(&self) -> Result<()> {
         self.embed("health check").await?;
         Ok(())
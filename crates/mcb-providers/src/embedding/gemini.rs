@@ -9,9 +9,9 @@ use reqwest::Client;
 
 use mcb_domain::error::{Error, Result};
 use mcb_domain::ports::providers::EmbeddingProvider;
-use mcb_domain::value_objects::Embedding;
+use mcb_domain::value_objects::{Embedding, EmbeddingBatchLimits};
 
-use crate::constants::{CONTENT_TYPE_JSON, EMBEDDING_DIMENSION_GEMINI};
+use crate::constants::{CONTENT_TYPE_JSON, EMBEDDING_DIMENSION_GEMINI, SINGLE_TEXT_API_BATCH_SIZE};
 
 /// Error message for request timeouts
 use crate::embedding::helpers::constructor;
@@ -188,6 +188,16 @@ impl EmbeddingProvider for GeminiEmbeddingProvider {
         Ok(results)
     }
 
+    fn batch_limits(&self) -> EmbeddingBatchLimits {
+        // Texts are embedded one request at a time; small batches keep
+        // concurrent batches busy
+        EmbeddingBatchLimits::new(SINGLE_TEXT_API_BATCH_SIZE, usize::MAX)
+    }
+
+    fn max_input_tokens(&self) -> usize {
+        self.max_tokens()
+    }
+
     fn dimensions(&self) -> usize {
         match self.api_model_name() {
             "gemini-embedding-001" => EMBEDDING_DIMENSION_GEMINI,
@@ -199,6 +209,10 @@ impl EmbeddingProvider for GeminiEmbeddingProvider {
     fn provider_name(&self) -> &str {
         "gemini"
     }
+
+    fn model_name(&self) -> &str {
+        self.model()
+    }
 }
 
 // ============================================================================
@@ -73,6 +73,10 @@ impl EmbeddingProvider for NullEmbeddingProvider {
         Ok(embeddings)
     }
 
+    fn max_input_tokens(&self) -> usize {
+        self.max_tokens()
+    }
+
     fn dimensions(&self) -> usize {
         EMBEDDING_DIMENSION_NULL
     }
@@ -80,6 +84,10 @@ impl EmbeddingProvider for NullEmbeddingProvider {
     fn provider_name(&self) -> &str {
         "null"
     }
+
+    fn model_name(&self) -> &str {
+        self.model()
+    }
 }
 
 impl NullEmbeddingProvider {
@@ -10,12 +10,12 @@ use reqwest::Client;
 
 use mcb_domain::error::{Error, Result};
 use mcb_domain::ports::providers::EmbeddingProvider;
-use mcb_domain::value_objects::Embedding;
+use mcb_domain::value_objects::{Embedding, EmbeddingBatchLimits};
 
 use crate::constants::{
     CONTENT_TYPE_JSON, EMBEDDING_DIMENSION_OLLAMA_ARCTIC, EMBEDDING_DIMENSION_OLLAMA_DEFAULT,
     EMBEDDING_DIMENSION_OLLAMA_MINILM, EMBEDDING_DIMENSION_OLLAMA_MXBAI,
-    EMBEDDING_DIMENSION_OLLAMA_NOMIC,
+    EMBEDDING_DIMENSION_OLLAMA_NOMIC, SINGLE_TEXT_API_BATCH_SIZE,
 };
 
 /// Error message for request timeouts
@@ -157,6 +157,16 @@ impl EmbeddingProvider for OllamaEmbeddingProvider {
         Ok(results)
     }
 
+    fn batch_limits(&self) -> EmbeddingBatchLimits {
+        // Texts are embedded one request at a time; small batches keep
+        // concurrent batches busy
+        EmbeddingBatchLimits::new(SINGLE_TEXT_API_BATCH_SIZE, usize::MAX)
+    }
+
+    fn max_input_tokens(&self) -> usize {
+        self.max_tokens()
+    }
+
     fn dimensions(&self) -> usize {
         match self.model.as_str() {
             "nomic-embed-text" => EMBEDDING_DIMENSION_OLLAMA_NOMIC,
@@ -170,6 +180,10 @@ impl EmbeddingProvider for OllamaEmbeddingProvider {
     fn provider_name(&self) -> &str {
         "ollama"
     }
+
+    fn model_name(&self) -> &str {
+        self.model()
+    }
 }
 
 // ============================================================================
@@ -10,11 +10,11 @@ use reqwest::Client;
 
 use mcb_domain::error::{Error, Result};
 use mcb_domain::ports::providers::EmbeddingProvider;
-use mcb_domain::value_objects::Embedding;
+use mcb_domain::value_objects::{Embedding, EmbeddingBatchLimits};
 
 use crate::constants::{
     CONTENT_TYPE_JSON, EMBEDDING_DIMENSION_OPENAI_ADA, EMBEDDING_DIMENSION_OPENAI_LARGE,
-    EMBEDDING_DIMENSION_OPENAI_SMALL,
+    EMBEDDING_DIMENSION_OPENAI_SMALL, OPENAI_MAX_BATCH_TOKENS, OPENAI_MAX_INPUTS_PER_REQUEST,
 };
 
 /// Error message for request timeouts
@@ -183,6 +183,14 @@ impl EmbeddingProvider for OpenAIEmbeddingProvider {
             .collect()
     }
 
+    fn batch_limits(&self) -> EmbeddingBatchLimits {
+        EmbeddingBatchLimits::new(OPENAI_MAX_INPUTS_PER_REQUEST, OPENAI_MAX_BATCH_TOKENS)
+    }
+
+    fn max_input_tokens(&self) -> usize {
+        self.max_tokens()
+    }
+
     fn dimensions(&self) -> usize {
         match self.model.as_str() {
             "text-embedding-3-small" => EMBEDDING_DIMENSION_OPENAI_SMALL,
@@ -195,6 +203,10 @@ impl EmbeddingProvider for OpenAIEmbeddingProvider {
     fn provider_name(&self) -> &str {
         "openai"
     }
+
+    fn model_name(&self) -> &str {
+        self.model()
+    }
 }
 
 // ============================================================================
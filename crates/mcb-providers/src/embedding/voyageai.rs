@@ -8,11 +8,11 @@ use reqwest::Client;
 
 use mcb_domain::error::{Error, Result};
 use mcb_domain::ports::providers::EmbeddingProvider;
-use mcb_domain::value_objects::Embedding;
+use mcb_domain::value_objects::{Embedding, EmbeddingBatchLimits};
 
 use crate::constants::{
     CONTENT_TYPE_JSON, EMBEDDING_DIMENSION_VOYAGEAI_CODE, EMBEDDING_DIMENSION_VOYAGEAI_DEFAULT,
-    VOYAGEAI_MAX_INPUT_TOKENS,
+    VOYAGEAI_MAX_BATCH_TOKENS, VOYAGEAI_MAX_INPUT_TOKENS, VOYAGEAI_MAX_INPUTS_PER_REQUEST,
 };
 use crate::embedding::helpers::constructor;
 use crate::utils::HttpResponseUtils;
@@ -161,6 +161,14 @@ impl EmbeddingProvider for VoyageAIEmbeddingProvider {
             .collect()
     }
 
+    fn batch_limits(&self) -> EmbeddingBatchLimits {
+        EmbeddingBatchLimits::new(VOYAGEAI_MAX_INPUTS_PER_REQUEST, VOYAGEAI_MAX_BATCH_TOKENS)
+    }
+
+    fn max_input_tokens(&self) -> usize {
+        self.max_tokens()
+    }
+
     fn dimensions(&self) -> usize {
         match self.model.as_str() {
             "voyage-code-3" => EMBEDDING_DIMENSION_VOYAGEAI_CODE,
@@ -171,6 +179,10 @@ impl EmbeddingProvider for VoyageAIEmbeddingProvider {
     fn provider_name(&self) -> &str {
         "voyageai"
     }
+
+    fn model_name(&self) -> &str {
+        self.model()
+    }
 }
 
 // ============================================================================
@@ -0,0 +1,213 @@
+//! Code-aware text analysis for BM25
+//!
+//! Turns chunk contents and queries into index terms. Plain word splitting
+//! treats `IndexingServiceImpl` as one opaque token, so a query for
+//! "indexing service" never matches it. The analyzer runs a pipeline over
+//! every identifier instead:
+//!
+//! 1. **Identifier splitting**: `IndexingServiceImpl` and
+//!    `get_indexing_status` are split on case changes and underscores into
+//!    their words, and the full identifier is kept as a term of its own
+//! 2. **Keyword stopwords**: keywords of the chunk's language (`pub`, `fn`,
+//!    `public`, `def`, ...) are dropped, they match every chunk
+//! 3. **Stemming** (optional): words of comment lines are reduced to their
+//!    stem, so "indexes" in a doc comment matches a query for "indexing"
+//! 4. **N-grams** (optional): character n-grams of each identifier match
+//!    partial identifiers such as `TokenBuck`
+//!
+//! Queries go through the same pipeline, without stopwords, and contribute
+//! both the plain and the stemmed form of each word.
+
+use serde::{Deserialize, Serialize};
+
+pub use mcb_domain::value_objects::split_identifier;
+
+use crate::constants::{
+    BM25_COMMENT_PREFIXES, BM25_KEYWORDS_C_FAMILY, BM25_KEYWORDS_GO, BM25_KEYWORDS_JAVASCRIPT,
+    BM25_KEYWORDS_JVM, BM25_KEYWORDS_PYTHON, BM25_KEYWORDS_RUST, BM25_KEYWORDS_SCRIPTING,
+    BM25_NGRAM_PREFIX, BM25_STEM_SUFFIXES, BM25_TOKEN_MIN_LENGTH, LANG_C, LANG_CPP, LANG_CSHARP,
+    LANG_GO, LANG_JAVA, LANG_JAVASCRIPT, LANG_KOTLIN, LANG_LUA, LANG_PHP, LANG_PYTHON, LANG_RUBY,
+    LANG_RUST, LANG_SCALA, LANG_TYPESCRIPT,
+};
+
+/// Settings of the BM25 analyzer pipeline
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnalyzerConfig {
+    /// Split identifiers on case changes and underscores, keeping the full
+    /// identifier as well
+    pub split_identifiers: bool,
+    /// Drop the keywords of the chunk's language
+    pub keyword_stopwords: bool,
+    /// Stem the words of comment lines and of queries
+    pub stemming: bool,
+    /// Length of the character n-grams indexed for partial identifier
+    /// matches; `None` disables n-grams
+    pub ngram_size: Option<usize>,
+}
+
+impl Default for AnalyzerConfig {
+    fn default() -> Self {
+        Self {
+            split_identifiers: true,
+            keyword_stopwords: true,
+            stemming: false,
+            ngram_size: None,
+        }
+    }
+}
+
+/// Analyzer turning code and queries into BM25 terms
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CodeAnalyzer {
+    config: AnalyzerConfig,
+}
+
+impl CodeAnalyzer {
+    /// Create an analyzer with the given settings
+    pub fn new(config: AnalyzerConfig) -> Self {
+        Self { config }
+    }
+
+    /// Settings of this analyzer
+    pub fn config(&self) -> &AnalyzerConfig {
+        &self.config
+    }
+
+    /// Terms of a document written in `language`
+    pub fn analyze(&self, text: &str, language: &str) -> Vec<String> {
+        let keywords = if self.config.keyword_stopwords {
+            language_keywords(language)
+        } else {
+            &[]
+        };
+
+        let mut terms = Vec::new();
+        for line in text.lines() {
+            let stem = self.config.stemming && is_comment_line(line);
+            for token in identifiers(line) {
+                for term in self.token_terms(token) {
+                    if keywords.contains(&term.as_str()) {
+                        continue;
+                    }
+                    terms.push(if stem { stem_word(&term) } else { term });
+                }
+                self.push_ngrams(token, &mut terms);
+            }
+        }
+        terms
+    }
+
+    /// Terms of a query
+    ///
+    /// Words are kept as typed and, with stemming enabled, stemmed as well,
+    /// matching both code identifiers and stemmed comments.
+    pub fn analyze_query(&self, query: &str) -> Vec<String> {
+        let mut terms = Vec::new();
+        for token in identifiers(query) {
+            for term in self.token_terms(token) {
+                if self.config.stemming {
+                    let stemmed = stem_word(&term);
+                    if stemmed != term {
+                        terms.push(stemmed);
+                    }
+                }
+                terms.push(term);
+            }
+            self.push_ngrams(token, &mut terms);
+        }
+        terms
+    }
+
+    /// Lowercased words of an identifier, plus the full identifier when split
+    fn token_terms(&self, token: &str) -> Vec<String> {
+        let mut terms = if self.config.split_identifiers {
+            split_identifier(token)
+        } else {
+            token
+                .split('_')
+                .filter(|part| !part.is_empty())
+                .map(str::to_lowercase)
+                .collect()
+        };
+        if self.config.split_identifiers && terms.len() > 1 {
+            terms.push(token.trim_matches('_').to_lowercase());
+        }
+        terms.retain(|term| term.chars().count() > BM25_TOKEN_MIN_LENGTH);
+        terms
+    }
+
+    /// Append the character n-grams of an identifier
+    fn push_ngrams(&self, token: &str, terms: &mut Vec<String>) {
+        let Some(size) = self.config.ngram_size.filter(|&size| size > 0) else {
+            return;
+        };
+        let chars: Vec<char> = token
+            .chars()
+            .filter(|c| *c != '_')
+            .flat_map(char::to_lowercase)
+            .collect();
+        if chars.len() < size {
+            return;
+        }
+        terms.extend(
+            chars
+                .windows(size)
+                .map(|gram| format!("{}{}", BM25_NGRAM_PREFIX, gram.iter().collect::<String>())),
+        );
+    }
+}
+
+/// Identifier-like tokens of a text: runs of alphanumerics and underscores
+fn identifiers(text: &str) -> impl Iterator<Item = &str> {
+    text.split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .filter(|token| token.chars().any(char::is_alphanumeric))
+}
+
+/// Reduce an English word to its stem with a few suffix rules
+///
+/// A light stemmer, not Porter's: it only needs to map the usual
+/// inflections of a word ("indexes", "indexed", "indexing") onto the same
+/// term, and is applied to documents and queries alike.
+pub fn stem_word(word: &str) -> String {
+    if !word.is_ascii() {
+        return word.to_string();
+    }
+    let mut stem = word.to_string();
+    for (suffix, replacement) in BM25_STEM_SUFFIXES {
+        if stem.len() > suffix.len() + 2 && stem.ends_with(suffix) {
+            if *suffix == "s" && (stem.ends_with("ss") || stem.ends_with("us")) {
+                break;
+            }
+            stem.truncate(stem.len() - suffix.len());
+            stem.push_str(replacement);
+            break;
+        }
+    }
+    // "cache" and "caching" meet at "cach"
+    if stem.len() > 4 && stem.ends_with('e') {
+        stem.pop();
+    }
+    stem
+}
+
+fn is_comment_line(line: &str) -> bool {
+    let line = line.trim_start();
+    line == "#"
+        || BM25_COMMENT_PREFIXES
+            .iter()
+            .any(|prefix| line.starts_with(prefix))
+}
+
+/// Keywords of a language, empty for languages without a list
+fn language_keywords(language: &str) -> &'static [&'static str] {
+    match language.to_lowercase().as_str() {
+        LANG_RUST => BM25_KEYWORDS_RUST,
+        LANG_PYTHON => BM25_KEYWORDS_PYTHON,
+        LANG_JAVASCRIPT | LANG_TYPESCRIPT => BM25_KEYWORDS_JAVASCRIPT,
+        LANG_JAVA | LANG_KOTLIN | LANG_SCALA => BM25_KEYWORDS_JVM,
+        LANG_GO => BM25_KEYWORDS_GO,
+        LANG_C | LANG_CPP | LANG_CSHARP => BM25_KEYWORDS_C_FAMILY,
+        LANG_RUBY | LANG_PHP | LANG_LUA => BM25_KEYWORDS_SCRIPTING,
+        _ => &[],
+    }
+}
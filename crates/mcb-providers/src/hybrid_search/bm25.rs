@@ -13,14 +13,22 @@
 //! - |D| = document length
 //! - avgdl = average document length
 //! - k1, b = tuning parameters
+//!
+//! The scorer keeps an inverted index of its documents, so it can retrieve
+//! the best matches of a query on its own as well as score a given document.
+//! Documents can be added and removed one at a time, and the whole index
+//! serializes for persistence. Documents and queries are turned into terms
+//! by a [`CodeAnalyzer`].
 
 use mcb_domain::entities::CodeChunk;
-use std::collections::{HashMap, HashSet};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
+use super::analyzer::CodeAnalyzer;
 use crate::constants::{BM25_TOKEN_MIN_LENGTH, HYBRID_SEARCH_BM25_B, HYBRID_SEARCH_BM25_K1};
 
 /// BM25 parameters for tuning the algorithm
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BM25Params {
     /// k1 parameter (term frequency saturation)
     /// Higher values increase the importance of term frequency
@@ -43,59 +51,105 @@ impl Default for BM25Params {
 
 /// BM25 scorer for text-based ranking
 ///
-/// This scorer maintains document frequency statistics and provides
-/// efficient scoring of documents against queries.
-#[derive(Debug)]
+/// This scorer maintains an inverted index with per-document term
+/// frequencies and provides efficient scoring and retrieval of documents
+/// against queries. Documents are identified by a position chosen by the
+/// caller; [`BM25Scorer::new`] uses their index in the given slice.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BM25Scorer {
-    /// Document frequencies for each term (how many documents contain each term)
-    document_freq: HashMap<String, usize>,
-    /// Total number of documents
-    total_docs: usize,
-    /// Average document length (in tokens)
-    avg_doc_len: f32,
+    /// Postings of each term: document position -> term frequency
+    postings: HashMap<String, HashMap<usize, usize>>,
+    /// Length of each indexed document (in tokens), by position
+    doc_lengths: HashMap<usize, usize>,
+    /// Sum of all document lengths (in tokens)
+    total_length: usize,
     /// BM25 parameters
     params: BM25Params,
+    /// Analyzer the postings were built with
+    analyzer: CodeAnalyzer,
 }
 
 impl BM25Scorer {
     /// Create a new BM25 scorer from a collection of documents
     ///
-    /// Builds the document frequency index from the provided documents.
+    /// Builds the inverted index from the provided documents with the
+    /// default analyzer.
     pub fn new(documents: &[CodeChunk], params: BM25Params) -> Self {
-        let total_docs = documents.len();
-        let mut document_freq = HashMap::new();
-        let mut total_length = 0.0;
-
-        // Calculate document frequencies and total length
-        for doc in documents {
-            let tokens = Self::tokenize(&doc.content);
-            let doc_length = tokens.len() as f32;
-            total_length += doc_length;
-
-            // Count unique terms in this document
-            let unique_terms: HashSet<_> = tokens.into_iter().collect();
-            for term in unique_terms {
-                *document_freq.entry(term).or_insert(0) += 1;
-            }
+        let mut scorer = Self::empty(params, CodeAnalyzer::default());
+        for (position, doc) in documents.iter().enumerate() {
+            scorer.add_document(position, doc);
         }
+        scorer
+    }
 
-        let avg_doc_len = if total_docs > 0 {
-            total_length / total_docs as f32
-        } else {
-            0.0
-        };
-
+    /// Create a scorer with an empty index
+    pub fn empty(params: BM25Params, analyzer: CodeAnalyzer) -> Self {
         Self {
-            document_freq,
-            total_docs,
-            avg_doc_len,
+            postings: HashMap::new(),
+            doc_lengths: HashMap::new(),
+            total_length: 0,
             params,
+            analyzer,
+        }
+    }
+
+    /// Index a document at `position`
+    ///
+    /// The position must be free: remove the document indexed there first.
+    pub fn add_document(&mut self, position: usize, document: &CodeChunk) {
+        let tokens = self.document_terms(document);
+        self.total_length += tokens.len();
+        let previous = self.doc_lengths.insert(position, tokens.len());
+        debug_assert!(previous.is_none(), "position {position} already indexed");
+
+        let mut term_freq: HashMap<String, usize> = HashMap::new();
+        for term in tokens {
+            *term_freq.entry(term).or_insert(0) += 1;
         }
+        for (term, tf) in term_freq {
+            self.postings.entry(term).or_default().insert(position, tf);
+        }
+    }
+
+    /// Drop the document indexed at `position`, given the document itself
+    ///
+    /// Only the postings of the document's own terms are visited. Returns
+    /// whether a document was indexed at that position.
+    pub fn remove_document(&mut self, position: usize, document: &CodeChunk) -> bool {
+        let Some(length) = self.doc_lengths.remove(&position) else {
+            return false;
+        };
+        self.total_length -= length;
+
+        for term in self.document_terms(document) {
+            if let Some(postings) = self.postings.get_mut(&term) {
+                postings.remove(&position);
+                if postings.is_empty() {
+                    self.postings.remove(&term);
+                }
+            }
+        }
+        true
+    }
+
+    /// Use other tuning parameters; the index itself does not depend on them
+    pub fn set_params(&mut self, params: BM25Params) {
+        self.params = params;
+    }
+
+    /// Terms a document is indexed under
+    pub fn document_terms(&self, document: &CodeChunk) -> Vec<String> {
+        self.analyzer.analyze(&document.content, &document.language)
+    }
+
+    /// Terms a query is matched with
+    pub fn query_terms(&self, query: &str) -> Vec<String> {
+        self.analyzer.analyze_query(query)
     }
 
     /// Score a document against a query using BM25
     pub fn score(&self, document: &CodeChunk, query: &str) -> f32 {
-        let query_terms = Self::tokenize(query);
+        let query_terms = self.query_terms(query);
         self.score_with_tokens(document, &query_terms)
     }
 
@@ -104,15 +158,14 @@ impl BM25Scorer {
     /// This method avoids re-tokenizing the query for each document, improving performance
     /// when scoring multiple documents against the same query.
     pub fn score_with_tokens(&self, document: &CodeChunk, query_terms: &[String]) -> f32 {
-        let doc_terms = Self::tokenize(&document.content);
+        let doc_terms = self.document_terms(document);
         let doc_length = doc_terms.len() as f32;
 
         // Early return for empty documents
-        if doc_length == 0.0 || self.avg_doc_len == 0.0 {
+        if doc_length == 0.0 || self.avg_doc_len() == 0.0 {
             return 0.0;
         }
 
-        let mut score = 0.0;
         let mut doc_term_freq: HashMap<&str, usize> = HashMap::new();
 
         // Count term frequencies in document
@@ -121,33 +174,73 @@ impl BM25Scorer {
         }
 
         // Calculate BM25 score for each query term
+        query_terms
+            .iter()
+            .map(|query_term| {
+                let tf = doc_term_freq.get(query_term.as_str()).copied().unwrap_or(0);
+                let df = self.postings.get(query_term).map_or(0, HashMap::len);
+                self.term_score(tf, df, doc_length)
+            })
+            .sum()
+    }
+
+    /// Rank the indexed documents matching any query term (best first)
+    ///
+    /// Returns the position of each matching document with its BM25 score.
+    /// Only the postings of the query terms are visited, so documents
+    /// sharing no term with the query cost nothing.
+    pub fn rank(&self, query_terms: &[String]) -> Vec<(usize, f32)> {
+        if self.avg_doc_len() == 0.0 {
+            return Vec::new();
+        }
+
+        let mut scores: HashMap<usize, f32> = HashMap::new();
         for query_term in query_terms {
-            let tf = doc_term_freq.get(query_term.as_str()).copied().unwrap_or(0) as f32;
-            let df = self.document_freq.get(query_term).copied().unwrap_or(0) as f32;
-
-            if df > 0.0 && tf > 0.0 {
-                // IDF calculation using Lucene/Elasticsearch variant that ensures positive IDF
-                // This avoids zero/negative IDF when terms appear in half or more documents
-                let idf = if self.total_docs > 1 {
-                    // Lucene BM25 IDF: ln(1 + (N - n + 0.5) / (n + 0.5))
-                    (1.0 + (self.total_docs as f32 - df + 0.5) / (df + 0.5)).ln()
-                } else {
-                    // Simplified IDF for single document (always positive)
-                    1.0
-                };
-
-                // Term frequency normalization
-                let tf_normalized = (tf * (self.params.k1 + 1.0))
-                    / (tf
-                        + self.params.k1
-                            * (1.0 - self.params.b
-                                + self.params.b * doc_length / self.avg_doc_len));
-
-                score += idf * tf_normalized;
+            let Some(postings) = self.postings.get(query_term) else {
+                continue;
+            };
+            for (&position, &tf) in postings {
+                let doc_length = self.doc_lengths[&position] as f32;
+                *scores.entry(position).or_insert(0.0) +=
+                    self.term_score(tf, postings.len(), doc_length);
             }
         }
 
-        score
+        let mut ranked: Vec<(usize, f32)> = scores.into_iter().collect();
+        ranked.sort_by(|a, b| {
+            b.1.partial_cmp(&a.1)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then(a.0.cmp(&b.0))
+        });
+        ranked
+    }
+
+    /// BM25 contribution of a term occurring `tf` times in a document of
+    /// `doc_length` tokens and in `df` documents overall
+    fn term_score(&self, tf: usize, df: usize, doc_length: f32) -> f32 {
+        if tf == 0 || df == 0 {
+            return 0.0;
+        }
+        let (tf, df) = (tf as f32, df as f32);
+
+        // IDF calculation using Lucene/Elasticsearch variant that ensures positive IDF
+        // This avoids zero/negative IDF when terms appear in half or more documents
+        let total_docs = self.total_docs();
+        let idf = if total_docs > 1 {
+            // Lucene BM25 IDF: ln(1 + (N - n + 0.5) / (n + 0.5))
+            (1.0 + (total_docs as f32 - df + 0.5) / (df + 0.5)).ln()
+        } else {
+            // Simplified IDF for single document (always positive)
+            1.0
+        };
+
+        // Term frequency normalization
+        let tf_normalized = (tf * (self.params.k1 + 1.0))
+            / (tf
+                + self.params.k1
+                    * (1.0 - self.params.b + self.params.b * doc_length / self.avg_doc_len()));
+
+        idf * tf_normalized
     }
 
     /// Score multiple documents with a single tokenization pass (batch optimization)
@@ -155,18 +248,19 @@ impl BM25Scorer {
     /// This is more efficient than calling `score()` for each document because
     /// the query is tokenized only once.
     pub fn score_batch(&self, documents: &[&CodeChunk], query: &str) -> Vec<f32> {
-        let query_terms = Self::tokenize(query);
+        let query_terms = self.query_terms(query);
         documents
             .iter()
             .map(|doc| self.score_with_tokens(doc, &query_terms))
             .collect()
     }
 
-    /// Tokenize text into terms
+    /// Tokenize text into plain words
     ///
     /// Performs lowercase normalization and splits on whitespace, punctuation,
     /// and underscores (for snake_case identifiers).
     /// Filters out tokens shorter than `BM25_TOKEN_MIN_LENGTH`.
+    /// The index itself uses the code-aware [`CodeAnalyzer`] pipeline.
     pub fn tokenize(text: &str) -> Vec<String> {
         text.to_lowercase()
             .split(|c: char| !c.is_alphanumeric())
@@ -177,21 +271,30 @@ impl BM25Scorer {
 
     /// Get the total number of indexed documents
     pub fn total_docs(&self) -> usize {
-        self.total_docs
+        self.doc_lengths.len()
     }
 
     /// Get the number of unique terms in the index
     pub fn unique_terms(&self) -> usize {
-        self.document_freq.len()
+        self.postings.len()
     }
 
     /// Get the average document length
     pub fn avg_doc_len(&self) -> f32 {
-        self.avg_doc_len
+        if self.doc_lengths.is_empty() {
+            0.0
+        } else {
+            self.total_length as f32 / self.doc_lengths.len() as f32
+        }
     }
 
     /// Get the BM25 parameters
     pub fn params(&self) -> &BM25Params {
         &self.params
     }
+
+    /// Get the analyzer the index was built with
+    pub fn analyzer(&self) -> &CodeAnalyzer {
+        &self.analyzer
+    }
 }
@@ -9,53 +9,77 @@
 //! Query Input
 //!     |
 //!     v
-//! Parallel Processing:
-//!     +-> BM25 Scorer (keyword matching)
-//!     |   +-> Term frequency score (0-1)
+//! Independent Retrieval:
+//!     +-> BM25 inverted index (keyword matching)
+//!     |   +-> Lexical candidates, filtered like the semantic ones
 //!     |
 //!     +-> Semantic (from vector store)
-//!         +-> Vector similarity score (0-1)
+//!         +-> Semantic candidates with similarity scores (0-1)
 //!
-//! Score Fusion:
-//!     Hybrid Score = bm25_weight * BM25 + semantic_weight * Semantic
+//! Score Fusion (union of both candidate sets):
+//!     Linear, Reciprocal Rank or Distribution-Based
 //!
 //! Rank Results (highest score first)
 //!     |
 //!     v
 //! Return Top-K Results
 //! ```
+//!
+//! # Persistence
+//!
+//! With an [`IndexStorage`] the collection indexes survive restarts: every
+//! change is journaled to disk and collections are restored on first use.
+//! Without one they live in memory only.
 
 use async_trait::async_trait;
-use mcb_domain::ports::providers::HybridSearchProvider;
-use mcb_domain::{entities::CodeChunk, error::Result, value_objects::SearchResult};
-use serde_json::Value;
-use std::collections::HashMap;
-use std::sync::RwLock;
-
-use super::bm25::{BM25Params, BM25Scorer};
-use crate::constants::{HYBRID_SEARCH_BM25_WEIGHT, HYBRID_SEARCH_SEMANTIC_WEIGHT};
+use mcb_domain::entities::CodeChunk;
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::providers::{HybridSearchProvider, HybridSearchResult};
+use mcb_domain::value_objects::{FusionStrategy, SearchQuery, SearchResult};
+use serde_json::{Map, Value, json};
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use super::analyzer::{AnalyzerConfig, CodeAnalyzer};
+use super::bm25::BM25Params;
+use super::fusion::{FusionParams, fuse, scale_to_max};
+use super::index::CollectionIndex;
+use super::storage::{IndexStorage, JournalEntry};
+use crate::constants::{
+    HYBRID_SEARCH_BM25_WEIGHT, HYBRID_SEARCH_RRF_K, HYBRID_SEARCH_SEMANTIC_WEIGHT,
+};
 
 /// Hybrid search engine combining BM25 and semantic search
 ///
-/// This engine maintains separate BM25 indexes for each collection and combines
-/// BM25 scores with semantic similarity scores provided by a vector store.
+/// This engine maintains separate BM25 indexes for each collection. A query
+/// retrieves lexical candidates from the index on its own and fuses them
+/// with the semantic candidates provided by a vector store, so exact
+/// identifier matches surface even when the embedding misses them.
 pub struct HybridSearchEngine {
-    /// Weight for BM25 score in hybrid combination (0.0-1.0)
-    bm25_weight: f32,
-    /// Weight for semantic score in hybrid combination (0.0-1.0)
-    semantic_weight: f32,
-    /// Collection indexes: collection_name -> (documents, scorer, document_index)
-    collections: RwLock<HashMap<String, CollectionIndex>>,
+    /// Weights and RRF constant of the fusion strategies
+    fusion_params: FusionParams,
+    /// Fusion strategy used when the query does not pick one
+    fusion: FusionStrategy,
+    /// BM25 parameters of the collection scorers
+    bm25_params: BM25Params,
+    /// Analyzer turning chunks and queries into BM25 terms
+    analyzer: CodeAnalyzer,
+    /// Where collection indexes are persisted, `None` to keep them in memory
+    storage: Option<IndexStorage>,
+    /// Collection indexes: collection_name -> index and journal length
+    collections: RwLock<HashMap<String, CollectionState>>,
+    /// Held across each change and its disk writes, so the journal records
+    /// changes in the order they were applied
+    io: tokio::sync::Mutex<()>,
 }
 
-/// Index for a single collection
-struct CollectionIndex {
-    /// Indexed documents
-    documents: Vec<CodeChunk>,
-    /// BM25 scorer for this collection
-    scorer: BM25Scorer,
-    /// Document index mapping (file_path:start_line -> document index)
-    document_index: HashMap<String, usize>,
+/// Index of a single collection
+struct CollectionState {
+    /// Indexed chunks and their BM25 inverted index
+    index: CollectionIndex,
+    /// Entries journaled since the last snapshot
+    journal_len: usize,
 }
 
 impl HybridSearchEngine {
@@ -77,29 +101,225 @@ impl HybridSearchEngine {
     /// more interpretable if they do.
     pub fn with_weights(bm25_weight: f32, semantic_weight: f32) -> Self {
         Self {
-            bm25_weight,
-            semantic_weight,
+            fusion_params: FusionParams {
+                bm25_weight,
+                semantic_weight,
+                rrf_k: HYBRID_SEARCH_RRF_K,
+            },
+            fusion: FusionStrategy::default(),
+            bm25_params: BM25Params::default(),
+            analyzer: CodeAnalyzer::default(),
+            storage: None,
             collections: RwLock::new(HashMap::new()),
+            io: tokio::sync::Mutex::new(()),
         }
     }
 
+    /// Use custom BM25 tuning parameters
+    pub fn with_bm25_params(mut self, params: BM25Params) -> Self {
+        self.bm25_params = params;
+        self
+    }
+
+    /// Use custom analyzer settings for chunks and queries
+    pub fn with_analyzer(mut self, config: AnalyzerConfig) -> Self {
+        self.analyzer = CodeAnalyzer::new(config);
+        self
+    }
+
+    /// Persist collection indexes under `directory`
+    ///
+    /// Collections persisted there before are restored on first use.
+    pub fn with_index_path(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.storage = Some(IndexStorage::new(directory));
+        self
+    }
+
+    /// Use a default fusion strategy other than linear
+    pub fn with_fusion(mut self, fusion: FusionStrategy) -> Self {
+        self.fusion = fusion;
+        self
+    }
+
+    /// Use a custom rank constant for reciprocal rank fusion
+    pub fn with_rrf_k(mut self, rrf_k: f32) -> Self {
+        self.fusion_params.rrf_k = rrf_k;
+        self
+    }
+
     /// Get BM25 weight
     pub fn bm25_weight(&self) -> f32 {
-        self.bm25_weight
+        self.fusion_params.bm25_weight
     }
 
     /// Get semantic weight
     pub fn semantic_weight(&self) -> f32 {
-        self.semantic_weight
+        self.fusion_params.semantic_weight
+    }
+
+    /// Get the default fusion strategy
+    pub fn fusion(&self) -> FusionStrategy {
+        self.fusion
+    }
+
+    /// Get the rank constant of reciprocal rank fusion
+    pub fn rrf_k(&self) -> f32 {
+        self.fusion_params.rrf_k
+    }
+
+    fn read_collections(&self) -> Result<RwLockReadGuard<'_, HashMap<String, CollectionState>>> {
+        self.collections
+            .read()
+            .map_err(|e| Error::internal(format!("Lock poisoned: {}", e)))
+    }
+
+    fn write_collections(&self) -> Result<RwLockWriteGuard<'_, HashMap<String, CollectionState>>> {
+        self.collections
+            .write()
+            .map_err(|e| Error::internal(format!("Lock poisoned: {}", e)))
     }
 
-    /// Normalize BM25 score to 0-1 range using sigmoid
-    fn normalize_bm25_score(score: f32) -> f32 {
-        if score > 0.0 {
-            1.0 / (1.0 + (-score).exp())
-        } else {
-            0.0
+    /// Restore a persisted collection unless loaded already
+    ///
+    /// A replayed or torn journal is compacted right away, so the journal
+    /// appended to afterwards never follows an entry torn by a crash. Must
+    /// be called with the `io` lock held.
+    async fn restore(&self, collection: &str) -> Result<()> {
+        let Some(storage) = self.storage.clone() else {
+            return Ok(());
+        };
+        if self.read_collections()?.contains_key(collection) {
+            return Ok(());
         }
+
+        let name = collection.to_string();
+        let params = self.bm25_params.clone();
+        let analyzer = self.analyzer.clone();
+        let restored = run_blocking(move || {
+            let restored = storage.load(&name, &params, &analyzer)?;
+            if let Some(restored) = &restored
+                && restored.needs_snapshot()
+            {
+                storage.snapshot(&name, &restored.index)?;
+            }
+            Ok(restored)
+        })
+        .await?;
+
+        if let Some(restored) = restored {
+            self.write_collections()?
+                .entry(collection.to_string())
+                .or_insert(CollectionState {
+                    index: restored.index,
+                    journal_len: 0,
+                });
+        }
+        Ok(())
+    }
+
+    /// Restore a persisted collection before reading it
+    async fn ensure_restored(&self, collection: &str) -> Result<()> {
+        if self.storage.is_none() || self.read_collections()?.contains_key(collection) {
+            return Ok(());
+        }
+        let _io = self.io.lock().await;
+        self.restore(collection).await
+    }
+
+    /// Apply changes to a collection index and journal them
+    ///
+    /// The index is updated under the write lock; journal appends and
+    /// snapshots run on the blocking pool once it is released.
+    async fn apply(
+        &self,
+        collection: &str,
+        changes: impl FnOnce(&CollectionIndex) -> Vec<JournalEntry>,
+    ) -> Result<()> {
+        let _io = self.io.lock().await;
+        self.restore(collection).await?;
+
+        let (entries, snapshot) = {
+            let mut collections = self.write_collections()?;
+            let empty = CollectionIndex::new(self.bm25_params.clone(), self.analyzer.clone());
+            let entries = changes(collections.get(collection).map_or(&empty, |s| &s.index));
+            if entries.is_empty() {
+                return Ok(());
+            }
+            let state = collections
+                .entry(collection.to_string())
+                .or_insert_with(|| CollectionState {
+                    index: empty,
+                    journal_len: 0,
+                });
+            for entry in &entries {
+                entry.apply(&mut state.index);
+            }
+            if self.storage.is_none() {
+                return Ok(());
+            }
+
+            state.journal_len += entries.len();
+            // Compacting writes the index as of this change
+            let snapshot =
+                IndexStorage::should_compact(state.journal_len, state.index.len()).then(|| {
+                    state.journal_len = 0;
+                    state.index.clone()
+                });
+            (entries, snapshot)
+        };
+
+        let Some(storage) = self.storage.clone() else {
+            return Ok(());
+        };
+        let name = collection.to_string();
+        run_blocking(move || {
+            storage.append(&name, &entries)?;
+            match snapshot {
+                Some(index) => storage.snapshot(&name, &index),
+                None => Ok(()),
+            }
+        })
+        .await
+    }
+}
+
+/// Run blocking index I/O off the async runtime
+async fn run_blocking<T, F>(task: F) -> Result<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T> + Send + 'static,
+{
+    tokio::task::spawn_blocking(task)
+        .await
+        .map_err(|e| Error::internal(format!("BM25 index task failed: {}", e)))?
+}
+
+/// Metadata of an indexed chunk, keyed like the vector store payloads that
+/// search filters are evaluated against
+fn filter_fields(chunk: &CodeChunk) -> Map<String, Value> {
+    let mut fields = Map::from_iter([
+        ("id".to_string(), json!(chunk.id)),
+        ("file_path".to_string(), json!(chunk.file_path)),
+        ("start_line".to_string(), json!(chunk.start_line)),
+        ("end_line".to_string(), json!(chunk.end_line)),
+        ("language".to_string(), json!(chunk.language)),
+    ]);
+    if let Some(node_type) = chunk.metadata.get("node_type") {
+        fields.insert("node_type".to_string(), node_type.clone());
+    }
+    fields
+}
+
+/// Search result for a chunk retrieved by the lexical index only
+fn lexical_result(chunk: &CodeChunk, bm25_score: f32) -> SearchResult {
+    SearchResult {
+        id: chunk.id.clone(),
+        file_path: chunk.file_path.clone(),
+        start_line: chunk.start_line,
+        content: chunk.content.clone(),
+        score: f64::from(bm25_score),
+        language: chunk.language.clone(),
+        symbol: chunk.symbol(),
     }
 }
 
@@ -113,120 +333,178 @@ impl Default for HybridSearchEngine {
 impl HybridSearchProvider for HybridSearchEngine {
     /// Index code chunks for hybrid search
     ///
-    /// Builds a BM25 index for the specified collection. If the collection
-    /// already exists, the new chunks are added to the existing index.
+    /// Adds the chunks to the collection's inverted index, replacing chunks
+    /// with the same id. The rest of the index is untouched.
     async fn index_chunks(&self, collection: &str, chunks: &[CodeChunk]) -> Result<()> {
-        let mut collections = self
-            .collections
-            .write()
-            .map_err(|e| mcb_domain::error::Error::internal(format!("Lock poisoned: {}", e)))?;
-
-        // Build document index and deduplicate
-        let mut documents = Vec::new();
-        let mut document_index = HashMap::new();
-
-        // If collection exists, start with existing documents
-        if let Some(existing) = collections.get(collection) {
-            documents = existing.documents.clone();
-            document_index = existing.document_index.clone();
-        }
-
-        // Add new documents, deduplicating by key
-        for chunk in chunks {
-            let key = format!("{}:{}", chunk.file_path, chunk.start_line);
-            if let std::collections::hash_map::Entry::Vacant(e) = document_index.entry(key) {
-                let idx = documents.len();
-                e.insert(idx);
-                documents.push(chunk.clone());
-            }
-        }
-
-        // Build BM25 scorer for all documents
-        let scorer = BM25Scorer::new(&documents, BM25Params::default());
+        self.apply(collection, |_| {
+            chunks
+                .iter()
+                .map(|chunk| JournalEntry::Upsert {
+                    chunk: chunk.clone(),
+                })
+                .collect()
+        })
+        .await
+    }
 
-        collections.insert(
-            collection.to_string(),
-            CollectionIndex {
-                documents,
-                scorer,
-                document_index,
-            },
-        );
+    /// Remove chunks from the collection's index by id
+    async fn remove_chunks(&self, collection: &str, chunk_ids: &[String]) -> Result<()> {
+        self.apply(collection, |_| {
+            chunk_ids
+                .iter()
+                .map(|id| JournalEntry::Remove { id: id.clone() })
+                .collect()
+        })
+        .await
+    }
 
-        Ok(())
+    /// Remove the chunks of the given files from the collection's index
+    async fn remove_file_chunks(&self, collection: &str, file_paths: &[String]) -> Result<()> {
+        self.apply(collection, |index| {
+            index
+                .file_chunk_ids(file_paths)
+                .into_iter()
+                .map(|id| JournalEntry::Remove { id })
+                .collect()
+        })
+        .await
     }
 
     /// Perform hybrid search combining BM25 and semantic scores
     ///
-    /// Takes semantic search results (from a vector store) and re-ranks them
-    /// using a combination of BM25 and semantic similarity scores.
+    /// Fuses the semantic results with the collection's lexical candidates
+    /// using the engine's default fusion strategy.
     async fn search(
         &self,
         collection: &str,
         query: &str,
         semantic_results: Vec<SearchResult>,
         limit: usize,
-    ) -> Result<Vec<SearchResult>> {
-        let collections = self
-            .collections
-            .read()
-            .map_err(|e| mcb_domain::error::Error::internal(format!("Lock poisoned: {}", e)))?;
+    ) -> Result<Vec<HybridSearchResult>> {
+        self.search_query(
+            collection,
+            &SearchQuery::new(query, limit),
+            semantic_results,
+        )
+        .await
+    }
+
+    /// Perform hybrid search for a full query
+    ///
+    /// The BM25 index retrieves its own candidates, restricted by the
+    /// query's filter, as many as there are semantic candidates (at least
+    /// `query.limit`). Both candidate sets are merged and ranked with the
+    /// query's fusion strategy, or the engine's default one.
+    async fn search_query(
+        &self,
+        collection: &str,
+        query: &SearchQuery,
+        semantic_results: Vec<SearchResult>,
+    ) -> Result<Vec<HybridSearchResult>> {
+        self.ensure_restored(collection).await?;
+        let collections = self.read_collections()?;
 
         // If collection doesn't exist, return semantic results as-is
-        let Some(index) = collections.get(collection) else {
-            return Ok(semantic_results.into_iter().take(limit).collect());
+        let Some(index) = collections.get(collection).map(|state| &state.index) else {
+            return Ok(semantic_results
+                .into_iter()
+                .take(query.limit)
+                .map(HybridSearchResult::semantic_only)
+                .collect());
         };
 
-        // Pre-tokenize query once for all BM25 scoring
-        let query_terms = BM25Scorer::tokenize(query);
+        // Lexical ranking of the whole collection, through the inverted index
+        let ranked = index.rank(&query.text);
+        let bm25_by_doc: HashMap<usize, f32> = ranked.iter().copied().collect();
 
-        // Calculate hybrid scores for semantic results
-        let mut scored_results: Vec<(SearchResult, f32)> = semantic_results
+        // Semantic candidates, with their BM25 score when indexed
+        let lexical_limit = semantic_results.len().max(query.limit);
+        let mut seen = HashSet::new();
+        let mut candidates: Vec<(SearchResult, Option<f32>, Option<f32>)> = semantic_results
             .into_iter()
             .map(|result| {
-                let doc_key = format!("{}:{}", result.file_path, result.start_line);
-                let semantic_score = result.score as f32;
-
-                // Look up document in index for BM25 scoring
-                let hybrid_score = if let Some(&doc_idx) = index.document_index.get(&doc_key) {
-                    let document = &index.documents[doc_idx];
-                    let bm25_score = index.scorer.score_with_tokens(document, &query_terms);
-                    let normalized_bm25 = Self::normalize_bm25_score(bm25_score);
-
-                    // Combine scores using weighted average
-                    self.bm25_weight * normalized_bm25 + self.semantic_weight * semantic_score
-                } else {
-                    // Document not found in BM25 index, use semantic score only
-                    self.semantic_weight * semantic_score
-                };
-
-                (result, hybrid_score)
+                // Vector stores return the stable chunk id the index is keyed by
+                let doc_idx = index.slot_of(&result.id);
+                if let Some(doc_idx) = doc_idx {
+                    seen.insert(doc_idx);
+                }
+                let bm25_score = doc_idx.and_then(|idx| bm25_by_doc.get(&idx).copied());
+                let semantic_score = Some(result.score as f32);
+                (result, bm25_score, semantic_score)
             })
             .collect();
 
+        // Lexical candidates the vector store did not return
+        let lexical_hits = ranked
+            .iter()
+            .filter_map(|&(doc_idx, score)| Some((doc_idx, index.document(doc_idx)?, score)))
+            .filter(|(_, chunk, _)| {
+                query
+                    .filter
+                    .as_ref()
+                    .is_none_or(|f| f.matches(&filter_fields(chunk)))
+            })
+            .take(lexical_limit)
+            .filter(|(doc_idx, _, _)| !seen.contains(doc_idx));
+        for (_, chunk, bm25_score) in lexical_hits {
+            candidates.push((lexical_result(chunk, bm25_score), Some(bm25_score), None));
+        }
+
+        let bm25_scores: Vec<Option<f32>> = candidates.iter().map(|c| c.1).collect();
+        let semantic_scores: Vec<Option<f32>> = candidates.iter().map(|c| c.2).collect();
+        let strategy = query.fusion.unwrap_or(self.fusion);
+        let hybrid_scores = fuse(
+            strategy,
+            &self.fusion_params,
+            &bm25_scores,
+            &semantic_scores,
+        );
+        let relative_bm25 = scale_to_max(&bm25_scores);
+
+        let mut scored_results: Vec<HybridSearchResult> = candidates
+            .into_iter()
+            .zip(hybrid_scores.into_iter().zip(relative_bm25))
+            .map(
+                |((result, _, semantic_score), (hybrid_score, bm25_score))| HybridSearchResult {
+                    result,
+                    bm25_score,
+                    semantic_score: semantic_score.unwrap_or(0.0),
+                    hybrid_score,
+                    rerank_score: None,
+                },
+            )
+            .collect();
+
         // Sort by hybrid score (descending)
-        scored_results.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        scored_results.sort_by(|a, b| {
+            b.hybrid_score
+                .partial_cmp(&a.hybrid_score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
 
         // Update scores in results and return top limit
         Ok(scored_results
             .into_iter()
-            .take(limit)
-            .map(|(mut result, hybrid_score)| {
-                result.score = hybrid_score as f64;
-                result
+            .take(query.limit)
+            .map(|mut hit| {
+                hit.result.score = hit.hybrid_score as f64;
+                hit
             })
             .collect())
     }
 
     /// Clear indexed data for a collection
+    ///
+    /// Persisted snapshots and journals of the collection are deleted too.
     async fn clear_collection(&self, collection: &str) -> Result<()> {
-        let mut collections = self
-            .collections
-            .write()
-            .map_err(|e| mcb_domain::error::Error::internal(format!("Lock poisoned: {}", e)))?;
+        let _io = self.io.lock().await;
+        self.write_collections()?.remove(collection);
 
-        collections.remove(collection);
-        Ok(())
+        let Some(storage) = self.storage.clone() else {
+            return Ok(());
+        };
+        let name = collection.to_string();
+        run_blocking(move || storage.delete(&name)).await
     }
 
     /// Get hybrid search statistics
@@ -241,11 +519,24 @@ impl HybridSearchProvider for HybridSearchEngine {
         // Global stats
         stats.insert(
             "bm25_weight".to_string(),
-            serde_json::json!(self.bm25_weight),
+            serde_json::json!(self.bm25_weight()),
         );
         stats.insert(
             "semantic_weight".to_string(),
-            serde_json::json!(self.semantic_weight),
+            serde_json::json!(self.semantic_weight()),
+        );
+        stats.insert(
+            "fusion".to_string(),
+            serde_json::json!(self.fusion.as_str()),
+        );
+        stats.insert("rrf_k".to_string(), serde_json::json!(self.rrf_k()));
+        stats.insert(
+            "analyzer".to_string(),
+            serde_json::json!(self.analyzer.config()),
+        );
+        stats.insert(
+            "index_path".to_string(),
+            serde_json::json!(self.storage.as_ref().map(|s| s.directory())),
         );
         stats.insert(
             "collection_count".to_string(),
@@ -254,15 +545,17 @@ impl HybridSearchProvider for HybridSearchEngine {
 
         // Per-collection stats
         let mut collection_stats = HashMap::new();
-        for (name, index) in collections.iter() {
+        for (name, state) in collections.iter() {
+            let scorer = state.index.scorer();
             collection_stats.insert(
                 name.clone(),
                 serde_json::json!({
-                    "total_documents": index.scorer.total_docs(),
-                    "unique_terms": index.scorer.unique_terms(),
-                    "average_doc_length": index.scorer.avg_doc_len(),
-                    "bm25_k1": index.scorer.params().k1,
-                    "bm25_b": index.scorer.params().b,
+                    "total_documents": scorer.total_docs(),
+                    "unique_terms": scorer.unique_terms(),
+                    "average_doc_length": scorer.avg_doc_len(),
+                    "bm25_k1": scorer.params().k1,
+                    "bm25_b": scorer.params().b,
+                    "journal_entries": state.journal_len,
                 }),
             );
         }
@@ -274,3 +567,59 @@ impl HybridSearchProvider for HybridSearchEngine {
         stats
     }
 }
+
+// ============================================================================
+// Auto-registration via linkme distributed slice
+// ============================================================================
+
+use mcb_application::ports::registry::{
+    HYBRID_SEARCH_PROVIDERS, HybridSearchProviderConfig, HybridSearchProviderEntry,
+};
+
+/// Analyzer settings of a provider config, defaulting the unset ones
+fn analyzer_config(config: &HybridSearchProviderConfig) -> AnalyzerConfig {
+    let defaults = AnalyzerConfig::default();
+    AnalyzerConfig {
+        split_identifiers: config
+            .split_identifiers
+            .unwrap_or(defaults.split_identifiers),
+        keyword_stopwords: config
+            .keyword_stopwords
+            .unwrap_or(defaults.keyword_stopwords),
+        stemming: config.stemming.unwrap_or(defaults.stemming),
+        ngram_size: config.ngram_size.or(defaults.ngram_size),
+    }
+}
+
+/// Factory function for creating hybrid search engine instances.
+fn hybrid_search_engine_factory(
+    config: &HybridSearchProviderConfig,
+) -> std::result::Result<Arc<dyn HybridSearchProvider>, String> {
+    let defaults = BM25Params::default();
+    let params = BM25Params {
+        k1: config.bm25_k1.unwrap_or(defaults.k1),
+        b: config.bm25_b.unwrap_or(defaults.b),
+    };
+    let engine = HybridSearchEngine::with_weights(
+        config.bm25_weight.unwrap_or(HYBRID_SEARCH_BM25_WEIGHT),
+        config
+            .semantic_weight
+            .unwrap_or(HYBRID_SEARCH_SEMANTIC_WEIGHT),
+    )
+    .with_bm25_params(params)
+    .with_fusion(config.fusion.unwrap_or_default())
+    .with_rrf_k(config.rrf_k.unwrap_or(HYBRID_SEARCH_RRF_K))
+    .with_analyzer(analyzer_config(config));
+    let engine = match &config.index_path {
+        Some(path) => engine.with_index_path(path),
+        None => engine,
+    };
+    Ok(Arc::new(engine))
+}
+
+#[linkme::distributed_slice(HYBRID_SEARCH_PROVIDERS)]
+static HYBRID_ENGINE_PROVIDER: HybridSearchProviderEntry = HybridSearchProviderEntry {
+    name: "hybrid",
+    description: "BM25 + semantic retrieval with linear, RRF or distribution-based fusion (persistent lexical index)",
+    factory: hybrid_search_engine_factory,
+};
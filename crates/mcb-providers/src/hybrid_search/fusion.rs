@@ -0,0 +1,132 @@
+//! Score fusion strategies
+//!
+//! Merges the BM25 and semantic rankings of a query into one fused score
+//! per candidate. Every candidate comes from at least one of the rankings;
+//! a candidate missing from a ranking contributes nothing for it.
+//!
+//! | Strategy | Fused score |
+//! |----------|-------------|
+//! | Linear | `bm25_weight * bm25 / max(bm25) + semantic_weight * semantic` |
+//! | Reciprocal rank | `Σ 1 / (rrf_k + rank)` over the rankings holding the candidate |
+//! | Distribution-based | weighted sum of scores mapped from `μ ± 3σ` onto `0..1` |
+
+use mcb_domain::value_objects::FusionStrategy;
+
+use crate::constants::{
+    HYBRID_SEARCH_BM25_WEIGHT, HYBRID_SEARCH_DISTRIBUTION_SIGMAS, HYBRID_SEARCH_RRF_K,
+    HYBRID_SEARCH_SEMANTIC_WEIGHT,
+};
+
+/// Settings shared by the fusion strategies
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FusionParams {
+    /// Weight of the BM25 ranking (linear and distribution-based)
+    pub bm25_weight: f32,
+    /// Weight of the semantic ranking (linear and distribution-based)
+    pub semantic_weight: f32,
+    /// Rank constant `k` of reciprocal rank fusion
+    pub rrf_k: f32,
+}
+
+impl Default for FusionParams {
+    fn default() -> Self {
+        Self {
+            bm25_weight: HYBRID_SEARCH_BM25_WEIGHT,
+            semantic_weight: HYBRID_SEARCH_SEMANTIC_WEIGHT,
+            rrf_k: HYBRID_SEARCH_RRF_K,
+        }
+    }
+}
+
+/// Fuse the BM25 and semantic scores of each candidate
+///
+/// `bm25` and `semantic` hold one entry per candidate, `None` when the
+/// ranking did not retrieve it. Returns the fused score of each candidate,
+/// in the same order.
+pub fn fuse(
+    strategy: FusionStrategy,
+    params: &FusionParams,
+    bm25: &[Option<f32>],
+    semantic: &[Option<f32>],
+) -> Vec<f32> {
+    debug_assert_eq!(bm25.len(), semantic.len());
+    let (bm25, semantic) = match strategy {
+        FusionStrategy::Linear => (scale_to_max(bm25), present_or_zero(semantic)),
+        FusionStrategy::ReciprocalRank => {
+            return reciprocal_ranks(bm25, params.rrf_k)
+                .into_iter()
+                .zip(reciprocal_ranks(semantic, params.rrf_k))
+                .map(|(b, s)| b + s)
+                .collect();
+        }
+        FusionStrategy::DistributionBased => (standardize(bm25), standardize(semantic)),
+    };
+    bm25.into_iter()
+        .zip(semantic)
+        .map(|(b, s)| params.bm25_weight * b + params.semantic_weight * s)
+        .collect()
+}
+
+/// Scale scores so the best one is 1.0
+///
+/// BM25 scores are unbounded and depend on the corpus, so they are only
+/// meaningful relative to the best lexical hit of the same query.
+pub fn scale_to_max(scores: &[Option<f32>]) -> Vec<f32> {
+    let max = scores.iter().flatten().copied().fold(0.0_f32, f32::max);
+    scores
+        .iter()
+        .map(|score| match score {
+            Some(score) if max > 0.0 => score / max,
+            _ => 0.0,
+        })
+        .collect()
+}
+
+fn present_or_zero(scores: &[Option<f32>]) -> Vec<f32> {
+    scores.iter().map(|score| score.unwrap_or(0.0)).collect()
+}
+
+/// `1 / (k + rank)` of each candidate, rank starting at 1 for the best score
+fn reciprocal_ranks(scores: &[Option<f32>], k: f32) -> Vec<f32> {
+    let mut ranked: Vec<(usize, f32)> = scores
+        .iter()
+        .enumerate()
+        .filter_map(|(i, score)| score.map(|score| (i, score)))
+        .collect();
+    ranked.sort_by(|a, b| {
+        b.1.partial_cmp(&a.1)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(a.0.cmp(&b.0))
+    });
+
+    let mut fused = vec![0.0; scores.len()];
+    for (rank, (i, _)) in ranked.into_iter().enumerate() {
+        fused[i] = 1.0 / (k + rank as f32 + 1.0);
+    }
+    fused
+}
+
+/// Map `mean - 3σ..mean + 3σ` of the present scores onto `0..1`
+///
+/// When all scores are equal there is no spread to normalize by, and every
+/// present score maps to 1.0.
+fn standardize(scores: &[Option<f32>]) -> Vec<f32> {
+    let present: Vec<f32> = scores.iter().flatten().copied().collect();
+    if present.is_empty() {
+        return vec![0.0; scores.len()];
+    }
+    let n = present.len() as f32;
+    let mean = present.iter().sum::<f32>() / n;
+    let sigma = (present.iter().map(|s| (s - mean).powi(2)).sum::<f32>() / n).sqrt();
+    let low = mean - HYBRID_SEARCH_DISTRIBUTION_SIGMAS * sigma;
+    let span = 2.0 * HYBRID_SEARCH_DISTRIBUTION_SIGMAS * sigma;
+
+    scores
+        .iter()
+        .map(|score| match score {
+            Some(_) if span <= f32::EPSILON => 1.0,
+            Some(score) => ((score - low) / span).clamp(0.0, 1.0),
+            None => 0.0,
+        })
+        .collect()
+}
@@ -0,0 +1,128 @@
+//! Lexical index of a collection
+//!
+//! Holds the indexed chunks of a collection next to their BM25 inverted
+//! index. Chunks are added and removed one at a time; a removed chunk frees
+//! its slot for the next one, so the index never has to be rebuilt.
+
+use mcb_domain::entities::CodeChunk;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+use super::analyzer::CodeAnalyzer;
+use super::bm25::{BM25Params, BM25Scorer};
+
+/// Lexical index of a single collection
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CollectionIndex {
+    /// Indexed chunks by slot; `None` for freed slots
+    documents: Vec<Option<CodeChunk>>,
+    /// Freed slots, reused before growing `documents`
+    free_slots: Vec<usize>,
+    /// Slot of each chunk id, the key semantic results are matched by
+    ids: HashMap<String, usize>,
+    /// BM25 inverted index over the chunk contents, keyed by slot
+    scorer: BM25Scorer,
+}
+
+impl CollectionIndex {
+    /// Create an empty index
+    pub fn new(params: BM25Params, analyzer: CodeAnalyzer) -> Self {
+        Self {
+            documents: Vec::new(),
+            free_slots: Vec::new(),
+            ids: HashMap::new(),
+            scorer: BM25Scorer::empty(params, analyzer),
+        }
+    }
+
+    /// Add a chunk, replacing the chunk with the same id
+    ///
+    /// Chunks starting at the same location, such as a function and a
+    /// closure on its first line, are distinct documents.
+    pub fn upsert(&mut self, chunk: &CodeChunk) {
+        self.remove(&chunk.id);
+
+        let slot = self.free_slots.pop().unwrap_or_else(|| {
+            self.documents.push(None);
+            self.documents.len() - 1
+        });
+        self.scorer.add_document(slot, chunk);
+        self.ids.insert(chunk.id.clone(), slot);
+        self.documents[slot] = Some(chunk.clone());
+    }
+
+    /// Remove a chunk by id, returning whether it was indexed
+    pub fn remove(&mut self, id: &str) -> bool {
+        let Some(slot) = self.ids.remove(id) else {
+            return false;
+        };
+        if let Some(chunk) = self.documents[slot].take() {
+            self.scorer.remove_document(slot, &chunk);
+        }
+        self.free_slots.push(slot);
+        true
+    }
+
+    /// Ids of the chunks extracted from any of `file_paths`
+    pub fn file_chunk_ids(&self, file_paths: &[String]) -> Vec<String> {
+        let file_paths: HashSet<&str> = file_paths.iter().map(String::as_str).collect();
+        self.documents
+            .iter()
+            .flatten()
+            .filter(|chunk| file_paths.contains(chunk.file_path.as_str()))
+            .map(|chunk| chunk.id.clone())
+            .collect()
+    }
+
+    /// Chunk stored in `slot`
+    pub fn document(&self, slot: usize) -> Option<&CodeChunk> {
+        self.documents.get(slot).and_then(Option::as_ref)
+    }
+
+    /// Slot of the chunk with id `id`
+    pub fn slot_of(&self, id: &str) -> Option<usize> {
+        self.ids.get(id).copied()
+    }
+
+    /// Rank the indexed chunks against a query, returning slots (best first)
+    pub fn rank(&self, query: &str) -> Vec<(usize, f32)> {
+        self.scorer.rank(&self.scorer.query_terms(query))
+    }
+
+    /// Number of indexed chunks
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Whether no chunk is indexed
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// BM25 scorer of the collection
+    pub fn scorer(&self) -> &BM25Scorer {
+        &self.scorer
+    }
+
+    /// Use other BM25 tuning parameters
+    pub fn set_params(&mut self, params: BM25Params) {
+        self.scorer.set_params(params);
+    }
+
+    /// Use another analyzer, re-analyzing every chunk if it differs
+    ///
+    /// Postings depend on the analyzer, so an index persisted with other
+    /// analyzer settings is rebuilt from its stored chunks.
+    pub fn set_analyzer(&mut self, analyzer: CodeAnalyzer) {
+        if self.scorer.analyzer() == &analyzer {
+            return;
+        }
+        let params = self.scorer.params().clone();
+        self.scorer = BM25Scorer::empty(params, analyzer);
+        for (slot, chunk) in self.documents.iter().enumerate() {
+            if let Some(chunk) = chunk {
+                self.scorer.add_document(slot, chunk);
+            }
+        }
+    }
+}
@@ -10,18 +10,18 @@
 //!     |
 //!     v
 //! +-------------------+     +-------------------+
-//! | BM25 Scorer       |     | Vector Store      |
+//! | BM25 Index        |     | Vector Store      |
 //! | (lexical match)   |     | (semantic match)  |
 //! +-------------------+     +-------------------+
 //!           |                       |
 //!           v                       v
-//!     BM25 Score              Semantic Score
+//!   Lexical Candidates      Semantic Candidates
 //!           |                       |
 //!           +----------+------------+
 //!                      |
 //!                      v
 //!              Score Fusion
-//!     hybrid = bm25_weight * bm25 + semantic_weight * semantic
+//!     linear | reciprocal rank | distribution-based
 //!                      |
 //!                      v
 //!              Ranked Results
@@ -44,15 +44,42 @@
 //!
 //! // Combine with BM25 for hybrid ranking
 //! let results = engine.search("my-project", "auth middleware", semantic_results, 10).await?;
+//!
+//! // Or pick the fusion strategy per query
+//! let query = SearchQuery::new("AuthMiddleware", 10).with_fusion(FusionStrategy::ReciprocalRank);
+//! let results = engine.search_query("my-project", &query, semantic_results).await?;
 //! ```
 //!
+//! ## Fusion Strategies
+//!
+//! | Strategy | Description |
+//! |----------|-------------|
+//! | `linear` (default) | Weighted sum of the semantic score and the BM25 score relative to the best lexical hit |
+//! | `reciprocal_rank` | `Σ 1 / (k + rank)` over both rankings (`k` = 60), ignores score scales |
+//! | `distribution_based` | Weighted sum of scores normalized by each ranking's mean and spread |
+//!
 //! ## Providers
 //!
 //! | Provider | Description | Use Case |
 //! |----------|-------------|----------|
-//! | `HybridSearchEngine` | Full BM25 + semantic hybrid | Production search |
+//! | `HybridSearchEngine` | Full BM25 + semantic hybrid, optionally persisted | Production search |
 //! | `NullHybridSearchProvider` | Pass-through (no BM25) | Testing, development |
 //!
+//! ## Persistence
+//!
+//! `HybridSearchEngine::with_index_path` stores each collection's inverted
+//! index on disk as a snapshot plus a journal of later changes (see
+//! [`storage`]), so chunks are added and removed incrementally and the
+//! lexical index is restored after a restart instead of being lost.
+//!
+//! ## Analysis
+//!
+//! Chunks and queries are turned into terms by a [`CodeAnalyzer`] that
+//! splits identifiers (`IndexingServiceImpl` also indexes "indexing",
+//! "service" and "impl"), drops the keywords of the chunk's language, and
+//! optionally stems comments and indexes character n-grams (see
+//! [`analyzer`]).
+//!
 //! ## BM25 Algorithm
 //!
 //! BM25 (Best Matching 25) scores documents based on term frequency and
@@ -65,11 +92,19 @@
 //! - `k1`: Term frequency saturation (default: 1.2)
 //! - `b`: Document length normalization (default: 0.75)
 
+pub mod analyzer;
 pub mod bm25;
 pub mod engine;
+pub mod fusion;
+pub mod index;
 pub mod null;
+pub mod storage;
 
 // Re-export main types
+pub use analyzer::{AnalyzerConfig, CodeAnalyzer};
 pub use bm25::{BM25Params, BM25Scorer};
 pub use engine::HybridSearchEngine;
+pub use fusion::{FusionParams, fuse};
+pub use index::CollectionIndex;
 pub use null::NullHybridSearchProvider;
+pub use storage::{IndexStorage, JournalEntry, RestoredIndex};
@@ -4,10 +4,11 @@
 //! This provider passes through semantic results without any BM25 enhancement.
 
 use async_trait::async_trait;
-use mcb_domain::ports::providers::HybridSearchProvider;
+use mcb_domain::ports::providers::{HybridSearchProvider, HybridSearchResult};
 use mcb_domain::{entities::CodeChunk, error::Result, value_objects::SearchResult};
 use serde_json::Value;
 use std::collections::HashMap;
+use std::sync::Arc;
 
 /// Null implementation of HybridSearchProvider
 ///
@@ -37,6 +38,16 @@ impl HybridSearchProvider for NullHybridSearchProvider {
         Ok(())
     }
 
+    /// Remove chunks (no-op)
+    async fn remove_chunks(&self, _collection: &str, _chunk_ids: &[String]) -> Result<()> {
+        Ok(())
+    }
+
+    /// Remove file chunks (no-op)
+    async fn remove_file_chunks(&self, _collection: &str, _file_paths: &[String]) -> Result<()> {
+        Ok(())
+    }
+
     /// Search (pass-through)
     ///
     /// Returns semantic results unchanged, limited to the requested count.
@@ -46,8 +57,12 @@ impl HybridSearchProvider for NullHybridSearchProvider {
         _query: &str,
         semantic_results: Vec<SearchResult>,
         limit: usize,
-    ) -> Result<Vec<SearchResult>> {
-        Ok(semantic_results.into_iter().take(limit).collect())
+    ) -> Result<Vec<HybridSearchResult>> {
+        Ok(semantic_results
+            .into_iter()
+            .take(limit)
+            .map(HybridSearchResult::semantic_only)
+            .collect())
     }
 
     /// Clear collection (no-op)
@@ -66,3 +81,25 @@ impl HybridSearchProvider for NullHybridSearchProvider {
         stats
     }
 }
+
+// ============================================================================
+// Auto-registration via linkme distributed slice
+// ============================================================================
+
+use mcb_application::ports::registry::{
+    HYBRID_SEARCH_PROVIDERS, HybridSearchProviderConfig, HybridSearchProviderEntry,
+};
+
+/// Factory function for creating null hybrid search provider instances.
+fn null_hybrid_search_factory(
+    _config: &HybridSearchProviderConfig,
+) -> std::result::Result<Arc<dyn HybridSearchProvider>, String> {
+    Ok(Arc::new(NullHybridSearchProvider::new()))
+}
+
+#[linkme::distributed_slice(HYBRID_SEARCH_PROVIDERS)]
+static NULL_HYBRID_SEARCH_PROVIDER: HybridSearchProviderEntry = HybridSearchProviderEntry {
+    name: "null",
+    description: "Pass-through hybrid search (semantic ranking only)",
+    factory: null_hybrid_search_factory,
+};
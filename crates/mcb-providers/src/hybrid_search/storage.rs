@@ -0,0 +1,240 @@
+//! On-disk persistence of collection indexes
+//!
+//! Each collection is stored as two files in the index directory:
+//!
+//! | File | Content |
+//! |------|---------|
+//! | `{collection}_bm25.json` | Snapshot of the whole [`CollectionIndex`] (postings, doc lengths, chunks) |
+//! | `{collection}_bm25.jsonl` | Journal of the changes made since the snapshot, one per line |
+//!
+//! Changes are appended to the journal as they happen, so indexing a few
+//! chunks never rewrites the whole index. Once the journal outgrows the
+//! index it is compacted: a new snapshot is written and the journal emptied.
+//! Restoring loads the snapshot and replays the journal; replaying a change
+//! twice is harmless, so a crash between the two steps loses nothing.
+
+use mcb_domain::entities::CodeChunk;
+use mcb_domain::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use super::analyzer::CodeAnalyzer;
+use super::bm25::BM25Params;
+use super::index::CollectionIndex;
+use crate::constants::HYBRID_SEARCH_JOURNAL_COMPACT_MIN;
+
+/// Change recorded in a collection journal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum JournalEntry {
+    /// A chunk was added or replaced
+    Upsert {
+        /// The indexed chunk
+        chunk: CodeChunk,
+    },
+    /// A chunk was removed
+    Remove {
+        /// Id of the removed chunk
+        id: String,
+    },
+}
+
+impl JournalEntry {
+    /// Apply the change to an index
+    pub fn apply(&self, index: &mut CollectionIndex) {
+        match self {
+            Self::Upsert { chunk } => index.upsert(chunk),
+            Self::Remove { id } => {
+                index.remove(id);
+            }
+        }
+    }
+}
+
+/// Collection index restored from disk
+#[derive(Debug)]
+pub struct RestoredIndex {
+    /// The restored index
+    pub index: CollectionIndex,
+    /// Journal entries replayed on top of the snapshot
+    pub replayed: usize,
+    /// Whether replay stopped at a torn entry, after which appended entries
+    /// would never be replayed
+    pub torn: bool,
+}
+
+impl RestoredIndex {
+    /// Whether the index must be snapshotted before the journal is appended to
+    pub fn needs_snapshot(&self) -> bool {
+        self.replayed > 0 || self.torn
+    }
+}
+
+/// Directory holding the persisted indexes of all collections
+#[derive(Debug, Clone)]
+pub struct IndexStorage {
+    directory: PathBuf,
+}
+
+impl IndexStorage {
+    /// Persist indexes under `directory`, created on first write
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+
+    /// Directory the indexes are stored in
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    fn snapshot_path(&self, collection: &str) -> PathBuf {
+        self.directory.join(format!("{}_bm25.json", collection))
+    }
+
+    fn journal_path(&self, collection: &str) -> PathBuf {
+        self.directory.join(format!("{}_bm25.jsonl", collection))
+    }
+
+    /// Restore a collection index, or `None` when nothing was persisted
+    ///
+    /// A snapshot built with another analyzer is re-analyzed, and counts as
+    /// replayed so it gets rewritten. Replay stops at the first torn entry.
+    pub fn load(
+        &self,
+        collection: &str,
+        params: &BM25Params,
+        analyzer: &CodeAnalyzer,
+    ) -> Result<Option<RestoredIndex>> {
+        let snapshot_path = self.snapshot_path(collection);
+        let journal_path = self.journal_path(collection);
+        if !snapshot_path.exists() && !journal_path.exists() {
+            return Ok(None);
+        }
+
+        let mut replayed = 0;
+        let mut torn = false;
+        let mut index = if snapshot_path.exists() {
+            let file = File::open(&snapshot_path)
+                .map_err(|e| Error::io(format!("Failed to read BM25 snapshot: {}", e)))?;
+            let mut index: CollectionIndex = serde_json::from_reader(BufReader::new(file))
+                .map_err(|e| Error::internal(format!("Failed to parse BM25 snapshot: {}", e)))?;
+            if index.scorer().analyzer() != analyzer {
+                index.set_analyzer(analyzer.clone());
+                replayed += 1;
+            }
+            index
+        } else {
+            CollectionIndex::new(params.clone(), analyzer.clone())
+        };
+        index.set_params(params.clone());
+
+        if journal_path.exists() {
+            let file = File::open(&journal_path)
+                .map_err(|e| Error::io(format!("Failed to read BM25 journal: {}", e)))?;
+            for line in BufReader::new(file).lines() {
+                let line =
+                    line.map_err(|e| Error::io(format!("Failed to read BM25 journal: {}", e)))?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                // A torn line from an interrupted append is dropped
+                let Ok(entry) = serde_json::from_str::<JournalEntry>(&line) else {
+                    torn = true;
+                    break;
+                };
+                entry.apply(&mut index);
+                replayed += 1;
+            }
+        }
+
+        Ok(Some(RestoredIndex {
+            index,
+            replayed,
+            torn,
+        }))
+    }
+
+    /// Append changes to a collection journal
+    pub fn append(&self, collection: &str, entries: &[JournalEntry]) -> Result<()> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+        self.ensure_directory()?;
+
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.journal_path(collection))
+            .map_err(|e| Error::io(format!("Failed to open BM25 journal: {}", e)))?;
+        let mut writer = BufWriter::new(file);
+        for entry in entries {
+            serde_json::to_writer(&mut writer, entry).map_err(|e| {
+                Error::internal(format!("Failed to serialize BM25 journal entry: {}", e))
+            })?;
+            writer
+                .write_all(b"\n")
+                .map_err(|e| Error::io(format!("Failed to write BM25 journal: {}", e)))?;
+        }
+        writer
+            .flush()
+            .map_err(|e| Error::io(format!("Failed to write BM25 journal: {}", e)))
+    }
+
+    /// Whether a journal of `journal_len` entries should be compacted into
+    /// a snapshot of an index holding `index_len` chunks
+    pub fn should_compact(journal_len: usize, index_len: usize) -> bool {
+        journal_len > index_len.max(HYBRID_SEARCH_JOURNAL_COMPACT_MIN)
+    }
+
+    /// Write a snapshot of a collection index and empty its journal
+    ///
+    /// The snapshot is written to a temporary file first and renamed over
+    /// the previous one, so a crash never leaves a partial snapshot behind.
+    pub fn snapshot(&self, collection: &str, index: &CollectionIndex) -> Result<()> {
+        self.ensure_directory()?;
+
+        let snapshot_path = self.snapshot_path(collection);
+        let temp_path = snapshot_path.with_extension("json.tmp");
+        let file = File::create(&temp_path)
+            .map_err(|e| Error::io(format!("Failed to write BM25 snapshot: {}", e)))?;
+        let mut writer = BufWriter::new(file);
+        serde_json::to_writer(&mut writer, index)
+            .map_err(|e| Error::internal(format!("Failed to serialize BM25 snapshot: {}", e)))?;
+        writer
+            .flush()
+            .map_err(|e| Error::io(format!("Failed to write BM25 snapshot: {}", e)))?;
+        drop(writer);
+        fs::rename(&temp_path, &snapshot_path)
+            .map_err(|e| Error::io(format!("Failed to write BM25 snapshot: {}", e)))?;
+
+        let journal_path = self.journal_path(collection);
+        if journal_path.exists() {
+            fs::remove_file(journal_path)
+                .map_err(|e| Error::io(format!("Failed to truncate BM25 journal: {}", e)))?;
+        }
+        Ok(())
+    }
+
+    /// Delete everything persisted for a collection
+    pub fn delete(&self, collection: &str) -> Result<()> {
+        for path in [
+            self.snapshot_path(collection),
+            self.journal_path(collection),
+        ] {
+            if path.exists() {
+                fs::remove_file(path)
+                    .map_err(|e| Error::io(format!("Failed to delete BM25 index: {}", e)))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn ensure_directory(&self) -> Result<()> {
+        fs::create_dir_all(&self.directory)
+            .map_err(|e| Error::io(format!("Failed to create BM25 index directory: {}", e)))
+    }
+}
@@ -0,0 +1,80 @@
+//! Bash language processor for AST-based code chunking.
+
+use crate::language::common::{
+    BaseProcessor, CHUNK_SIZE_SHELL, DocCommentStyle, LanguageConfig, LanguageProcessor,
+    NodeExtractionRule, TS_NODE_FUNCTION_DEFINITION,
+};
+use mcb_domain::entities::CodeChunk;
+use mcb_domain::value_objects::{ChunkSizePolicy, Language};
+
+/// Bash language processor.
+pub struct BashProcessor {
+    processor: BaseProcessor,
+}
+
+impl Default for BashProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BashProcessor {
+    /// Create a new Bash language processor
+    pub fn new() -> Self {
+        let config = LanguageConfig::new(tree_sitter_bash::LANGUAGE.into())
+            .with_rules(Self::extraction_rules())
+            .with_fallback_patterns(Self::fallback_patterns())
+            .with_doc_comments(DocCommentStyle::Preceding(&["#"]))
+            .with_chunk_size(CHUNK_SIZE_SHELL);
+
+        Self {
+            processor: BaseProcessor::new(config),
+        }
+    }
+
+    fn extraction_rules() -> Vec<NodeExtractionRule> {
+        vec![NodeExtractionRule {
+            node_types: vec![TS_NODE_FUNCTION_DEFINITION.to_string()],
+            min_length: 40,
+            min_lines: 2,
+            max_depth: 4,
+            priority: 10,
+            include_context: true,
+        }]
+    }
+
+    fn fallback_patterns() -> Vec<String> {
+        ["function ", r"[A-Za-z_][A-Za-z0-9_]*\s*\(\)"]
+            .iter()
+            .map(|p| format!("^{}", p))
+            .collect()
+    }
+}
+
+impl LanguageProcessor for BashProcessor {
+    fn config(&self) -> &LanguageConfig {
+        self.processor.config()
+    }
+
+    fn extract_chunks_with_tree_sitter(
+        &self,
+        tree: &tree_sitter::Tree,
+        content: &str,
+        file_name: &str,
+        language: &Language,
+        policy: &ChunkSizePolicy,
+    ) -> Vec<CodeChunk> {
+        self.processor
+            .extract_chunks_with_tree_sitter(tree, content, file_name, language, policy)
+    }
+
+    fn extract_chunks_fallback(
+        &self,
+        content: &str,
+        file_name: &str,
+        language: &Language,
+    ) -> Vec<CodeChunk> {
+        self.processor
+            .extract_chunks_fallback(content, file_name, language)
+    }
+}
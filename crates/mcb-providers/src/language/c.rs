@@ -5,7 +5,7 @@ use crate::language::common::{
     NodeExtractionRule, TS_NODE_FUNCTION_DEFINITION,
 };
 use mcb_domain::entities::CodeChunk;
-use mcb_domain::value_objects::Language;
+use mcb_domain::value_objects::{ChunkSizePolicy, Language};
 
 /// C language processor.
 pub struct CProcessor {
@@ -53,9 +53,10 @@ impl LanguageProcessor for CProcessor {
         content: &str,
         file_name: &str,
         language: &Language,
+        policy: &ChunkSizePolicy,
     ) -> Vec<CodeChunk> {
         self.processor
-            .extract_chunks_with_tree_sitter(tree, content, file_name, language)
+            .extract_chunks_with_tree_sitter(tree, content, file_name, language, policy)
     }
 
     fn extract_chunks_fallback(
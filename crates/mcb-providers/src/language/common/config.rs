@@ -7,6 +7,7 @@ use super::constants::{
     DEFAULT_CHUNK_SIZE, NODE_EXTRACTION_DEFAULT_PRIORITY, NODE_EXTRACTION_MAX_DEPTH,
     NODE_EXTRACTION_MIN_LENGTH, NODE_EXTRACTION_MIN_LINES,
 };
+use super::symbols::DocCommentStyle;
 
 /// Rule for extracting specific AST node types
 #[derive(Debug, Clone)]
@@ -74,6 +75,13 @@ pub struct LanguageConfig {
     pub fallback_patterns: Vec<String>,
     /// Chunk size for generic fallback
     pub chunk_size: usize,
+    /// How doc comments are attached to declarations
+    pub doc_comments: DocCommentStyle,
+    /// Call targets that declare definitions, for grammars where they are
+    /// plain calls; when set, only those calls are extracted
+    pub definition_calls: &'static [&'static str],
+    /// Fields whose subtrees hold no declarations and are never traversed
+    pub skipped_fields: &'static [&'static str],
 }
 
 impl LanguageConfig {
@@ -84,6 +92,9 @@ impl LanguageConfig {
             extraction_rules: Vec::new(),
             fallback_patterns: Vec::new(),
             chunk_size: DEFAULT_CHUNK_SIZE,
+            doc_comments: DocCommentStyle::default(),
+            definition_calls: &[],
+            skipped_fields: &[],
         }
     }
 
@@ -111,6 +122,25 @@ impl LanguageConfig {
         self
     }
 
+    /// Set how doc comments are attached to declarations
+    pub fn with_doc_comments(mut self, doc_comments: DocCommentStyle) -> Self {
+        self.doc_comments = doc_comments;
+        self
+    }
+
+    /// Set the call targets that declare definitions (Elixir's `def`)
+    pub fn with_definition_calls(mut self, definition_calls: &'static [&'static str]) -> Self {
+        self.definition_calls = definition_calls;
+        self
+    }
+
+    /// Set the fields never traversed (Haskell's `type`, whose function
+    /// types share the node type of function declarations)
+    pub fn with_skipped_fields(mut self, skipped_fields: &'static [&'static str]) -> Self {
+        self.skipped_fields = skipped_fields;
+        self
+    }
+
     /// Get the tree-sitter language
     pub fn get_language(&self) -> tree_sitter::Language {
         self.ts_language.clone()
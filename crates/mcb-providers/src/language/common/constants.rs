@@ -49,6 +49,24 @@ pub const CHUNK_SIZE_SWIFT: usize = 15;
 /// Kotlin language chunk size
 pub const CHUNK_SIZE_KOTLIN: usize = 15;
 
+/// Scala language chunk size
+pub const CHUNK_SIZE_SCALA: usize = 15;
+
+/// Elixir language chunk size
+pub const CHUNK_SIZE_ELIXIR: usize = 15;
+
+/// Lua language chunk size
+pub const CHUNK_SIZE_LUA: usize = 15;
+
+/// Shell script language chunk size
+pub const CHUNK_SIZE_SHELL: usize = 15;
+
+/// Haskell language chunk size
+pub const CHUNK_SIZE_HASKELL: usize = 15;
+
+/// Zig language chunk size
+pub const CHUNK_SIZE_ZIG: usize = 15;
+
 /// Generic/fallback language chunk size (for unsupported languages)
 pub const CHUNK_SIZE_GENERIC: usize = 15;
 
@@ -68,6 +86,136 @@ pub const NODE_EXTRACTION_MAX_DEPTH: usize = 3;
 /// Node extraction rule default priority
 pub const NODE_EXTRACTION_DEFAULT_PRIORITY: i32 = 5;
 
+// ============================================================================
+// Symbol Extraction
+// ============================================================================
+
+/// Comment prefixes marking doc comments in C-family languages
+pub const DOC_COMMENT_PREFIXES_DEFAULT: &[&str] = &["///", "/**"];
+
+/// Fields holding types in Haskell, where a function type is a `function`
+/// node like a function declaration
+pub const SKIPPED_FIELDS_HASKELL: &[&str] = &["type"];
+
+/// Call targets that declare a definition in Elixir, where `def` and
+/// `defmodule` are ordinary calls
+pub const DEFINITION_CALLS_ELIXIR: &[&str] = &[
+    "def",
+    "defp",
+    "defmacro",
+    "defmacrop",
+    "defguard",
+    "defguardp",
+    "defmodule",
+    "defprotocol",
+    "defimpl",
+];
+
+/// Maximum characters kept of a symbol signature
+pub const SYMBOL_SIGNATURE_MAX_LENGTH: usize = 200;
+
+/// Maximum characters kept of a symbol doc comment
+pub const SYMBOL_DOC_MAX_LENGTH: usize = 500;
+
+// ============================================================================
+// Import Extraction
+// ============================================================================
+
+/// Node types of import statements across the supported grammars
+pub const IMPORT_NODE_TYPES: &[&str] = &[
+    "use_declaration",
+    "extern_crate_declaration",
+    "import_statement",
+    "import_from_statement",
+    "import_declaration",
+    "import_header",
+    "preproc_include",
+    "using_directive",
+    "namespace_use_declaration",
+    "import",
+];
+
+/// Node types grouping import statements
+pub const IMPORT_LIST_NODE_TYPES: &[&str] = &["import_list", "imports"];
+
+/// Words of import statements that never name an imported item
+pub const IMPORT_KEYWORDS: &[&str] = &[
+    "use",
+    "import",
+    "from",
+    "as",
+    "pub",
+    "crate",
+    "self",
+    "super",
+    "extern",
+    "include",
+    "using",
+    "static",
+    "namespace",
+    "function",
+    "const",
+    "type",
+    "require",
+    "qualified",
+    "hiding",
+];
+
+/// Maximum imports attached to a chunk
+pub const CHUNK_IMPORTS_MAX: usize = 16;
+
+// ============================================================================
+// Documentation Chunking
+// ============================================================================
+
+/// Characters underlining (and overlining) reStructuredText section titles
+pub const RST_ADORNMENT_CHARS: &[char] = &[
+    '=', '-', '`', ':', '\'', '"', '~', '^', '_', '*', '+', '#', '<', '>',
+];
+
+/// reStructuredText directives introducing a code block
+pub const RST_CODE_DIRECTIVES: &[&str] = &[".. code-block::", ".. code::", ".. sourcecode::"];
+
+/// Separator of the headings in a section path
+pub const HEADING_PATH_SEPARATOR: &str = " > ";
+
+// ============================================================================
+// Configuration and Data File Chunking
+// ============================================================================
+
+/// Separator of the keys in a configuration key path
+pub const KEY_PATH_SEPARATOR: &str = ".";
+
+/// Objects named by SQL `CREATE`, `ALTER` and `DROP` statements
+pub const SQL_OBJECT_TYPES: &[&str] = &[
+    "TABLE",
+    "VIEW",
+    "INDEX",
+    "FUNCTION",
+    "PROCEDURE",
+    "TRIGGER",
+    "TYPE",
+    "SEQUENCE",
+    "SCHEMA",
+    "EXTENSION",
+    "DOMAIN",
+    "DATABASE",
+];
+
+/// Words between a SQL verb and the object type (`CREATE OR REPLACE VIEW`)
+pub const SQL_OBJECT_MODIFIERS: &[&str] = &[
+    "OR",
+    "REPLACE",
+    "TEMP",
+    "TEMPORARY",
+    "UNIQUE",
+    "MATERIALIZED",
+    "UNLOGGED",
+    "GLOBAL",
+    "LOCAL",
+    "RECURSIVE",
+];
+
 // ============================================================================
 // Language Identifiers (String constants for extensibility)
 // ============================================================================
@@ -111,6 +259,54 @@ pub const LANG_SWIFT: &str = "swift";
 /// Kotlin language identifier
 pub const LANG_KOTLIN: &str = "kotlin";
 
+/// Scala language identifier
+pub const LANG_SCALA: &str = "scala";
+
+/// Elixir language identifier
+pub const LANG_ELIXIR: &str = "elixir";
+
+/// Lua language identifier
+pub const LANG_LUA: &str = "lua";
+
+/// Haskell language identifier
+pub const LANG_HASKELL: &str = "haskell";
+
+/// Zig language identifier
+pub const LANG_ZIG: &str = "zig";
+
+/// Shell script language identifier
+pub const LANG_SHELL: &str = "shell";
+
+/// Dockerfile language identifier
+pub const LANG_DOCKERFILE: &str = "dockerfile";
+
+/// Makefile language identifier
+pub const LANG_MAKEFILE: &str = "makefile";
+
+/// Markdown document identifier
+pub const LANG_MARKDOWN: &str = "markdown";
+
+/// reStructuredText document identifier
+pub const LANG_RESTRUCTUREDTEXT: &str = "restructuredtext";
+
+/// AsciiDoc document identifier
+pub const LANG_ASCIIDOC: &str = "asciidoc";
+
+/// Plain text document identifier
+pub const LANG_TEXT: &str = "text";
+
+/// TOML file identifier
+pub const LANG_TOML: &str = "toml";
+
+/// YAML file identifier
+pub const LANG_YAML: &str = "yaml";
+
+/// JSON file identifier
+pub const LANG_JSON: &str = "json";
+
+/// SQL script identifier
+pub const LANG_SQL: &str = "sql";
+
 /// Unknown/unsupported language identifier
 pub const LANG_UNKNOWN: &str = "unknown";
 
@@ -130,6 +326,9 @@ pub const TS_NODE_METHOD_DECLARATION: &str = "method_declaration";
 /// Class declaration node type
 pub const TS_NODE_CLASS_DECLARATION: &str = "class_declaration";
 
+/// Call node type (for Elixir definitions)
+pub const TS_NODE_CALL: &str = "call";
+
 /// Interface declaration node type
 pub const AST_NODE_INTERFACE_DECLARATION: &str = "interface_declaration";
 
@@ -158,9 +158,11 @@ impl<'a> GenericFallbackChunker<'a> {
         }
 
         let chunk = CodeChunk {
-            id: format!(
-                "{}_{}_{}",
-                params.file_name, params.start_line, params.end_line
+            id: CodeChunk::stable_id(
+                params.file_name,
+                "fallback",
+                params.start_line as u32,
+                &content,
             ),
             content,
             file_path: params.file_name.to_string(),
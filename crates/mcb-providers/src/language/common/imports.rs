@@ -0,0 +1,133 @@
+//! Import extraction
+//!
+//! Collects the import statements of a file and attaches to each chunk the
+//! ones whose imported names the chunk uses, so the chunk can be embedded
+//! with the context it depends on.
+
+use super::constants::{
+    CHUNK_IMPORTS_MAX, IMPORT_KEYWORDS, IMPORT_LIST_NODE_TYPES, IMPORT_NODE_TYPES,
+};
+use mcb_domain::entities::CodeChunk;
+use std::collections::HashSet;
+
+/// Top-level import statements of a file, each on a single line
+///
+/// Grouped imports such as Go's `import ( ... )` yield one entry per spec.
+pub fn file_imports(tree: &tree_sitter::Tree, content: &str) -> Vec<String> {
+    let mut imports = Vec::new();
+    collect_imports(tree.root_node(), content, &mut imports);
+    imports
+}
+
+fn collect_imports(node: tree_sitter::Node, content: &str, imports: &mut Vec<String>) {
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        if IMPORT_LIST_NODE_TYPES.contains(&child.kind()) {
+            collect_imports(child, content, imports);
+        } else if IMPORT_NODE_TYPES.contains(&child.kind()) {
+            let specs = import_specs(child);
+            if specs.is_empty() {
+                imports.push(single_line(node_text(child, content)));
+            } else {
+                imports.extend(
+                    specs
+                        .into_iter()
+                        .map(|spec| format!("import {}", single_line(node_text(spec, content)))),
+                );
+            }
+        }
+    }
+}
+
+/// Specs of a grouped import declaration
+fn import_specs(node: tree_sitter::Node) -> Vec<tree_sitter::Node> {
+    let mut cursor = node.walk();
+    let Some(list) = node
+        .named_children(&mut cursor)
+        .find(|child| child.kind() == "import_spec_list")
+    else {
+        return Vec::new();
+    };
+    let mut cursor = list.walk();
+    list.named_children(&mut cursor)
+        .filter(|spec| spec.kind() == "import_spec")
+        .collect()
+}
+
+fn node_text<'c>(node: tree_sitter::Node, content: &'c str) -> &'c str {
+    content.get(node.byte_range()).unwrap_or_default()
+}
+
+fn single_line(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Record in each chunk's metadata the imports it refers to
+///
+/// Imports the chunk already contains, and chunks covering import statements
+/// themselves, are left alone.
+pub fn attach_imports(chunks: &mut [CodeChunk], imports: &[String]) {
+    if imports.is_empty() {
+        return;
+    }
+    let imported: Vec<(&String, HashSet<&str>)> = imports
+        .iter()
+        .map(|import| (import, imported_names(import)))
+        .collect();
+
+    for chunk in chunks {
+        let is_import = chunk
+            .metadata
+            .get("node_type")
+            .and_then(|t| t.as_str())
+            .is_some_and(|t| IMPORT_NODE_TYPES.contains(&t));
+        if is_import {
+            continue;
+        }
+
+        let used: HashSet<&str> = identifiers(&chunk.content).collect();
+        let relevant: Vec<&String> = imported
+            .iter()
+            .filter(|(import, names)| {
+                !names.is_disjoint(&used) && !chunk.content.contains(import.as_str())
+            })
+            .map(|(import, _)| *import)
+            .take(CHUNK_IMPORTS_MAX)
+            .collect();
+        if !relevant.is_empty()
+            && let Some(metadata) = chunk.metadata.as_object_mut()
+        {
+            metadata.insert(
+                CodeChunk::IMPORTS_METADATA_KEY.to_string(),
+                serde_json::json!(relevant),
+            );
+        }
+    }
+}
+
+fn identifiers(text: &str) -> impl Iterator<Item = &str> {
+    text.split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .filter(|word| !word.is_empty())
+}
+
+/// Names an import brings into scope: the last segment of each path
+///
+/// `use std::collections::{HashMap, HashSet};` imports `HashMap` and
+/// `HashSet`, `import "net/http"` imports `http`.
+fn imported_names(import: &str) -> HashSet<&str> {
+    let mut names = HashSet::new();
+    let mut rest = import;
+    while let Some(start) = rest.find(|c: char| c.is_alphanumeric() || c == '_') {
+        let word_end = rest[start..]
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .map_or(rest.len(), |end| start + end);
+        let word = &rest[start..word_end];
+        rest = &rest[word_end..];
+
+        let continues_path = rest.trim_start().starts_with([':', '.', '/', '\\']);
+        if !continues_path && word.len() > 1 && !IMPORT_KEYWORDS.contains(&word) {
+            names.insert(word);
+        }
+    }
+    names
+}
@@ -5,7 +5,10 @@
 pub mod config;
 pub mod constants;
 pub mod fallback;
+pub mod imports;
 pub mod processor;
+pub mod sizing;
+pub mod symbols;
 pub mod traverser;
 
 // Re-export commonly used types
@@ -13,4 +16,5 @@ pub use config::{LanguageConfig, NodeExtractionRule, NodeExtractionRuleBuilder};
 pub use constants::*;
 pub use fallback::GenericFallbackChunker;
 pub use processor::{BaseProcessor, LanguageProcessor};
+pub use symbols::DocCommentStyle;
 pub use traverser::AstTraverser;
@@ -5,9 +5,11 @@
 
 use super::config::LanguageConfig;
 use super::fallback::GenericFallbackChunker;
+use super::imports::{attach_imports, file_imports};
+use super::sizing::merge_small_chunks;
 use super::traverser::AstTraverser;
 use mcb_domain::entities::CodeChunk;
-use mcb_domain::value_objects::Language;
+use mcb_domain::value_objects::{ChunkSizePolicy, Language};
 
 /// Trait for language-specific processing
 ///
@@ -23,7 +25,8 @@ use mcb_domain::value_objects::Language;
 ///     .ok_or_else(|| anyhow::anyhow!("Failed to parse content"))?;
 ///
 /// // Extract chunks using AST
-/// let chunks = processor.extract_chunks_with_tree_sitter(&tree, content, "main.rs", &Language::Rust);
+/// let policy = ChunkSizePolicy::default();
+/// let chunks = processor.extract_chunks_with_tree_sitter(&tree, content, "main.rs", &Language::Rust, &policy);
 ///
 /// // Or use fallback for unsupported syntax
 /// let chunks = processor.extract_chunks_fallback(content, "main.rs", &Language::Rust);
@@ -32,13 +35,14 @@ pub trait LanguageProcessor: Send + Sync {
     /// Get language configuration
     fn config(&self) -> &LanguageConfig;
 
-    /// Extract chunks using tree-sitter
+    /// Extract chunks using tree-sitter, sized by `policy`
     fn extract_chunks_with_tree_sitter(
         &self,
         tree: &tree_sitter::Tree,
         content: &str,
         file_name: &str,
         language: &Language,
+        policy: &ChunkSizePolicy,
     ) -> Vec<CodeChunk>;
 
     /// Extract chunks using fallback method
@@ -84,16 +88,25 @@ impl LanguageProcessor for BaseProcessor {
         content: &str,
         file_name: &str,
         language: &Language,
+        policy: &ChunkSizePolicy,
     ) -> Vec<CodeChunk> {
         let mut chunks = Vec::new();
         let mut cursor = tree.walk();
 
         if cursor.goto_first_child() {
-            let traverser =
-                AstTraverser::new(&self.config().extraction_rules, language).with_max_chunks(75);
+            let traverser = AstTraverser::new(&self.config().extraction_rules, language)
+                .with_doc_comments(self.config().doc_comments)
+                .with_definition_calls(self.config().definition_calls)
+                .with_skipped_fields(self.config().skipped_fields)
+                .with_size_policy(*policy)
+                .with_max_chunks(75);
             traverser.traverse_and_extract(&mut cursor, content, file_name, 0, &mut chunks);
         }
 
+        let lines: Vec<&str> = content.lines().collect();
+        let mut chunks = merge_small_chunks(chunks, &lines, policy);
+        attach_imports(&mut chunks, &file_imports(tree, content));
+
         // Sort chunks by priority (highest first) and then by line number
         chunks.sort_by(|a, b| {
             let a_priority = a
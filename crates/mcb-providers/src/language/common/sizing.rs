@@ -0,0 +1,202 @@
+//! Token-based chunk sizing
+//!
+//! Splits declarations larger than the chunk size policy at statement
+//! boundaries, and merges runs of tiny adjacent chunks.
+
+use mcb_domain::entities::CodeChunk;
+use mcb_domain::value_objects::{ChunkSizePolicy, CodeSymbol};
+
+/// Inclusive range of source rows
+pub type RowRange = (usize, usize);
+
+/// Split `node` into row ranges of at most `policy.max_tokens` each
+///
+/// Ranges end on statement boundaries of the node's body, descending into
+/// statements that are too large on their own and splitting by line as a
+/// last resort. Every range after the first starts with up to
+/// `policy.overlap_tokens` of the rows before it.
+pub fn split_node_rows(
+    node: tree_sitter::Node,
+    lines: &[&str],
+    policy: &ChunkSizePolicy,
+) -> Vec<RowRange> {
+    if lines.is_empty() {
+        return Vec::new();
+    }
+    let segments = statement_segments(node, lines, policy);
+    pack_rows(&segments, lines, policy)
+}
+
+/// Pack consecutive `segments` into row ranges of at most `policy.max_tokens`
+///
+/// Every range after the first starts with up to `policy.overlap_tokens` of
+/// the rows before it. A single segment over the maximum stays whole.
+pub fn pack_rows(segments: &[RowRange], lines: &[&str], policy: &ChunkSizePolicy) -> Vec<RowRange> {
+    let parts = pack_segments(segments, lines, policy);
+    add_overlap(&parts, lines, policy)
+}
+
+/// Merge adjacent chunks smaller than `policy.min_tokens`
+///
+/// Small chunks separated by at most one line merge into one, and a run
+/// keeps growing while the merged chunk is still below the minimum and
+/// within the maximum. Merged chunks drop the symbol of their parts and
+/// record how many chunks they cover.
+pub fn merge_small_chunks(
+    mut chunks: Vec<CodeChunk>,
+    lines: &[&str],
+    policy: &ChunkSizePolicy,
+) -> Vec<CodeChunk> {
+    chunks.sort_by_key(|chunk| (chunk.start_line, chunk.end_line));
+
+    let mut merged: Vec<CodeChunk> = Vec::with_capacity(chunks.len());
+    for chunk in chunks {
+        if let Some(last) = merged.last_mut()
+            && let Some(combined) = merge_pair(last, &chunk, lines, policy)
+        {
+            *last = combined;
+            continue;
+        }
+        merged.push(chunk);
+    }
+    merged
+}
+
+fn merge_pair(
+    first: &CodeChunk,
+    second: &CodeChunk,
+    lines: &[&str],
+    policy: &ChunkSizePolicy,
+) -> Option<CodeChunk> {
+    let (start, end) = (first.start_line as usize, second.end_line as usize);
+    let adjacent = second.start_line > first.end_line && second.start_line <= first.end_line + 2;
+    let small = policy.estimate_tokens(&first.content) < policy.min_tokens
+        && policy.estimate_tokens(&second.content) < policy.min_tokens;
+    if !adjacent || !small || end >= lines.len() {
+        return None;
+    }
+
+    let content = lines[start..=end].join("\n").trim().to_string();
+    if policy.estimate_tokens(&content) > policy.max_tokens {
+        return None;
+    }
+
+    let merged_count = |chunk: &CodeChunk| {
+        chunk
+            .metadata
+            .get("merged_chunks")
+            .and_then(|n| n.as_u64())
+            .unwrap_or(1)
+    };
+    let mut metadata = first.metadata.clone();
+    if let Some(map) = metadata.as_object_mut() {
+        map.remove(CodeSymbol::METADATA_KEY);
+        map.insert(
+            "merged_chunks".to_string(),
+            serde_json::json!(merged_count(first) + merged_count(second)),
+        );
+    }
+
+    Some(CodeChunk {
+        id: CodeChunk::stable_id(&first.file_path, "merged", first.start_line, &content),
+        content,
+        file_path: first.file_path.clone(),
+        start_line: first.start_line,
+        end_line: second.end_line,
+        language: first.language.clone(),
+        metadata,
+    })
+}
+
+fn row_tokens(lines: &[&str], (start, end): RowRange, policy: &ChunkSizePolicy) -> usize {
+    policy.estimate_tokens(&lines[start..=end].join("\n"))
+}
+
+fn node_rows(node: tree_sitter::Node, lines: &[&str]) -> RowRange {
+    let last = lines.len() - 1;
+    let start = node.start_position().row.min(last);
+    (start, node.end_position().row.clamp(start, last))
+}
+
+/// Contiguous row ranges of `node`, each a statement or the lines between them
+fn statement_segments(
+    node: tree_sitter::Node,
+    lines: &[&str],
+    policy: &ChunkSizePolicy,
+) -> Vec<RowRange> {
+    let (start, end) = node_rows(node, lines);
+    let container = node.child_by_field_name("body").unwrap_or(node);
+    let mut cursor = container.walk();
+    let statements: Vec<_> = container.named_children(&mut cursor).collect();
+    if statements.is_empty() {
+        return (start..=end).map(|row| (row, row)).collect();
+    }
+
+    let mut segments = Vec::new();
+    let mut next_row = start;
+    for statement in statements {
+        let (statement_start, statement_end) = node_rows(statement, lines);
+        if statement_end < next_row {
+            continue;
+        }
+        if statement_start > next_row {
+            segments.push((next_row, statement_start - 1));
+        }
+        let rows = (statement_start.max(next_row), statement_end);
+        if rows.0 == statement_start && row_tokens(lines, rows, policy) > policy.max_tokens {
+            segments.extend(statement_segments(statement, lines, policy));
+        } else {
+            segments.push(rows);
+        }
+        next_row = statement_end + 1;
+    }
+    if next_row <= end {
+        segments.push((next_row, end));
+    }
+    segments
+}
+
+/// Greedily join consecutive segments while they leave room for the overlap
+fn pack_segments(segments: &[RowRange], lines: &[&str], policy: &ChunkSizePolicy) -> Vec<RowRange> {
+    let budget = policy.max_tokens - policy.overlap_tokens;
+    let mut parts = Vec::new();
+    let mut current: Option<RowRange> = None;
+    for &(start, end) in segments {
+        current = match current {
+            Some((current_start, _))
+                if row_tokens(lines, (current_start, end), policy) <= budget =>
+            {
+                Some((current_start, end))
+            }
+            Some(part) => {
+                parts.push(part);
+                Some((start, end))
+            }
+            None => Some((start, end)),
+        };
+    }
+    parts.extend(current);
+    parts
+}
+
+/// Extend every part after the first backwards by up to the overlap budget
+///
+/// A part never grows past `policy.max_tokens` through its overlap.
+fn add_overlap(parts: &[RowRange], lines: &[&str], policy: &ChunkSizePolicy) -> Vec<RowRange> {
+    parts
+        .iter()
+        .enumerate()
+        .map(|(i, &(start, end))| {
+            let floor = if i == 0 { start } else { parts[i - 1].0 };
+            let mut overlap_start = start;
+            while overlap_start > floor
+                && row_tokens(lines, (overlap_start - 1, start - 1), policy)
+                    <= policy.overlap_tokens
+                && row_tokens(lines, (overlap_start - 1, end), policy) <= policy.max_tokens
+            {
+                overlap_start -= 1;
+            }
+            (overlap_start, end)
+        })
+        .collect()
+}
@@ -0,0 +1,328 @@
+//! Symbol extraction from tree-sitter nodes
+//!
+//! Reads the name, kind, signature, enclosing scope and doc comment of the
+//! declaration a chunk covers, using the grammar's field names where they
+//! exist and falling back to identifier children otherwise. Grammars where
+//! definitions are plain calls (Elixir's `def`) name them by their arguments.
+
+use super::config::NodeExtractionRule;
+use super::constants::{
+    DOC_COMMENT_PREFIXES_DEFAULT, SYMBOL_DOC_MAX_LENGTH, SYMBOL_SIGNATURE_MAX_LENGTH, TS_NODE_CALL,
+};
+use mcb_domain::value_objects::CodeSymbol;
+
+/// How a language attaches documentation to a declaration
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocCommentStyle {
+    /// Comments directly above the declaration starting with one of the prefixes
+    Preceding(&'static [&'static str]),
+    /// A string literal as the first statement of the body (Python)
+    Docstring,
+}
+
+impl Default for DocCommentStyle {
+    fn default() -> Self {
+        Self::Preceding(DOC_COMMENT_PREFIXES_DEFAULT)
+    }
+}
+
+/// Fields holding the declared name, in lookup order
+const NAME_FIELDS: &[&str] = &["name", "declarator", "type"];
+
+/// Dotted names that name a declaration as a whole (Lua's `function M.run()`)
+const QUALIFIED_NAME_NODES: &[&str] = &["dot_index_expression", "method_index_expression"];
+
+/// Comment nodes whose type does not contain "comment" (Haskell)
+const DOC_COMMENT_NODES: &[&str] = &["haddock"];
+
+/// Nodes wrapping a declaration that carry its doc comment instead
+const DECLARATION_WRAPPERS: &[&str] = &["export_statement", "decorated_definition"];
+
+/// Nodes allowed between a doc comment and its declaration
+const DOC_COMMENT_SKIPPED: &[&str] = &[
+    "attribute_item",
+    "attribute",
+    "annotation",
+    "marker_annotation",
+    "decorator",
+    "signature",
+];
+
+/// Short kind labels, matched in order against the node type
+const SYMBOL_KINDS: &[(&str, &str)] = &[
+    ("impl", "impl"),
+    ("method", "method"),
+    ("function", "fn"),
+    ("class", "class"),
+    ("struct", "struct"),
+    ("enum", "enum"),
+    ("trait", "trait"),
+    ("interface", "interface"),
+    ("protocol", "protocol"),
+    ("object", "object"),
+    ("mod", "module"),
+    ("macro", "macro"),
+    ("const", "const"),
+    ("static", "static"),
+    ("type", "type"),
+];
+
+/// Extract the symbol declared by `node`
+///
+/// Returns `None` for nodes without a name, such as imports. The parent
+/// scope is the nearest ancestor matched by one of `rules`; with
+/// `definition_calls`, only calls to one of them count as scopes.
+pub fn extract_symbol(
+    node: tree_sitter::Node,
+    content: &str,
+    rules: &[NodeExtractionRule],
+    doc_style: DocCommentStyle,
+    definition_calls: &[&str],
+) -> Option<CodeSymbol> {
+    let definition = definition_call(node, content, definition_calls);
+    let name = match definition {
+        Some(_) => definition_call_name(node, content),
+        None => symbol_name(node, content).or_else(|| {
+            // Anonymous functions take the name they are assigned to
+            node.parent()
+                .and_then(|parent| parent.child_by_field_name("name"))
+                .map(|name| node_text(name, content).to_string())
+        }),
+    }?;
+
+    let mut ancestor = node.parent();
+    let mut parent = None;
+    while let Some(scope) = ancestor {
+        let is_scope = rules
+            .iter()
+            .any(|rule| rule.node_types.iter().any(|t| t == scope.kind()));
+        if is_scope
+            && (definition_calls.is_empty()
+                || definition_call(scope, content, definition_calls).is_some())
+        {
+            parent = signature(scope, content);
+            break;
+        }
+        ancestor = scope.parent();
+    }
+
+    let doc = match doc_style {
+        DocCommentStyle::Preceding(prefixes) => preceding_doc(node, content, prefixes),
+        DocCommentStyle::Docstring => docstring(node, content),
+    };
+
+    let kind = match definition {
+        Some(keyword) => definition_call_kind(keyword),
+        None => symbol_kind(node.kind()),
+    };
+
+    Some(CodeSymbol {
+        name,
+        kind: kind.to_string(),
+        signature: signature(node, content),
+        parent,
+        doc,
+    })
+}
+
+fn node_text<'c>(node: tree_sitter::Node, content: &'c str) -> &'c str {
+    content.get(node.byte_range()).unwrap_or_default()
+}
+
+/// Target of a call to one of `definition_calls`, such as Elixir's `def`
+pub fn definition_call<'c>(
+    node: tree_sitter::Node,
+    content: &'c str,
+    definition_calls: &[&str],
+) -> Option<&'c str> {
+    if node.kind() != TS_NODE_CALL {
+        return None;
+    }
+    let target = node_text(node.child_by_field_name("target")?, content);
+    definition_calls.contains(&target).then_some(target)
+}
+
+/// Name defined by a definition call: the head of its first argument
+///
+/// `def run(args) when is_list(args)` defines `run` and
+/// `defmodule Mcb.Indexer` defines `Mcb.Indexer`.
+fn definition_call_name(node: tree_sitter::Node, content: &str) -> Option<String> {
+    let mut cursor = node.walk();
+    let arguments = node
+        .named_children(&mut cursor)
+        .find(|child| child.kind() == "arguments")?;
+    let mut head = arguments.named_child(0)?;
+    loop {
+        head = match head.kind() {
+            TS_NODE_CALL => head.child_by_field_name("target")?,
+            "binary_operator" => head.child_by_field_name("left")?,
+            _ => return Some(node_text(head, content).to_string()),
+        };
+    }
+}
+
+/// Kind of a definition call: `defmodule` is a module, `def` a function
+fn definition_call_kind(keyword: &str) -> &str {
+    match symbol_kind(keyword) {
+        kind if kind == keyword => "fn",
+        kind => kind,
+    }
+}
+
+/// Resolve the declared name through the name fields of the grammar
+fn symbol_name(node: tree_sitter::Node, content: &str) -> Option<String> {
+    for field in NAME_FIELDS {
+        if let Some(child) = node.child_by_field_name(field) {
+            if child.kind().contains("identifier")
+                || child.named_child_count() == 0
+                || QUALIFIED_NAME_NODES.contains(&child.kind())
+            {
+                return Some(node_text(child, content).to_string());
+            }
+            if let Some(name) = symbol_name(child, content) {
+                return Some(name);
+            }
+        }
+    }
+
+    // Grammars without name fields (Go type specs, Kotlin)
+    let mut cursor = node.walk();
+    let children: Vec<_> = node.named_children(&mut cursor).collect();
+    children.into_iter().find_map(|child| {
+        if child.kind().ends_with("identifier") {
+            Some(node_text(child, content).to_string())
+        } else if child.kind().ends_with("_spec") {
+            symbol_name(child, content)
+        } else {
+            None
+        }
+    })
+}
+
+fn symbol_kind(node_type: &str) -> &str {
+    SYMBOL_KINDS
+        .iter()
+        .find(|(needle, _)| node_type.contains(needle))
+        .map_or(node_type, |(_, label)| label)
+}
+
+/// Declaration header: everything before the body, on a single line
+fn signature(node: tree_sitter::Node, content: &str) -> Option<String> {
+    let text = node_text(node, content);
+    let header = match node.child_by_field_name("body") {
+        Some(body) if body.start_byte() > node.start_byte() => {
+            &text[..body.start_byte() - node.start_byte()]
+        }
+        _ => text.lines().next().unwrap_or_default(),
+    };
+
+    let header = header.split_whitespace().collect::<Vec<_>>().join(" ");
+    let header = header.trim_end_matches(['{', ':', ' ']);
+    let header = header.strip_suffix(" do").unwrap_or(header);
+    if header.is_empty() {
+        return None;
+    }
+    Some(truncate(header, SYMBOL_SIGNATURE_MAX_LENGTH))
+}
+
+/// Contiguous comments directly above the declaration
+fn preceding_doc(node: tree_sitter::Node, content: &str, prefixes: &[&str]) -> Option<String> {
+    let mut anchor = node
+        .parent()
+        .filter(|parent| DECLARATION_WRAPPERS.contains(&parent.kind()))
+        .unwrap_or(node);
+    // The first declaration of a list starting with it is preceded by what
+    // precedes the list (Haskell's `declarations`)
+    while anchor.prev_named_sibling().is_none() {
+        match anchor.parent() {
+            Some(parent) if parent.start_byte() == anchor.start_byte() => anchor = parent,
+            _ => break,
+        }
+    }
+
+    let mut comments = Vec::new();
+    let mut next_row = anchor.start_position().row;
+    let mut sibling = anchor.prev_named_sibling();
+    while let Some(current) = sibling {
+        let kind = current.kind();
+        let adjacent = current.end_position().row + 1 >= next_row;
+        if !adjacent {
+            break;
+        }
+        if DOC_COMMENT_SKIPPED.contains(&kind) {
+            next_row = current.start_position().row;
+        } else if kind.contains("comment") || DOC_COMMENT_NODES.contains(&kind) {
+            let text = node_text(current, content).trim();
+            if !prefixes.iter().any(|prefix| text.starts_with(prefix)) {
+                break;
+            }
+            comments.push(text);
+            next_row = current.start_position().row;
+        } else if let Some(trailing) = trailing_doc_node(current) {
+            // Grammars may attach the comment to the node before it
+            // (Haskell's `imports`)
+            sibling = Some(trailing);
+            continue;
+        } else {
+            break;
+        }
+        sibling = current.prev_named_sibling();
+    }
+
+    comments.reverse();
+    clean_doc(comments.into_iter().flat_map(str::lines))
+}
+
+/// Doc comment node ending `node`, for grammars nesting it there
+fn trailing_doc_node(node: tree_sitter::Node) -> Option<tree_sitter::Node> {
+    let last = node.named_child(node.named_child_count().checked_sub(1)? as u32)?;
+    DOC_COMMENT_NODES.contains(&last.kind()).then_some(last)
+}
+
+/// First statement of the body when it is a string literal
+fn docstring(node: tree_sitter::Node, content: &str) -> Option<String> {
+    let statement = node.child_by_field_name("body")?.named_child(0)?;
+    let string = if statement.kind() == "expression_statement" {
+        statement.named_child(0)?
+    } else {
+        statement
+    };
+    if string.kind() != "string" {
+        return None;
+    }
+
+    let text = node_text(string, content).trim_matches(['"', '\'']);
+    clean_doc(text.lines())
+}
+
+/// Strip comment markers and surrounding blank lines
+fn clean_doc<'c>(lines: impl Iterator<Item = &'c str>) -> Option<String> {
+    let lines: Vec<&str> = lines
+        .map(|line| {
+            let line = line.trim();
+            let line = [
+                "///", "//!", "//", "/**", "/*!", "/*", "#", "---", "-- |", "--", "{- |", "{-|",
+            ]
+            .iter()
+            .find_map(|marker| line.strip_prefix(marker))
+            .unwrap_or(line);
+            let line = line.strip_suffix("*/").unwrap_or(line);
+            let line = line.strip_suffix("-}").unwrap_or(line).trim();
+            line.strip_prefix('*').unwrap_or(line).trim()
+        })
+        .skip_while(|line| line.is_empty())
+        .collect();
+    let doc = lines.join("\n");
+    let doc = doc.trim_end();
+    if doc.is_empty() {
+        return None;
+    }
+    Some(truncate(doc, SYMBOL_DOC_MAX_LENGTH))
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    match text.char_indices().nth(max_chars) {
+        Some((end, _)) => format!("{}...", &text[..end]),
+        None => text.to_string(),
+    }
+}
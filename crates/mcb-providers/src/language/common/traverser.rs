@@ -4,9 +4,11 @@
 //! and extracts code chunks according to configurable rules.
 
 use super::config::NodeExtractionRule;
+use super::sizing::{RowRange, split_node_rows};
+use super::symbols::{DocCommentStyle, definition_call, extract_symbol};
 use mcb_domain::entities::CodeChunk;
 use mcb_domain::error::{Error, Result};
-use mcb_domain::value_objects::Language;
+use mcb_domain::value_objects::{ChunkSizePolicy, CodeSymbol, Language};
 use std::collections::HashMap;
 
 /// Parameters for creating a code chunk
@@ -15,9 +17,10 @@ struct ChunkParams<'a> {
     content: String,
     file_name: &'a str,
     node_type: &'a str,
+    rows: RowRange,
     depth: usize,
     priority: i32,
-    chunk_index: usize,
+    symbol: Option<CodeSymbol>,
 }
 
 /// Context for extracting a chunk from a node
@@ -26,7 +29,6 @@ struct ExtractionContext<'a> {
     file_name: &'a str,
     depth: usize,
     rule: &'a NodeExtractionRule,
-    chunk_index: usize,
 }
 
 /// Generic AST node traverser with configurable rules
@@ -34,6 +36,10 @@ pub struct AstTraverser<'a> {
     rules: &'a [NodeExtractionRule],
     language: &'a Language,
     max_chunks: usize,
+    doc_comments: DocCommentStyle,
+    definition_calls: &'a [&'a str],
+    skipped_fields: &'a [&'a str],
+    size_policy: ChunkSizePolicy,
 }
 
 impl<'a> AstTraverser<'a> {
@@ -43,9 +49,38 @@ impl<'a> AstTraverser<'a> {
             rules,
             language,
             max_chunks: 100,
+            doc_comments: DocCommentStyle::default(),
+            definition_calls: &[],
+            skipped_fields: &[],
+            size_policy: ChunkSizePolicy::default(),
         }
     }
 
+    /// Configure the token bounds chunks are split to
+    pub fn with_size_policy(mut self, size_policy: ChunkSizePolicy) -> Self {
+        self.size_policy = size_policy;
+        self
+    }
+
+    /// Configure how doc comments are attached to declarations
+    pub fn with_doc_comments(mut self, doc_comments: DocCommentStyle) -> Self {
+        self.doc_comments = doc_comments;
+        self
+    }
+
+    /// Only extract calls to `definition_calls`, for grammars where
+    /// definitions are calls (Elixir)
+    pub fn with_definition_calls(mut self, definition_calls: &'a [&'a str]) -> Self {
+        self.definition_calls = definition_calls;
+        self
+    }
+
+    /// Never match or descend into nodes held by `skipped_fields`
+    pub fn with_skipped_fields(mut self, skipped_fields: &'a [&'a str]) -> Self {
+        self.skipped_fields = skipped_fields;
+        self
+    }
+
     /// Configure the maximum number of chunks to extract
     pub fn with_max_chunks(mut self, max_chunks: usize) -> Self {
         self.max_chunks = max_chunks;
@@ -67,6 +102,16 @@ impl<'a> AstTraverser<'a> {
         }
 
         loop {
+            if cursor
+                .field_name()
+                .is_some_and(|field| self.skipped_fields.contains(&field))
+            {
+                if !cursor.goto_next_sibling() {
+                    break;
+                }
+                continue;
+            }
+
             let node = cursor.node();
             let node_type = node.kind();
 
@@ -75,14 +120,18 @@ impl<'a> AstTraverser<'a> {
                 if !rule.node_types.contains(&node_type.to_string()) {
                     continue;
                 }
+                if !self.definition_calls.is_empty()
+                    && definition_call(node, content, self.definition_calls).is_none()
+                {
+                    continue;
+                }
                 let ctx = ExtractionContext {
                     content,
                     file_name,
                     depth,
                     rule,
-                    chunk_index: chunks.len(),
                 };
-                if let Some(chunk) = self.try_extract_chunk(node, ctx) {
+                for chunk in self.try_extract_chunks(node, ctx) {
                     chunks.push(chunk);
                     if chunks.len() >= self.max_chunks {
                         return;
@@ -154,32 +203,48 @@ impl<'a> AstTraverser<'a> {
         (Some(context), Some(context_lines))
     }
 
-    /// Try to extract a chunk from a node matching a rule
-    fn try_extract_chunk(
+    /// Try to extract chunks from a node matching a rule
+    ///
+    /// Nodes over the size policy are split into several chunks.
+    fn try_extract_chunks(
         &self,
         node: tree_sitter::Node,
         ctx: ExtractionContext,
-    ) -> Option<CodeChunk> {
+    ) -> Vec<CodeChunk> {
         let (code, context) = if ctx.rule.include_context {
             Self::extract_node_with_context(node, ctx.content, 3)
         } else {
             (Self::extract_node_content(node, ctx.content).ok(), None)
         };
 
-        let code = code?;
+        let Some(code) = code else {
+            return Vec::new();
+        };
         if code.len() < ctx.rule.min_length || code.lines().count() < ctx.rule.min_lines {
-            return None;
+            return Vec::new();
+        }
+
+        let symbol = extract_symbol(
+            node,
+            ctx.content,
+            self.rules,
+            self.doc_comments,
+            self.definition_calls,
+        );
+        if self.size_policy.estimate_tokens(&code) > self.size_policy.max_tokens {
+            return self.split_oversized_node(node, &ctx, symbol);
         }
 
         let chunk_params = ChunkParams {
             content: code,
             file_name: ctx.file_name,
             node_type: node.kind(),
+            rows: (node.start_position().row, node.end_position().row),
             depth: ctx.depth,
             priority: ctx.rule.priority,
-            chunk_index: ctx.chunk_index,
+            symbol,
         };
-        let mut chunk = self.create_chunk_from_node(node, chunk_params);
+        let mut chunk = self.create_chunk(chunk_params);
 
         // Add context metadata if available
         if let Some(context_lines) = context {
@@ -191,22 +256,59 @@ impl<'a> AstTraverser<'a> {
             }
         }
 
-        Some(chunk)
+        vec![chunk]
+    }
+
+    /// Split a node over the size policy into one chunk per part
+    fn split_oversized_node(
+        &self,
+        node: tree_sitter::Node,
+        ctx: &ExtractionContext,
+        symbol: Option<CodeSymbol>,
+    ) -> Vec<CodeChunk> {
+        let lines: Vec<&str> = ctx.content.lines().collect();
+        let parts = split_node_rows(node, &lines, &self.size_policy);
+        let part_count = parts.len();
+
+        parts
+            .into_iter()
+            .enumerate()
+            .filter_map(|(part, (start, end))| {
+                let content = lines[start..=end].join("\n").trim().to_string();
+                if content.is_empty() {
+                    return None;
+                }
+                let mut chunk = self.create_chunk(ChunkParams {
+                    content,
+                    file_name: ctx.file_name,
+                    node_type: node.kind(),
+                    rows: (start, end),
+                    depth: ctx.depth,
+                    priority: ctx.rule.priority,
+                    symbol: symbol.clone(),
+                });
+                if let Some(metadata) = chunk.metadata.as_object_mut() {
+                    metadata.insert("part".to_string(), serde_json::json!(part));
+                    metadata.insert("parts".to_string(), serde_json::json!(part_count));
+                }
+                Some(chunk)
+            })
+            .collect()
     }
 
-    fn create_chunk_from_node(&self, node: tree_sitter::Node, params: ChunkParams) -> CodeChunk {
-        let start_line = node.start_position().row;
-        let end_line = node.end_position().row;
+    fn create_chunk(&self, params: ChunkParams) -> CodeChunk {
+        let (start_line, end_line) = params.rows;
+        let symbol_key = params
+            .symbol
+            .as_ref()
+            .map_or_else(|| params.node_type.to_string(), CodeSymbol::to_string);
 
         CodeChunk {
-            id: format!(
-                "{}_{}_{}_{}_{}_{}",
+            id: CodeChunk::stable_id(
                 params.file_name,
-                params.node_type,
-                start_line,
-                end_line,
-                params.priority,
-                params.chunk_index
+                &symbol_key,
+                start_line as u32,
+                &params.content,
             ),
             content: params.content,
             file_path: params.file_name.to_string(),
@@ -219,6 +321,12 @@ impl<'a> AstTraverser<'a> {
                 meta.insert("node_type".to_string(), serde_json::json!(params.node_type));
                 meta.insert("depth".to_string(), serde_json::json!(params.depth));
                 meta.insert("priority".to_string(), serde_json::json!(params.priority));
+                if let Some(symbol) = params.symbol {
+                    meta.insert(
+                        CodeSymbol::METADATA_KEY.to_string(),
+                        serde_json::json!(symbol),
+                    );
+                }
                 serde_json::to_value(meta).unwrap_or(serde_json::json!({}))
             },
         }
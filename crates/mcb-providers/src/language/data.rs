@@ -0,0 +1,799 @@
+//! Configuration and data file chunking
+//!
+//! Splits TOML by table, YAML and JSON by key (YAML Kubernetes manifests
+//! by document) and SQL by statement. Chunks record the key path, or the
+//! name of the object a SQL statement acts on, so configuration is found by
+//! what it configures.
+
+use super::common::constants::{
+    KEY_PATH_SEPARATOR, LANG_JSON, LANG_SQL, LANG_TOML, LANG_YAML, SQL_OBJECT_MODIFIERS,
+    SQL_OBJECT_TYPES,
+};
+use super::common::sizing::{RowRange, pack_rows};
+use mcb_domain::entities::CodeChunk;
+use mcb_domain::value_objects::{ChunkSizePolicy, CodeSymbol, Language};
+
+/// Format of a configuration or data file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataFormat {
+    /// TOML, chunked by table
+    Toml,
+    /// YAML, chunked by document and key
+    Yaml,
+    /// JSON, chunked by key
+    Json,
+    /// SQL scripts, chunked by statement
+    Sql,
+}
+
+impl DataFormat {
+    /// Format of files in `language`, `None` for other languages
+    pub fn from_language(language: &str) -> Option<Self> {
+        match language {
+            LANG_TOML => Some(Self::Toml),
+            LANG_YAML => Some(Self::Yaml),
+            LANG_JSON => Some(Self::Json),
+            LANG_SQL => Some(Self::Sql),
+            _ => None,
+        }
+    }
+}
+
+/// Part of a file chunked on its own
+struct Entry {
+    rows: RowRange,
+    node_type: &'static str,
+    key_path: Vec<String>,
+    object_name: Option<String>,
+    symbol: Option<CodeSymbol>,
+}
+
+impl Entry {
+    /// Entry for the value of `key_path`, declared on `header`
+    fn key(rows: RowRange, node_type: &'static str, key_path: Vec<String>, header: &str) -> Self {
+        let symbol = key_path.last().map(|name| CodeSymbol {
+            name: name.clone(),
+            kind: node_type.to_string(),
+            signature: Some(header.trim().to_string()),
+            parent: (key_path.len() > 1)
+                .then(|| key_path[..key_path.len() - 1].join(KEY_PATH_SEPARATOR)),
+            doc: None,
+        });
+        Self {
+            rows,
+            node_type,
+            key_path,
+            object_name: None,
+            symbol,
+        }
+    }
+}
+
+/// Chunker of configuration and data files
+///
+/// Entries larger than the size policy are split between lines.
+pub struct DataFileChunker {
+    format: DataFormat,
+}
+
+impl DataFileChunker {
+    /// Create a chunker for files in `format`
+    pub fn new(format: DataFormat) -> Self {
+        Self { format }
+    }
+
+    /// Chunk a file into tables, keys, documents or statements sized by `policy`
+    pub fn chunk(
+        &self,
+        content: &str,
+        file_name: &str,
+        language: &Language,
+        policy: &ChunkSizePolicy,
+    ) -> Vec<CodeChunk> {
+        let lines: Vec<&str> = content.lines().collect();
+        if lines.is_empty() {
+            return Vec::new();
+        }
+
+        let entries = match self.format {
+            DataFormat::Toml => toml_entries(&lines),
+            DataFormat::Yaml => yaml_entries(&lines, policy),
+            DataFormat::Json => json_entries(content, &lines, policy),
+            DataFormat::Sql => sql_entries(content, &lines),
+        };
+        entries
+            .into_iter()
+            .flat_map(|entry| entry_chunks(entry, &lines, file_name, language, policy))
+            .collect()
+    }
+}
+
+/// Chunks of an entry, split between lines when over the maximum
+fn entry_chunks(
+    entry: Entry,
+    lines: &[&str],
+    file_name: &str,
+    language: &Language,
+    policy: &ChunkSizePolicy,
+) -> Vec<CodeChunk> {
+    let (mut start, mut end) = entry.rows;
+    while start < end && lines[start].trim().is_empty() {
+        start += 1;
+    }
+    while end > start && lines[end].trim().is_empty() {
+        end -= 1;
+    }
+    if lines[start..=end].iter().all(|line| line.trim().is_empty()) {
+        return Vec::new();
+    }
+
+    let parts = if policy.estimate_tokens(&lines[start..=end].join("\n")) > policy.max_tokens {
+        let segments: Vec<RowRange> = (start..=end).map(|row| (row, row)).collect();
+        pack_rows(&segments, lines, policy)
+    } else {
+        vec![(start, end)]
+    };
+    let part_count = parts.len();
+    let key = entry
+        .symbol
+        .as_ref()
+        .map_or_else(|| entry.node_type.to_string(), CodeSymbol::to_string);
+
+    parts
+        .into_iter()
+        .enumerate()
+        .map(|(part, (start, end))| {
+            let content = lines[start..=end].join("\n").trim_end().to_string();
+            let mut metadata = serde_json::json!({
+                "file": file_name,
+                "node_type": entry.node_type,
+            });
+            if let Some(map) = metadata.as_object_mut() {
+                if !entry.key_path.is_empty() {
+                    map.insert("key_path".to_string(), serde_json::json!(entry.key_path));
+                }
+                if let Some(name) = &entry.object_name {
+                    map.insert("object_name".to_string(), serde_json::json!(name));
+                }
+                if let Some(symbol) = &entry.symbol {
+                    map.insert(
+                        CodeSymbol::METADATA_KEY.to_string(),
+                        serde_json::json!(symbol),
+                    );
+                }
+                if part_count > 1 {
+                    map.insert("part".to_string(), serde_json::json!(part));
+                    map.insert("parts".to_string(), serde_json::json!(part_count));
+                }
+            }
+
+            CodeChunk {
+                id: CodeChunk::stable_id(file_name, &key, start as u32, &content),
+                content,
+                file_path: file_name.to_string(),
+                start_line: start as u32,
+                end_line: end as u32,
+                language: language.clone(),
+                metadata,
+            }
+        })
+        .collect()
+}
+
+fn indentation(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+/// First row of the comment lines directly above `row`, not before `floor`
+fn leading_comments(lines: &[&str], row: usize, floor: usize, marker: &str) -> usize {
+    let mut start = row;
+    while start > floor && lines[start - 1].trim_start().starts_with(marker) {
+        start -= 1;
+    }
+    start
+}
+
+fn unquote(key: &str) -> String {
+    let key = key.trim();
+    key.strip_prefix('"')
+        .and_then(|k| k.strip_suffix('"'))
+        .or_else(|| key.strip_prefix('\'').and_then(|k| k.strip_suffix('\'')))
+        .unwrap_or(key)
+        .to_string()
+}
+
+// ============================================================================
+// TOML
+// ============================================================================
+
+/// Root keys and one entry per table, each with the comments above it
+fn toml_entries(lines: &[&str]) -> Vec<Entry> {
+    let mut headers: Vec<(usize, Vec<String>)> = Vec::new();
+    let mut multiline: Option<&str> = None;
+    for (row, line) in lines.iter().enumerate() {
+        if let Some(delimiter) = multiline {
+            if line.matches(delimiter).count() % 2 == 1 {
+                multiline = None;
+            }
+            continue;
+        }
+        let trimmed = line.trim();
+        if let Some(path) = toml_table_header(trimmed) {
+            headers.push((row, path));
+            continue;
+        }
+        multiline = ["\"\"\"", "'''"]
+            .into_iter()
+            .find(|delimiter| trimmed.matches(delimiter).count() % 2 == 1);
+    }
+
+    let mut starts = Vec::with_capacity(headers.len());
+    for (i, (row, _)) in headers.iter().enumerate() {
+        let floor = if i == 0 { 0 } else { headers[i - 1].0 + 1 };
+        starts.push(leading_comments(lines, *row, floor, "#"));
+    }
+
+    let mut entries = Vec::new();
+    let first = starts.first().copied().unwrap_or(lines.len());
+    if first > 0 {
+        entries.push(Entry {
+            rows: (0, first - 1),
+            node_type: "table",
+            key_path: Vec::new(),
+            object_name: None,
+            symbol: None,
+        });
+    }
+    for (i, (row, path)) in headers.into_iter().enumerate() {
+        let end = starts.get(i + 1).map_or(lines.len(), |next| *next) - 1;
+        entries.push(Entry::key((starts[i], end), "table", path, lines[row]));
+    }
+    entries
+}
+
+/// Key path of a `[table]` or `[[array.of.tables]]` header
+fn toml_table_header(line: &str) -> Option<Vec<String>> {
+    let inner = line.strip_prefix('[')?;
+    let (inner, close) = match inner.strip_prefix('[') {
+        Some(inner) => (inner, "]]"),
+        None => (inner, "]"),
+    };
+    let name = &inner[..inner.find(close)?];
+    let path = split_dotted_key(name);
+    (!path.is_empty() && path.iter().all(|key| !key.is_empty())).then_some(path)
+}
+
+/// Split a dotted key outside of quotes
+fn split_dotted_key(key: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut quote = None;
+    for c in key.chars() {
+        match (c, quote) {
+            ('"' | '\'', None) => quote = Some(c),
+            (c, Some(q)) if c == q => quote = None,
+            ('.', None) => parts.push(std::mem::take(&mut current)),
+            _ => current.push(c),
+        }
+    }
+    parts.push(current);
+    parts
+        .into_iter()
+        .map(|part| part.trim().to_string())
+        .collect()
+}
+
+// ============================================================================
+// YAML
+// ============================================================================
+
+/// One entry per Kubernetes document, other documents split by key
+fn yaml_entries(lines: &[&str], policy: &ChunkSizePolicy) -> Vec<Entry> {
+    let mut entries = Vec::new();
+    for document in yaml_documents(lines) {
+        match kubernetes_object(lines, document) {
+            Some((kind, name)) => {
+                let key_path: Vec<String> = [Some(kind.clone()), name.clone()]
+                    .into_iter()
+                    .flatten()
+                    .collect();
+                entries.push(Entry {
+                    rows: document,
+                    node_type: "document",
+                    key_path,
+                    object_name: name.clone(),
+                    symbol: Some(CodeSymbol {
+                        name: name.unwrap_or_else(|| kind.clone()),
+                        kind: kind.clone(),
+                        signature: Some(format!("kind: {kind}")),
+                        parent: None,
+                        doc: None,
+                    }),
+                });
+            }
+            None => entries.extend(yaml_key_entries(lines, document, 0, &[], policy)),
+        }
+    }
+    entries
+}
+
+/// Row ranges of the documents of a YAML stream
+fn yaml_documents(lines: &[&str]) -> Vec<RowRange> {
+    let mut documents = Vec::new();
+    let mut start = 0;
+    for (row, line) in lines.iter().enumerate() {
+        let separator = (line.starts_with("---")
+            && line[3..].chars().next().is_none_or(char::is_whitespace))
+            || line.trim_end() == "...";
+        if separator {
+            if row > start {
+                documents.push((start, row - 1));
+            }
+            start = row + 1;
+        }
+    }
+    if start < lines.len() {
+        documents.push((start, lines.len() - 1));
+    }
+    documents
+}
+
+/// Kind and name of a document describing a Kubernetes object
+fn kubernetes_object(lines: &[&str], (start, end): RowRange) -> Option<(String, Option<String>)> {
+    let rows = &lines[start..=end];
+    let top_level = |key: &str| {
+        rows.iter()
+            .position(|line| line.starts_with(key))
+            .map(|i| (i, rows[i][key.len()..].trim().to_string()))
+    };
+    let (_, kind) = top_level("kind:")?;
+    top_level("apiVersion:")?;
+
+    let name = top_level("metadata:").and_then(|(metadata, _)| {
+        rows[metadata + 1..]
+            .iter()
+            .take_while(|line| line.trim().is_empty() || line.starts_with(char::is_whitespace))
+            .map(|line| line.trim())
+            .find_map(|line| line.strip_prefix("name:"))
+            .map(unquote)
+    });
+    Some((unquote(&kind), name))
+}
+
+/// Entries of the keys indented by `indent` within `rows`
+///
+/// Keys over the maximum are split into their own keys when they have any.
+fn yaml_key_entries(
+    lines: &[&str],
+    (start, end): RowRange,
+    indent: usize,
+    parent: &[String],
+    policy: &ChunkSizePolicy,
+) -> Vec<Entry> {
+    let keys: Vec<(usize, String)> = (start..=end)
+        .filter(|&row| indentation(lines[row]) == indent)
+        .filter_map(|row| yaml_key(lines[row].trim_start()).map(|key| (row, key)))
+        .collect();
+    if keys.is_empty() {
+        return vec![Entry::key(
+            (start, end),
+            "key",
+            parent.to_vec(),
+            lines[start],
+        )];
+    }
+
+    let mut starts = Vec::with_capacity(keys.len());
+    for (i, (row, _)) in keys.iter().enumerate() {
+        let floor = if i == 0 { start } else { keys[i - 1].0 + 1 };
+        starts.push(leading_comments(lines, *row, floor, "#"));
+    }
+
+    let mut entries = Vec::new();
+    if starts[0] > start {
+        entries.push(Entry::key(
+            (start, starts[0] - 1),
+            "key",
+            parent.to_vec(),
+            lines[start],
+        ));
+    }
+    for (i, (row, key)) in keys.into_iter().enumerate() {
+        let entry_end = starts.get(i + 1).map_or(end + 1, |next| *next) - 1;
+        let mut path = parent.to_vec();
+        path.push(key);
+
+        let oversized =
+            policy.estimate_tokens(&lines[starts[i]..=entry_end].join("\n")) > policy.max_tokens;
+        let child_indent = (row + 1..=entry_end)
+            .map(|child| lines[child])
+            .find(|line| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+            .map(indentation)
+            .filter(|child_indent| *child_indent > indent);
+        match child_indent {
+            Some(child_indent) if oversized && row < entry_end => {
+                entries.extend(yaml_key_entries(
+                    lines,
+                    (row + 1, entry_end),
+                    child_indent,
+                    &path,
+                    policy,
+                ));
+            }
+            _ => entries.push(Entry::key((starts[i], entry_end), "key", path, lines[row])),
+        }
+    }
+    entries
+}
+
+/// Key of a `key: value` or `key:` line
+fn yaml_key(line: &str) -> Option<String> {
+    if line.starts_with(['#', '-', '{', '[', '|', '>']) {
+        return None;
+    }
+    let mut quote = None;
+    for (i, c) in line.char_indices() {
+        match (c, quote) {
+            ('"' | '\'', None) if i == 0 => quote = Some(c),
+            (c, Some(q)) if c == q => quote = None,
+            (':', None) => {
+                let rest = &line[i + 1..];
+                if rest.is_empty() || rest.starts_with(char::is_whitespace) {
+                    let key = unquote(&line[..i]);
+                    return (!key.is_empty()).then_some(key);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+// ============================================================================
+// JSON
+// ============================================================================
+
+/// Member of a JSON object: its key path and rows
+struct JsonMember {
+    path: Vec<String>,
+    rows: RowRange,
+}
+
+/// One entry per key of the root object, oversized objects split by key
+///
+/// Files with several keys on a line (minified JSON) are one entry.
+fn json_entries(content: &str, lines: &[&str], policy: &ChunkSizePolicy) -> Vec<Entry> {
+    let members = json_members(content);
+    let mut selected = Vec::new();
+    select_json_members(&members, &[], lines, policy, &mut selected);
+
+    let shares_rows = selected
+        .windows(2)
+        .any(|pair| pair[1].rows.0 <= pair[0].rows.1);
+    if selected.is_empty() || shares_rows {
+        return vec![Entry {
+            rows: (0, lines.len() - 1),
+            node_type: "key",
+            key_path: Vec::new(),
+            object_name: None,
+            symbol: None,
+        }];
+    }
+    selected
+        .into_iter()
+        .map(|member| {
+            Entry::key(
+                member.rows,
+                "key",
+                member.path.clone(),
+                lines[member.rows.0],
+            )
+        })
+        .collect()
+}
+
+fn select_json_members<'m>(
+    members: &'m [JsonMember],
+    parent: &[String],
+    lines: &[&str],
+    policy: &ChunkSizePolicy,
+    selected: &mut Vec<&'m JsonMember>,
+) {
+    let children = members
+        .iter()
+        .filter(|member| member.path.len() == parent.len() + 1 && member.path.starts_with(parent));
+    for member in children {
+        let (start, end) = member.rows;
+        let oversized = policy.estimate_tokens(&lines[start..=end].join("\n")) > policy.max_tokens;
+        let has_children = members.iter().any(|child| {
+            child.path.len() > member.path.len() && child.path.starts_with(&member.path)
+        });
+        if oversized && has_children {
+            select_json_members(members, &member.path, lines, policy, selected);
+        } else {
+            selected.push(member);
+        }
+    }
+}
+
+/// Container being scanned: its key path and, for objects, the open member
+enum JsonContainer {
+    Object {
+        path: Vec<String>,
+        member: Option<(String, usize)>,
+    },
+    Array {
+        path: Vec<String>,
+    },
+}
+
+/// Members of all objects in a JSON document, in order of their end
+fn json_members(content: &str) -> Vec<JsonMember> {
+    let bytes = content.as_bytes();
+    let mut stack: Vec<JsonContainer> = Vec::new();
+    let mut members = Vec::new();
+    let mut row = 0;
+    let mut last_row = 0;
+
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\n' => row += 1,
+            b'"' => {
+                let start = i + 1;
+                i += 1;
+                while i < bytes.len() && bytes[i] != b'"' {
+                    if bytes[i] == b'\\' {
+                        i += 1;
+                    } else if bytes[i] == b'\n' {
+                        row += 1;
+                    }
+                    i += 1;
+                }
+                let text = content.get(start..i.min(bytes.len())).unwrap_or_default();
+                let next = bytes[(i + 1).min(bytes.len())..]
+                    .iter()
+                    .find(|b| !b.is_ascii_whitespace());
+                if next == Some(&b':')
+                    && let Some(JsonContainer::Object { path, member }) = stack.last_mut()
+                {
+                    if let Some((key, start_row)) = member.take() {
+                        members.push(JsonMember {
+                            path: [path.clone(), vec![key]].concat(),
+                            rows: (start_row, last_row),
+                        });
+                    }
+                    *member = Some((text.to_string(), row));
+                }
+                last_row = row;
+            }
+            b'{' | b'[' => {
+                let path = match stack.last() {
+                    Some(JsonContainer::Object {
+                        path,
+                        member: Some((key, _)),
+                    }) => [path.clone(), vec![key.clone()]].concat(),
+                    Some(JsonContainer::Object { path, .. } | JsonContainer::Array { path }) => {
+                        path.clone()
+                    }
+                    None => Vec::new(),
+                };
+                stack.push(if bytes[i] == b'{' {
+                    JsonContainer::Object { path, member: None }
+                } else {
+                    JsonContainer::Array { path }
+                });
+                last_row = row;
+            }
+            b'}' | b']' => {
+                if let Some(JsonContainer::Object {
+                    path,
+                    member: Some((key, start_row)),
+                }) = stack.pop()
+                {
+                    members.push(JsonMember {
+                        path: [path, vec![key]].concat(),
+                        rows: (start_row, last_row),
+                    });
+                }
+                last_row = row;
+            }
+            b if !b.is_ascii_whitespace() => last_row = row,
+            _ => {}
+        }
+        i += 1;
+    }
+
+    members.sort_by_key(|member| member.rows);
+    members
+}
+
+// ============================================================================
+// SQL
+// ============================================================================
+
+/// One entry per statement, with the comments above it
+fn sql_entries(content: &str, lines: &[&str]) -> Vec<Entry> {
+    sql_statements(content)
+        .into_iter()
+        .map(|(start, end)| {
+            let code_start = (start..=end)
+                .find(|&row| {
+                    let line = lines[row].trim();
+                    !line.is_empty() && !line.starts_with("--")
+                })
+                .unwrap_or(start);
+            let statement = lines[code_start..=end].join("\n");
+            let doc: Vec<&str> = lines[start..code_start]
+                .iter()
+                .filter_map(|line| line.trim().strip_prefix("--"))
+                .map(str::trim)
+                .collect();
+
+            let object = sql_object(&statement);
+            Entry {
+                rows: (start, end),
+                node_type: "statement",
+                key_path: Vec::new(),
+                object_name: object.as_ref().map(|(_, name)| name.clone()),
+                symbol: object.map(|(kind, name)| CodeSymbol {
+                    name,
+                    kind,
+                    signature: Some(lines[code_start].trim().to_string()),
+                    parent: None,
+                    doc: (!doc.is_empty()).then(|| doc.join("\n")),
+                }),
+            }
+        })
+        .collect()
+}
+
+/// Rows of each statement, from its leading comments to its terminator
+///
+/// Strings, quoted identifiers, comments and dollar-quoted bodies are
+/// skipped; `DELIMITER` lines change the terminator as in MySQL scripts.
+fn sql_statements(content: &str) -> Vec<RowRange> {
+    let bytes = content.as_bytes();
+    let mut statements = Vec::new();
+    let mut delimiter = ";".to_string();
+    let mut start: Option<usize> = None;
+    let mut has_code = false;
+    let mut row = 0;
+    let mut last_row = 0;
+
+    let mut i = 0;
+    while i < bytes.len() {
+        let rest = &content[i..];
+        let at_line_start = i == 0 || bytes[i - 1] == b'\n';
+
+        if at_line_start
+            && !has_code
+            && rest.len() >= 10
+            && rest.as_bytes()[..10].eq_ignore_ascii_case(b"delimiter ")
+        {
+            let line_end = rest.find('\n').unwrap_or(rest.len());
+            delimiter = rest[10..line_end].trim().to_string();
+            if delimiter.is_empty() {
+                delimiter = ";".to_string();
+            }
+            start = None;
+            i += line_end;
+            continue;
+        }
+
+        let b = bytes[i];
+        if b == b'\n' {
+            row += 1;
+            i += 1;
+            continue;
+        }
+        if b.is_ascii_whitespace() {
+            i += 1;
+            continue;
+        }
+        start.get_or_insert(row);
+
+        let skipped = if rest.starts_with("--") {
+            Some(rest.find('\n').unwrap_or(rest.len()))
+        } else if rest.starts_with("/*") {
+            Some(rest.find("*/").map_or(rest.len(), |end| end + 2))
+        } else {
+            has_code = true;
+            match b {
+                b'\'' | b'"' | b'`' => {
+                    Some(rest[1..].find(b as char).map_or(rest.len(), |end| end + 2))
+                }
+                b'$' => dollar_quoted(rest),
+                _ => None,
+            }
+        };
+        if let Some(length) = skipped {
+            row += rest[..length].matches('\n').count();
+            last_row = row;
+            i += length.max(1);
+            continue;
+        }
+
+        last_row = row;
+        if rest.starts_with(delimiter.as_str()) {
+            if let Some(first) = start.take() {
+                statements.push((first, row));
+            }
+            has_code = false;
+            i += delimiter.len();
+            continue;
+        }
+        i += rest.chars().next().map_or(1, char::len_utf8);
+    }
+
+    if has_code && let Some(first) = start {
+        statements.push((first, last_row));
+    }
+    statements
+}
+
+/// Length of a `$tag$ ... $tag$` body starting `text`
+fn dollar_quoted(text: &str) -> Option<usize> {
+    let tag_end = text[1..].find('$')? + 2;
+    let tag = &text[..tag_end];
+    if !tag[1..tag.len() - 1]
+        .chars()
+        .all(|c| c.is_alphanumeric() || c == '_')
+    {
+        return None;
+    }
+    Some(
+        text[tag_end..]
+            .find(tag)
+            .map_or(text.len(), |end| tag_end + end + tag.len()),
+    )
+}
+
+/// Kind and name of the object a statement creates, alters, drops or writes
+fn sql_object(statement: &str) -> Option<(String, String)> {
+    let words: Vec<&str> = statement.split_whitespace().take(16).collect();
+    let upper: Vec<String> = words.iter().map(|word| word.to_uppercase()).collect();
+    let verb = upper.first()?.as_str();
+
+    let (kind, name_at) = match verb {
+        "CREATE" | "ALTER" | "DROP" => {
+            let mut i = 1;
+            while upper
+                .get(i)
+                .is_some_and(|word| SQL_OBJECT_MODIFIERS.contains(&word.as_str()))
+            {
+                i += 1;
+            }
+            let object = upper.get(i)?;
+            if !SQL_OBJECT_TYPES.contains(&object.as_str()) {
+                return None;
+            }
+            let kind = if verb == "CREATE" {
+                object.to_lowercase()
+            } else {
+                format!("{} {}", verb.to_lowercase(), object.to_lowercase())
+            };
+            i += 1;
+            while upper.get(i).is_some_and(|word| {
+                ["IF", "NOT", "EXISTS", "CONCURRENTLY"].contains(&word.as_str())
+            }) {
+                i += 1;
+            }
+            (kind, i)
+        }
+        "INSERT" if upper.get(1).is_some_and(|word| word == "INTO") => ("insert".to_string(), 2),
+        "UPDATE" => ("update".to_string(), 1),
+        "DELETE" if upper.get(1).is_some_and(|word| word == "FROM") => ("delete".to_string(), 2),
+        _ => return None,
+    };
+
+    let name: String = words
+        .get(name_at)?
+        .split('(')
+        .next()
+        .unwrap_or_default()
+        .chars()
+        .filter(|c| !matches!(c, '"' | '`' | '[' | ']' | ';'))
+        .collect();
+    (!name.is_empty()).then_some((kind, name))
+}
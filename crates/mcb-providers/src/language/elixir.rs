@@ -0,0 +1,91 @@
+//! Elixir language processor for AST-based code chunking.
+//!
+//! Elixir has no declaration nodes: `defmodule` and `def` are calls, so
+//! only calls to [`DEFINITION_CALLS_ELIXIR`] are extracted.
+
+use crate::language::common::{
+    BaseProcessor, CHUNK_SIZE_ELIXIR, DEFINITION_CALLS_ELIXIR, DocCommentStyle, LanguageConfig,
+    LanguageProcessor, NodeExtractionRule, TS_NODE_CALL,
+};
+use mcb_domain::entities::CodeChunk;
+use mcb_domain::value_objects::{ChunkSizePolicy, Language};
+
+/// Elixir language processor.
+pub struct ElixirProcessor {
+    processor: BaseProcessor,
+}
+
+impl Default for ElixirProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ElixirProcessor {
+    /// Create a new Elixir language processor
+    pub fn new() -> Self {
+        let config = LanguageConfig::new(tree_sitter_elixir::LANGUAGE.into())
+            .with_rules(Self::extraction_rules())
+            .with_fallback_patterns(Self::fallback_patterns())
+            .with_definition_calls(DEFINITION_CALLS_ELIXIR)
+            .with_doc_comments(DocCommentStyle::Preceding(&["#"]))
+            .with_chunk_size(CHUNK_SIZE_ELIXIR);
+
+        Self {
+            processor: BaseProcessor::new(config),
+        }
+    }
+
+    fn extraction_rules() -> Vec<NodeExtractionRule> {
+        vec![NodeExtractionRule {
+            node_types: vec![TS_NODE_CALL.to_string()],
+            min_length: 40,
+            min_lines: 2,
+            max_depth: 4,
+            priority: 10,
+            include_context: true,
+        }]
+    }
+
+    fn fallback_patterns() -> Vec<String> {
+        [
+            "def ",
+            "defp ",
+            "defmacro ",
+            "defmodule ",
+            "defprotocol ",
+            "defimpl ",
+        ]
+        .iter()
+        .map(|p| format!("^{}", p))
+        .collect()
+    }
+}
+
+impl LanguageProcessor for ElixirProcessor {
+    fn config(&self) -> &LanguageConfig {
+        self.processor.config()
+    }
+
+    fn extract_chunks_with_tree_sitter(
+        &self,
+        tree: &tree_sitter::Tree,
+        content: &str,
+        file_name: &str,
+        language: &Language,
+        policy: &ChunkSizePolicy,
+    ) -> Vec<CodeChunk> {
+        self.processor
+            .extract_chunks_with_tree_sitter(tree, content, file_name, language, policy)
+    }
+
+    fn extract_chunks_fallback(
+        &self,
+        content: &str,
+        file_name: &str,
+        language: &Language,
+    ) -> Vec<CodeChunk> {
+        self.processor
+            .extract_chunks_fallback(content, file_name, language)
+    }
+}
@@ -3,18 +3,25 @@
 //! Provides the main IntelligentChunker that orchestrates language-specific
 //! chunking using tree-sitter and fallback methods.
 
-use super::common::constants::CHUNK_SIZE_GENERIC;
-use super::helpers::{is_language_supported, language_from_extension};
+use super::common::constants::{
+    CHUNK_SIZE_GENERIC, LANG_ASCIIDOC, LANG_JSON, LANG_MARKDOWN, LANG_RESTRUCTUREDTEXT, LANG_SQL,
+    LANG_TEXT, LANG_TOML, LANG_YAML,
+};
+use super::helpers::{
+    detect_language, is_language_supported, supported_extensions, supported_file_names,
+    supported_interpreters,
+};
 use super::{
-    CProcessor, CSharpProcessor, CppProcessor, GoProcessor, JavaProcessor, JavaScriptProcessor,
-    KotlinProcessor, LanguageProcessor, PhpProcessor, PythonProcessor, RubyProcessor,
-    RustProcessor, SwiftProcessor,
+    BashProcessor, CProcessor, CSharpProcessor, CppProcessor, DataFileChunker, DataFormat,
+    ElixirProcessor, GoProcessor, HaskellProcessor, JavaProcessor, JavaScriptProcessor,
+    KotlinProcessor, LanguageProcessor, LuaProcessor, MarkupChunker, MarkupFormat, PhpProcessor,
+    PythonProcessor, RubyProcessor, RustProcessor, ScalaProcessor, SwiftProcessor, ZigProcessor,
 };
 use async_trait::async_trait;
 use mcb_application::domain_services::chunking::{ChunkingOptions, ChunkingResult, CodeChunker};
 use mcb_domain::entities::CodeChunk;
 use mcb_domain::error::{Error, Result};
-use mcb_domain::value_objects::Language;
+use mcb_domain::value_objects::{ChunkSizePolicy, Language};
 use std::collections::HashMap;
 use std::path::Path;
 use std::sync::LazyLock;
@@ -77,18 +84,55 @@ pub(crate) static LANGUAGE_PROCESSORS: LazyLock<
         "kotlin".to_string(),
         Box::new(KotlinProcessor::new()) as Box<dyn LanguageProcessor + Send + Sync>,
     );
+    processors.insert(
+        "scala".to_string(),
+        Box::new(ScalaProcessor::new()) as Box<dyn LanguageProcessor + Send + Sync>,
+    );
+    processors.insert(
+        "elixir".to_string(),
+        Box::new(ElixirProcessor::new()) as Box<dyn LanguageProcessor + Send + Sync>,
+    );
+    processors.insert(
+        "lua".to_string(),
+        Box::new(LuaProcessor::new()) as Box<dyn LanguageProcessor + Send + Sync>,
+    );
+    processors.insert(
+        "shell".to_string(),
+        Box::new(BashProcessor::new()) as Box<dyn LanguageProcessor + Send + Sync>,
+    );
+    processors.insert(
+        "haskell".to_string(),
+        Box::new(HaskellProcessor::new()) as Box<dyn LanguageProcessor + Send + Sync>,
+    );
+    processors.insert(
+        "zig".to_string(),
+        Box::new(ZigProcessor::new()) as Box<dyn LanguageProcessor + Send + Sync>,
+    );
 
     processors
 });
 
 /// Intelligent chunking engine using tree-sitter
 #[derive(Default)]
-pub struct IntelligentChunker;
+pub struct IntelligentChunker {
+    size_policy: ChunkSizePolicy,
+}
 
 impl IntelligentChunker {
     /// Create a new intelligent chunker
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// Size chunks by `size_policy` instead of the default policy
+    pub fn with_size_policy(mut self, size_policy: ChunkSizePolicy) -> Self {
+        self.size_policy = size_policy;
+        self
+    }
+
+    /// Get the chunk size policy of `chunk_code`
+    pub fn size_policy(&self) -> ChunkSizePolicy {
+        self.size_policy
     }
 
     /// Chunk code based on language-specific structural analysis
@@ -98,12 +142,32 @@ impl IntelligentChunker {
         file_name: &str,
         language: &Language,
     ) -> Vec<CodeChunk> {
+        self.chunk_code_with_policy(content, file_name, language, &self.size_policy)
+    }
+
+    /// Chunk code into chunks sized by `policy`
+    pub fn chunk_code_with_policy(
+        &self,
+        content: &str,
+        file_name: &str,
+        language: &Language,
+        policy: &ChunkSizePolicy,
+    ) -> Vec<CodeChunk> {
+        // Documentation and data files are chunked by their own structure
+        if let Some(format) = MarkupFormat::from_language(language) {
+            return MarkupChunker::new(format).chunk(content, file_name, language, policy);
+        }
+        if let Some(format) = DataFormat::from_language(language) {
+            return DataFileChunker::new(format).chunk(content, file_name, language, policy);
+        }
+
         if let Some(processor) = LANGUAGE_PROCESSORS.get(language) {
             // Try tree-sitter parsing first
             match self.parse_with_tree_sitter(content, processor.get_language()) {
                 Ok(tree) => {
-                    let chunks = processor
-                        .extract_chunks_with_tree_sitter(&tree, content, file_name, language);
+                    let chunks = processor.extract_chunks_with_tree_sitter(
+                        &tree, content, file_name, language, policy,
+                    );
                     if !chunks.is_empty() {
                         return chunks;
                     }
@@ -129,12 +193,10 @@ impl IntelligentChunker {
         file_name: String,
         language: Language,
     ) -> Vec<CodeChunk> {
-        tokio::task::spawn_blocking(move || {
-            let chunker = Self::new();
-            chunker.chunk_code(&content, &file_name, &language)
-        })
-        .await
-        .unwrap_or_default()
+        let chunker = Self::new().with_size_policy(self.size_policy);
+        tokio::task::spawn_blocking(move || chunker.chunk_code(&content, &file_name, &language))
+            .await
+            .unwrap_or_default()
     }
 
     /// Generic chunking for unsupported languages
@@ -156,7 +218,7 @@ impl IntelligentChunker {
             }
 
             chunks.push(CodeChunk {
-                id: format!("{}_{}", file_name, chunk_idx),
+                id: CodeChunk::stable_id(file_name, "generic", start_line as u32, &content),
                 content,
                 file_path: file.clone(),
                 start_line: start_line as u32,
@@ -197,17 +259,16 @@ impl CodeChunker for IntelligentChunker {
     async fn chunk_file(
         &self,
         file_path: &Path,
-        _options: ChunkingOptions,
+        options: ChunkingOptions,
     ) -> Result<ChunkingResult> {
         let content = tokio::fs::read_to_string(file_path)
             .await
             .map_err(|e| Error::io(e.to_string()))?;
 
         let file_name = file_path.to_string_lossy().to_string();
-        let ext = file_path.extension().and_then(|e| e.to_str()).unwrap_or("");
-        let language = language_from_extension(ext);
+        let language = detect_language(&file_name, &content);
 
-        self.chunk_content(&content, &file_name, language, _options)
+        self.chunk_content(&content, &file_name, language, options)
             .await
     }
 
@@ -216,9 +277,10 @@ impl CodeChunker for IntelligentChunker {
         content: &str,
         file_name: &str,
         language: Language,
-        _options: ChunkingOptions,
+        options: ChunkingOptions,
     ) -> Result<ChunkingResult> {
-        let chunks = self.chunk_code(content, file_name, &language);
+        let chunks =
+            self.chunk_code_with_policy(content, file_name, &language, &options.size_policy);
         let used_ast = is_language_supported(&language);
 
         Ok(ChunkingResult {
@@ -242,7 +304,23 @@ impl CodeChunker for IntelligentChunker {
     }
 
     fn supported_languages(&self) -> Vec<Language> {
-        LANGUAGE_PROCESSORS.keys().cloned().collect()
+        LANGUAGE_PROCESSORS
+            .keys()
+            .cloned()
+            .chain(
+                [
+                    LANG_MARKDOWN,
+                    LANG_RESTRUCTUREDTEXT,
+                    LANG_ASCIIDOC,
+                    LANG_TEXT,
+                    LANG_TOML,
+                    LANG_YAML,
+                    LANG_JSON,
+                    LANG_SQL,
+                ]
+                .map(str::to_string),
+            )
+            .collect()
     }
 }
 
@@ -262,6 +340,13 @@ impl UniversalLanguageChunkingProvider {
             chunker: IntelligentChunker::new(),
         }
     }
+
+    /// Size chunks by `size_policy` instead of the default policy
+    pub fn with_size_policy(size_policy: ChunkSizePolicy) -> Self {
+        Self {
+            chunker: IntelligentChunker::new().with_size_policy(size_policy),
+        }
+    }
 }
 
 impl Default for UniversalLanguageChunkingProvider {
@@ -276,18 +361,37 @@ impl mcb_domain::ports::providers::LanguageChunkingProvider for UniversalLanguag
     }
 
     fn extensions(&self) -> &[&'static str] {
-        &[
-            "rs", "py", "js", "ts", "java", "go", "c", "cpp", "cs", "rb", "php", "swift", "kt",
-        ]
+        supported_extensions()
+    }
+
+    fn file_names(&self) -> &[&'static str] {
+        supported_file_names()
+    }
+
+    fn interpreters(&self) -> &[&'static str] {
+        supported_interpreters()
     }
 
     fn chunk(&self, content: &str, file_path: &str) -> Vec<mcb_domain::entities::CodeChunk> {
-        let path = std::path::Path::new(file_path);
-        let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
-        let language = super::helpers::language_from_extension(ext);
+        let language = detect_language(file_path, content);
         self.chunker.chunk_code(content, file_path, &language)
     }
 
+    fn chunk_with_policy(
+        &self,
+        content: &str,
+        file_path: &str,
+        policy: &ChunkSizePolicy,
+    ) -> Vec<mcb_domain::entities::CodeChunk> {
+        let language = detect_language(file_path, content);
+        self.chunker
+            .chunk_code_with_policy(content, file_path, &language, policy)
+    }
+
+    fn chunk_size_policy(&self) -> ChunkSizePolicy {
+        self.chunker.size_policy()
+    }
+
     fn provider_name(&self) -> &str {
         "universal"
     }
@@ -306,9 +410,11 @@ use mcb_domain::ports::providers::LanguageChunkingProvider as LanguageProviderPo
 
 /// Factory function for creating universal language chunking provider instances.
 fn universal_language_factory(
-    _config: &LanguageProviderConfig,
+    config: &LanguageProviderConfig,
 ) -> std::result::Result<Arc<dyn LanguageProviderPort>, String> {
-    Ok(Arc::new(UniversalLanguageChunkingProvider::new()))
+    Ok(Arc::new(
+        UniversalLanguageChunkingProvider::with_size_policy(config.chunk_size_policy()),
+    ))
 }
 
 #[linkme::distributed_slice(LANGUAGE_PROVIDERS)]
@@ -1,11 +1,11 @@
 //! Go language processor for AST-based code chunking.
 
 use crate::language::common::{
-    BaseProcessor, CHUNK_SIZE_GO, LanguageConfig, LanguageProcessor, NodeExtractionRule,
-    TS_NODE_FUNCTION_DECLARATION, TS_NODE_METHOD_DECLARATION,
+    BaseProcessor, CHUNK_SIZE_GO, DocCommentStyle, LanguageConfig, LanguageProcessor,
+    NodeExtractionRule, TS_NODE_FUNCTION_DECLARATION, TS_NODE_METHOD_DECLARATION,
 };
 use mcb_domain::entities::CodeChunk;
-use mcb_domain::value_objects::Language;
+use mcb_domain::value_objects::{ChunkSizePolicy, Language};
 
 /// Go language processor.
 pub struct GoProcessor {
@@ -40,6 +40,7 @@ impl GoProcessor {
                 r"^interface ".to_string(),
                 r"^struct ".to_string(),
             ])
+            .with_doc_comments(DocCommentStyle::Preceding(&["//"]))
             .with_chunk_size(CHUNK_SIZE_GO);
 
         Self {
@@ -59,9 +60,10 @@ impl LanguageProcessor for GoProcessor {
         content: &str,
         file_name: &str,
         language: &Language,
+        policy: &ChunkSizePolicy,
     ) -> Vec<CodeChunk> {
         self.processor
-            .extract_chunks_with_tree_sitter(tree, content, file_name, language)
+            .extract_chunks_with_tree_sitter(tree, content, file_name, language, policy)
     }
 
     fn extract_chunks_fallback(
@@ -0,0 +1,97 @@
+//! Haskell language processor for AST-based code chunking.
+
+use crate::language::common::{
+    BaseProcessor, CHUNK_SIZE_HASKELL, DocCommentStyle, LanguageConfig, LanguageProcessor,
+    NodeExtractionRule, SKIPPED_FIELDS_HASKELL,
+};
+use mcb_domain::entities::CodeChunk;
+use mcb_domain::value_objects::{ChunkSizePolicy, Language};
+
+/// Haskell language processor.
+pub struct HaskellProcessor {
+    processor: BaseProcessor,
+}
+
+impl Default for HaskellProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HaskellProcessor {
+    /// Create a new Haskell language processor
+    pub fn new() -> Self {
+        let config = LanguageConfig::new(tree_sitter_haskell::LANGUAGE.into())
+            .with_rules(Self::extraction_rules())
+            .with_fallback_patterns(Self::fallback_patterns())
+            .with_doc_comments(DocCommentStyle::Preceding(&["-- |", "{- |", "{-|"]))
+            .with_skipped_fields(SKIPPED_FIELDS_HASKELL)
+            .with_chunk_size(CHUNK_SIZE_HASKELL);
+
+        Self {
+            processor: BaseProcessor::new(config),
+        }
+    }
+
+    fn extraction_rules() -> Vec<NodeExtractionRule> {
+        vec![
+            NodeExtractionRule {
+                node_types: vec![
+                    "function".to_string(),
+                    "data_type".to_string(),
+                    "newtype".to_string(),
+                    "class".to_string(),
+                    "instance".to_string(),
+                ],
+                min_length: 40,
+                min_lines: 2,
+                max_depth: 4,
+                priority: 10,
+                include_context: true,
+            },
+            NodeExtractionRule {
+                node_types: vec!["type_synomym".to_string(), "type_family".to_string()],
+                min_length: 25,
+                min_lines: 1,
+                max_depth: 3,
+                priority: 5,
+                include_context: false,
+            },
+        ]
+    }
+
+    fn fallback_patterns() -> Vec<String> {
+        ["data ", "newtype ", "class ", "instance ", "type "]
+            .iter()
+            .map(|p| format!("^{}", p))
+            .collect()
+    }
+}
+
+impl LanguageProcessor for HaskellProcessor {
+    fn config(&self) -> &LanguageConfig {
+        self.processor.config()
+    }
+
+    fn extract_chunks_with_tree_sitter(
+        &self,
+        tree: &tree_sitter::Tree,
+        content: &str,
+        file_name: &str,
+        language: &Language,
+        policy: &ChunkSizePolicy,
+    ) -> Vec<CodeChunk> {
+        self.processor
+            .extract_chunks_with_tree_sitter(tree, content, file_name, language, policy)
+    }
+
+    fn extract_chunks_fallback(
+        &self,
+        content: &str,
+        file_name: &str,
+        language: &Language,
+    ) -> Vec<CodeChunk> {
+        self.processor
+            .extract_chunks_fallback(content, file_name, language)
+    }
+}
@@ -4,6 +4,9 @@
 //! and other utility functions for working with language identifiers.
 
 use super::common::constants::*;
+use mcb_domain::ports::providers::shebang_interpreter;
+use std::path::Path;
+use std::sync::LazyLock;
 
 /// Extension to language mapping table
 const EXTENSION_LANG_MAP: &[(&[&str], &str)] = &[
@@ -20,8 +23,60 @@ const EXTENSION_LANG_MAP: &[(&[&str], &str)] = &[
     (&["php", "phtml"], LANG_PHP),
     (&["swift"], LANG_SWIFT),
     (&["kt", "kts"], LANG_KOTLIN),
+    (&["scala", "sc"], LANG_SCALA),
+    (&["ex", "exs"], LANG_ELIXIR),
+    (&["lua"], LANG_LUA),
+    (&["hs"], LANG_HASKELL),
+    (&["zig"], LANG_ZIG),
+    (&["sh", "bash", "zsh"], LANG_SHELL),
+    (&["md", "markdown", "mdx"], LANG_MARKDOWN),
+    (&["rst", "rest"], LANG_RESTRUCTUREDTEXT),
+    (&["adoc", "asciidoc"], LANG_ASCIIDOC),
+    (&["txt", "text"], LANG_TEXT),
+    (&["toml"], LANG_TOML),
+    (&["yaml", "yml"], LANG_YAML),
+    (&["json"], LANG_JSON),
+    (&["sql"], LANG_SQL),
 ];
 
+/// Exact file name to language mapping table
+const FILE_NAME_LANG_MAP: &[(&[&str], &str)] = &[
+    (&["Dockerfile", "Containerfile"], LANG_DOCKERFILE),
+    (&["Makefile", "GNUmakefile", "makefile"], LANG_MAKEFILE),
+    (
+        &["Rakefile", "Gemfile", "Guardfile", "Podfile", "Vagrantfile"],
+        LANG_RUBY,
+    ),
+];
+
+/// `#!` interpreter to language mapping table
+const INTERPRETER_LANG_MAP: &[(&[&str], &str)] = &[
+    (&["python", "pypy"], LANG_PYTHON),
+    (&["node", "nodejs"], LANG_JAVASCRIPT),
+    (&["ts-node"], LANG_TYPESCRIPT),
+    (&["ruby"], LANG_RUBY),
+    (&["elixir"], LANG_ELIXIR),
+    (&["lua", "luajit"], LANG_LUA),
+    (&["runghc", "runhaskell"], LANG_HASKELL),
+    (&["php"], LANG_PHP),
+    (&["sh", "bash", "zsh", "dash", "ksh"], LANG_SHELL),
+];
+
+/// Flatten the keys of a mapping table
+fn table_keys(table: &[(&[&'static str], &str)]) -> Vec<&'static str> {
+    table
+        .iter()
+        .flat_map(|(keys, _)| keys.iter().copied())
+        .collect()
+}
+
+static SUPPORTED_EXTENSIONS: LazyLock<Vec<&'static str>> =
+    LazyLock::new(|| table_keys(EXTENSION_LANG_MAP));
+static SUPPORTED_FILE_NAMES: LazyLock<Vec<&'static str>> =
+    LazyLock::new(|| table_keys(FILE_NAME_LANG_MAP));
+static SUPPORTED_INTERPRETERS: LazyLock<Vec<&'static str>> =
+    LazyLock::new(|| table_keys(INTERPRETER_LANG_MAP));
+
 /// Detect language from file extension
 ///
 /// Returns a string identifier for the programming language based on the file extension.
@@ -35,6 +90,60 @@ pub fn language_from_extension(ext: &str) -> String {
         .unwrap_or_else(|| LANG_UNKNOWN.to_string())
 }
 
+/// Detect language from an exact file name (e.g. `Dockerfile`)
+pub fn language_from_file_name(file_name: &str) -> Option<String> {
+    FILE_NAME_LANG_MAP
+        .iter()
+        .find(|(names, _)| names.contains(&file_name))
+        .map(|(_, lang)| (*lang).to_string())
+}
+
+/// Detect language from a `#!` interpreter name (e.g. `python`)
+pub fn language_from_interpreter(interpreter: &str) -> Option<String> {
+    INTERPRETER_LANG_MAP
+        .iter()
+        .find(|(names, _)| names.contains(&interpreter))
+        .map(|(_, lang)| (*lang).to_string())
+}
+
+/// Detect the language of a file from its extension, name or `#!` line
+///
+/// Returns "unknown" when none of them is recognized.
+pub fn detect_language(file_path: &str, content: &str) -> String {
+    let path = Path::new(file_path);
+    let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+    let language = language_from_extension(ext);
+    if language != LANG_UNKNOWN {
+        return language;
+    }
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .and_then(language_from_file_name)
+        .or_else(|| {
+            content
+                .lines()
+                .next()
+                .and_then(shebang_interpreter)
+                .and_then(language_from_interpreter)
+        })
+        .unwrap_or_else(|| LANG_UNKNOWN.to_string())
+}
+
+/// All file extensions with a known language
+pub fn supported_extensions() -> &'static [&'static str] {
+    &SUPPORTED_EXTENSIONS
+}
+
+/// All exact file names with a known language
+pub fn supported_file_names() -> &'static [&'static str] {
+    &SUPPORTED_FILE_NAMES
+}
+
+/// All `#!` interpreters with a known language
+pub fn supported_interpreters() -> &'static [&'static str] {
+    &SUPPORTED_INTERPRETERS
+}
+
 /// Check if a language is supported for AST-based chunking
 pub fn is_language_supported(language: &str) -> bool {
     matches!(
@@ -52,6 +161,12 @@ pub fn is_language_supported(language: &str) -> bool {
             | LANG_PHP
             | LANG_SWIFT
             | LANG_KOTLIN
+            | LANG_SCALA
+            | LANG_ELIXIR
+            | LANG_LUA
+            | LANG_SHELL
+            | LANG_HASKELL
+            | LANG_ZIG
     )
 }
 
@@ -69,6 +184,12 @@ const LANG_CHUNK_SIZE_MAP: &[(&[&str], usize)] = &[
     (&[LANG_PHP], CHUNK_SIZE_PHP),
     (&[LANG_SWIFT], CHUNK_SIZE_SWIFT),
     (&[LANG_KOTLIN], CHUNK_SIZE_KOTLIN),
+    (&[LANG_SCALA], CHUNK_SIZE_SCALA),
+    (&[LANG_ELIXIR], CHUNK_SIZE_ELIXIR),
+    (&[LANG_LUA], CHUNK_SIZE_LUA),
+    (&[LANG_SHELL], CHUNK_SIZE_SHELL),
+    (&[LANG_HASKELL], CHUNK_SIZE_HASKELL),
+    (&[LANG_ZIG], CHUNK_SIZE_ZIG),
 ];
 
 /// Get the chunk size for a specific language
@@ -96,5 +217,19 @@ pub fn supported_languages() -> Vec<String> {
         LANG_PHP.to_string(),
         LANG_SWIFT.to_string(),
         LANG_KOTLIN.to_string(),
+        LANG_SCALA.to_string(),
+        LANG_ELIXIR.to_string(),
+        LANG_LUA.to_string(),
+        LANG_SHELL.to_string(),
+        LANG_HASKELL.to_string(),
+        LANG_ZIG.to_string(),
+        LANG_MARKDOWN.to_string(),
+        LANG_RESTRUCTUREDTEXT.to_string(),
+        LANG_ASCIIDOC.to_string(),
+        LANG_TEXT.to_string(),
+        LANG_TOML.to_string(),
+        LANG_YAML.to_string(),
+        LANG_JSON.to_string(),
+        LANG_SQL.to_string(),
     ]
 }
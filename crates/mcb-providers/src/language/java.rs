@@ -5,7 +5,7 @@ use crate::language::common::{
     LanguageProcessor, NodeExtractionRule, TS_NODE_CLASS_DECLARATION, TS_NODE_METHOD_DECLARATION,
 };
 use mcb_domain::entities::CodeChunk;
-use mcb_domain::value_objects::Language;
+use mcb_domain::value_objects::{ChunkSizePolicy, Language};
 
 /// Java language processor.
 pub struct JavaProcessor {
@@ -60,9 +60,10 @@ impl LanguageProcessor for JavaProcessor {
         content: &str,
         file_name: &str,
         language: &Language,
+        policy: &ChunkSizePolicy,
     ) -> Vec<CodeChunk> {
         self.processor
-            .extract_chunks_with_tree_sitter(tree, content, file_name, language)
+            .extract_chunks_with_tree_sitter(tree, content, file_name, language, policy)
     }
 
     fn extract_chunks_fallback(
@@ -0,0 +1,667 @@
+//! Documentation chunking
+//!
+//! Splits Markdown, reStructuredText, AsciiDoc and plain text documents
+//! into one chunk per section, recording the path of headings leading to
+//! it, and one chunk per code block tagged with the block's language.
+
+use super::common::constants::{
+    HEADING_PATH_SEPARATOR, LANG_ASCIIDOC, LANG_MARKDOWN, LANG_RESTRUCTUREDTEXT, LANG_TEXT,
+    LANG_UNKNOWN, RST_ADORNMENT_CHARS, RST_CODE_DIRECTIVES,
+};
+use super::common::sizing::{RowRange, pack_rows};
+use super::helpers::language_from_extension;
+use mcb_domain::entities::CodeChunk;
+use mcb_domain::value_objects::{ChunkSizePolicy, CodeSymbol, Language};
+
+/// Markup format of a documentation file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarkupFormat {
+    /// Markdown, with ATX and setext headings and fenced code blocks
+    Markdown,
+    /// reStructuredText, with adorned titles and code directives
+    ReStructuredText,
+    /// AsciiDoc, with `=` titles and source blocks
+    AsciiDoc,
+    /// Plain text without structure
+    PlainText,
+}
+
+impl MarkupFormat {
+    /// Format of documents in `language`, `None` for programming languages
+    pub fn from_language(language: &str) -> Option<Self> {
+        match language {
+            LANG_MARKDOWN => Some(Self::Markdown),
+            LANG_RESTRUCTUREDTEXT => Some(Self::ReStructuredText),
+            LANG_ASCIIDOC => Some(Self::AsciiDoc),
+            LANG_TEXT => Some(Self::PlainText),
+            _ => None,
+        }
+    }
+}
+
+/// Structural element of a document
+#[derive(Debug)]
+enum Block {
+    /// Section title spanning `rows`, adornment lines included
+    Heading {
+        level: usize,
+        title: String,
+        rows: RowRange,
+    },
+    /// Body of a code block, without its delimiters
+    Code {
+        language: Option<String>,
+        rows: Option<RowRange>,
+    },
+    /// A line of prose
+    Text(usize),
+}
+
+/// Section being collected: its heading path, title rows and prose rows
+struct Section {
+    path: Vec<String>,
+    heading: Option<RowRange>,
+    rows: Vec<usize>,
+}
+
+/// Chunker of documentation files
+///
+/// Sections larger than the size policy are split between paragraphs, and
+/// code blocks between lines; the parts record their position like split
+/// code declarations.
+pub struct MarkupChunker {
+    format: MarkupFormat,
+}
+
+impl MarkupChunker {
+    /// Create a chunker for documents in `format`
+    pub fn new(format: MarkupFormat) -> Self {
+        Self { format }
+    }
+
+    /// Chunk a document into sections and code blocks sized by `policy`
+    pub fn chunk(
+        &self,
+        content: &str,
+        file_name: &str,
+        language: &Language,
+        policy: &ChunkSizePolicy,
+    ) -> Vec<CodeChunk> {
+        let lines: Vec<&str> = content.lines().collect();
+        let blocks = match self.format {
+            MarkupFormat::Markdown => parse_markdown(&lines),
+            MarkupFormat::ReStructuredText => parse_rst(&lines),
+            MarkupFormat::AsciiDoc => parse_asciidoc(&lines),
+            MarkupFormat::PlainText => (0..lines.len()).map(Block::Text).collect(),
+        };
+
+        let ctx = ChunkContext {
+            lines: &lines,
+            file_name,
+            language,
+            policy,
+        };
+        let mut chunks = Vec::new();
+        let mut path: Vec<(usize, String)> = Vec::new();
+        let mut section = Section {
+            path: Vec::new(),
+            heading: None,
+            rows: Vec::new(),
+        };
+        for block in blocks {
+            match block {
+                Block::Heading { level, title, rows } => {
+                    chunks.extend(ctx.section_chunks(&section));
+                    while path.last().is_some_and(|(parent, _)| *parent >= level) {
+                        path.pop();
+                    }
+                    path.push((level, title));
+                    section = Section {
+                        path: path.iter().map(|(_, title)| title.clone()).collect(),
+                        heading: Some(rows),
+                        rows: Vec::new(),
+                    };
+                }
+                Block::Code {
+                    language,
+                    rows: Some(rows),
+                } => chunks.extend(ctx.code_chunks(&section.path, language, rows)),
+                Block::Code { rows: None, .. } => {}
+                Block::Text(row) => section.rows.push(row),
+            }
+        }
+        chunks.extend(ctx.section_chunks(&section));
+
+        chunks.sort_by_key(|chunk| (chunk.start_line, chunk.end_line));
+        chunks
+    }
+}
+
+/// What the chunks of a section or code block are labelled with
+struct ChunkLabel<'a> {
+    node_type: &'a str,
+    language: &'a Language,
+    symbol: Option<CodeSymbol>,
+    path: &'a [String],
+}
+
+/// Document being chunked
+struct ChunkContext<'a> {
+    lines: &'a [&'a str],
+    file_name: &'a str,
+    language: &'a Language,
+    policy: &'a ChunkSizePolicy,
+}
+
+impl ChunkContext<'_> {
+    /// Chunks of a section's title and prose, none when it has no prose
+    fn section_chunks(&self, section: &Section) -> Vec<CodeChunk> {
+        if section
+            .rows
+            .iter()
+            .all(|&row| self.lines[row].trim().is_empty())
+        {
+            return Vec::new();
+        }
+
+        let mut rows: Vec<usize> = section
+            .heading
+            .map(|(start, end)| (start..=end).collect())
+            .unwrap_or_default();
+        let heading_rows = rows.len();
+        rows.extend(&section.rows);
+        let view: Vec<&str> = rows.iter().map(|&row| self.lines[row]).collect();
+
+        let mut segments = Vec::new();
+        if heading_rows > 0 {
+            segments.push((0, heading_rows - 1));
+        }
+        for (start, end) in paragraphs(&view, heading_rows) {
+            if self.policy.estimate_tokens(&view[start..=end].join("\n")) > self.policy.max_tokens {
+                segments.extend((start..=end).map(|row| (row, row)));
+            } else {
+                segments.push((start, end));
+            }
+        }
+
+        let symbol = section.path.last().map(|title| CodeSymbol {
+            name: title.clone(),
+            kind: "section".to_string(),
+            signature: Some(title.clone()),
+            parent: heading_parent(&section.path[..section.path.len() - 1]),
+            doc: None,
+        });
+        let label = ChunkLabel {
+            node_type: "section",
+            language: self.language,
+            symbol,
+            path: &section.path,
+        };
+        self.chunks(&view, &rows, &segments, label)
+    }
+
+    /// Chunks of a code block, tagged with its language
+    fn code_chunks(
+        &self,
+        path: &[String],
+        language: Option<String>,
+        (start, end): RowRange,
+    ) -> Vec<CodeChunk> {
+        let rows: Vec<usize> = (start..=end).collect();
+        let view = dedent(&self.lines[start..=end]);
+        let view: Vec<&str> = view.iter().map(String::as_str).collect();
+        let segments: Vec<RowRange> = (0..view.len()).map(|row| (row, row)).collect();
+
+        let symbol = CodeSymbol {
+            name: language.clone().unwrap_or_else(|| "code".to_string()),
+            kind: "code_block".to_string(),
+            signature: None,
+            parent: heading_parent(path),
+            doc: None,
+        };
+        let chunk_language = language.unwrap_or_else(|| self.language.clone());
+        let label = ChunkLabel {
+            node_type: "code_block",
+            language: &chunk_language,
+            symbol: Some(symbol),
+            path,
+        };
+        self.chunks(&view, &rows, &segments, label)
+    }
+
+    /// Pack `segments` of `view` into chunks, mapping view rows to `rows`
+    fn chunks(
+        &self,
+        view: &[&str],
+        rows: &[usize],
+        segments: &[RowRange],
+        label: ChunkLabel,
+    ) -> Vec<CodeChunk> {
+        let whole = (0, view.len() - 1);
+        let parts = if self.policy.estimate_tokens(&view.join("\n")) > self.policy.max_tokens {
+            pack_rows(segments, view, self.policy)
+        } else {
+            vec![whole]
+        };
+        let part_count = parts.len();
+        let key = label
+            .symbol
+            .as_ref()
+            .map_or_else(|| label.node_type.to_string(), CodeSymbol::to_string);
+
+        parts
+            .into_iter()
+            .enumerate()
+            .filter_map(|(part, (mut start, mut end))| {
+                // Blank lines around a part are not part of it
+                while start < end && view[start].trim().is_empty() {
+                    start += 1;
+                }
+                while end > start && view[end].trim().is_empty() {
+                    end -= 1;
+                }
+                let content = view[start..=end].join("\n").trim_end().to_string();
+                if content.trim().is_empty() {
+                    return None;
+                }
+
+                let mut metadata = serde_json::json!({
+                    "file": self.file_name,
+                    "node_type": label.node_type,
+                    "heading_path": label.path,
+                });
+                if let Some(map) = metadata.as_object_mut() {
+                    if let Some(symbol) = &label.symbol {
+                        map.insert(
+                            CodeSymbol::METADATA_KEY.to_string(),
+                            serde_json::json!(symbol),
+                        );
+                    }
+                    if part_count > 1 {
+                        map.insert("part".to_string(), serde_json::json!(part));
+                        map.insert("parts".to_string(), serde_json::json!(part_count));
+                    }
+                }
+
+                Some(CodeChunk {
+                    id: CodeChunk::stable_id(self.file_name, &key, rows[start] as u32, &content),
+                    content,
+                    file_path: self.file_name.to_string(),
+                    start_line: rows[start] as u32,
+                    end_line: rows[end] as u32,
+                    language: label.language.clone(),
+                    metadata,
+                })
+            })
+            .collect()
+    }
+}
+
+fn heading_parent(path: &[String]) -> Option<String> {
+    (!path.is_empty()).then(|| path.join(HEADING_PATH_SEPARATOR))
+}
+
+/// Runs of non-blank lines of `lines` from `from` on
+fn paragraphs(lines: &[&str], from: usize) -> Vec<RowRange> {
+    let mut paragraphs = Vec::new();
+    let mut start = None;
+    for (row, line) in lines.iter().enumerate().skip(from) {
+        match (line.trim().is_empty(), start) {
+            (false, None) => start = Some(row),
+            (true, Some(first)) => {
+                paragraphs.push((first, row - 1));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(first) = start {
+        paragraphs.push((first, lines.len() - 1));
+    }
+    paragraphs
+}
+
+/// Remove the indentation common to all non-blank lines
+fn dedent(lines: &[&str]) -> Vec<String> {
+    let indent = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| indentation(line))
+        .min()
+        .unwrap_or(0);
+    lines
+        .iter()
+        .map(|line| line.get(indent..).unwrap_or_default().to_string())
+        .collect()
+}
+
+fn indentation(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+/// Language named by a code block's info string
+///
+/// File extensions resolve to their language (`py` is `python`); other
+/// names are kept as written.
+fn block_language(info: &str) -> Option<String> {
+    let name = info
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .next()
+        .unwrap_or_default()
+        .trim_matches(['{', '}', '.'])
+        .to_lowercase();
+    if name.is_empty() {
+        return None;
+    }
+    let language = language_from_extension(&name);
+    Some(if language == LANG_UNKNOWN {
+        name
+    } else {
+        language
+    })
+}
+
+/// Code block with body rows `start..end`, `None` rows when empty
+fn code_block(info: &str, start: usize, end: usize) -> Block {
+    Block::Code {
+        language: block_language(info),
+        rows: (start < end).then(|| (start, end - 1)),
+    }
+}
+
+// ============================================================================
+// Markdown
+// ============================================================================
+
+fn parse_markdown(lines: &[&str]) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut row = 0;
+
+    // YAML front matter is prose, not a setext heading
+    if lines.first().is_some_and(|line| line.trim_end() == "---")
+        && let Some(end) = (1..lines.len()).find(|&r| lines[r].trim_end() == "---")
+    {
+        blocks.extend((0..=end).map(Block::Text));
+        row = end + 1;
+    }
+
+    while row < lines.len() {
+        let line = lines[row];
+        if let Some((fence, info)) = code_fence(line) {
+            let end = (row + 1..lines.len())
+                .find(|&r| closes_fence(lines[r], fence))
+                .unwrap_or(lines.len());
+            blocks.push(code_block(info, row + 1, end));
+            row = end + 1;
+        } else if let Some((level, title)) = atx_heading(line) {
+            blocks.push(Block::Heading {
+                level,
+                title,
+                rows: (row, row),
+            });
+            row += 1;
+        } else if let Some(level) = lines.get(row + 1).and_then(|next| setext_level(line, next)) {
+            blocks.push(Block::Heading {
+                level,
+                title: line.trim().to_string(),
+                rows: (row, row + 1),
+            });
+            row += 2;
+        } else {
+            blocks.push(Block::Text(row));
+            row += 1;
+        }
+    }
+    blocks
+}
+
+/// Opening fence of a code block and its info string
+fn code_fence(line: &str) -> Option<(&str, &str)> {
+    let trimmed = line.trim_start();
+    let fence_char = trimmed.chars().next().filter(|c| *c == '`' || *c == '~')?;
+    let length = trimmed.len() - trimmed.trim_start_matches(fence_char).len();
+    if length < 3 {
+        return None;
+    }
+    let info = trimmed[length..].trim();
+    if fence_char == '`' && info.contains('`') {
+        return None;
+    }
+    Some((&trimmed[..length], info))
+}
+
+fn closes_fence(line: &str, fence: &str) -> bool {
+    let trimmed = line.trim();
+    let fence_char = fence.chars().next().unwrap_or('`');
+    trimmed.len() >= fence.len() && trimmed.chars().all(|c| c == fence_char)
+}
+
+/// Level and title of a `#` heading, shared by Markdown and AsciiDoc
+fn atx_heading(line: &str) -> Option<(usize, String)> {
+    prefixed_heading(line, '#')
+}
+
+fn prefixed_heading(line: &str, marker: char) -> Option<(usize, String)> {
+    let level = line.len() - line.trim_start_matches(marker).len();
+    let rest = &line[level..];
+    if !(1..=6).contains(&level) || !rest.starts_with(' ') {
+        return None;
+    }
+    let title = rest.trim().trim_end_matches(marker).trim();
+    if title.is_empty() {
+        return None;
+    }
+    Some((level, title.to_string()))
+}
+
+/// Level of a title underlined by `===` (1) or `---` (2)
+fn setext_level(line: &str, next: &str) -> Option<usize> {
+    let underline = next.trim_end();
+    if line.trim().is_empty() || line.starts_with(' ') || underline.len() < 3 {
+        return None;
+    }
+    if underline.chars().all(|c| c == '=') {
+        Some(1)
+    } else if underline.chars().all(|c| c == '-') {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+// ============================================================================
+// reStructuredText
+// ============================================================================
+
+fn parse_rst(lines: &[&str]) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    // Title styles in order of appearance, which defines their level
+    let mut styles: Vec<(char, bool)> = Vec::new();
+    let mut level_of = |style: (char, bool)| {
+        let position = styles.iter().position(|s| *s == style).unwrap_or_else(|| {
+            styles.push(style);
+            styles.len() - 1
+        });
+        position + 1
+    };
+
+    let mut row = 0;
+    while row < lines.len() {
+        let line = lines[row];
+        let next = lines.get(row + 1).copied().unwrap_or_default();
+
+        // Title between an overline and an underline
+        if let Some(mark) = adornment(line)
+            && let Some(under) = lines.get(row + 2)
+            && adornment(under) == Some(mark)
+            && !next.trim().is_empty()
+            && adornment(next).is_none()
+        {
+            blocks.push(Block::Heading {
+                level: level_of((mark, true)),
+                title: next.trim().to_string(),
+                rows: (row, row + 2),
+            });
+            row += 3;
+            continue;
+        }
+
+        // Title followed by an underline at least as long
+        if !line.trim().is_empty()
+            && !line.starts_with(char::is_whitespace)
+            && adornment(line).is_none()
+            && let Some(mark) = adornment(next)
+            && next.trim_end().chars().count() >= line.trim_end().chars().count()
+        {
+            blocks.push(Block::Heading {
+                level: level_of((mark, false)),
+                title: line.trim().to_string(),
+                rows: (row, row + 1),
+            });
+            row += 2;
+            continue;
+        }
+
+        let trimmed = line.trim_start();
+        if let Some(directive) = RST_CODE_DIRECTIVES
+            .iter()
+            .find(|directive| trimmed.starts_with(**directive))
+        {
+            let indent = indentation(line);
+            let mut body = row + 1;
+            // Directive options such as `:linenos:`
+            while lines.get(body).is_some_and(|option| {
+                option.trim_start().starts_with(':') && indentation(option) > indent
+            }) {
+                body += 1;
+            }
+            let (rows, end) = indented_block(lines, body, indent);
+            blocks.push(Block::Code {
+                language: block_language(trimmed[directive.len()..].trim()),
+                rows,
+            });
+            row = end;
+            continue;
+        }
+
+        blocks.push(Block::Text(row));
+        row += 1;
+
+        // A paragraph ending in `::` introduces an indented literal block
+        if trimmed.trim_end().ends_with("::") && !trimmed.starts_with("..") {
+            let (rows, end) = indented_block(lines, row, indentation(line));
+            if rows.is_some() {
+                blocks.push(Block::Code {
+                    language: None,
+                    rows,
+                });
+                row = end;
+            }
+        }
+    }
+    blocks
+}
+
+/// Character of a line made of one repeated adornment character
+fn adornment(line: &str) -> Option<char> {
+    let line = line.trim_end();
+    let first = line.chars().next()?;
+    (line.len() >= 3 && RST_ADORNMENT_CHARS.contains(&first) && line.chars().all(|c| c == first))
+        .then_some(first)
+}
+
+/// Rows indented deeper than `indent` from `from` on, and the row after them
+fn indented_block(lines: &[&str], from: usize, indent: usize) -> (Option<RowRange>, usize) {
+    let mut first = None;
+    let mut last = None;
+    for (row, line) in lines.iter().enumerate().skip(from) {
+        if line.trim().is_empty() {
+            continue;
+        }
+        if indentation(line) <= indent {
+            break;
+        }
+        first.get_or_insert(row);
+        last = Some(row);
+    }
+    match (first, last) {
+        (Some(first), Some(last)) => (Some((first, last)), last + 1),
+        _ => (None, from),
+    }
+}
+
+// ============================================================================
+// AsciiDoc
+// ============================================================================
+
+fn parse_asciidoc(lines: &[&str]) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut row = 0;
+    while row < lines.len() {
+        let line = lines[row];
+
+        if let Some((level, title)) = prefixed_heading(line, '=').or_else(|| atx_heading(line)) {
+            blocks.push(Block::Heading {
+                level,
+                title,
+                rows: (row, row),
+            });
+            row += 1;
+            continue;
+        }
+
+        if let Some((fence, info)) = code_fence(line) {
+            let end = (row + 1..lines.len())
+                .find(|&r| closes_fence(lines[r], fence))
+                .unwrap_or(lines.len());
+            blocks.push(code_block(info, row + 1, end));
+            row = end + 1;
+            continue;
+        }
+
+        // `[source,lang]` attributes a delimited block or the next paragraph
+        let source = line
+            .trim()
+            .strip_prefix("[source")
+            .and_then(|rest| rest.strip_suffix(']'))
+            .map(|rest| rest.trim_start_matches(',').trim());
+        let delimited = |row: usize| lines.get(row).copied().filter(|l| listing_delimiter(l));
+
+        if let Some(info) = source {
+            blocks.push(Block::Text(row));
+            if let Some(delimiter) = delimited(row + 1) {
+                let end = (row + 2..lines.len())
+                    .find(|&r| lines[r].trim_end() == delimiter.trim_end())
+                    .unwrap_or(lines.len());
+                blocks.push(code_block(info, row + 2, end));
+                row = end + 1;
+            } else {
+                let end = (row + 1..lines.len())
+                    .find(|&r| lines[r].trim().is_empty())
+                    .unwrap_or(lines.len());
+                blocks.push(code_block(info, row + 1, end));
+                row = end;
+            }
+            continue;
+        }
+
+        if let Some(delimiter) = delimited(row) {
+            let end = (row + 1..lines.len())
+                .find(|&r| lines[r].trim_end() == delimiter.trim_end())
+                .unwrap_or(lines.len());
+            blocks.push(code_block("", row + 1, end));
+            row = end + 1;
+            continue;
+        }
+
+        blocks.push(Block::Text(row));
+        row += 1;
+    }
+    blocks
+}
+
+/// `----` listing or `....` literal block delimiter
+fn listing_delimiter(line: &str) -> bool {
+    let line = line.trim_end();
+    line.len() >= 4 && (line.chars().all(|c| c == '-') || line.chars().all(|c| c == '.'))
+}
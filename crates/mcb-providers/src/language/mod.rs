@@ -27,6 +27,17 @@
 //! | [`PhpProcessor`] | PHP | Complete |
 //! | [`SwiftProcessor`] | Swift | Complete |
 //! | [`KotlinProcessor`] | Kotlin | Complete |
+//! | [`ScalaProcessor`] | Scala | Complete |
+//! | [`ElixirProcessor`] | Elixir | Complete |
+//! | [`LuaProcessor`] | Lua | Complete |
+//! | [`BashProcessor`] | Bash/shell scripts | Complete |
+//! | [`HaskellProcessor`] | Haskell | Complete |
+//! | [`ZigProcessor`] | Zig | Complete |
+//!
+//! Documentation (Markdown, reStructuredText, AsciiDoc and plain text) is
+//! chunked by section and code block by [`MarkupChunker`]; configuration
+//! and data files (TOML, YAML, JSON and SQL) by table, key or statement by
+//! [`DataFileChunker`].
 
 /// Common utilities and base types for language processors
 pub mod common;
@@ -37,37 +48,59 @@ pub mod helpers;
 /// Intelligent chunking engine using tree-sitter
 pub mod engine;
 
+/// Structure-aware chunking of configuration and data files
+pub mod data;
+
+/// Section-based chunking of documentation files
+pub mod markup;
+
 // Language-specific processors
+pub mod bash;
 pub mod c;
 pub mod cpp;
 pub mod csharp;
+pub mod elixir;
 pub mod go;
+pub mod haskell;
 pub mod java;
 pub mod javascript;
 pub mod kotlin;
+pub mod lua;
 pub mod php;
 pub mod python;
 pub mod ruby;
 pub mod rust;
+pub mod scala;
 pub mod swift;
+pub mod zig;
 
 // Re-export processors for convenience
+pub use bash::BashProcessor;
 pub use c::CProcessor;
 pub use common::{BaseProcessor, LanguageConfig, LanguageProcessor, NodeExtractionRule};
 pub use cpp::CppProcessor;
 pub use csharp::CSharpProcessor;
+pub use data::{DataFileChunker, DataFormat};
+pub use elixir::ElixirProcessor;
 pub use go::GoProcessor;
+pub use haskell::HaskellProcessor;
 pub use java::JavaProcessor;
 pub use javascript::JavaScriptProcessor;
 pub use kotlin::KotlinProcessor;
+pub use lua::LuaProcessor;
+pub use markup::{MarkupChunker, MarkupFormat};
 pub use php::PhpProcessor;
 pub use python::PythonProcessor;
 pub use ruby::RubyProcessor;
 pub use rust::RustProcessor;
+pub use scala::ScalaProcessor;
 pub use swift::SwiftProcessor;
+pub use zig::ZigProcessor;
 
 // Re-export engine and helpers
 pub use engine::{IntelligentChunker, UniversalLanguageChunkingProvider};
 pub use helpers::{
-    get_chunk_size, is_language_supported, language_from_extension, supported_languages,
+    detect_language, get_chunk_size, is_language_supported, language_from_extension,
+    language_from_file_name, language_from_interpreter, supported_extensions, supported_file_names,
+    supported_interpreters, supported_languages,
 };
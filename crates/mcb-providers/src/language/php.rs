@@ -5,7 +5,7 @@ use crate::language::common::{
     TS_NODE_CLASS_DECLARATION, TS_NODE_FUNCTION_DEFINITION, TS_NODE_METHOD_DECLARATION,
 };
 use mcb_domain::entities::CodeChunk;
-use mcb_domain::value_objects::Language;
+use mcb_domain::value_objects::{ChunkSizePolicy, Language};
 
 /// PHP language processor.
 pub struct PhpProcessor {
@@ -58,9 +58,10 @@ impl LanguageProcessor for PhpProcessor {
         content: &str,
         file_name: &str,
         language: &Language,
+        policy: &ChunkSizePolicy,
     ) -> Vec<CodeChunk> {
         self.processor
-            .extract_chunks_with_tree_sitter(tree, content, file_name, language)
+            .extract_chunks_with_tree_sitter(tree, content, file_name, language, policy)
     }
 
     fn extract_chunks_fallback(
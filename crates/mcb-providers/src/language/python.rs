@@ -1,11 +1,11 @@
 //! Python language processor for AST-based code chunking.
 
 use crate::language::common::{
-    BaseProcessor, CHUNK_SIZE_PYTHON, LanguageConfig, LanguageProcessor, NodeExtractionRule,
-    TS_NODE_FUNCTION_DEFINITION,
+    BaseProcessor, CHUNK_SIZE_PYTHON, DocCommentStyle, LanguageConfig, LanguageProcessor,
+    NodeExtractionRule, TS_NODE_FUNCTION_DEFINITION,
 };
 use mcb_domain::entities::CodeChunk;
-use mcb_domain::value_objects::Language;
+use mcb_domain::value_objects::{ChunkSizePolicy, Language};
 
 /// Python language processor with function and class extraction.
 pub struct PythonProcessor {
@@ -34,6 +34,7 @@ impl PythonProcessor {
                 include_context: true,
             }])
             .with_fallback_patterns(vec![r"^def ".to_string(), r"^class ".to_string()])
+            .with_doc_comments(DocCommentStyle::Docstring)
             .with_chunk_size(CHUNK_SIZE_PYTHON);
 
         Self {
@@ -53,9 +54,10 @@ impl LanguageProcessor for PythonProcessor {
         content: &str,
         file_name: &str,
         language: &Language,
+        policy: &ChunkSizePolicy,
     ) -> Vec<CodeChunk> {
         self.processor
-            .extract_chunks_with_tree_sitter(tree, content, file_name, language)
+            .extract_chunks_with_tree_sitter(tree, content, file_name, language, policy)
     }
 
     fn extract_chunks_fallback(
@@ -1,10 +1,11 @@
 //! Ruby language processor for AST-based code chunking.
 
 use crate::language::common::{
-    BaseProcessor, CHUNK_SIZE_RUBY, LanguageConfig, LanguageProcessor, NodeExtractionRule,
+    BaseProcessor, CHUNK_SIZE_RUBY, DocCommentStyle, LanguageConfig, LanguageProcessor,
+    NodeExtractionRule,
 };
 use mcb_domain::entities::CodeChunk;
-use mcb_domain::value_objects::Language;
+use mcb_domain::value_objects::{ChunkSizePolicy, Language};
 
 /// Ruby language processor.
 pub struct RubyProcessor {
@@ -38,6 +39,7 @@ impl RubyProcessor {
                 r"^class ".to_string(),
                 r"^module ".to_string(),
             ])
+            .with_doc_comments(DocCommentStyle::Preceding(&["#"]))
             .with_chunk_size(CHUNK_SIZE_RUBY);
 
         Self {
@@ -57,9 +59,10 @@ impl LanguageProcessor for RubyProcessor {
         content: &str,
         file_name: &str,
         language: &Language,
+        policy: &ChunkSizePolicy,
     ) -> Vec<CodeChunk> {
         self.processor
-            .extract_chunks_with_tree_sitter(tree, content, file_name, language)
+            .extract_chunks_with_tree_sitter(tree, content, file_name, language, policy)
     }
 
     fn extract_chunks_fallback(
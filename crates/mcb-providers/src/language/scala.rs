@@ -0,0 +1,105 @@
+//! Scala language processor for AST-based code chunking.
+
+use crate::language::common::{
+    BaseProcessor, CHUNK_SIZE_SCALA, LanguageConfig, LanguageProcessor, NodeExtractionRule,
+    TS_NODE_FUNCTION_DEFINITION,
+};
+use mcb_domain::entities::CodeChunk;
+use mcb_domain::value_objects::{ChunkSizePolicy, Language};
+
+/// Scala language processor.
+pub struct ScalaProcessor {
+    processor: BaseProcessor,
+}
+
+impl Default for ScalaProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ScalaProcessor {
+    /// Create a new Scala language processor
+    pub fn new() -> Self {
+        let config = LanguageConfig::new(tree_sitter_scala::LANGUAGE.into())
+            .with_rules(Self::extraction_rules())
+            .with_fallback_patterns(Self::fallback_patterns())
+            .with_chunk_size(CHUNK_SIZE_SCALA);
+
+        Self {
+            processor: BaseProcessor::new(config),
+        }
+    }
+
+    fn extraction_rules() -> Vec<NodeExtractionRule> {
+        vec![
+            NodeExtractionRule {
+                node_types: vec![
+                    TS_NODE_FUNCTION_DEFINITION.to_string(),
+                    "class_definition".to_string(),
+                    "object_definition".to_string(),
+                    "trait_definition".to_string(),
+                    "enum_definition".to_string(),
+                ],
+                min_length: 40,
+                min_lines: 2,
+                max_depth: 4,
+                priority: 10,
+                include_context: true,
+            },
+            NodeExtractionRule {
+                node_types: vec![
+                    "given_definition".to_string(),
+                    "type_definition".to_string(),
+                ],
+                min_length: 25,
+                min_lines: 1,
+                max_depth: 3,
+                priority: 5,
+                include_context: false,
+            },
+        ]
+    }
+
+    fn fallback_patterns() -> Vec<String> {
+        [
+            "def ",
+            "class ",
+            "case class ",
+            "object ",
+            "trait ",
+            "enum ",
+        ]
+        .iter()
+        .map(|p| format!("^{}", p))
+        .collect()
+    }
+}
+
+impl LanguageProcessor for ScalaProcessor {
+    fn config(&self) -> &LanguageConfig {
+        self.processor.config()
+    }
+
+    fn extract_chunks_with_tree_sitter(
+        &self,
+        tree: &tree_sitter::Tree,
+        content: &str,
+        file_name: &str,
+        language: &Language,
+        policy: &ChunkSizePolicy,
+    ) -> Vec<CodeChunk> {
+        self.processor
+            .extract_chunks_with_tree_sitter(tree, content, file_name, language, policy)
+    }
+
+    fn extract_chunks_fallback(
+        &self,
+        content: &str,
+        file_name: &str,
+        language: &Language,
+    ) -> Vec<CodeChunk> {
+        self.processor
+            .extract_chunks_fallback(content, file_name, language)
+    }
+}
@@ -0,0 +1,93 @@
+//! Zig language processor for AST-based code chunking.
+
+use crate::language::common::{
+    BaseProcessor, CHUNK_SIZE_ZIG, LanguageConfig, LanguageProcessor, NodeExtractionRule,
+    TS_NODE_FUNCTION_DECLARATION,
+};
+use mcb_domain::entities::CodeChunk;
+use mcb_domain::value_objects::{ChunkSizePolicy, Language};
+
+/// Zig language processor.
+pub struct ZigProcessor {
+    processor: BaseProcessor,
+}
+
+impl Default for ZigProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ZigProcessor {
+    /// Create a new Zig language processor
+    pub fn new() -> Self {
+        let config = LanguageConfig::new(tree_sitter_zig::LANGUAGE.into())
+            .with_rules(Self::extraction_rules())
+            .with_fallback_patterns(Self::fallback_patterns())
+            .with_chunk_size(CHUNK_SIZE_ZIG);
+
+        Self {
+            processor: BaseProcessor::new(config),
+        }
+    }
+
+    fn extraction_rules() -> Vec<NodeExtractionRule> {
+        vec![
+            NodeExtractionRule {
+                node_types: vec![
+                    TS_NODE_FUNCTION_DECLARATION.to_string(),
+                    "test_declaration".to_string(),
+                ],
+                min_length: 40,
+                min_lines: 2,
+                max_depth: 4,
+                priority: 10,
+                include_context: true,
+            },
+            // Containers are constants: `const Point = struct { ... };`
+            NodeExtractionRule {
+                node_types: vec!["variable_declaration".to_string()],
+                min_length: 25,
+                min_lines: 2,
+                max_depth: 3,
+                priority: 5,
+                include_context: false,
+            },
+        ]
+    }
+
+    fn fallback_patterns() -> Vec<String> {
+        ["fn ", "pub fn ", "const ", "pub const ", "test "]
+            .iter()
+            .map(|p| format!("^{}", p))
+            .collect()
+    }
+}
+
+impl LanguageProcessor for ZigProcessor {
+    fn config(&self) -> &LanguageConfig {
+        self.processor.config()
+    }
+
+    fn extract_chunks_with_tree_sitter(
+        &self,
+        tree: &tree_sitter::Tree,
+        content: &str,
+        file_name: &str,
+        language: &Language,
+        policy: &ChunkSizePolicy,
+    ) -> Vec<CodeChunk> {
+        self.processor
+            .extract_chunks_with_tree_sitter(tree, content, file_name, language, policy)
+    }
+
+    fn extract_chunks_fallback(
+        &self,
+        content: &str,
+        file_name: &str,
+        language: &Language,
+    ) -> Vec<CodeChunk> {
+        self.processor
+            .extract_chunks_fallback(content, file_name, language)
+    }
+}
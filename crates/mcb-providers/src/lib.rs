@@ -16,6 +16,7 @@
 //! | Cache | `CacheProvider` | Moka, Redis, Null |
 //! | Events | `EventPublisher` | Tokio, Nats, Null |
 //! | Hybrid Search | `HybridSearchProvider` | HybridSearchEngine, Null |
+//! | Reranker | `RerankerProvider` | HTTP, FastEmbed, Null |
 //! | Language | `LanguageChunkingProvider` | Rust, Python, Go, Java, etc. |
 //!
 //! ## Feature Flags
@@ -39,7 +40,7 @@
 pub use mcb_domain::error::{Error, Result};
 pub use mcb_domain::ports::providers::{
     CacheProvider, EmbeddingProvider, HybridSearchProvider, LanguageChunkingProvider,
-    VectorStoreProvider,
+    RerankerProvider, VectorStoreProvider,
 };
 
 // Re-export CryptoProvider from domain (for encrypted vector store)
@@ -57,6 +58,11 @@ pub mod utils;
 /// Implements `EmbeddingProvider` trait for various embedding APIs.
 pub mod embedding;
 
+/// Reranker provider implementations
+///
+/// Implements `RerankerProvider` trait for second-stage ranking of search results.
+pub mod reranker;
+
 /// Vector store provider implementations
 ///
 /// Implements `VectorStoreProvider` trait for vector storage backends.
@@ -0,0 +1,207 @@
+//! FastEmbed Local Reranker Provider
+//!
+//! Implements the RerankerProvider port using the cross-encoder reranker
+//! models of the fastembed library. Uses ONNX models for inference without
+//! external API calls.
+
+use async_trait::async_trait;
+use fastembed::{RerankInitOptions, RerankerModel, TextRerank};
+use tokio::sync::{mpsc, oneshot};
+
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::providers::RerankerProvider;
+
+use crate::constants::FASTEMBED_RERANK_BATCH_SIZE;
+
+/// Messages for the FastEmbed reranker actor
+enum FastEmbedRerankMessage {
+    Rerank {
+        query: String,
+        documents: Vec<String>,
+        tx: oneshot::Sender<Result<Vec<f32>>>,
+    },
+}
+
+/// FastEmbed local reranker provider using Actor pattern
+///
+/// Like [`FastEmbedProvider`](crate::embedding::FastEmbedProvider), the
+/// ONNX model is owned by an actor task and requests go through a channel,
+/// so no lock is held around inference.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use mcb_providers::reranker::FastEmbedRerankerProvider;
+///
+/// let provider = FastEmbedRerankerProvider::new().expect("Failed to initialize");
+/// // provider is now ready to rerank documents locally
+/// ```
+pub struct FastEmbedRerankerProvider {
+    sender: mpsc::Sender<FastEmbedRerankMessage>,
+    model_name: String,
+}
+
+impl FastEmbedRerankerProvider {
+    /// Create a new FastEmbed reranker with the default model (BGERerankerBase)
+    pub fn new() -> Result<Self> {
+        Self::with_model(RerankerModel::BGERerankerBase)
+    }
+
+    /// Create a new FastEmbed reranker with a specific model
+    pub fn with_model(model: RerankerModel) -> Result<Self> {
+        let model_name = format!("{:?}", model);
+        let init_options = RerankInitOptions::new(model).with_show_download_progress(true);
+        let text_rerank = TextRerank::try_new(init_options).map_err(|e| {
+            Error::internal(format!("Failed to initialize FastEmbed reranker: {}", e))
+        })?;
+
+        let (tx, rx) = mpsc::channel(100);
+        let mut actor = FastEmbedRerankActor {
+            receiver: rx,
+            model: text_rerank,
+        };
+        tokio::spawn(async move {
+            actor.run().await;
+        });
+
+        Ok(Self {
+            sender: tx,
+            model_name,
+        })
+    }
+
+    /// Get the model name
+    pub fn model(&self) -> &str {
+        &self.model_name
+    }
+}
+
+#[async_trait]
+impl RerankerProvider for FastEmbedRerankerProvider {
+    async fn rerank(&self, query: &str, documents: &[String]) -> Result<Vec<f32>> {
+        if documents.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let (tx, rx) = oneshot::channel();
+        self.sender
+            .send(FastEmbedRerankMessage::Rerank {
+                query: query.to_string(),
+                documents: documents.to_vec(),
+                tx,
+            })
+            .await
+            .map_err(|_| Error::internal("FastEmbed reranker actor channel closed"))?;
+
+        rx.await
+            .unwrap_or_else(|_| Err(Error::internal("FastEmbed reranker actor closed")))
+    }
+
+    fn provider_name(&self) -> &str {
+        "fastembed"
+    }
+
+    fn model_name(&self) -> &str {
+        self.model()
+    }
+}
+
+impl Clone for FastEmbedRerankerProvider {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+            model_name: self.model_name.clone(),
+        }
+    }
+}
+
+/// Internal actor that processes rerank requests
+struct FastEmbedRerankActor {
+    receiver: mpsc::Receiver<FastEmbedRerankMessage>,
+    model: TextRerank,
+}
+
+impl FastEmbedRerankActor {
+    async fn run(&mut self) {
+        while let Some(msg) = self.receiver.recv().await {
+            match msg {
+                FastEmbedRerankMessage::Rerank {
+                    query,
+                    documents,
+                    tx,
+                } => {
+                    let _ = tx.send(self.rerank(&query, &documents));
+                }
+            }
+        }
+    }
+
+    /// Scores of the documents in request order
+    fn rerank(&mut self, query: &str, documents: &[String]) -> Result<Vec<f32>> {
+        let document_refs: Vec<&str> = documents.iter().map(|s| s.as_str()).collect();
+        let ranked = self
+            .model
+            .rerank(
+                query,
+                document_refs,
+                false,
+                Some(FASTEMBED_RERANK_BATCH_SIZE),
+            )
+            .map_err(|e| Error::internal(format!("FastEmbed reranking failed: {}", e)))?;
+
+        // Results come back sorted by score; put them back in request order
+        let mut scores = vec![f32::NEG_INFINITY; documents.len()];
+        for result in ranked {
+            if let Some(score) = scores.get_mut(result.index) {
+                *score = result.score;
+            }
+        }
+        Ok(scores)
+    }
+}
+
+// ============================================================================
+// Auto-registration via linkme distributed slice
+// ============================================================================
+
+use std::sync::Arc;
+
+use mcb_application::ports::registry::{
+    RERANKER_PROVIDERS, RerankerProviderConfig, RerankerProviderEntry,
+};
+
+/// Parse model name string to RerankerModel enum
+fn parse_reranker_model(model_name: &str) -> RerankerModel {
+    match model_name.to_lowercase().as_str() {
+        "bgererankerv2m3" | "bge-reranker-v2-m3" => RerankerModel::BGERerankerV2M3,
+        "jinarerankerv1turboen" | "jina-reranker-v1-turbo-en" => {
+            RerankerModel::JINARerankerV1TurboEn
+        }
+        "jinarerankerv2basemultilingual" | "jina-reranker-v2-base-multilingual" => {
+            RerankerModel::JINARerankerV2BaseMultiligual
+        }
+        _ => RerankerModel::BGERerankerBase, // default
+    }
+}
+
+/// Factory function for creating FastEmbed reranker provider instances.
+fn fastembed_reranker_factory(
+    config: &RerankerProviderConfig,
+) -> std::result::Result<Arc<dyn RerankerProvider>, String> {
+    let model = config
+        .model
+        .as_deref()
+        .map(parse_reranker_model)
+        .unwrap_or(RerankerModel::BGERerankerBase);
+    let provider = FastEmbedRerankerProvider::with_model(model)
+        .map_err(|e| format!("Failed to create FastEmbed reranker: {e}"))?;
+
+    Ok(Arc::new(provider))
+}
+
+#[linkme::distributed_slice(RERANKER_PROVIDERS)]
+static FASTEMBED_PROVIDER: RerankerProviderEntry = RerankerProviderEntry {
+    name: "fastembed",
+    description: "FastEmbed local cross-encoder reranker (BGERerankerBase, BGERerankerV2M3, etc.)",
+    factory: fastembed_reranker_factory,
+};
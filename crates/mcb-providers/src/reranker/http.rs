@@ -0,0 +1,214 @@
+//! HTTP Reranker Provider
+//!
+//! Implements the RerankerProvider port against a hosted reranking API.
+//! VoyageAI and Cohere share the request shape (`query`, `documents`,
+//! `model` posted to `{base_url}/rerank`) and return the scores under `data`
+//! and `results` respectively; both are accepted, so self-hosted servers
+//! speaking either dialect work too.
+
+use async_trait::async_trait;
+use reqwest::Client;
+
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::providers::RerankerProvider;
+
+use crate::constants::{
+    CONTENT_TYPE_JSON, RERANKER_HTTP_DEFAULT_BASE_URL, RERANKER_HTTP_DEFAULT_MODEL,
+};
+use crate::embedding::helpers::constructor;
+use crate::utils::HttpResponseUtils;
+
+/// HTTP reranker provider
+///
+/// Receives HTTP client via constructor injection.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use mcb_providers::reranker::HttpRerankerProvider;
+/// use reqwest::Client;
+///
+/// fn example() {
+///     let provider = HttpRerankerProvider::new(
+///         Some("voyage-your-api-key".to_string()),
+///         None,
+///         "rerank-2".to_string(),
+///         Client::new(),
+///     );
+/// }
+/// ```
+pub struct HttpRerankerProvider {
+    api_key: Option<String>,
+    base_url: Option<String>,
+    model: String,
+    http_client: Client,
+}
+
+impl HttpRerankerProvider {
+    /// Create a new HTTP reranker provider
+    ///
+    /// # Arguments
+    /// * `api_key` - Optional API key, sent as a bearer token
+    /// * `base_url` - Optional custom base URL (defaults to VoyageAI API)
+    /// * `model` - Model name (e.g., "rerank-2", "rerank-v3.5")
+    /// * `http_client` - Reqwest HTTP client for making API requests
+    pub fn new(
+        api_key: Option<String>,
+        base_url: Option<String>,
+        model: String,
+        http_client: Client,
+    ) -> Self {
+        let api_key = api_key
+            .map(|key| constructor::validate_api_key(&key))
+            .filter(|key| !key.is_empty());
+        let base_url = constructor::validate_url(base_url);
+        Self {
+            api_key,
+            base_url,
+            model,
+            http_client,
+        }
+    }
+
+    /// Get the effective base URL
+    fn effective_base_url(&self) -> String {
+        constructor::get_effective_url(self.base_url.as_deref(), RERANKER_HTTP_DEFAULT_BASE_URL)
+    }
+
+    /// Get the model name for this provider
+    pub fn model(&self) -> &str {
+        &self.model
+    }
+
+    /// Get the base URL for this provider
+    pub fn base_url(&self) -> String {
+        self.effective_base_url()
+    }
+
+    /// Send rerank request and get response data
+    async fn fetch_scores(&self, query: &str, documents: &[String]) -> Result<serde_json::Value> {
+        let payload = serde_json::json!({
+            "query": query,
+            "documents": documents,
+            "model": self.model
+        });
+
+        let url = format!("{}/rerank", self.effective_base_url().trim_end_matches('/'));
+        let mut request = self
+            .http_client
+            .post(url)
+            .header("Content-Type", CONTENT_TYPE_JSON)
+            .json(&payload);
+        if let Some(api_key) = &self.api_key {
+            request = request.header("Authorization", format!("Bearer {}", api_key));
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| Error::network(format!("Rerank request failed: {}", e)))?;
+
+        HttpResponseUtils::check_and_parse(response, "HTTP reranker").await
+    }
+
+    /// Scores of the documents in request order
+    ///
+    /// Results come back sorted by relevance, each naming the `index` of
+    /// the document it scores.
+    fn parse_scores(response: &serde_json::Value, count: usize) -> Result<Vec<f32>> {
+        let items = response["results"]
+            .as_array()
+            .or_else(|| response["data"].as_array())
+            .ok_or_else(|| {
+                Error::internal("Invalid rerank response: missing results array".to_string())
+            })?;
+
+        let mut scores: Vec<Option<f32>> = vec![None; count];
+        for item in items {
+            let index = item["index"]
+                .as_u64()
+                .map(|index| index as usize)
+                .filter(|index| *index < count)
+                .ok_or_else(|| {
+                    Error::internal(format!("Invalid rerank result index: {}", item["index"]))
+                })?;
+            let score = item["relevance_score"].as_f64().ok_or_else(|| {
+                Error::internal(format!("Missing relevance score for document {}", index))
+            })?;
+            scores[index] = Some(score as f32);
+        }
+
+        scores
+            .into_iter()
+            .enumerate()
+            .map(|(index, score)| {
+                score.ok_or_else(|| {
+                    Error::internal(format!("Rerank response missing document {}", index))
+                })
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl RerankerProvider for HttpRerankerProvider {
+    async fn rerank(&self, query: &str, documents: &[String]) -> Result<Vec<f32>> {
+        if documents.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let response = self.fetch_scores(query, documents).await?;
+        Self::parse_scores(&response, documents.len())
+    }
+
+    fn provider_name(&self) -> &str {
+        "http"
+    }
+
+    fn model_name(&self) -> &str {
+        self.model()
+    }
+}
+
+// ============================================================================
+// Auto-registration via linkme distributed slice
+// ============================================================================
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use mcb_application::ports::registry::{
+    RERANKER_PROVIDERS, RerankerProviderConfig, RerankerProviderEntry,
+};
+
+/// Factory function for creating HTTP reranker provider instances.
+fn http_reranker_factory(
+    config: &RerankerProviderConfig,
+) -> std::result::Result<Arc<dyn RerankerProvider>, String> {
+    let model = config
+        .model
+        .clone()
+        .unwrap_or_else(|| RERANKER_HTTP_DEFAULT_MODEL.to_string());
+    let timeout = config
+        .timeout_secs
+        .map(Duration::from_secs)
+        .unwrap_or_else(constructor::default_timeout);
+    let http_client = Client::builder()
+        .timeout(timeout)
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {e}"))?;
+
+    Ok(Arc::new(HttpRerankerProvider::new(
+        config.api_key.clone(),
+        config.base_url.clone(),
+        model,
+        http_client,
+    )))
+}
+
+#[linkme::distributed_slice(RERANKER_PROVIDERS)]
+static HTTP_PROVIDER: RerankerProviderEntry = RerankerProviderEntry {
+    name: "http",
+    description: "HTTP reranker (VoyageAI / Cohere compatible /rerank API)",
+    factory: http_reranker_factory,
+};
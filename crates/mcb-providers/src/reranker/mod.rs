@@ -0,0 +1,31 @@
+//! Reranker Provider Implementations
+//!
+//! Re-scores the top search results against the query with a model that
+//! reads both together, trading latency for precision at the top of the
+//! ranking.
+//!
+//! ## Available Providers
+//!
+//! | Provider | Type | Status |
+//! |----------|------|--------|
+//! | [`NullRerankerProvider`] | Testing | Complete |
+//! | [`HttpRerankerProvider`] | Cloud / self-hosted | Complete |
+//! | [`FastEmbedRerankerProvider`] | Local ML | Complete (optional) |
+//!
+//! ## Provider Selection Guide
+//!
+//! - **Null**: Keeps the fused order, for tests and when reranking is off
+//! - **HTTP**: VoyageAI, Cohere or any server speaking their `/rerank` API
+//! - **FastEmbed**: Local ONNX cross-encoders such as BGE reranker
+//!   (requires `reranker-fastembed` feature)
+
+#[cfg(feature = "reranker-fastembed")]
+pub mod fastembed;
+pub mod http;
+pub mod null;
+
+// Re-export for convenience
+#[cfg(feature = "reranker-fastembed")]
+pub use fastembed::FastEmbedRerankerProvider;
+pub use http::HttpRerankerProvider;
+pub use null::NullRerankerProvider;
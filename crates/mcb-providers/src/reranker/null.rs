@@ -0,0 +1,76 @@
+//! Null reranker provider for testing and development
+//!
+//! Returns no scores, so search results keep their fused order.
+
+use async_trait::async_trait;
+
+use mcb_domain::error::Result;
+use mcb_domain::ports::providers::RerankerProvider;
+
+/// Null reranker provider
+///
+/// Does not rank: search results keep the order first-stage retrieval gave
+/// them. Used when reranking is disabled and in tests.
+///
+/// # Example
+///
+/// ```rust
+/// use mcb_providers::reranker::NullRerankerProvider;
+/// use mcb_domain::ports::providers::RerankerProvider;
+///
+/// let provider = NullRerankerProvider::new();
+/// assert_eq!(provider.provider_name(), "null");
+/// ```
+pub struct NullRerankerProvider;
+
+impl NullRerankerProvider {
+    /// Create a new null reranker provider
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for NullRerankerProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl RerankerProvider for NullRerankerProvider {
+    async fn rerank(&self, _query: &str, _documents: &[String]) -> Result<Vec<f32>> {
+        Ok(Vec::new())
+    }
+
+    fn provider_name(&self) -> &str {
+        "null"
+    }
+
+    fn model_name(&self) -> &str {
+        "null"
+    }
+}
+
+// ============================================================================
+// Auto-registration via linkme distributed slice
+// ============================================================================
+
+use std::sync::Arc;
+
+use mcb_application::ports::registry::{
+    RERANKER_PROVIDERS, RerankerProviderConfig, RerankerProviderEntry,
+};
+
+/// Factory function for creating null reranker provider instances.
+fn null_reranker_factory(
+    _config: &RerankerProviderConfig,
+) -> std::result::Result<Arc<dyn RerankerProvider>, String> {
+    Ok(Arc::new(NullRerankerProvider::new()))
+}
+
+#[linkme::distributed_slice(RERANKER_PROVIDERS)]
+static NULL_PROVIDER: RerankerProviderEntry = RerankerProviderEntry {
+    name: "null",
+    description: "Null provider (no reranking, keeps the fused order)",
+    factory: null_reranker_factory,
+};
@@ -0,0 +1,107 @@
+//! Tests for token-based splitting and merging of code chunks
+
+use crate::language::IntelligentChunker;
+use mcb_domain::value_objects::ChunkSizePolicy;
+
+fn long_rust_function(statements: usize) -> String {
+    let body: String = (0..statements)
+        .map(|i| format!("    let value_{i} = compute_value(input, {i});\n"))
+        .collect();
+    format!("/// Sum many values\nfn sum_values(input: u64) -> u64 {{\n{body}    input\n}}\n")
+}
+
+#[test]
+fn test_oversized_function_is_split_within_max_tokens() {
+    let policy = ChunkSizePolicy::new(64, 4, 16);
+    let content = long_rust_function(40);
+    let chunks = IntelligentChunker::new().chunk_code_with_policy(
+        &content,
+        "src/sum.rs",
+        &"rust".into(),
+        &policy,
+    );
+
+    let parts: Vec<_> = chunks
+        .iter()
+        .filter(|chunk| chunk.metadata.get("parts").is_some())
+        .collect();
+    assert!(parts.len() > 1, "expected the function to be split");
+    for part in &parts {
+        assert!(policy.estimate_tokens(&part.content) <= policy.max_tokens);
+        assert_eq!(
+            part.symbol().map(|symbol| symbol.name).as_deref(),
+            Some("sum_values")
+        );
+        assert_eq!(part.metadata["parts"], serde_json::json!(parts.len()));
+    }
+
+    // Consecutive parts overlap by at least one line
+    for pair in parts.windows(2) {
+        assert!(pair[1].start_line <= pair[0].end_line);
+        assert!(pair[1].start_line > pair[0].start_line);
+    }
+
+    let ids: std::collections::HashSet<_> = parts.iter().map(|part| &part.id).collect();
+    assert_eq!(ids.len(), parts.len());
+}
+
+#[test]
+fn test_function_within_max_tokens_is_not_split() {
+    let content = long_rust_function(4);
+    let chunks = IntelligentChunker::new().chunk_code(&content, "src/sum.rs", &"rust".into());
+
+    assert!(
+        chunks
+            .iter()
+            .all(|chunk| chunk.metadata.get("parts").is_none())
+    );
+    assert!(chunks.iter().any(|chunk| {
+        chunk.symbol().map(|symbol| symbol.name).as_deref() == Some("sum_values")
+    }));
+}
+
+#[test]
+fn test_tiny_adjacent_chunks_are_merged() {
+    let policy = ChunkSizePolicy::new(512, 32, 0);
+    let content = "const MAX_RETRIES: u32 = 5;\nconst RETRY_DELAY: u64 = 250;\nconst BACKOFF_FACTOR: u32 = 2;\n\nfn main() {\n    let total = MAX_RETRIES as u64 * RETRY_DELAY * BACKOFF_FACTOR as u64;\n    println!(\"{total}\");\n    report_total(total, \"constants\");\n}\n";
+    let chunks = IntelligentChunker::new().chunk_code_with_policy(
+        content,
+        "src/main.rs",
+        &"rust".into(),
+        &policy,
+    );
+
+    let merged: Vec<_> = chunks
+        .iter()
+        .filter(|chunk| chunk.metadata.get("merged_chunks").is_some())
+        .collect();
+    assert_eq!(merged.len(), 1);
+    assert_eq!(merged[0].metadata["merged_chunks"], serde_json::json!(3));
+    assert!(
+        merged[0].content.contains("MAX_RETRIES") && merged[0].content.contains("BACKOFF_FACTOR")
+    );
+    assert!(merged[0].symbol().is_none());
+}
+
+#[test]
+fn test_identical_merged_chunks_keep_distinct_ids() {
+    let policy = ChunkSizePolicy::new(512, 32, 0);
+    let constants = "const MAX_RETRIES: u32 = 5;\nconst RETRY_DELAY: u64 = 250;\nconst BACKOFF_FACTOR: u32 = 2;\n";
+    let function = "fn main() {\n    let total = MAX_RETRIES as u64 * RETRY_DELAY * BACKOFF_FACTOR as u64;\n    println!(\"{total}\");\n    report_total(total, \"constants\");\n}\n";
+    let content = format!("{constants}\n{function}\n{constants}");
+    let chunks = IntelligentChunker::new().chunk_code_with_policy(
+        &content,
+        "src/main.rs",
+        &"rust".into(),
+        &policy,
+    );
+
+    let merged: Vec<_> = chunks
+        .iter()
+        .filter(|chunk| chunk.metadata.get("merged_chunks").is_some())
+        .collect();
+    assert_eq!(merged.len(), 2);
+    assert_eq!(merged[0].content, merged[1].content);
+    // Same body in the same file, stored as two entries
+    assert_ne!(merged[0].id, merged[1].id);
+}
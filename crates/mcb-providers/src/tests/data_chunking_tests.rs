@@ -0,0 +1,211 @@
+//! Tests for table, key, document and statement chunking of data files
+
+use crate::language::{IntelligentChunker, detect_language};
+use mcb_domain::entities::CodeChunk;
+use mcb_domain::value_objects::ChunkSizePolicy;
+
+fn chunk(content: &str, file_name: &str) -> Vec<CodeChunk> {
+    let language = detect_language(file_name, content);
+    IntelligentChunker::new().chunk_code(content, file_name, &language)
+}
+
+fn key_path(chunk: &CodeChunk) -> Vec<String> {
+    serde_json::from_value(chunk.metadata["key_path"].clone()).unwrap_or_default()
+}
+
+fn with_key_path<'c>(chunks: &'c [CodeChunk], path: &[&str]) -> &'c CodeChunk {
+    chunks
+        .iter()
+        .find(|chunk| key_path(chunk) == path)
+        .unwrap_or_else(|| panic!("no chunk for {path:?}"))
+}
+
+#[test]
+fn test_toml_is_chunked_by_table_with_its_comments() {
+    let content = r#"log_level = "info"
+
+[providers.embedding]
+provider = "ollama"
+
+# Milvus connection
+[providers.vector_store.milvus]
+address = "http://localhost:19530"
+timeout_secs = 30
+
+[[server.transports]]
+mode = "stdio"
+"#;
+    let chunks = chunk(content, "config/default.toml");
+
+    assert_eq!(chunks.len(), 4);
+    assert_eq!(chunks[0].content, "log_level = \"info\"");
+    assert!(chunks[0].metadata.get("key_path").is_none());
+
+    let milvus = with_key_path(&chunks, &["providers", "vector_store", "milvus"]);
+    assert!(
+        milvus
+            .content
+            .starts_with("# Milvus connection\n[providers.vector_store.milvus]")
+    );
+    assert!(milvus.content.contains("timeout_secs = 30"));
+    assert_eq!(milvus.start_line, 5);
+    let symbol = milvus.symbol().expect("table symbol");
+    assert_eq!(symbol.kind, "table");
+    assert_eq!(symbol.parent.as_deref(), Some("providers.vector_store"));
+
+    with_key_path(&chunks, &["server", "transports"]);
+}
+
+#[test]
+fn test_kubernetes_yaml_is_chunked_by_document() {
+    let content = "\
+apiVersion: apps/v1
+kind: Deployment
+metadata:
+  name: mcb-server
+spec:
+  replicas: 2
+---
+apiVersion: v1
+kind: Service
+metadata:
+  name: mcb-server
+  labels:
+    app: mcb
+spec:
+  ports:
+    - port: 3000
+";
+    let chunks = chunk(content, "k8s/server.yaml");
+
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(key_path(&chunks[0]), ["Deployment", "mcb-server"]);
+    assert_eq!(key_path(&chunks[1]), ["Service", "mcb-server"]);
+    assert_eq!(chunks[1].metadata["object_name"], "mcb-server");
+    assert_eq!(chunks[1].symbol().expect("symbol").kind, "Service");
+    assert!(chunks[1].content.starts_with("apiVersion: v1"));
+    assert_eq!(chunks[1].start_line, 7);
+}
+
+#[test]
+fn test_large_yaml_keys_are_split_into_their_keys() {
+    let service = |name: &str| {
+        format!(
+            "  {name}:\n    image: registry.example.com/{name}:latest\n    restart: unless-stopped\n    environment:\n      - RUST_LOG=info\n"
+        )
+    };
+    let content = format!(
+        "version: \"3.8\"\nservices:\n{}{}{}",
+        service("server"),
+        service("milvus"),
+        service("ollama")
+    );
+    let policy = ChunkSizePolicy::new(48, 4, 0);
+
+    let chunks = IntelligentChunker::new().chunk_code_with_policy(
+        &content,
+        "docker-compose.yml",
+        &"yaml".into(),
+        &policy,
+    );
+
+    let paths: Vec<Vec<String>> = chunks.iter().map(key_path).collect();
+    assert_eq!(
+        paths,
+        [
+            vec!["version"],
+            vec!["services", "server"],
+            vec!["services", "milvus"],
+            vec!["services", "ollama"],
+        ]
+    );
+    assert!(chunks[2].content.trim_start().starts_with("milvus:"));
+    assert_eq!(
+        chunks[2].symbol().expect("symbol").parent.as_deref(),
+        Some("services")
+    );
+}
+
+#[test]
+fn test_json_is_chunked_by_top_level_key() {
+    let content = r#"{
+  "name": "mcb-admin",
+  "scripts": {
+    "build": "vite build",
+    "test": "vitest"
+  },
+  "dependencies": {
+    "react": "^18.2.0"
+  }
+}
+"#;
+    let chunks = chunk(content, "web/package.json");
+
+    let paths: Vec<Vec<String>> = chunks.iter().map(key_path).collect();
+    assert_eq!(paths, [vec!["name"], vec!["scripts"], vec!["dependencies"]]);
+    assert_eq!(chunks[1].start_line, 2);
+    assert_eq!(chunks[1].end_line, 5);
+    assert!(chunks[1].content.contains("vitest"));
+
+    // Minified JSON has no lines to split between
+    let minified = chunk(r#"{"a": 1, "b": {"c": 2}}"#, "data.json");
+    assert_eq!(minified.len(), 1);
+}
+
+#[test]
+fn test_sql_is_chunked_by_statement_with_object_names() {
+    let content = "\
+-- Indexed collections
+CREATE TABLE IF NOT EXISTS collections (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL -- display name; unique
+);
+
+CREATE INDEX idx_collections_name ON collections (name);
+
+CREATE OR REPLACE FUNCTION touch() RETURNS trigger AS $$
+BEGIN
+    NEW.updated_at = now();
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+INSERT INTO collections (id, name) VALUES ('default', 'it''s; default');
+";
+    let chunks = chunk(content, "migrations/001_init.sql");
+
+    let objects: Vec<(String, String)> = chunks
+        .iter()
+        .map(|chunk| {
+            let symbol = chunk.symbol().expect("statement symbol");
+            (symbol.kind, symbol.name)
+        })
+        .collect();
+    assert_eq!(
+        objects,
+        [
+            ("table".to_string(), "collections".to_string()),
+            ("index".to_string(), "idx_collections_name".to_string()),
+            ("function".to_string(), "touch".to_string()),
+            ("insert".to_string(), "collections".to_string()),
+        ]
+    );
+
+    let table = &chunks[0];
+    assert_eq!((table.start_line, table.end_line), (0, 4));
+    assert_eq!(table.metadata["object_name"], "collections");
+    assert_eq!(
+        table.symbol().and_then(|symbol| symbol.doc).as_deref(),
+        Some("Indexed collections")
+    );
+    assert_eq!((chunks[2].start_line, chunks[2].end_line), (8, 13));
+}
+
+#[test]
+fn test_data_files_are_detected() {
+    assert_eq!(detect_language("config/default.toml", ""), "toml");
+    assert_eq!(detect_language("docker-compose.yml", ""), "yaml");
+    assert_eq!(detect_language("k8s/deploy.yaml", ""), "yaml");
+    assert_eq!(detect_language("package.json", ""), "json");
+    assert_eq!(detect_language("migrations/001_init.sql", ""), "sql");
+}
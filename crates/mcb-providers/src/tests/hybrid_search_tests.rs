@@ -1,10 +1,16 @@
 //! Tests for hybrid search providers
 
 use crate::constants::{HYBRID_SEARCH_BM25_WEIGHT, HYBRID_SEARCH_SEMANTIC_WEIGHT};
-use crate::hybrid_search::{BM25Params, BM25Scorer, HybridSearchEngine, NullHybridSearchProvider};
+use crate::hybrid_search::analyzer::{split_identifier, stem_word};
+use crate::hybrid_search::{
+    AnalyzerConfig, BM25Params, BM25Scorer, CodeAnalyzer, CollectionIndex, FusionParams,
+    HybridSearchEngine, IndexStorage, NullHybridSearchProvider, fuse,
+};
 use mcb_domain::entities::CodeChunk;
 use mcb_domain::ports::providers::HybridSearchProvider;
-use mcb_domain::value_objects::SearchResult;
+use mcb_domain::value_objects::{
+    FilterField, FusionStrategy, MetadataFilter, SearchQuery, SearchResult,
+};
 
 // ============================================================================
 // Test Helpers
@@ -30,6 +36,7 @@ fn create_test_search_result(file_path: &str, start_line: u32, score: f64) -> Se
         start_line,
         score,
         language: "Rust".to_string(),
+        symbol: None,
     }
 }
 
@@ -198,9 +205,13 @@ async fn test_hybrid_search() {
     assert_eq!(results.len(), 2);
     // Auth chunk should rank higher due to strong BM25 boost overcoming semantic difference
     assert_eq!(
-        results[0].file_path, "auth.rs",
+        results[0].result.file_path, "auth.rs",
         "Auth should rank first due to BM25 boost"
     );
+    // Per-component scores are preserved alongside the fused score
+    assert!(results[0].bm25_score > results[1].bm25_score);
+    assert!((results[0].semantic_score - 0.7).abs() < 1e-6);
+    assert!((results[0].result.score - results[0].hybrid_score as f64).abs() < 1e-6);
 }
 
 #[tokio::test]
@@ -235,7 +246,459 @@ async fn test_search_without_index() {
         .unwrap();
 
     assert_eq!(results.len(), 2);
-    assert_eq!(results[0].file_path, "a.rs");
+    assert_eq!(results[0].result.file_path, "a.rs");
+    assert_eq!(results[0].bm25_score, 0.0);
+}
+
+#[test]
+fn test_bm25_rank_retrieves_matching_documents() {
+    let chunks = vec![
+        create_test_chunk("fn parse_config() {}", "config.rs", 1),
+        create_test_chunk("fn render_page() {}", "view.rs", 1),
+        create_test_chunk("fn parse_config_file() { parse_config() }", "loader.rs", 1),
+    ];
+    let scorer = BM25Scorer::new(&chunks, BM25Params::default());
+
+    let ranked = scorer.rank(&scorer.query_terms("parse_config"));
+
+    // Only documents sharing a term are returned, best first
+    assert_eq!(ranked.len(), 2);
+    assert!(ranked.iter().all(|(position, _)| *position != 1));
+    assert!(ranked[0].1 >= ranked[1].1);
+    for (position, score) in ranked {
+        let expected = scorer.score(&chunks[position], "parse_config");
+        assert!((score - expected).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn test_fuse_strategies() {
+    let params = FusionParams::default();
+    let bm25 = [Some(8.0), None, Some(2.0)];
+    let semantic = [Some(0.5), Some(0.9), None];
+
+    let linear = fuse(FusionStrategy::Linear, &params, &bm25, &semantic);
+    assert!((linear[0] - (params.bm25_weight + params.semantic_weight * 0.5)).abs() < 1e-6);
+    assert!((linear[1] - params.semantic_weight * 0.9).abs() < 1e-6);
+    assert!((linear[2] - params.bm25_weight * 0.25).abs() < 1e-6);
+
+    let rrf = fuse(FusionStrategy::ReciprocalRank, &params, &bm25, &semantic);
+    let k = params.rrf_k;
+    assert!((rrf[0] - (1.0 / (k + 1.0) + 1.0 / (k + 2.0))).abs() < 1e-6);
+    assert!((rrf[1] - 1.0 / (k + 1.0)).abs() < 1e-6);
+    assert!((rrf[2] - 1.0 / (k + 2.0)).abs() < 1e-6);
+
+    let distribution = fuse(FusionStrategy::DistributionBased, &params, &bm25, &semantic);
+    assert!(distribution.iter().all(|s| (0.0..=1.0).contains(s)));
+    assert!(distribution[0] > distribution[2]);
+}
+
+#[tokio::test]
+async fn test_hybrid_search_surfaces_lexical_only_hits() {
+    let engine = HybridSearchEngine::new();
+    let chunks = vec![
+        create_test_chunk("fn load_settings() {}", "settings.rs", 1),
+        create_test_chunk(
+            "struct TokenBucketLimiter { capacity: u32 }",
+            "limiter.rs",
+            1,
+        ),
+        create_test_chunk("fn start_server() {}", "server.rs", 1),
+    ];
+    engine.index_chunks("test", &chunks).await.unwrap();
+
+    // The vector store missed the identifier entirely
+    let semantic_results = vec![
+        create_test_search_result("settings.rs", 1, 0.8),
+        create_test_search_result("server.rs", 1, 0.7),
+    ];
+
+    let results = engine
+        .search("test", "TokenBucketLimiter", semantic_results, 10)
+        .await
+        .unwrap();
+
+    assert_eq!(results.len(), 3);
+    let hit = results
+        .iter()
+        .find(|r| r.result.file_path == "limiter.rs")
+        .expect("lexical hit should be retrieved");
+    assert_eq!(hit.semantic_score, 0.0);
+    assert_eq!(hit.bm25_score, 1.0);
+    assert_eq!(hit.result.content, chunks[1].content);
+}
+
+#[tokio::test]
+async fn test_hybrid_search_reciprocal_rank_fusion() {
+    let engine = HybridSearchEngine::new();
+    let chunks = vec![
+        create_test_chunk("fn retry_request() { backoff() }", "retry.rs", 1),
+        create_test_chunk("fn backoff() {}", "backoff.rs", 1),
+        create_test_chunk("fn log_error() {}", "log.rs", 1),
+    ];
+    engine.index_chunks("test", &chunks).await.unwrap();
+
+    let semantic_results = vec![
+        create_test_search_result("log.rs", 1, 0.9),
+        create_test_search_result("retry.rs", 1, 0.8),
+    ];
+    let query = SearchQuery::new("retry_request", 10).with_fusion(FusionStrategy::ReciprocalRank);
+
+    let results = engine
+        .search_query("test", &query, semantic_results)
+        .await
+        .unwrap();
+
+    // retry.rs is first lexically and second semantically
+    let k = engine.rrf_k();
+    assert_eq!(results[0].result.file_path, "retry.rs");
+    assert!((results[0].hybrid_score - (1.0 / (k + 1.0) + 1.0 / (k + 2.0))).abs() < 1e-6);
+    assert_eq!(results[1].result.file_path, "log.rs");
+    assert!((results[1].hybrid_score - 1.0 / (k + 1.0)).abs() < 1e-6);
+}
+
+#[tokio::test]
+async fn test_hybrid_search_default_fusion_from_engine() {
+    let engine = HybridSearchEngine::new().with_fusion(FusionStrategy::DistributionBased);
+    assert_eq!(engine.fusion(), FusionStrategy::DistributionBased);
+
+    let chunks = vec![
+        create_test_chunk("fn open_socket() {}", "net.rs", 1),
+        create_test_chunk("fn close_socket() {}", "net.rs", 5),
+    ];
+    engine.index_chunks("test", &chunks).await.unwrap();
+
+    let semantic_results = vec![
+        create_test_search_result("net.rs", 1, 0.9),
+        create_test_search_result("net.rs", 5, 0.3),
+    ];
+
+    let results = engine
+        .search("test", "open_socket", semantic_results, 10)
+        .await
+        .unwrap();
+
+    assert_eq!(results[0].result.start_line, 1);
+    assert!(
+        results
+            .iter()
+            .all(|r| (0.0..=1.0).contains(&r.hybrid_score))
+    );
+
+    let stats = engine.get_stats().await;
+    assert_eq!(
+        stats.get("fusion"),
+        Some(&serde_json::json!("distribution_based"))
+    );
+}
+
+#[tokio::test]
+async fn test_hybrid_search_filters_lexical_hits() {
+    let engine = HybridSearchEngine::new();
+    let chunks = vec![
+        create_test_chunk("fn parse_header() {}", "src/http.rs", 1),
+        create_test_chunk(
+            "fn parse_header_test() { parse_header() }",
+            "tests/http.rs",
+            1,
+        ),
+    ];
+    engine.index_chunks("test", &chunks).await.unwrap();
+
+    let query = SearchQuery::new("parse_header", 10)
+        .with_filter(MetadataFilter::prefix(FilterField::FilePath, "src/"));
+
+    let results = engine
+        .search_query("test", &query, Vec::new())
+        .await
+        .unwrap();
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].result.file_path, "src/http.rs");
+}
+
+#[test]
+fn test_bm25_scorer_add_and_remove_documents() {
+    let chunks = vec![
+        create_test_chunk("fn parse_config() {}", "config.rs", 1),
+        create_test_chunk("fn render_page() {}", "view.rs", 1),
+    ];
+    let mut scorer = BM25Scorer::new(&chunks, BM25Params::default());
+    let rebuilt = BM25Scorer::new(&chunks[1..], BM25Params::default());
+
+    assert!(scorer.remove_document(0, &chunks[0]));
+    assert!(!scorer.remove_document(0, &chunks[0]));
+
+    // Removing leaves the same statistics as never indexing
+    assert_eq!(scorer.total_docs(), rebuilt.total_docs());
+    assert_eq!(scorer.unique_terms(), rebuilt.unique_terms());
+    assert!(scorer.rank(&scorer.query_terms("parse_config")).is_empty());
+
+    scorer.add_document(0, &create_test_chunk("fn load_config() {}", "config.rs", 1));
+    let ranked = scorer.rank(&scorer.query_terms("config"));
+    assert_eq!(ranked.len(), 1);
+    assert_eq!(ranked[0].0, 0);
+}
+
+#[test]
+fn test_collection_index_upsert_replaces_chunks() {
+    let mut index = CollectionIndex::new(BM25Params::default(), CodeAnalyzer::default());
+    let original = create_test_chunk("fn parse_config() {}", "config.rs", 1);
+    index.upsert(&original);
+
+    // Re-indexed chunk: same id, new content
+    let mut edited = create_test_chunk("fn load_settings() {}", "config.rs", 1);
+    edited.id = original.id.clone();
+    index.upsert(&edited);
+
+    assert_eq!(index.len(), 1);
+    assert!(index.rank("parse").is_empty());
+    let slot = index.slot_of(&edited.id).expect("chunk indexed");
+    assert_eq!(index.document(slot), Some(&edited));
+
+    // A chunk nested on the same row is a document of its own
+    let mut nested = create_test_chunk("const load = () => settings();", "config.rs", 1);
+    nested.id = "config.rs:1:arrow".to_string();
+    index.upsert(&nested);
+    assert_eq!(index.len(), 2);
+    assert_eq!(index.document(slot), Some(&edited));
+
+    assert!(index.remove(&edited.id));
+    assert!(index.remove(&nested.id));
+    assert!(index.is_empty());
+    assert_eq!(index.slot_of(&edited.id), None);
+}
+
+// ============================================================================
+// Analyzer Tests
+// ============================================================================
+
+#[test]
+fn test_split_identifier() {
+    assert_eq!(
+        split_identifier("IndexingServiceImpl"),
+        vec!["indexing", "service", "impl"]
+    );
+    assert_eq!(
+        split_identifier("get_indexing_status"),
+        vec!["get", "indexing", "status"]
+    );
+    assert_eq!(
+        split_identifier("HTTPServerError"),
+        vec!["http", "server", "error"]
+    );
+    assert_eq!(split_identifier("utf8Decoder"), vec!["utf8", "decoder"]);
+}
+
+#[test]
+fn test_analyzer_keeps_full_identifier_and_drops_keywords() {
+    let analyzer = CodeAnalyzer::default();
+    let terms = analyzer.analyze("pub struct IndexingServiceImpl;", "rust");
+
+    assert!(terms.contains(&"indexing".to_string()));
+    assert!(terms.contains(&"service".to_string()));
+    assert!(terms.contains(&"indexingserviceimpl".to_string()));
+    assert!(!terms.contains(&"pub".to_string()));
+    assert!(!terms.contains(&"struct".to_string()));
+
+    // Keywords of other languages are ordinary words
+    let terms = analyzer.analyze("struct Point", "python");
+    assert!(terms.contains(&"struct".to_string()));
+}
+
+#[test]
+fn test_split_identifiers_match_natural_language_queries() {
+    let chunks = vec![
+        create_test_chunk("pub struct IndexingServiceImpl {}", "indexing.rs", 1),
+        create_test_chunk("pub struct SearchServiceImpl {}", "search.rs", 1),
+    ];
+    let scorer = BM25Scorer::new(&chunks, BM25Params::default());
+    let ranked = scorer.rank(&scorer.query_terms("indexing service"));
+
+    assert_eq!(ranked[0].0, 0);
+    assert!(
+        scorer.score(&chunks[0], "IndexingServiceImpl")
+            > scorer.score(&chunks[1], "IndexingServiceImpl")
+    );
+
+    let mut unsplit = BM25Scorer::empty(
+        BM25Params::default(),
+        CodeAnalyzer::new(AnalyzerConfig {
+            split_identifiers: false,
+            ..AnalyzerConfig::default()
+        }),
+    );
+    unsplit.add_document(0, &chunks[0]);
+    // Without splitting the identifier is one opaque term
+    assert!(unsplit.rank(&unsplit.query_terms("indexing")).is_empty());
+}
+
+#[test]
+fn test_stemming_matches_inflected_comments() {
+    assert_eq!(stem_word("indexes"), stem_word("indexing"));
+    assert_eq!(stem_word("caching"), stem_word("cache"));
+    assert_eq!(stem_word("status"), "status");
+
+    let chunk = create_test_chunk(
+        "/// Rebuilds the indexes of a collection\nfn rebuild() {}",
+        "rebuild.rs",
+        1,
+    );
+    let analyzer = CodeAnalyzer::new(AnalyzerConfig {
+        stemming: true,
+        ..AnalyzerConfig::default()
+    });
+    let mut stemmed = BM25Scorer::empty(BM25Params::default(), analyzer);
+    stemmed.add_document(0, &chunk);
+    let unstemmed = BM25Scorer::new(std::slice::from_ref(&chunk), BM25Params::default());
+
+    assert_eq!(stemmed.rank(&stemmed.query_terms("indexing")).len(), 1);
+    assert!(
+        unstemmed
+            .rank(&unstemmed.query_terms("indexing"))
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_ngrams_match_partial_identifiers() {
+    let chunks = [
+        create_test_chunk("struct TokenBucketLimiter;", "limiter.rs", 1),
+        create_test_chunk("struct RequestQueue;", "queue.rs", 1),
+    ];
+    let analyzer = CodeAnalyzer::new(AnalyzerConfig {
+        ngram_size: Some(3),
+        ..AnalyzerConfig::default()
+    });
+    let mut scorer = BM25Scorer::empty(BM25Params::default(), analyzer);
+    for (position, chunk) in chunks.iter().enumerate() {
+        scorer.add_document(position, chunk);
+    }
+
+    // "buck" is no word of either chunk, only a prefix of "bucket"
+    assert!(scorer.rank(&["buck".to_string()]).is_empty());
+    let ranked = scorer.rank(&scorer.query_terms("Buck"));
+    assert_eq!(ranked.len(), 1);
+    assert_eq!(ranked[0].0, 0);
+}
+
+#[test]
+fn test_collection_index_reanalyzes_on_analyzer_change() {
+    let mut index = CollectionIndex::new(BM25Params::default(), CodeAnalyzer::default());
+    index.upsert(&create_test_chunk(
+        "struct TokenBucketLimiter;",
+        "limiter.rs",
+        1,
+    ));
+    assert!(index.rank("Buck").is_empty());
+
+    index.set_analyzer(CodeAnalyzer::new(AnalyzerConfig {
+        ngram_size: Some(3),
+        ..AnalyzerConfig::default()
+    }));
+    assert_eq!(index.rank("Buck").len(), 1);
+}
+
+#[test]
+fn test_index_storage_compaction_threshold() {
+    assert!(!IndexStorage::should_compact(10, 5));
+    assert!(!IndexStorage::should_compact(5_000, 10_000));
+    assert!(IndexStorage::should_compact(10_001, 10_000));
+}
+
+#[tokio::test]
+async fn test_remove_chunks_and_file_chunks() {
+    let engine = HybridSearchEngine::new();
+    let chunks = vec![
+        create_test_chunk("fn parse_header() {}", "http.rs", 1),
+        create_test_chunk("fn parse_body() {}", "http.rs", 10),
+        create_test_chunk("fn parse_args() {}", "cli.rs", 1),
+    ];
+    engine.index_chunks("test", &chunks).await.unwrap();
+
+    engine
+        .remove_chunks("test", &[chunks[2].id.clone()])
+        .await
+        .unwrap();
+    let results = engine
+        .search("test", "parse", Vec::new(), 10)
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|r| r.result.file_path == "http.rs"));
+
+    engine
+        .remove_file_chunks("test", &["http.rs".to_string()])
+        .await
+        .unwrap();
+    let results = engine
+        .search("test", "parse", Vec::new(), 10)
+        .await
+        .unwrap();
+    assert!(results.is_empty());
+}
+
+#[tokio::test]
+async fn test_persisted_index_survives_restart() {
+    let dir = tempfile::tempdir().unwrap();
+    let chunks = vec![
+        create_test_chunk("struct TokenBucketLimiter {}", "limiter.rs", 1),
+        create_test_chunk("fn start_server() {}", "server.rs", 1),
+        create_test_chunk("fn stop_server() {}", "server.rs", 5),
+    ];
+
+    let engine = HybridSearchEngine::new().with_index_path(dir.path());
+    engine.index_chunks("test", &chunks).await.unwrap();
+    engine
+        .remove_file_chunks("test", &["server.rs".to_string()])
+        .await
+        .unwrap();
+    drop(engine);
+
+    // A new engine restores the collection from its journal
+    let restarted = HybridSearchEngine::new().with_index_path(dir.path());
+    let results = restarted
+        .search("test", "TokenBucketLimiter", Vec::new(), 10)
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].result.content, chunks[0].content);
+    assert!(
+        restarted
+            .search("test", "server", Vec::new(), 10)
+            .await
+            .unwrap()
+            .is_empty()
+    );
+
+    // The replayed journal was compacted into a snapshot
+    assert!(dir.path().join("test_bm25.json").exists());
+    assert!(!dir.path().join("test_bm25.jsonl").exists());
+
+    restarted.clear_collection("test").await.unwrap();
+    assert!(!dir.path().join("test_bm25.json").exists());
+    let cleared = HybridSearchEngine::new().with_index_path(dir.path());
+    let stats = cleared.get_stats().await;
+    assert_eq!(stats.get("collection_count"), Some(&serde_json::json!(0)));
+}
+
+#[tokio::test]
+async fn test_torn_first_journal_entry_is_compacted_away() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("test_bm25.jsonl"), "{\"op\":\"ups").unwrap();
+
+    let engine = HybridSearchEngine::new().with_index_path(dir.path());
+    let chunk = create_test_chunk("struct TokenBucketLimiter {}", "limiter.rs", 1);
+    engine.index_chunks("test", &[chunk]).await.unwrap();
+    drop(engine);
+
+    // Entries appended after the torn one are restored
+    let restarted = HybridSearchEngine::new().with_index_path(dir.path());
+    let results = restarted
+        .search("test", "TokenBucketLimiter", Vec::new(), 10)
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 1);
 }
 
 // ============================================================================
@@ -268,9 +731,10 @@ async fn test_null_provider_search_passthrough() {
 
     // Should return first 2 results unchanged
     assert_eq!(results.len(), 2);
-    assert_eq!(results[0].file_path, "a.rs");
-    assert!((results[0].score - 0.9).abs() < f64::EPSILON);
-    assert_eq!(results[1].file_path, "b.rs");
+    assert_eq!(results[0].result.file_path, "a.rs");
+    assert!((results[0].result.score - 0.9).abs() < f64::EPSILON);
+    assert!((results[0].hybrid_score - 0.9).abs() < 1e-6);
+    assert_eq!(results[1].result.file_path, "b.rs");
 }
 
 #[tokio::test]
@@ -0,0 +1,276 @@
+//! Tests for the Scala, Elixir, Lua, Bash, Haskell and Zig processors
+
+use crate::language::{
+    BashProcessor, ElixirProcessor, HaskellProcessor, IntelligentChunker, LanguageProcessor,
+    LuaProcessor, ScalaProcessor, ZigProcessor, is_language_supported,
+};
+use mcb_domain::entities::CodeChunk;
+use mcb_domain::value_objects::CodeSymbol;
+
+fn chunk(content: &str, file_name: &str, language: &str) -> Vec<CodeChunk> {
+    IntelligentChunker::new().chunk_code(content, file_name, &language.into())
+}
+
+fn symbol_named(chunks: &[CodeChunk], name: &str) -> CodeSymbol {
+    chunks
+        .iter()
+        .filter_map(CodeChunk::symbol)
+        .find(|symbol| symbol.name == name)
+        .unwrap_or_else(|| panic!("no chunk for symbol {name}"))
+}
+
+#[test]
+fn test_new_languages_are_chunked_by_ast() {
+    for language in ["scala", "elixir", "lua", "shell", "haskell", "zig"] {
+        assert!(is_language_supported(language), "{language} not supported");
+    }
+}
+
+#[test]
+fn test_new_grammars_parse_with_the_linked_tree_sitter() {
+    let sources: [(&str, Box<dyn LanguageProcessor>, &str); 6] = [
+        (
+            "scala",
+            Box::new(ScalaProcessor::new()),
+            "object Main { def run(x: Int): Int = x }",
+        ),
+        (
+            "elixir",
+            Box::new(ElixirProcessor::new()),
+            "defmodule Main do\n  def run(x), do: x\nend\n",
+        ),
+        (
+            "lua",
+            Box::new(LuaProcessor::new()),
+            "local function run(x) return x end\n",
+        ),
+        (
+            "bash",
+            Box::new(BashProcessor::new()),
+            "run() { echo \"$1\"; }\n",
+        ),
+        (
+            "haskell",
+            Box::new(HaskellProcessor::new()),
+            "run :: Int -> Int\nrun x = x\n",
+        ),
+        (
+            "zig",
+            Box::new(ZigProcessor::new()),
+            "fn run(x: i32) i32 { return x; }\n",
+        ),
+    ];
+
+    for (language, processor, source) in sources {
+        // A grammar built for an incompatible ABI is rejected here
+        let mut parser = tree_sitter::Parser::new();
+        parser
+            .set_language(&processor.config().get_language())
+            .unwrap_or_else(|e| panic!("{language} grammar rejected: {e}"));
+        let tree = parser
+            .parse(source, None)
+            .unwrap_or_else(|| panic!("{language} source not parsed"));
+        assert!(
+            !tree.root_node().has_error(),
+            "{language} parse errors: {}",
+            tree.root_node().to_sexp()
+        );
+    }
+}
+
+#[test]
+fn test_scala_methods_are_scoped_by_their_class() {
+    let content = r#"
+/** Indexes repositories into the vector store */
+class RepositoryIndexer(store: VectorStore) {
+  def index(files: Seq[String]): Int = {
+    val chunks = files.flatMap(chunker.chunk)
+    store.insert(chunks)
+    chunks.size
+  }
+}
+
+
+object RepositoryIndexer {
+  def apply(store: VectorStore): RepositoryIndexer =
+    new RepositoryIndexer(store)
+}
+"#;
+    let chunks = chunk(content, "src/Indexer.scala", "scala");
+
+    let method = symbol_named(&chunks, "index");
+    assert_eq!(method.kind, "fn");
+    assert_eq!(
+        method.parent.as_deref(),
+        Some("class RepositoryIndexer(store: VectorStore)")
+    );
+    let class = symbol_named(&chunks, "RepositoryIndexer");
+    assert_eq!(
+        class.doc.as_deref(),
+        Some("Indexes repositories into the vector store")
+    );
+}
+
+#[test]
+fn test_elixir_definitions_are_named_by_their_arguments() {
+    let content = r#"
+defmodule Mcb.Indexer do
+  # Index every file of the repository
+  def index(repo, opts) when is_list(opts) do
+    repo
+    |> Repo.files()
+    |> Enum.map(&chunk/1)
+  end
+
+
+  defp chunk(file) do
+    file
+    |> File.read!()
+    |> Chunker.split()
+  end
+end
+"#;
+    let chunks = chunk(content, "lib/indexer.ex", "elixir");
+
+    let module = symbol_named(&chunks, "Mcb.Indexer");
+    assert_eq!(module.kind, "module");
+    let index = symbol_named(&chunks, "index");
+    assert_eq!(index.kind, "fn");
+    assert_eq!(index.parent.as_deref(), Some("defmodule Mcb.Indexer"));
+    assert_eq!(
+        index.doc.as_deref(),
+        Some("Index every file of the repository")
+    );
+    assert_eq!(symbol_named(&chunks, "chunk").kind, "fn");
+
+    // Calls that define nothing are not chunks
+    assert!(
+        chunks
+            .iter()
+            .filter_map(CodeChunk::symbol)
+            .all(|symbol| !["Enum", "File", "Repo"].contains(&symbol.name.as_str()))
+    );
+}
+
+#[test]
+fn test_lua_functions_keep_their_qualified_names() {
+    let content = r#"
+local M = {}
+
+--- Start the indexer for the given workspace
+function M.start(workspace)
+  local client = vim.lsp.start_client({ name = "mcb" })
+  return client
+end
+
+
+local function notify(message)
+  vim.notify("mcb: " .. message, vim.log.levels.INFO)
+end
+
+return M
+"#;
+    let chunks = chunk(content, "lua/mcb/init.lua", "lua");
+
+    let start = symbol_named(&chunks, "M.start");
+    assert_eq!(start.kind, "fn");
+    assert_eq!(
+        start.doc.as_deref(),
+        Some("Start the indexer for the given workspace")
+    );
+    symbol_named(&chunks, "notify");
+}
+
+#[test]
+fn test_bash_functions_are_chunks() {
+    let content = r#"#!/usr/bin/env bash
+set -euo pipefail
+
+# Deploy the server image to the cluster
+deploy() {
+  local tag="$1"
+  kubectl set image deployment/mcb-server server="mcb:${tag}"
+  kubectl rollout status deployment/mcb-server
+}
+
+
+function rollback {
+  kubectl rollout undo deployment/mcb-server
+  kubectl rollout status deployment/mcb-server
+}
+
+deploy "$@"
+"#;
+    let chunks = chunk(content, "scripts/deploy.sh", "shell");
+
+    let deploy = symbol_named(&chunks, "deploy");
+    assert_eq!(deploy.kind, "fn");
+    assert_eq!(
+        deploy.doc.as_deref(),
+        Some("Deploy the server image to the cluster")
+    );
+    symbol_named(&chunks, "rollback");
+}
+
+#[test]
+fn test_haskell_declarations_take_haddock_docs() {
+    let content = r#"
+module Indexer (indexFiles) where
+
+import qualified Data.Map as Map
+
+-- | Chunks indexed for a single file
+data FileChunks = FileChunks
+  { filePath :: FilePath
+  , chunks :: [String]
+  }
+
+
+-- | Index the given files
+indexFiles :: [FilePath] -> IO (Map.Map FilePath FileChunks)
+indexFiles paths = do
+  entries <- mapM readChunks paths
+  pure (Map.fromList (zip paths entries))
+"#;
+    let chunks = chunk(content, "src/Indexer.hs", "haskell");
+
+    let function = symbol_named(&chunks, "indexFiles");
+    assert_eq!(function.kind, "fn");
+    assert_eq!(function.doc.as_deref(), Some("Index the given files"));
+    let data = symbol_named(&chunks, "FileChunks");
+    assert_eq!(
+        data.doc.as_deref(),
+        Some("Chunks indexed for a single file")
+    );
+}
+
+#[test]
+fn test_zig_functions_and_containers_are_chunks() {
+    let content = r#"
+const std = @import("std");
+
+/// A chunk of indexed source code
+pub const Chunk = struct {
+    path: []const u8,
+    start_line: u32,
+};
+
+
+/// Split `source` into chunks of at most `max_lines` lines
+pub fn split(allocator: std.mem.Allocator, source: []const u8, max_lines: u32) ![]Chunk {
+    var chunks = std.ArrayList(Chunk).init(allocator);
+    _ = source;
+    _ = max_lines;
+    return chunks.toOwnedSlice();
+}
+"#;
+    let chunks = chunk(content, "src/chunker.zig", "zig");
+
+    let split = symbol_named(&chunks, "split");
+    assert_eq!(split.kind, "fn");
+    assert_eq!(
+        split.doc.as_deref(),
+        Some("Split `source` into chunks of at most `max_lines` lines")
+    );
+    symbol_named(&chunks, "Chunk");
+}
@@ -0,0 +1,35 @@
+//! Tests for language detection used by file discovery and chunking
+
+use crate::language::{UniversalLanguageChunkingProvider, detect_language};
+use mcb_domain::ports::providers::LanguageChunkingProvider;
+
+#[test]
+fn test_detect_language_by_extension_name_and_shebang() {
+    assert_eq!(detect_language("src/App.kt", ""), "kotlin");
+    assert_eq!(detect_language("ui/View.tsx", ""), "typescript");
+    assert_eq!(detect_language("src/Indexer.scala", ""), "scala");
+    assert_eq!(detect_language("lib/indexer.ex", ""), "elixir");
+    assert_eq!(detect_language("lua/mcb/init.lua", ""), "lua");
+    assert_eq!(detect_language("src/Indexer.hs", ""), "haskell");
+    assert_eq!(detect_language("src/chunker.zig", ""), "zig");
+    assert_eq!(detect_language("docker/Dockerfile", ""), "dockerfile");
+    assert_eq!(detect_language("Makefile", ""), "makefile");
+    assert_eq!(
+        detect_language("bin/deploy", "#!/usr/bin/env python3\nprint()"),
+        "python"
+    );
+    assert_eq!(detect_language("bin/setup", "#!/bin/bash\necho"), "shell");
+    assert_eq!(detect_language("notes", "just text"), "unknown");
+}
+
+#[test]
+fn test_universal_provider_advertises_all_known_files() {
+    let provider = UniversalLanguageChunkingProvider::new();
+
+    for ext in ["hpp", "kts", "jsx", "cs", "swift", "rb", "exs", "sc", "zig"] {
+        assert!(provider.supports_extension(ext), "missing extension {ext}");
+    }
+    assert!(provider.supports_file("Dockerfile", None));
+    assert!(provider.supports_file("run", Some("#!/usr/bin/env node")));
+    assert!(!provider.supports_file("LICENSE", None));
+}
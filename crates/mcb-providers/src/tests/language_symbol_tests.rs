@@ -0,0 +1,161 @@
+//! Tests for symbol and import metadata extracted by the language processors
+
+use crate::language::IntelligentChunker;
+use mcb_domain::entities::CodeChunk;
+use mcb_domain::value_objects::CodeSymbol;
+
+fn symbol_named(chunks: &[CodeChunk], name: &str) -> CodeSymbol {
+    chunks
+        .iter()
+        .filter_map(CodeChunk::symbol)
+        .find(|symbol| symbol.name == name)
+        .unwrap_or_else(|| panic!("no chunk for symbol {name}"))
+}
+
+#[test]
+fn test_rust_method_symbol_has_parent_signature_and_doc() {
+    let content = r#"
+pub struct ConfigLoader {
+    root: std::path::PathBuf,
+}
+
+impl ConfigLoader {
+    /// Load the configuration file
+    ///
+    /// Falls back to defaults when the file is missing.
+    #[inline]
+    pub fn load_config(&self, name: &str) -> Result<Config, Error> {
+        let path = self.root.join(name);
+        read_config(&path)
+    }
+}
+"#;
+    let chunks = IntelligentChunker::new().chunk_code(content, "src/config.rs", &"rust".into());
+
+    let symbol = symbol_named(&chunks, "load_config");
+    assert_eq!(symbol.kind, "fn");
+    assert_eq!(symbol.parent.as_deref(), Some("impl ConfigLoader"));
+    assert_eq!(
+        symbol.signature.as_deref(),
+        Some("pub fn load_config(&self, name: &str) -> Result<Config, Error>")
+    );
+    assert_eq!(
+        symbol.doc.as_deref(),
+        Some("Load the configuration file\n\nFalls back to defaults when the file is missing.")
+    );
+    assert_eq!(
+        symbol.to_string(),
+        "fn `load_config` in `impl ConfigLoader`"
+    );
+
+    let impl_symbol = symbol_named(&chunks, "ConfigLoader");
+    assert!(["impl", "struct"].contains(&impl_symbol.kind.as_str()));
+    assert_eq!(impl_symbol.parent, None);
+}
+
+#[test]
+fn test_python_symbols_use_docstrings() {
+    let content = r#"
+class Repository:
+    """Stores indexed documents."""
+
+    def find(self, query, limit=10):
+        """Return the documents matching the query."""
+        return [doc for doc in self.docs if query in doc][:limit]
+"#;
+    let chunks = IntelligentChunker::new().chunk_code(content, "repo.py", &"python".into());
+
+    let method = symbol_named(&chunks, "find");
+    assert_eq!(method.kind, "fn");
+    assert_eq!(method.parent.as_deref(), Some("class Repository"));
+    assert_eq!(
+        method.signature.as_deref(),
+        Some("def find(self, query, limit=10)")
+    );
+    assert_eq!(
+        method.doc.as_deref(),
+        Some("Return the documents matching the query.")
+    );
+
+    let class = symbol_named(&chunks, "Repository");
+    assert_eq!(class.kind, "class");
+    assert_eq!(class.doc.as_deref(), Some("Stores indexed documents."));
+}
+
+#[test]
+fn test_symbol_changes_chunk_identity() {
+    let content = "fn first() {\n    work();\n    more_work();\n}\n\nfn second() {\n    work();\n    more_work();\n}\n";
+    let chunks = IntelligentChunker::new().chunk_code(content, "src/lib.rs", &"rust".into());
+
+    let mut names: Vec<String> = chunks
+        .iter()
+        .filter_map(CodeChunk::symbol)
+        .map(|symbol| symbol.name)
+        .collect();
+    names.sort();
+    assert_eq!(names, ["first", "second"]);
+    assert_ne!(chunks[0].id, chunks[1].id);
+}
+
+#[test]
+fn test_chunks_record_the_imports_they_use() {
+    let content = r#"
+use std::collections::{HashMap, HashSet};
+use std::net::TcpListener;
+
+/// Words shorter than this are not indexed
+const MIN_WORD_LENGTH: usize = 3;
+
+/// Words never indexed
+const STOP_WORDS: &[&str] = &["the", "and"];
+
+pub fn index_words(words: &[String]) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for word in words {
+        *counts.entry(word.clone()).or_insert(0) += 1;
+    }
+    counts
+}
+"#;
+    let chunks = IntelligentChunker::new().chunk_code(content, "src/words.rs", &"rust".into());
+
+    let function = chunks
+        .iter()
+        .find(|chunk| {
+            chunk
+                .symbol()
+                .is_some_and(|symbol| symbol.name == "index_words")
+        })
+        .expect("function chunk");
+    assert_eq!(
+        function.imports(),
+        ["use std::collections::{HashMap, HashSet};"]
+    );
+}
+
+#[test]
+fn test_grouped_go_imports_are_recorded_per_spec() {
+    let content = r#"
+package server
+
+import (
+    "fmt"
+    "net/http"
+)
+
+func Serve(addr string) error {
+    fmt.Println("listening on", addr)
+    return http.ListenAndServe(addr, nil)
+}
+"#;
+    let chunks = IntelligentChunker::new().chunk_code(content, "server.go", &"go".into());
+
+    let function = chunks
+        .iter()
+        .find(|chunk| chunk.symbol().is_some_and(|symbol| symbol.name == "Serve"))
+        .expect("function chunk");
+    assert_eq!(
+        function.imports(),
+        ["import \"fmt\"", "import \"net/http\""]
+    );
+}
@@ -0,0 +1,201 @@
+//! Tests for section and code block chunking of documentation files
+
+use crate::language::{IntelligentChunker, detect_language};
+use mcb_domain::entities::CodeChunk;
+use mcb_domain::value_objects::ChunkSizePolicy;
+
+fn chunk(content: &str, file_name: &str) -> Vec<CodeChunk> {
+    let language = detect_language(file_name, content);
+    IntelligentChunker::new().chunk_code(content, file_name, &language)
+}
+
+fn heading_path(chunk: &CodeChunk) -> Vec<String> {
+    serde_json::from_value(chunk.metadata["heading_path"].clone()).unwrap_or_default()
+}
+
+fn sections(chunks: &[CodeChunk]) -> Vec<&CodeChunk> {
+    chunks
+        .iter()
+        .filter(|chunk| chunk.metadata["node_type"] == "section")
+        .collect()
+}
+
+#[test]
+fn test_markdown_sections_keep_their_heading_path() {
+    let content = r#"---
+status: accepted
+---
+
+# ADR 024: Dependency injection with dill
+
+## Context
+
+Shaku needs a module per component and macros on every provider.
+
+## Decision
+
+We choose dill over shaku because catalogs are built at runtime.
+
+```rust
+let catalog = dill::CatalogBuilder::new().build();
+```
+
+### Consequences
+
+Providers no longer derive `Component`.
+"#;
+    let chunks = chunk(content, "docs/adr/024-dill.md");
+
+    let paths: Vec<Vec<String>> = sections(&chunks).into_iter().map(heading_path).collect();
+    assert_eq!(
+        paths,
+        [
+            vec![],
+            vec!["ADR 024: Dependency injection with dill", "Context"],
+            vec!["ADR 024: Dependency injection with dill", "Decision"],
+            vec![
+                "ADR 024: Dependency injection with dill",
+                "Decision",
+                "Consequences"
+            ],
+        ]
+    );
+
+    let decision = sections(&chunks)[2];
+    assert!(decision.content.starts_with("## Decision"));
+    assert!(decision.content.contains("dill over shaku"));
+    assert!(!decision.content.contains("CatalogBuilder"));
+    assert_eq!(decision.language, "markdown");
+    let symbol = decision.symbol().expect("section symbol");
+    assert_eq!(symbol.kind, "section");
+    assert_eq!(
+        symbol.parent.as_deref(),
+        Some("ADR 024: Dependency injection with dill")
+    );
+
+    let code = chunks
+        .iter()
+        .find(|chunk| chunk.metadata["node_type"] == "code_block")
+        .expect("code block chunk");
+    assert_eq!(code.language, "rust");
+    assert_eq!(
+        code.content,
+        "let catalog = dill::CatalogBuilder::new().build();"
+    );
+    assert_eq!(
+        heading_path(code),
+        ["ADR 024: Dependency injection with dill", "Decision"]
+    );
+    assert_eq!(code.start_line, 15);
+}
+
+#[test]
+fn test_restructuredtext_titles_and_code_directives() {
+    let content = "\
+=======
+Indexer
+=======
+
+Walks the repository.
+
+Usage
+-----
+
+Run the indexer::
+
+    mcb index .
+
+.. code-block:: python
+   :linenos:
+
+   def index(path):
+       return walk(path)
+
+Limits
+------
+
+Files over one megabyte are skipped.
+";
+    let chunks = chunk(content, "docs/indexer.rst");
+
+    let paths: Vec<Vec<String>> = sections(&chunks).into_iter().map(heading_path).collect();
+    assert_eq!(
+        paths,
+        [
+            vec!["Indexer"],
+            vec!["Indexer", "Usage"],
+            vec!["Indexer", "Limits"],
+        ]
+    );
+
+    let code: Vec<&CodeChunk> = chunks
+        .iter()
+        .filter(|chunk| chunk.metadata["node_type"] == "code_block")
+        .collect();
+    assert_eq!(code.len(), 2);
+    assert_eq!(code[0].content, "mcb index .");
+    assert_eq!(code[0].language, "restructuredtext");
+    assert_eq!(code[1].language, "python");
+    assert_eq!(code[1].content, "def index(path):\n    return walk(path)");
+}
+
+#[test]
+fn test_asciidoc_titles_and_source_blocks() {
+    let content = "\
+= Operations Guide
+
+== Backups
+
+Snapshots are taken nightly.
+
+[source,bash]
+----
+mcb backup --all
+----
+";
+    let chunks = chunk(content, "docs/ops.adoc");
+
+    let backups = sections(&chunks)
+        .into_iter()
+        .find(|chunk| chunk.content.contains("nightly"))
+        .expect("backups section");
+    assert_eq!(heading_path(backups), ["Operations Guide", "Backups"]);
+
+    let code = chunks
+        .iter()
+        .find(|chunk| chunk.metadata["node_type"] == "code_block")
+        .expect("source block chunk");
+    assert_eq!(code.language, "shell");
+    assert_eq!(code.content, "mcb backup --all");
+}
+
+#[test]
+fn test_long_sections_are_split_between_paragraphs() {
+    let paragraph = "The indexer reads every file, splits it into chunks and embeds them.";
+    let body: Vec<&str> = std::iter::repeat_n(paragraph, 12).collect();
+    let content = format!("# Indexing\n\n{}\n", body.join("\n\n"));
+    let policy = ChunkSizePolicy::new(64, 4, 0);
+
+    let chunks = IntelligentChunker::new().chunk_code_with_policy(
+        &content,
+        "README.md",
+        &"markdown".into(),
+        &policy,
+    );
+
+    assert!(chunks.len() > 1);
+    for chunk in &chunks {
+        assert!(policy.estimate_tokens(&chunk.content) <= policy.max_tokens);
+        assert_eq!(heading_path(chunk), ["Indexing"]);
+        assert_eq!(chunk.metadata["parts"], serde_json::json!(chunks.len()));
+        assert!(!chunk.content.starts_with('\n'));
+    }
+}
+
+#[test]
+fn test_documentation_files_are_detected() {
+    assert_eq!(detect_language("README.md", ""), "markdown");
+    assert_eq!(detect_language("docs/index.rst", ""), "restructuredtext");
+    assert_eq!(detect_language("docs/guide.adoc", ""), "asciidoc");
+    assert_eq!(detect_language("NOTES.txt", ""), "text");
+}
@@ -2,5 +2,15 @@
 //!
 //! These tests are inside the crate to access internal modules.
 
+mod chunk_sizing_tests;
+mod data_chunking_tests;
 #[cfg(feature = "hybrid-search")]
 mod hybrid_search_tests;
+mod language_chunking_tests;
+mod language_detection_tests;
+mod language_symbol_tests;
+mod markup_chunking_tests;
+mod reranker_tests;
+mod vector_store_file_path_tests;
+mod vector_store_filter_tests;
+mod vector_store_upsert_tests;
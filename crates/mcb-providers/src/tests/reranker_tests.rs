@@ -0,0 +1,192 @@
+//! Tests for reranker providers
+//!
+//! The HTTP reranker is exercised against a one-shot mock server on a local
+//! port, so no external API is involved.
+
+use crate::reranker::{HttpRerankerProvider, NullRerankerProvider};
+use mcb_application::ports::registry::{RerankerProviderConfig, resolve_reranker_provider};
+use mcb_domain::ports::providers::RerankerProvider;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+/// Serve a single HTTP response, returning the base URL and the raw request
+async fn serve_once(status: &'static str, body: &'static str) -> (String, JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+    let handle = tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut request = Vec::new();
+        let mut buffer = [0u8; 4096];
+        loop {
+            let read = stream.read(&mut buffer).await.unwrap();
+            request.extend_from_slice(&buffer[..read]);
+            if read == 0 || request_complete(&request) {
+                break;
+            }
+        }
+
+        let response = format!(
+            "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        );
+        stream.write_all(response.as_bytes()).await.unwrap();
+        String::from_utf8_lossy(&request).into_owned()
+    });
+
+    (base_url, handle)
+}
+
+/// Whether the headers and the whole `Content-Length` body were received
+fn request_complete(request: &[u8]) -> bool {
+    let text = String::from_utf8_lossy(request);
+    let Some((headers, body)) = text.split_once("\r\n\r\n") else {
+        return false;
+    };
+    let length = headers
+        .lines()
+        .find_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            name.eq_ignore_ascii_case("content-length")
+                .then(|| value.trim().parse::<usize>().ok())?
+        })
+        .unwrap_or(0);
+    body.len() >= length
+}
+
+fn documents() -> Vec<String> {
+    vec![
+        "fn render_page() {}".to_string(),
+        "fn parse_config() {}".to_string(),
+        "fn load_config() {}".to_string(),
+    ]
+}
+
+fn provider(base_url: String, api_key: Option<&str>) -> HttpRerankerProvider {
+    HttpRerankerProvider::new(
+        api_key.map(str::to_string),
+        Some(base_url),
+        "rerank-2".to_string(),
+        reqwest::Client::new(),
+    )
+}
+
+// ============================================================================
+// HTTP Reranker Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_http_reranker_reads_voyage_response() {
+    let (base_url, request) = serve_once(
+        "200 OK",
+        r#"{"data":[{"index":1,"relevance_score":0.9},{"index":2,"relevance_score":0.5},{"index":0,"relevance_score":0.1}]}"#,
+    )
+    .await;
+
+    let scores = provider(base_url, Some("secret"))
+        .rerank("where is the config parsed", &documents())
+        .await
+        .unwrap();
+
+    // Scores come back in document order, whatever order the API used
+    assert_eq!(scores, vec![0.1, 0.9, 0.5]);
+
+    let request = request.await.unwrap();
+    assert!(request.starts_with("POST /rerank "));
+    assert!(request.contains("Bearer secret"));
+    assert!(request.contains(r#""query":"where is the config parsed""#));
+    assert!(request.contains(r#""model":"rerank-2""#));
+}
+
+#[tokio::test]
+async fn test_http_reranker_reads_cohere_response() {
+    let (base_url, request) = serve_once(
+        "200 OK",
+        r#"{"id":"r-1","results":[{"index":2,"relevance_score":0.8},{"index":0,"relevance_score":0.3},{"index":1,"relevance_score":0.2}]}"#,
+    )
+    .await;
+
+    let scores = provider(base_url, None)
+        .rerank("load config", &documents())
+        .await
+        .unwrap();
+
+    assert_eq!(scores, vec![0.3, 0.2, 0.8]);
+    // Self-hosted servers may not need a key
+    assert!(!request.await.unwrap().contains("Authorization"));
+}
+
+#[tokio::test]
+async fn test_http_reranker_rejects_incomplete_response() {
+    let (base_url, _request) = serve_once(
+        "200 OK",
+        r#"{"results":[{"index":0,"relevance_score":0.3}]}"#,
+    )
+    .await;
+
+    let result = provider(base_url, None)
+        .rerank("load config", &documents())
+        .await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_http_reranker_surfaces_api_errors() {
+    let (base_url, _request) = serve_once("401 Unauthorized", r#"{"detail":"bad key"}"#).await;
+
+    let err = provider(base_url, Some("wrong"))
+        .rerank("load config", &documents())
+        .await
+        .unwrap_err();
+
+    assert!(err.to_string().contains("authentication failed"));
+}
+
+#[tokio::test]
+async fn test_http_reranker_skips_empty_requests() {
+    // No server: an empty candidate list must not reach the network
+    let scores = provider("http://127.0.0.1:9".to_string(), None)
+        .rerank("load config", &[])
+        .await
+        .unwrap();
+
+    assert!(scores.is_empty());
+}
+
+// ============================================================================
+// Null Reranker and Registry Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_null_reranker_keeps_order() {
+    let provider = NullRerankerProvider::new();
+
+    let scores = provider.rerank("load config", &documents()).await.unwrap();
+
+    assert!(scores.is_empty());
+    assert_eq!(provider.provider_name(), "null");
+}
+
+#[test]
+fn test_reranker_registry_resolves_providers() {
+    let null = resolve_reranker_provider(&RerankerProviderConfig::new("null")).unwrap();
+    assert_eq!(null.provider_name(), "null");
+
+    let http = resolve_reranker_provider(
+        &RerankerProviderConfig::new("http")
+            .with_base_url("http://localhost:8080")
+            .with_model("rerank-v3.5")
+            .with_timeout_secs(5),
+    )
+    .unwrap();
+    assert_eq!(http.provider_name(), "http");
+    assert_eq!(http.model_name(), "rerank-v3.5");
+}
@@ -0,0 +1,125 @@
+//! Tests for listing and deleting the vectors of a file
+
+use crate::vector_store::InMemoryVectorStoreProvider;
+use mcb_domain::ports::providers::VectorStoreProvider;
+use mcb_domain::value_objects::Embedding;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+fn embedding(vector: Vec<f32>) -> Embedding {
+    Embedding {
+        dimensions: vector.len(),
+        vector,
+        model: "test".to_string(),
+    }
+}
+
+fn chunk_metadata(file_path: &str, start_line: u32) -> HashMap<String, Value> {
+    HashMap::from([
+        ("file_path".to_string(), json!(file_path)),
+        ("language".to_string(), json!("rust")),
+        ("start_line".to_string(), json!(start_line)),
+        ("content".to_string(), json!("fn test() {}")),
+    ])
+}
+
+fn ids(names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| name.to_string()).collect()
+}
+
+async fn assert_file_path_operations(store: &dyn VectorStoreProvider) {
+    store.create_collection("test", 2).await.unwrap();
+    store
+        .upsert_vectors(
+            "test",
+            &ids(&["a1", "a2", "b1"]),
+            &[
+                embedding(vec![1.0, 0.0]),
+                embedding(vec![0.9, 0.1]),
+                embedding(vec![0.0, 1.0]),
+            ],
+            vec![
+                chunk_metadata("src/a.rs", 1),
+                chunk_metadata("src/a.rs", 10),
+                chunk_metadata("src/b.rs", 1),
+            ],
+        )
+        .await
+        .unwrap();
+
+    let mut listed: Vec<String> = store
+        .list_by_file_path("test", "src/a.rs")
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|r| r.id)
+        .collect();
+    listed.sort();
+    assert_eq!(listed, ids(&["a1", "a2"]));
+
+    // Moving a chunk to another file re-indexes it under the new path
+    store
+        .upsert_vectors(
+            "test",
+            &ids(&["a2"]),
+            &[embedding(vec![0.9, 0.1])],
+            vec![chunk_metadata("src/b.rs", 10)],
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        store
+            .list_by_file_path("test", "src/a.rs")
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+
+    store.delete_by_file_path("test", "src/b.rs").await.unwrap();
+    assert!(
+        store
+            .list_by_file_path("test", "src/b.rs")
+            .await
+            .unwrap()
+            .is_empty()
+    );
+    let remaining = store.list_vectors("test", 10).await.unwrap();
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].id, "a1");
+
+    // Deleting a file without vectors is not an error
+    store
+        .delete_by_file_path("test", "src/missing.rs")
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_in_memory_file_path_operations() {
+    assert_file_path_operations(&InMemoryVectorStoreProvider::new()).await;
+}
+
+#[cfg(feature = "vectorstore-filesystem")]
+#[tokio::test]
+async fn test_filesystem_file_path_operations() {
+    use crate::vector_store::{FilesystemVectorStore, FilesystemVectorStoreConfig};
+
+    let dir = tempfile::tempdir().unwrap();
+    let config = FilesystemVectorStoreConfig {
+        base_path: dir.path().to_path_buf(),
+        dimensions: 2,
+        ..Default::default()
+    };
+    let store = FilesystemVectorStore::new(config.clone()).await.unwrap();
+    assert_file_path_operations(&store).await;
+
+    // The file index is rebuilt when the collection is loaded from disk
+    let reopened = FilesystemVectorStore::new(config).await.unwrap();
+    let listed = reopened
+        .list_by_file_path("test", "src/a.rs")
+        .await
+        .unwrap();
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].id, "a1");
+}
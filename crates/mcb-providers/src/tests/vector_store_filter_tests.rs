@@ -0,0 +1,181 @@
+//! Tests for metadata filter evaluation in vector store providers
+
+use crate::vector_store::InMemoryVectorStoreProvider;
+use mcb_domain::ports::providers::VectorStoreProvider;
+use mcb_domain::value_objects::{Embedding, FilterField, MetadataFilter};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+fn embedding(vector: Vec<f32>) -> Embedding {
+    Embedding {
+        dimensions: vector.len(),
+        vector,
+        model: "test".to_string(),
+    }
+}
+
+fn chunk_metadata(file_path: &str, language: &str, start_line: u32) -> HashMap<String, Value> {
+    HashMap::from([
+        ("file_path".to_string(), json!(file_path)),
+        ("language".to_string(), json!(language)),
+        ("start_line".to_string(), json!(start_line)),
+        ("end_line".to_string(), json!(start_line + 5)),
+        ("content".to_string(), json!("fn test() {}")),
+    ])
+}
+
+async fn populated_store() -> InMemoryVectorStoreProvider {
+    let store = InMemoryVectorStoreProvider::new();
+    store.create_collection("test", 2).await.unwrap();
+    store
+        .insert_vectors(
+            "test",
+            &[
+                embedding(vec![1.0, 0.0]),
+                embedding(vec![0.9, 0.1]),
+                embedding(vec![0.0, 1.0]),
+            ],
+            vec![
+                chunk_metadata("crates/core/src/lib.rs", "rust", 1),
+                chunk_metadata("scripts/build.py", "python", 10),
+                chunk_metadata("crates/cli/src/main.rs", "rust", 20),
+            ],
+        )
+        .await
+        .unwrap();
+    store
+}
+
+#[tokio::test]
+async fn test_in_memory_prefilters_before_top_k() {
+    let store = populated_store().await;
+    let filter = MetadataFilter::eq(FilterField::Language, "rust");
+
+    // Without filtering, top-2 would include the python chunk
+    let results = store
+        .search_similar("test", &[1.0, 0.0], 2, Some(&filter))
+        .await
+        .unwrap();
+
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|r| r.language == "rust"));
+}
+
+#[tokio::test]
+async fn test_in_memory_filter_combinators() {
+    let store = populated_store().await;
+    let filter = MetadataFilter::glob(FilterField::FilePath, "crates/**/*.rs")
+        .and(MetadataFilter::prefix(FilterField::FilePath, "crates/cli/").negate());
+
+    let results = store
+        .search_similar("test", &[1.0, 0.0], 10, Some(&filter))
+        .await
+        .unwrap();
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].file_path, "crates/core/src/lib.rs");
+}
+
+#[cfg(feature = "vectorstore-milvus")]
+#[test]
+fn test_milvus_filter_translation() {
+    use crate::vector_store::milvus::milvus_filter_expr;
+
+    let filter = MetadataFilter::prefix(FilterField::FilePath, "src/")
+        .and(MetadataFilter::one_of(
+            FilterField::Language,
+            ["rust", "go"],
+        ))
+        .and(MetadataFilter::line_range(Some(10), None));
+
+    assert_eq!(
+        milvus_filter_expr(&filter),
+        r#"(file_path like "src/%") and (language in ["rust", "go"]) and (end_line >= 10)"#
+    );
+
+    // Negated globs cannot be widened, so they are left to the local re-check
+    let negated = MetadataFilter::glob(FilterField::FilePath, "**/tests/**").negate();
+    assert_eq!(milvus_filter_expr(&negated), r#"not (chunk_id == "")"#);
+}
+
+#[cfg(feature = "vectorstore-milvus")]
+#[test]
+fn test_milvus_rows_are_selected_by_quoted_chunk_ids() {
+    use crate::vector_store::milvus::milvus_ids_expr;
+
+    let ids = vec!["3f9a0c".to_string(), r#"a"b\c"#.to_string()];
+    assert_eq!(
+        milvus_ids_expr(&ids),
+        r#"chunk_id in ["3f9a0c", "a\"b\\c"]"#
+    );
+}
+
+#[cfg(feature = "vectorstore-milvus")]
+#[test]
+fn test_milvus_outdated_schemas_are_detected() {
+    use crate::vector_store::milvus::milvus_schema_is_current;
+
+    let current = [
+        "chunk_id",
+        "vector",
+        "file_path",
+        "start_line",
+        "end_line",
+        "language",
+        "node_type",
+        "content",
+        "symbol",
+    ];
+    assert!(milvus_schema_is_current(current));
+
+    // Rows keyed by an auto-generated id
+    let auto_id = ["id"].into_iter().chain(current);
+    assert!(!milvus_schema_is_current(auto_id));
+
+    // Collections created before chunk metadata was stored
+    let legacy = ["id", "vector", "file_path", "start_line", "content"];
+    assert!(!milvus_schema_is_current(legacy));
+}
+
+#[cfg(feature = "vectorstore-milvus")]
+#[test]
+fn test_milvus_glob_selects_root_level_files() {
+    use crate::vector_store::milvus::{glob_to_like, milvus_filter_expr};
+
+    let filter = MetadataFilter::glob(FilterField::FilePath, "**/*.rs");
+    assert_eq!(milvus_filter_expr(&filter), r#"file_path like "%.rs""#);
+
+    // `like` patterns may only widen their globs
+    for (pattern, path) in [
+        ("**/*.rs", "main.rs"),
+        ("**/*.rs", "src/main.rs"),
+        ("src/**/mod.rs", "src/mod.rs"),
+        ("src/**/mod.rs", "src/a/b/mod.rs"),
+        ("**/my_file.rs", "my_file.rs"),
+    ] {
+        assert!(
+            like_matches(&glob_to_like(pattern), path),
+            "{pattern} should select {path}"
+        );
+    }
+    assert!(!like_matches(&glob_to_like("**/my_file.rs"), "myXfile.rs"));
+}
+
+/// Evaluate a `like` pattern (`%`, `_` and `\` escapes) against a value
+#[cfg(feature = "vectorstore-milvus")]
+fn like_matches(pattern: &str, value: &str) -> bool {
+    fn matches(p: &[char], v: &[char]) -> bool {
+        match p.split_first() {
+            None => v.is_empty(),
+            Some(('%', rest)) => (0..=v.len()).any(|i| matches(rest, &v[i..])),
+            Some(('_', rest)) => !v.is_empty() && matches(rest, &v[1..]),
+            Some(('\\', [escaped, rest @ ..])) => {
+                v.first() == Some(escaped) && matches(rest, &v[1..])
+            }
+            Some((c, rest)) => v.first() == Some(c) && matches(rest, &v[1..]),
+        }
+    }
+    let pattern: Vec<char> = pattern.chars().collect();
+    let value: Vec<char> = value.chars().collect();
+    matches(&pattern, &value)
+}
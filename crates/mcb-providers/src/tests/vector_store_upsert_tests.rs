@@ -0,0 +1,133 @@
+//! Tests for upserting vectors under stable chunk ids
+
+use crate::vector_store::InMemoryVectorStoreProvider;
+use mcb_domain::ports::providers::VectorStoreProvider;
+use mcb_domain::value_objects::Embedding;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+fn embedding(vector: Vec<f32>) -> Embedding {
+    Embedding {
+        dimensions: vector.len(),
+        vector,
+        model: "test".to_string(),
+    }
+}
+
+fn chunk_metadata(content: &str) -> HashMap<String, Value> {
+    HashMap::from([
+        ("file_path".to_string(), json!("src/lib.rs")),
+        ("language".to_string(), json!("rust")),
+        ("start_line".to_string(), json!(1)),
+        ("content".to_string(), json!(content)),
+    ])
+}
+
+/// Store the same chunk twice, then a second chunk, and check the first was replaced
+async fn assert_upsert_replaces(store: &dyn VectorStoreProvider) {
+    store.create_collection("test", 2).await.unwrap();
+    let ids = vec!["chunk-a".to_string()];
+
+    store
+        .upsert_vectors(
+            "test",
+            &ids,
+            &[embedding(vec![1.0, 0.0])],
+            vec![chunk_metadata("fn a() {}")],
+        )
+        .await
+        .unwrap();
+    store
+        .upsert_vectors(
+            "test",
+            &ids,
+            &[embedding(vec![0.0, 1.0])],
+            vec![chunk_metadata("fn a() { 1 }")],
+        )
+        .await
+        .unwrap();
+    store
+        .upsert_vectors(
+            "test",
+            &["chunk-b".to_string()],
+            &[embedding(vec![1.0, 1.0])],
+            vec![chunk_metadata("fn b() {}")],
+        )
+        .await
+        .unwrap();
+
+    let listed = store.list_vectors("test", 10).await.unwrap();
+    assert_eq!(listed.len(), 2);
+
+    let results = store
+        .search_similar("test", &[0.0, 1.0], 10, None)
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].id, "chunk-a");
+    assert_eq!(results[0].content, "fn a() { 1 }");
+
+    store.delete_vectors("test", &ids).await.unwrap();
+    let remaining = store.get_vectors_by_ids("test", &ids).await.unwrap();
+    assert!(remaining.is_empty());
+    assert_eq!(store.list_vectors("test", 10).await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_in_memory_upsert_replaces_existing_entries() {
+    assert_upsert_replaces(&InMemoryVectorStoreProvider::new()).await;
+}
+
+#[tokio::test]
+async fn test_in_memory_upsert_rejects_mismatched_ids() {
+    let store = InMemoryVectorStoreProvider::new();
+    store.create_collection("test", 2).await.unwrap();
+
+    let result = store
+        .upsert_vectors(
+            "test",
+            &[],
+            &[embedding(vec![1.0, 0.0])],
+            vec![chunk_metadata("fn a() {}")],
+        )
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_in_memory_inserted_ids_are_unique_after_deletes() {
+    let store = InMemoryVectorStoreProvider::new();
+    store.create_collection("test", 2).await.unwrap();
+    let vectors = [embedding(vec![1.0, 0.0]), embedding(vec![0.0, 1.0])];
+    let metadata = || vec![chunk_metadata("fn a() {}"), chunk_metadata("fn b() {}")];
+
+    let first = store
+        .insert_vectors("test", &vectors, metadata())
+        .await
+        .unwrap();
+    store.delete_vectors("test", &first[..1]).await.unwrap();
+    let second = store
+        .insert_vectors("test", &vectors, metadata())
+        .await
+        .unwrap();
+
+    assert!(second.iter().all(|id| !first.contains(id)));
+    assert_eq!(store.list_vectors("test", 10).await.unwrap().len(), 3);
+}
+
+#[cfg(feature = "vectorstore-filesystem")]
+#[tokio::test]
+async fn test_filesystem_upsert_replaces_existing_entries() {
+    use crate::vector_store::{FilesystemVectorStore, FilesystemVectorStoreConfig};
+
+    let dir = tempfile::tempdir().unwrap();
+    let store = FilesystemVectorStore::new(FilesystemVectorStoreConfig {
+        base_path: dir.path().to_path_buf(),
+        dimensions: 2,
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+
+    assert_upsert_replaces(&store).await;
+}
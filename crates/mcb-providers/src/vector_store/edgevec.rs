@@ -6,18 +6,18 @@
 
 use async_trait::async_trait;
 use dashmap::DashMap;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use tokio::sync::{mpsc, oneshot};
 
 use crate::constants::{
-    EDGEVEC_DEFAULT_DIMENSIONS, EDGEVEC_HNSW_EF_CONSTRUCTION, EDGEVEC_HNSW_EF_SEARCH,
-    EDGEVEC_HNSW_M, EDGEVEC_HNSW_M0,
+    EDGEVEC_DEFAULT_DIMENSIONS, EDGEVEC_FILTER_OVERFETCH_FACTOR, EDGEVEC_HNSW_EF_CONSTRUCTION,
+    EDGEVEC_HNSW_EF_SEARCH, EDGEVEC_HNSW_M, EDGEVEC_HNSW_M0,
 };
 use crate::utils::JsonExt;
 use edgevec::hnsw::VectorId;
 use mcb_domain::error::{Error, Result};
 use mcb_domain::ports::providers::{VectorStoreAdmin, VectorStoreProvider};
-use mcb_domain::value_objects::{Embedding, SearchResult};
+use mcb_domain::value_objects::{CodeSymbol, Embedding, MetadataFilter, SearchResult};
 
 /// EdgeVec vector store configuration
 #[derive(Debug, Clone, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
@@ -141,16 +141,18 @@ enum EdgeVecMessage {
         name: String,
         tx: oneshot::Sender<Result<()>>,
     },
-    InsertVectors {
+    UpsertVectors {
         collection: String,
+        ids: Vec<String>,
         vectors: Vec<Embedding>,
         metadata: Vec<HashMap<String, serde_json::Value>>,
-        tx: oneshot::Sender<Result<Vec<String>>>,
+        tx: oneshot::Sender<Result<()>>,
     },
     SearchSimilar {
         collection: String,
         query_vector: Vec<f32>,
         limit: usize,
+        filter: Option<MetadataFilter>,
         tx: oneshot::Sender<Result<Vec<SearchResult>>>,
     },
     DeleteVectors {
@@ -172,6 +174,11 @@ enum EdgeVecMessage {
         ids: Vec<String>,
         tx: oneshot::Sender<Result<Vec<SearchResult>>>,
     },
+    FileVectorIds {
+        collection: String,
+        file_path: String,
+        tx: oneshot::Sender<Vec<String>>,
+    },
     CollectionExists {
         name: String,
         tx: oneshot::Sender<Result<bool>>,
@@ -202,6 +209,20 @@ impl EdgeVecVectorStoreProvider {
         })
     }
 
+    /// IDs of the vectors stored for a file, from the actor's file index
+    async fn file_vector_ids(&self, collection: &str, file_path: &str) -> Result<Vec<String>> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self
+            .sender
+            .send(EdgeVecMessage::FileVectorIds {
+                collection: collection.to_string(),
+                file_path: file_path.to_string(),
+                tx,
+            })
+            .await;
+        rx.await.map_err(|_| Error::internal("Actor closed"))
+    }
+
     /// Create a new EdgeVec provider with custom collection
     pub fn with_collection(config: EdgeVecConfig, collection: String) -> Result<Self> {
         let mut provider = Self::new(config)?;
@@ -281,11 +302,33 @@ impl VectorStoreProvider for EdgeVecVectorStoreProvider {
         vectors: &[Embedding],
         metadata: Vec<HashMap<String, serde_json::Value>>,
     ) -> Result<Vec<String>> {
+        let ids: Vec<String> = (0..vectors.len())
+            .map(|_| format!("{}_{}", collection, uuid::Uuid::new_v4()))
+            .collect();
+        self.upsert_vectors(collection, &ids, vectors, metadata)
+            .await?;
+        Ok(ids)
+    }
+
+    async fn upsert_vectors(
+        &self,
+        collection: &str,
+        ids: &[String],
+        vectors: &[Embedding],
+        metadata: Vec<HashMap<String, serde_json::Value>>,
+    ) -> Result<()> {
+        if ids.len() != vectors.len() || vectors.len() != metadata.len() {
+            return Err(Error::invalid_argument(
+                "IDs, vectors and metadata length mismatch",
+            ));
+        }
+
         let (tx, rx) = oneshot::channel();
         let _ = self
             .sender
-            .send(EdgeVecMessage::InsertVectors {
+            .send(EdgeVecMessage::UpsertVectors {
                 collection: collection.to_string(),
+                ids: ids.to_vec(),
                 vectors: vectors.to_vec(),
                 metadata,
                 tx,
@@ -300,7 +343,7 @@ impl VectorStoreProvider for EdgeVecVectorStoreProvider {
         collection: &str,
         query_vector: &[f32],
         limit: usize,
-        _filter: Option<&str>,
+        filter: Option<&MetadataFilter>,
     ) -> Result<Vec<SearchResult>> {
         let (tx, rx) = oneshot::channel();
         let _ = self
@@ -309,6 +352,7 @@ impl VectorStoreProvider for EdgeVecVectorStoreProvider {
                 collection: collection.to_string(),
                 query_vector: query_vector.to_vec(),
                 limit,
+                filter: filter.cloned(),
                 tx,
             })
             .await;
@@ -330,6 +374,23 @@ impl VectorStoreProvider for EdgeVecVectorStoreProvider {
             .unwrap_or_else(|_| Err(Error::internal("Actor closed")))
     }
 
+    async fn delete_by_file_path(&self, collection: &str, file_path: &str) -> Result<()> {
+        let ids = self.file_vector_ids(collection, file_path).await?;
+        if ids.is_empty() {
+            return Ok(());
+        }
+        self.delete_vectors(collection, &ids).await
+    }
+
+    async fn list_by_file_path(
+        &self,
+        collection: &str,
+        file_path: &str,
+    ) -> Result<Vec<SearchResult>> {
+        let ids = self.file_vector_ids(collection, file_path).await?;
+        self.get_vectors_by_ids(collection, &ids).await
+    }
+
     async fn get_vectors_by_ids(
         &self,
         collection: &str,
@@ -368,7 +429,10 @@ struct EdgeVecActor {
     index: edgevec::HnswIndex,
     storage: edgevec::VectorStorage,
     metadata_store: DashMap<String, HashMap<String, serde_json::Value>>,
-    id_map: DashMap<String, VectorId>,
+    /// HNSW vector of each (collection, external ID)
+    id_map: DashMap<(String, String), VectorId>,
+    /// External IDs of the vectors of each (collection, file path)
+    file_index: DashMap<(String, String), HashSet<String>>,
     config: EdgeVecConfig,
 }
 
@@ -398,10 +462,22 @@ impl EdgeVecActor {
             storage,
             metadata_store: DashMap::new(),
             id_map: DashMap::new(),
+            file_index: DashMap::new(),
             config,
         })
     }
 
+    /// Remove an external ID from the file index entry of its metadata
+    fn unindex_file(&self, collection: &str, external_id: &str, metadata: &serde_json::Value) {
+        if let Some(file_path) = metadata.opt_str("file_path") {
+            let key = (collection.to_string(), file_path.to_string());
+            if let Some(mut ids) = self.file_index.get_mut(&key) {
+                ids.remove(external_id);
+            }
+            self.file_index.remove_if(&key, |_, ids| ids.is_empty());
+        }
+    }
+
     async fn run(mut self) {
         while let Some(msg) = self.receiver.recv().await {
             match msg {
@@ -411,39 +487,56 @@ impl EdgeVecActor {
                 }
                 EdgeVecMessage::DeleteCollection { name, tx } => {
                     if let Some((_, collection_metadata)) = self.metadata_store.remove(&name) {
-                        for external_id in collection_metadata.keys() {
-                            if let Some(vector_id) = self.id_map.remove(external_id) {
-                                let _ = self.index.soft_delete(vector_id.1);
+                        for external_id in collection_metadata.into_keys() {
+                            if let Some((_, vector_id)) =
+                                self.id_map.remove(&(name.clone(), external_id))
+                            {
+                                let _ = self.index.soft_delete(vector_id);
                             }
                         }
                     }
+                    self.file_index.retain(|key, _| key.0 != name);
                     let _ = tx.send(Ok(()));
                 }
-                EdgeVecMessage::InsertVectors {
+                EdgeVecMessage::UpsertVectors {
                     collection,
+                    ids,
                     vectors,
                     metadata,
                     tx,
                 } => {
-                    let mut ids = Vec::with_capacity(vectors.len());
                     let mut collection_metadata =
                         self.metadata_store.entry(collection.clone()).or_default();
-                    let mut result = Ok(Vec::new());
-
-                    for (vector, meta) in vectors.into_iter().zip(metadata.into_iter()) {
-                        let external_id = format!("{}_{}", collection, uuid::Uuid::new_v4());
+                    let mut result = Ok(());
 
+                    for ((external_id, vector), meta) in ids.into_iter().zip(vectors).zip(metadata)
+                    {
                         match self.index.insert(&vector.vector, &mut self.storage) {
                             Ok(vector_id) => {
-                                self.id_map.insert(external_id.clone(), vector_id);
-                                let mut enriched_metadata = meta.clone();
+                                // Replace the vector previously stored under this ID
+                                if let Some(previous) = self
+                                    .id_map
+                                    .insert((collection.clone(), external_id.clone()), vector_id)
+                                {
+                                    let _ = self.index.soft_delete(previous);
+                                }
+                                let mut enriched_metadata = meta;
                                 enriched_metadata
                                     .insert("id".to_string(), serde_json::json!(external_id));
-                                collection_metadata.insert(
+                                if let Some(file_path) = enriched_metadata.opt_str("file_path") {
+                                    self.file_index
+                                        .entry((collection.clone(), file_path.to_string()))
+                                        .or_default()
+                                        .insert(external_id.clone());
+                                }
+                                if let Some(previous) = collection_metadata.insert(
                                     external_id.clone(),
                                     serde_json::json!(enriched_metadata),
-                                );
-                                ids.push(external_id);
+                                ) && previous.opt_str("file_path")
+                                    != enriched_metadata.opt_str("file_path")
+                                {
+                                    self.unindex_file(&collection, &external_id, &previous);
+                                }
                             }
                             Err(e) => {
                                 result =
@@ -452,24 +545,38 @@ impl EdgeVecActor {
                             }
                         }
                     }
-                    let response = if result.is_ok() { Ok(ids) } else { result };
-                    let _ = tx.send(response);
+                    let _ = tx.send(result);
                 }
                 EdgeVecMessage::SearchSimilar {
                     collection,
                     query_vector,
                     limit,
+                    filter,
                     tx,
                 } => {
-                    let result = match self.index.search(&query_vector, limit, &self.storage) {
+                    // HNSW has no metadata pre-filtering: widen the candidate set
+                    // and evaluate the filter on each candidate's metadata
+                    let candidates = if filter.is_some() {
+                        limit.saturating_mul(EDGEVEC_FILTER_OVERFETCH_FACTOR)
+                    } else {
+                        limit
+                    };
+                    let result = match self.index.search(&query_vector, candidates, &self.storage) {
                         Ok(results) => {
-                            let mut final_results = Vec::with_capacity(results.len());
+                            let mut final_results = Vec::with_capacity(limit);
                             if let Some(collection_metadata) = self.metadata_store.get(&collection)
                             {
                                 for res in results {
+                                    if final_results.len() >= limit {
+                                        break;
+                                    }
+
                                     let external_id = self.id_map.iter().find_map(|entry| {
-                                        if *entry.value() == res.vector_id {
-                                            Some(entry.key().clone())
+                                        let (entry_collection, entry_id) = entry.key();
+                                        if *entry.value() == res.vector_id
+                                            && *entry_collection == collection
+                                        {
+                                            Some(entry_id.clone())
                                         } else {
                                             None
                                         }
@@ -479,6 +586,9 @@ impl EdgeVecActor {
                                         if let Some(meta_val) = collection_metadata.get(&ext_id) {
                                             let meta =
                                                 meta_val.as_object().cloned().unwrap_or_default();
+                                            if filter.as_ref().is_some_and(|f| !f.matches(&meta)) {
+                                                continue;
+                                            }
                                             let start_line = meta
                                                 .opt_u64("start_line")
                                                 .or_else(|| meta.opt_u64("line_number"))
@@ -491,6 +601,9 @@ impl EdgeVecActor {
                                                 content: meta.string_or("content", ""),
                                                 score: res.distance as f64,
                                                 language: meta.string_or("language", "unknown"),
+                                                symbol: CodeSymbol::from_metadata(
+                                                    meta.get(CodeSymbol::METADATA_KEY),
+                                                ),
                                             });
                                         }
                                     }
@@ -510,10 +623,14 @@ impl EdgeVecActor {
                     if let Some(mut collection_metadata) = self.metadata_store.get_mut(&collection)
                     {
                         for id in ids {
-                            if let Some((_, vector_id)) = self.id_map.remove(&id) {
+                            if let Some((_, vector_id)) =
+                                self.id_map.remove(&(collection.clone(), id.clone()))
+                            {
                                 let _ = self.index.soft_delete(vector_id);
                             }
-                            collection_metadata.remove(&id);
+                            if let Some(previous) = collection_metadata.remove(&id) {
+                                self.unindex_file(&collection, &id, &previous);
+                            }
                         }
                     }
                     let _ = tx.send(Ok(()));
@@ -558,6 +675,9 @@ impl EdgeVecActor {
                                 content: meta.string_or("content", ""),
                                 score: 1.0,
                                 language: meta.string_or("language", "unknown"),
+                                symbol: CodeSymbol::from_metadata(
+                                    meta.get(CodeSymbol::METADATA_KEY),
+                                ),
                             });
                         }
                     }
@@ -584,12 +704,27 @@ impl EdgeVecActor {
                                     content: meta.string_or("content", ""),
                                     score: 1.0,
                                     language: meta.string_or("language", "unknown"),
+                                    symbol: CodeSymbol::from_metadata(
+                                        meta.get(CodeSymbol::METADATA_KEY),
+                                    ),
                                 });
                             }
                         }
                     }
                     let _ = tx.send(Ok(final_results));
                 }
+                EdgeVecMessage::FileVectorIds {
+                    collection,
+                    file_path,
+                    tx,
+                } => {
+                    let ids = self
+                        .file_index
+                        .get(&(collection, file_path))
+                        .map(|ids| ids.iter().cloned().collect())
+                        .unwrap_or_default();
+                    let _ = tx.send(ids);
+                }
                 EdgeVecMessage::CollectionExists { name, tx } => {
                     let exists = self.metadata_store.contains_key(&name);
                     let _ = tx.send(Ok(exists));
@@ -23,7 +23,7 @@ use async_trait::async_trait;
 use mcb_domain::error::{Error, Result};
 use mcb_domain::ports::providers::{CryptoProvider, EncryptedData};
 use mcb_domain::ports::providers::{VectorStoreAdmin, VectorStoreProvider};
-use mcb_domain::value_objects::{Embedding, SearchResult};
+use mcb_domain::value_objects::{CodeSymbol, Embedding, MetadataFilter, SearchResult};
 use serde_json::Value;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -44,6 +44,7 @@ use std::sync::Arc;
 /// - `file_path` - For search result construction
 /// - `start_line` - For search result construction
 /// - `language` - For search result construction
+/// - `symbol` - For search result construction
 pub struct EncryptedVectorStoreProvider<P: VectorStoreProvider> {
     /// Underlying vector store provider
     inner: P,
@@ -92,7 +93,14 @@ impl<P: VectorStoreProvider> EncryptedVectorStoreProvider<P> {
         );
 
         // Preserve unencrypted fields for filtering and SearchResult construction
-        for key in ["content", "file_path", "language"] {
+        for key in [
+            "content",
+            "file_path",
+            "language",
+            "node_type",
+            "end_line",
+            CodeSymbol::METADATA_KEY,
+        ] {
             if let Some(val) = meta.get(key) {
                 processed.insert(key.to_string(), val.clone());
             }
@@ -163,12 +171,35 @@ impl<P: VectorStoreProvider> VectorStoreProvider for EncryptedVectorStoreProvide
             .await
     }
 
+    async fn upsert_vectors(
+        &self,
+        collection: &str,
+        ids: &[String],
+        vectors: &[Embedding],
+        metadata: Vec<HashMap<String, Value>>,
+    ) -> Result<()> {
+        if vectors.len() != metadata.len() {
+            return Err(Error::invalid_argument(
+                "Vectors and metadata length mismatch",
+            ));
+        }
+
+        let processed_metadata: Vec<_> = metadata
+            .iter()
+            .map(|meta| self.encrypt_metadata(meta))
+            .collect::<Result<Vec<_>>>()?;
+
+        self.inner
+            .upsert_vectors(collection, ids, vectors, processed_metadata)
+            .await
+    }
+
     async fn search_similar(
         &self,
         collection: &str,
         query_vector: &[f32],
         limit: usize,
-        filter: Option<&str>,
+        filter: Option<&MetadataFilter>,
     ) -> Result<Vec<SearchResult>> {
         // Search using the inner provider (vectors are unencrypted)
         // Note: The inner provider returns results with partial metadata (unencrypted fields only)
@@ -182,6 +213,19 @@ impl<P: VectorStoreProvider> VectorStoreProvider for EncryptedVectorStoreProvide
         self.inner.delete_vectors(collection, ids).await
     }
 
+    async fn delete_by_file_path(&self, collection: &str, file_path: &str) -> Result<()> {
+        // `file_path` is kept in plaintext, so the inner provider can match it
+        self.inner.delete_by_file_path(collection, file_path).await
+    }
+
+    async fn list_by_file_path(
+        &self,
+        collection: &str,
+        file_path: &str,
+    ) -> Result<Vec<SearchResult>> {
+        self.inner.list_by_file_path(collection, file_path).await
+    }
+
     async fn get_vectors_by_ids(
         &self,
         collection: &str,
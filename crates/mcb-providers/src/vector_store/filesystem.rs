@@ -12,9 +12,9 @@ use async_trait::async_trait;
 use dashmap::DashMap;
 use mcb_domain::error::{Error, Result};
 use mcb_domain::ports::providers::{VectorStoreAdmin, VectorStoreProvider};
-use mcb_domain::value_objects::{Embedding, SearchResult};
+use mcb_domain::value_objects::{CodeSymbol, Embedding, MetadataFilter, SearchResult};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::io::{Read, Seek, Write};
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -76,8 +76,7 @@ struct IndexEntry {
     shard_id: u32,
     /// Offset within the shard file
     offset: u64,
-    /// Vector metadata
-    #[allow(dead_code)]
+    /// Vector metadata (used to pre-filter searches without reading shards)
     metadata: HashMap<String, serde_json::Value>,
 }
 
@@ -87,6 +86,8 @@ pub struct FilesystemVectorStore {
     config: FilesystemVectorStoreConfig,
     /// Global index cache ((collection, ID) -> IndexEntry)
     index_cache: Arc<DashMap<(String, String), IndexEntry>>,
+    /// Secondary index of vector IDs by file ((collection, file_path) -> IDs)
+    file_index: Arc<DashMap<(String, String), HashSet<String>>>,
     /// Shard metadata cache ((collection, shard_id) -> ShardMetadata)
     shard_cache: Arc<DashMap<(String, u32), ShardMetadata>>,
     /// Next shard ID to use per collection
@@ -161,6 +162,7 @@ impl FilesystemVectorStore {
         let store = Self {
             config,
             index_cache: Arc::new(DashMap::new()),
+            file_index: Arc::new(DashMap::new()),
             shard_cache: Arc::new(DashMap::new()),
             next_shard_ids: Arc::new(DashMap::new()),
         };
@@ -179,6 +181,7 @@ impl FilesystemVectorStore {
             let index: HashMap<String, IndexEntry> =
                 file_utils::read_json(&index_path, "collection index").await?;
             for (id, entry) in index {
+                self.index_file(collection, &entry);
                 self.index_cache.insert((collection.to_string(), id), entry);
             }
         }
@@ -249,6 +252,35 @@ impl FilesystemVectorStore {
         Ok(())
     }
 
+    /// Add an index entry to the file index
+    fn index_file(&self, collection: &str, entry: &IndexEntry) {
+        if let Some(file_path) = entry.metadata.opt_str("file_path") {
+            self.file_index
+                .entry((collection.to_string(), file_path.to_string()))
+                .or_default()
+                .insert(entry.id.clone());
+        }
+    }
+
+    /// Remove an index entry from the file index
+    fn unindex_file(&self, collection: &str, entry: &IndexEntry) {
+        if let Some(file_path) = entry.metadata.opt_str("file_path") {
+            let key = (collection.to_string(), file_path.to_string());
+            if let Some(mut ids) = self.file_index.get_mut(&key) {
+                ids.remove(&entry.id);
+            }
+            self.file_index.remove_if(&key, |_, ids| ids.is_empty());
+        }
+    }
+
+    /// IDs of the vectors of a file
+    fn file_ids(&self, collection: &str, file_path: &str) -> Vec<String> {
+        self.file_index
+            .get(&(collection.to_string(), file_path.to_string()))
+            .map(|ids| ids.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
     /// Get shard file path for a collection
     fn get_shard_path(&self, collection: &str, shard_id: u32) -> PathBuf {
         self.config
@@ -381,6 +413,49 @@ impl FilesystemVectorStore {
         .map_err(|e: std::io::Error| Error::io(format!("Failed to read from shard: {}", e)))
     }
 
+    /// Append vectors to shards and point their index entries at them
+    ///
+    /// An ID already in the index is re-pointed at the new data; the bytes it
+    /// previously pointed at are left unreferenced in their shard.
+    async fn write_entries(
+        &self,
+        collection: &str,
+        ids: &[String],
+        vectors: &[Embedding],
+        metadata: &[HashMap<String, serde_json::Value>],
+    ) -> Result<()> {
+        // Ensure state is loaded
+        if !self.next_shard_ids.contains_key(collection) {
+            self.load_collection_state(collection).await?;
+        }
+
+        for ((id, vector), meta) in ids.iter().zip(vectors).zip(metadata) {
+            let shard_id = self.find_optimal_shard(collection);
+            let offset = self
+                .write_vector_to_shard(collection, shard_id, id, &vector.vector, meta)
+                .await?;
+
+            let index_entry = IndexEntry {
+                id: id.clone(),
+                shard_id,
+                offset,
+                metadata: meta.clone(),
+            };
+
+            self.index_file(collection, &index_entry);
+            if let Some(previous) = self
+                .index_cache
+                .insert((collection.to_string(), id.clone()), index_entry)
+                && previous.metadata.opt_str("file_path") != meta.opt_str("file_path")
+            {
+                self.unindex_file(collection, &previous);
+            }
+        }
+
+        // Save state
+        self.save_collection_state(collection).await
+    }
+
     /// Find optimal shard for new vector
     fn find_optimal_shard(&self, collection: &str) -> u32 {
         // Find shard with most available capacity
@@ -409,11 +484,15 @@ impl FilesystemVectorStore {
     }
 
     /// Perform similarity search using brute force
+    ///
+    /// When a filter is given, index entries are filtered on their cached
+    /// metadata before any shard is read.
     async fn brute_force_search(
         &self,
         collection: &str,
         query_vector: &[f32],
         limit: usize,
+        filter: Option<&MetadataFilter>,
     ) -> Result<Vec<SearchResult>> {
         let mut results = Vec::new();
 
@@ -422,6 +501,7 @@ impl FilesystemVectorStore {
             .index_cache
             .iter()
             .filter(|r| r.key().0 == collection)
+            .filter(|r| filter.is_none_or(|f| f.matches(&r.value().metadata)))
             .map(|r| r.value().clone())
             .collect();
 
@@ -447,6 +527,7 @@ impl FilesystemVectorStore {
                     content,
                     score: similarity as f64,
                     language,
+                    symbol: CodeSymbol::from_metadata(metadata.get(CodeSymbol::METADATA_KEY)),
                 });
             }
         }
@@ -582,6 +663,7 @@ impl VectorStoreProvider for FilesystemVectorStore {
 
         // Clear caches
         self.index_cache.retain(|k, _| k.0 != name);
+        self.file_index.retain(|k, _| k.0 != name);
         self.shard_cache.retain(|k, _| k.0 != name);
         self.next_shard_ids.remove(name);
 
@@ -594,44 +676,28 @@ impl VectorStoreProvider for FilesystemVectorStore {
         vectors: &[Embedding],
         metadata: Vec<std::collections::HashMap<String, serde_json::Value>>,
     ) -> Result<Vec<String>> {
-        // Ensure state is loaded
-        if !self.next_shard_ids.contains_key(collection) {
-            self.load_collection_state(collection).await?;
-        }
-
-        let mut ids = Vec::new();
-
-        for (i, (vector, meta)) in vectors.iter().zip(metadata.iter()).enumerate() {
-            let id = format!(
-                "{}_{}_{}",
-                collection,
-                i,
-                std::time::SystemTime::now()
-                    .duration_since(std::time::UNIX_EPOCH)
-                    .unwrap_or_default()
-                    .as_nanos()
-            );
-            let shard_id = self.find_optimal_shard(collection);
-            let offset = self
-                .write_vector_to_shard(collection, shard_id, &id, &vector.vector, meta)
-                .await?;
-
-            let index_entry = IndexEntry {
-                id: id.clone(),
-                shard_id,
-                offset,
-                metadata: meta.clone(),
-            };
+        let ids: Vec<String> = (0..vectors.len())
+            .map(|_| format!("{}_{}", collection, uuid::Uuid::new_v4()))
+            .collect();
+        self.write_entries(collection, &ids, vectors, &metadata)
+            .await?;
+        Ok(ids)
+    }
 
-            self.index_cache
-                .insert((collection.to_string(), id.clone()), index_entry);
-            ids.push(id);
+    async fn upsert_vectors(
+        &self,
+        collection: &str,
+        ids: &[String],
+        vectors: &[Embedding],
+        metadata: Vec<HashMap<String, serde_json::Value>>,
+    ) -> Result<()> {
+        if ids.len() != vectors.len() || vectors.len() != metadata.len() {
+            return Err(Error::invalid_argument(
+                "IDs, vectors and metadata length mismatch",
+            ));
         }
-
-        // Save state
-        self.save_collection_state(collection).await?;
-
-        Ok(ids)
+        self.write_entries(collection, ids, vectors, &metadata)
+            .await
     }
 
     async fn search_similar(
@@ -639,14 +705,14 @@ impl VectorStoreProvider for FilesystemVectorStore {
         collection: &str,
         query_vector: &[f32],
         limit: usize,
-        _filter: Option<&str>,
+        filter: Option<&MetadataFilter>,
     ) -> Result<Vec<SearchResult>> {
         // Ensure state is loaded
         if !self.next_shard_ids.contains_key(collection) {
             self.load_collection_state(collection).await?;
         }
 
-        self.brute_force_search(collection, query_vector, limit)
+        self.brute_force_search(collection, query_vector, limit, filter)
             .await
     }
 
@@ -658,8 +724,12 @@ impl VectorStoreProvider for FilesystemVectorStore {
 
         // Remove from index
         for id in ids {
-            self.index_cache
-                .remove(&(collection.to_string(), id.clone()));
+            if let Some((_, entry)) = self
+                .index_cache
+                .remove(&(collection.to_string(), id.clone()))
+            {
+                self.unindex_file(collection, &entry);
+            }
         }
 
         // Save state
@@ -667,6 +737,33 @@ impl VectorStoreProvider for FilesystemVectorStore {
         Ok(())
     }
 
+    async fn delete_by_file_path(&self, collection: &str, file_path: &str) -> Result<()> {
+        // Ensure state is loaded
+        if !self.next_shard_ids.contains_key(collection) {
+            self.load_collection_state(collection).await?;
+        }
+
+        let ids = self.file_ids(collection, file_path);
+        if ids.is_empty() {
+            return Ok(());
+        }
+        self.delete_vectors(collection, &ids).await
+    }
+
+    async fn list_by_file_path(
+        &self,
+        collection: &str,
+        file_path: &str,
+    ) -> Result<Vec<SearchResult>> {
+        // Ensure state is loaded
+        if !self.next_shard_ids.contains_key(collection) {
+            self.load_collection_state(collection).await?;
+        }
+
+        let ids = self.file_ids(collection, file_path);
+        self.get_vectors_by_ids(collection, &ids).await
+    }
+
     async fn get_vectors_by_ids(
         &self,
         collection: &str,
@@ -699,6 +796,7 @@ impl VectorStoreProvider for FilesystemVectorStore {
                         content,
                         score: 1.0,
                         language,
+                        symbol: CodeSymbol::from_metadata(metadata.get(CodeSymbol::METADATA_KEY)),
                     });
                 }
             }
@@ -741,6 +839,7 @@ impl VectorStoreProvider for FilesystemVectorStore {
                     content,
                     score: 1.0,
                     language,
+                    symbol: CodeSymbol::from_metadata(metadata.get(CodeSymbol::METADATA_KEY)),
                 });
             }
         }
@@ -8,21 +8,66 @@ use async_trait::async_trait;
 use dashmap::DashMap;
 use mcb_domain::error::{Error, Result};
 use mcb_domain::ports::providers::{VectorStoreAdmin, VectorStoreProvider};
-use mcb_domain::value_objects::{Embedding, SearchResult};
+use mcb_domain::value_objects::{CodeSymbol, Embedding, MetadataFilter, SearchResult};
 use serde_json::Value;
 use std::cmp::Ordering;
-use std::collections::{BinaryHeap, HashMap};
+use std::collections::{BinaryHeap, HashMap, HashSet};
 use std::sync::Arc;
 
 /// In-memory storage entry type
 type CollectionEntry = (Embedding, HashMap<String, Value>);
 
+/// Vectors of a collection keyed by ID, indexed by file path
+#[derive(Default)]
+struct Collection {
+    entries: HashMap<String, CollectionEntry>,
+    /// IDs of the entries of each file
+    by_file: HashMap<String, HashSet<String>>,
+}
+
+impl Collection {
+    /// Insert an entry, replacing any entry with the same ID
+    fn insert(&mut self, id: String, entry: CollectionEntry) {
+        self.remove(&id);
+        if let Some(file_path) = entry.1.opt_str("file_path") {
+            self.by_file
+                .entry(file_path.to_string())
+                .or_default()
+                .insert(id.clone());
+        }
+        self.entries.insert(id, entry);
+    }
+
+    fn remove(&mut self, id: &str) {
+        let Some((_embedding, metadata)) = self.entries.remove(id) else {
+            return;
+        };
+        if let Some(file_path) = metadata.opt_str("file_path")
+            && let Some(ids) = self.by_file.get_mut(file_path)
+        {
+            ids.remove(id);
+            if ids.is_empty() {
+                self.by_file.remove(file_path);
+            }
+        }
+    }
+
+    /// IDs of the entries of a file
+    fn file_ids(&self, file_path: &str) -> Vec<String> {
+        self.by_file
+            .get(file_path)
+            .map(|ids| ids.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
 /// In-memory vector store provider
 ///
-/// Stores vectors and metadata in memory using concurrent hash maps.
+/// Stores vectors and metadata in memory using concurrent hash maps, keyed by
+/// collection and then by vector ID, with a secondary index by file path.
 /// Useful for development and testing where persistence is not required.
 pub struct InMemoryVectorStoreProvider {
-    collections: Arc<DashMap<String, Vec<CollectionEntry>>>,
+    collections: Arc<DashMap<String, Collection>>,
 }
 
 impl InMemoryVectorStoreProvider {
@@ -50,7 +95,7 @@ impl VectorStoreAdmin for InMemoryVectorStoreProvider {
         let count = self
             .collections
             .get(collection)
-            .map(|data| data.len())
+            .map(|data| data.entries.len())
             .unwrap_or(0);
 
         let mut stats = HashMap::new();
@@ -83,7 +128,8 @@ impl VectorStoreProvider for InMemoryVectorStoreProvider {
                 name
             )));
         }
-        self.collections.insert(name.to_string(), Vec::new());
+        self.collections
+            .insert(name.to_string(), Collection::default());
         Ok(())
     }
 
@@ -104,23 +150,45 @@ impl VectorStoreProvider for InMemoryVectorStoreProvider {
             .ok_or_else(|| Error::vector_db(format!("Collection '{}' not found", collection)))?;
 
         let mut ids = Vec::with_capacity(vectors.len());
-        for (vector, mut meta) in vectors.iter().zip(metadata) {
-            let id = format!("{}_{}", collection, coll.len());
-            // Store the generated ID in metadata for deletion
-            meta.insert("generated_id".to_string(), serde_json::json!(&id));
-            coll.push((vector.clone(), meta));
+        for (vector, meta) in vectors.iter().zip(metadata) {
+            let id = format!("{}_{}", collection, uuid::Uuid::new_v4());
+            coll.insert(id.clone(), (vector.clone(), meta));
             ids.push(id);
         }
 
         Ok(ids)
     }
 
+    async fn upsert_vectors(
+        &self,
+        collection: &str,
+        ids: &[String],
+        vectors: &[Embedding],
+        metadata: Vec<HashMap<String, Value>>,
+    ) -> Result<()> {
+        if ids.len() != vectors.len() || vectors.len() != metadata.len() {
+            return Err(Error::invalid_argument(
+                "IDs, vectors and metadata length mismatch",
+            ));
+        }
+
+        let mut coll = self
+            .collections
+            .get_mut(collection)
+            .ok_or_else(|| Error::vector_db(format!("Collection '{}' not found", collection)))?;
+
+        for ((id, vector), meta) in ids.iter().zip(vectors).zip(metadata) {
+            coll.insert(id.clone(), (vector.clone(), meta));
+        }
+        Ok(())
+    }
+
     async fn search_similar(
         &self,
         collection: &str,
         query_vector: &[f32],
         limit: usize,
-        _filter: Option<&str>,
+        filter: Option<&MetadataFilter>,
     ) -> Result<Vec<SearchResult>> {
         // Return empty results for non-existent collections (graceful degradation)
         let coll = match self.collections.get(collection) {
@@ -134,14 +202,19 @@ impl VectorStoreProvider for InMemoryVectorStoreProvider {
         // Use min-heap for top-k selection: O(n log k) instead of O(n log n)
         let mut heap: BinaryHeap<ScoredItem> = BinaryHeap::with_capacity(limit + 1);
 
-        for (i, (embedding, _metadata)) in coll.iter().enumerate() {
+        for (id, (embedding, metadata)) in &coll.entries {
+            // Pre-filter candidates so the top-k is taken over matching vectors only
+            if filter.is_some_and(|f| !f.matches(metadata)) {
+                continue;
+            }
+
             let similarity =
                 cosine_similarity_with_norm(query_vector, &embedding.vector, query_norm);
 
             if heap.len() < limit {
                 heap.push(ScoredItem {
                     score: similarity,
-                    index: i,
+                    id,
                 });
             } else if let Some(min) = heap.peek() {
                 // Only add if better than current minimum
@@ -149,7 +222,7 @@ impl VectorStoreProvider for InMemoryVectorStoreProvider {
                     heap.pop();
                     heap.push(ScoredItem {
                         score: similarity,
-                        index: i,
+                        id,
                     });
                 }
             }
@@ -162,8 +235,8 @@ impl VectorStoreProvider for InMemoryVectorStoreProvider {
         let search_results = items
             .into_iter()
             .map(|item| {
-                let (_embedding, metadata) = &coll[item.index];
-                metadata_to_search_result(metadata, item.score as f64)
+                let (_embedding, metadata) = &coll.entries[item.id];
+                metadata_to_search_result(item.id, metadata, item.score as f64)
             })
             .collect();
 
@@ -176,14 +249,37 @@ impl VectorStoreProvider for InMemoryVectorStoreProvider {
             .get_mut(collection)
             .ok_or_else(|| Error::vector_db(format!("Collection '{}' not found", collection)))?;
 
-        // Remove vectors by their generated IDs
-        coll.retain(|(_embedding, metadata)| {
-            let generated_id = metadata.str_or("generated_id", "");
-            !ids.contains(&generated_id.to_string())
-        });
+        for id in ids {
+            coll.remove(id);
+        }
+        Ok(())
+    }
+
+    async fn delete_by_file_path(&self, collection: &str, file_path: &str) -> Result<()> {
+        let mut coll = self
+            .collections
+            .get_mut(collection)
+            .ok_or_else(|| Error::vector_db(format!("Collection '{}' not found", collection)))?;
+
+        for id in coll.file_ids(file_path) {
+            coll.remove(&id);
+        }
         Ok(())
     }
 
+    async fn list_by_file_path(
+        &self,
+        collection: &str,
+        file_path: &str,
+    ) -> Result<Vec<SearchResult>> {
+        let ids = self
+            .collections
+            .get(collection)
+            .ok_or_else(|| Error::vector_db(format!("Collection '{}' not found", collection)))?
+            .file_ids(file_path);
+        self.get_vectors_by_ids(collection, &ids).await
+    }
+
     async fn get_vectors_by_ids(
         &self,
         collection: &str,
@@ -194,13 +290,13 @@ impl VectorStoreProvider for InMemoryVectorStoreProvider {
             .get(collection)
             .ok_or_else(|| Error::vector_db(format!("Collection '{}' not found", collection)))?;
 
-        let results = coll
+        let results = ids
             .iter()
-            .filter(|(_embedding, metadata)| {
-                let generated_id = metadata.str_or("generated_id", "");
-                ids.contains(&generated_id.to_string())
+            .filter_map(|id| {
+                coll.entries
+                    .get(id)
+                    .map(|(_embedding, metadata)| metadata_to_search_result(id, metadata, 1.0))
             })
-            .map(|(_embedding, metadata)| metadata_to_search_result(metadata, 1.0))
             .collect();
 
         Ok(results)
@@ -213,9 +309,10 @@ impl VectorStoreProvider for InMemoryVectorStoreProvider {
             .ok_or_else(|| Error::vector_db(format!("Collection '{}' not found", collection)))?;
 
         let results = coll
+            .entries
             .iter()
             .take(limit)
-            .map(|(_embedding, metadata)| metadata_to_search_result(metadata, 1.0))
+            .map(|(id, (_embedding, metadata))| metadata_to_search_result(id, metadata, 1.0))
             .collect();
 
         Ok(results)
@@ -226,14 +323,14 @@ impl VectorStoreProvider for InMemoryVectorStoreProvider {
 ///
 /// Uses reverse ordering so BinaryHeap acts as a min-heap (smallest scores at top).
 #[derive(PartialEq)]
-struct ScoredItem {
+struct ScoredItem<'a> {
     score: f32,
-    index: usize,
+    id: &'a str,
 }
 
-impl Eq for ScoredItem {}
+impl Eq for ScoredItem<'_> {}
 
-impl Ord for ScoredItem {
+impl Ord for ScoredItem<'_> {
     fn cmp(&self, other: &Self) -> Ordering {
         // Reverse ordering for min-heap behavior: smallest at top
         other
@@ -243,7 +340,7 @@ impl Ord for ScoredItem {
     }
 }
 
-impl PartialOrd for ScoredItem {
+impl PartialOrd for ScoredItem<'_> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
@@ -255,8 +352,11 @@ fn compute_norm(v: &[f32]) -> f32 {
 }
 
 /// Convert metadata to SearchResult
-fn metadata_to_search_result(metadata: &HashMap<String, Value>, score: f64) -> SearchResult {
-    let id = metadata.string_or("generated_id", "");
+fn metadata_to_search_result(
+    id: &str,
+    metadata: &HashMap<String, Value>,
+    score: f64,
+) -> SearchResult {
     let start_line = metadata
         .opt_u64("start_line")
         .or_else(|| metadata.opt_u64("line_number"))
@@ -264,12 +364,13 @@ fn metadata_to_search_result(metadata: &HashMap<String, Value>, score: f64) -> S
     let language = metadata.string_or("language", "unknown");
 
     SearchResult {
-        id,
+        id: id.to_string(),
         file_path: metadata.string_or("file_path", ""),
         start_line,
         content: metadata.string_or("content", ""),
         score,
         language,
+        symbol: CodeSymbol::from_metadata(metadata.get(CodeSymbol::METADATA_KEY)),
     }
 }
 
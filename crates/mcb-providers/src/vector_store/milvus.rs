@@ -4,13 +4,14 @@
 //! Supports production-scale vector storage with automatic indexing and distributed search.
 
 use crate::constants::{
-    MILVUS_FIELD_VARCHAR_MAX_LENGTH, MILVUS_IVFFLAT_NLIST, MILVUS_METADATA_VARCHAR_MAX_LENGTH,
+    MILVUS_FIELD_VARCHAR_MAX_LENGTH, MILVUS_FILTER_OVERFETCH_FACTOR, MILVUS_IVFFLAT_NLIST,
+    MILVUS_METADATA_VARCHAR_MAX_LENGTH, MILVUS_QUERY_MAX_LIMIT,
 };
 use crate::utils::JsonExt;
 use async_trait::async_trait;
 use mcb_domain::error::{Error, Result};
 use mcb_domain::ports::providers::{VectorStoreAdmin, VectorStoreProvider};
-use mcb_domain::value_objects::{Embedding, SearchResult};
+use mcb_domain::value_objects::{CodeSymbol, Embedding, MetadataFilter, SearchResult};
 use milvus::client::Client;
 use milvus::data::FieldColumn;
 use milvus::proto::schema::DataType;
@@ -27,6 +28,27 @@ pub struct MilvusVectorStoreProvider {
 /// Default connection timeout in seconds
 const DEFAULT_TIMEOUT_SECS: u64 = 10;
 
+/// Boolean expression matching every row (chunk ids are never empty)
+const MILVUS_EXPR_ALL: &str = r#"chunk_id != """#;
+
+/// Boolean expression matching no row
+const MILVUS_EXPR_NONE: &str = r#"chunk_id == """#;
+
+/// Fields a collection must have to be written by this provider
+///
+/// Collections created before rows were keyed by chunk id lack some of them.
+const MILVUS_SCHEMA_FIELDS: &[&str] = &[
+    "chunk_id",
+    "vector",
+    "file_path",
+    "start_line",
+    "end_line",
+    "language",
+    "node_type",
+    "content",
+    "symbol",
+];
+
 impl MilvusVectorStoreProvider {
     /// Helper method to convert Milvus errors to domain errors
     fn map_milvus_error<T, E: std::fmt::Display>(
@@ -36,6 +58,281 @@ impl MilvusVectorStoreProvider {
         result.map_err(|e| Error::vector_db(format!("Failed to {}: {}", operation, e)))
     }
 
+    /// Query up to `limit` rows matching a boolean expression
+    async fn query_rows(
+        &self,
+        collection: &str,
+        expr: &str,
+        limit: usize,
+        operation: &str,
+    ) -> Result<Vec<SearchResult>> {
+        // Ensure collection is loaded
+        self.client
+            .load_collection(collection, None)
+            .await
+            .map_err(|e| {
+                Error::vector_db(format!("Failed to load collection '{}': {}", collection, e))
+            })?;
+
+        use milvus::query::QueryOptions;
+        let mut query_options = QueryOptions::new();
+        // Milvus rejects query windows above its maximum
+        query_options = query_options
+            .limit(limit.min(MILVUS_QUERY_MAX_LIMIT) as i64)
+            .output_fields(vec![
+                "chunk_id".to_string(),
+                "file_path".to_string(),
+                "start_line".to_string(),
+                "content".to_string(),
+                "symbol".to_string(),
+            ]);
+
+        let query_results = Self::map_milvus_error(
+            self.client.query(collection, expr, &query_options).await,
+            operation,
+        )?;
+
+        // Convert results to our format
+        let mut results = Vec::new();
+
+        // Map columns by name
+        let mut columns_map = HashMap::new();
+        for column in &query_results {
+            columns_map.insert(column.name.as_str(), column);
+        }
+
+        let row_count = if let Some(col) = query_results.first() {
+            col.len()
+        } else {
+            0
+        };
+
+        for i in 0..row_count {
+            let id_str = columns_map
+                .get("chunk_id")
+                .and_then(|col| col.get(i))
+                .map(|v| match v {
+                    Value::String(id) => id.to_string(),
+                    _ => "unknown".to_string(),
+                })
+                .unwrap_or_else(|| "unknown".to_string());
+
+            let file_path = columns_map
+                .get("file_path")
+                .and_then(|col| col.get(i))
+                .map(|v| match v {
+                    Value::String(s) => s.to_string(),
+                    _ => "unknown".to_string(),
+                })
+                .unwrap_or_else(|| "unknown".to_string());
+
+            let start_line = columns_map
+                .get("start_line")
+                .or_else(|| columns_map.get("line_number"))
+                .and_then(|col| col.get(i))
+                .map(|v| match v {
+                    Value::Long(n) => n as u32,
+                    _ => 0,
+                })
+                .unwrap_or(0);
+
+            let content = columns_map
+                .get("content")
+                .and_then(|col| col.get(i))
+                .map(|v| match v {
+                    Value::String(s) => s.to_string(),
+                    _ => "".to_string(),
+                })
+                .unwrap_or_default();
+
+            results.push(SearchResult {
+                id: id_str,
+                file_path,
+                start_line,
+                content,
+                score: 1.0,
+                language: "unknown".to_string(),
+                symbol: symbol_at(&columns_map, i),
+            });
+        }
+
+        Ok(results)
+    }
+
+    /// Build the columns of rows keyed by the given chunk ids
+    fn row_columns(
+        chunk_ids: Vec<String>,
+        vectors: &[Embedding],
+        metadata: &[HashMap<String, serde_json::Value>],
+    ) -> Result<Vec<FieldColumn>> {
+        if vectors.is_empty() {
+            return Err(Error::vector_db(
+                "No vectors provided for insertion".to_string(),
+            ));
+        }
+
+        if vectors.len() != metadata.len() {
+            return Err(Error::vector_db(format!(
+                "Vectors ({}) and metadata ({}) arrays must have the same length",
+                vectors.len(),
+                metadata.len()
+            )));
+        }
+
+        if chunk_ids.len() != vectors.len() {
+            return Err(Error::vector_db(format!(
+                "IDs ({}) and vectors ({}) arrays must have the same length",
+                chunk_ids.len(),
+                vectors.len()
+            )));
+        }
+        if chunk_ids.iter().any(String::is_empty) {
+            return Err(Error::vector_db("Chunk ids must not be empty".to_string()));
+        }
+
+        // Validate all vectors have the same dimensions
+        let expected_dims = vectors[0].dimensions;
+        for (i, vector) in vectors.iter().enumerate() {
+            if vector.dimensions != expected_dims {
+                return Err(Error::vector_db(format!(
+                    "Vector at index {} has dimensions {} but expected {}",
+                    i, vector.dimensions, expected_dims
+                )));
+            }
+        }
+
+        // Prepare data for insertion
+        let mut vectors_flat = Vec::new();
+        let mut file_paths = Vec::new();
+        let mut start_lines = Vec::new();
+        let mut end_lines = Vec::new();
+        let mut languages = Vec::new();
+        let mut node_types = Vec::new();
+        let mut contents = Vec::new();
+        let mut symbols = Vec::new();
+
+        for (embedding, meta) in vectors.iter().zip(metadata) {
+            vectors_flat.extend_from_slice(&embedding.vector);
+
+            let file_path = meta.string_or("file_path", "unknown");
+            let start_line = meta
+                .opt_i64("start_line")
+                .or_else(|| meta.opt_i64("line_number"))
+                .unwrap_or(0);
+            let end_line = meta.opt_i64("end_line").unwrap_or(start_line);
+            let content = meta.string_or("content", "");
+
+            file_paths.push(file_path);
+            start_lines.push(start_line);
+            end_lines.push(end_line);
+            languages.push(meta.string_or("language", "unknown"));
+            node_types.push(meta.string_or("node_type", ""));
+            contents.push(content);
+            // Symbols are stored as JSON, empty when the chunk has none
+            symbols.push(
+                meta.get(CodeSymbol::METADATA_KEY)
+                    .map(|symbol| symbol.to_string())
+                    .unwrap_or_default(),
+            );
+        }
+
+        let chunk_id_column = FieldColumn {
+            name: "chunk_id".to_string(),
+            dtype: DataType::VarChar,
+            value: ValueVec::String(chunk_ids),
+            dim: 1,
+            max_length: MILVUS_FIELD_VARCHAR_MAX_LENGTH,
+            is_dynamic: false,
+        };
+        let vector_column = FieldColumn {
+            name: "vector".to_string(),
+            dtype: DataType::FloatVector,
+            value: ValueVec::Float(vectors_flat),
+            dim: expected_dims as i64,
+            max_length: 0,
+            is_dynamic: false,
+        };
+        let file_path_column = FieldColumn {
+            name: "file_path".to_string(),
+            dtype: DataType::VarChar,
+            value: ValueVec::String(file_paths),
+            dim: 1,
+            max_length: MILVUS_FIELD_VARCHAR_MAX_LENGTH,
+            is_dynamic: false,
+        };
+        let start_line_column = FieldColumn {
+            name: "start_line".to_string(),
+            dtype: DataType::Int64,
+            value: ValueVec::Long(start_lines),
+            dim: 1,
+            max_length: 0,
+            is_dynamic: false,
+        };
+        let end_line_column = FieldColumn {
+            name: "end_line".to_string(),
+            dtype: DataType::Int64,
+            value: ValueVec::Long(end_lines),
+            dim: 1,
+            max_length: 0,
+            is_dynamic: false,
+        };
+        let language_column = FieldColumn {
+            name: "language".to_string(),
+            dtype: DataType::VarChar,
+            value: ValueVec::String(languages),
+            dim: 1,
+            max_length: MILVUS_FIELD_VARCHAR_MAX_LENGTH,
+            is_dynamic: false,
+        };
+        let node_type_column = FieldColumn {
+            name: "node_type".to_string(),
+            dtype: DataType::VarChar,
+            value: ValueVec::String(node_types),
+            dim: 1,
+            max_length: MILVUS_FIELD_VARCHAR_MAX_LENGTH,
+            is_dynamic: false,
+        };
+        let content_column = FieldColumn {
+            name: "content".to_string(),
+            dtype: DataType::VarChar,
+            value: ValueVec::String(contents),
+            dim: 1,
+            max_length: MILVUS_METADATA_VARCHAR_MAX_LENGTH,
+            is_dynamic: false,
+        };
+        let symbol_column = FieldColumn {
+            name: "symbol".to_string(),
+            dtype: DataType::VarChar,
+            value: ValueVec::String(symbols),
+            dim: 1,
+            max_length: MILVUS_METADATA_VARCHAR_MAX_LENGTH,
+            is_dynamic: false,
+        };
+
+        Ok(vec![
+            chunk_id_column,
+            vector_column,
+            file_path_column,
+            start_line_column,
+            end_line_column,
+            language_column,
+            node_type_column,
+            content_column,
+            symbol_column,
+        ])
+    }
+
+    /// Check that an existing collection has the fields this provider writes
+    async fn has_current_schema(&self, name: &str) -> Result<bool> {
+        let collection = Self::map_milvus_error(
+            self.client.describe_collection(name).await,
+            "describe collection",
+        )?;
+        Ok(milvus_schema_is_current(
+            collection.fields.iter().map(|field| field.name.as_str()),
+        ))
+    }
+
     /// Create a new Milvus vector store provider
     ///
     /// # Arguments
@@ -78,8 +375,24 @@ impl MilvusVectorStoreProvider {
 
 #[async_trait]
 impl VectorStoreAdmin for MilvusVectorStoreProvider {
+    /// Collections with an outdated schema are dropped and reported missing,
+    /// so that they are created again and re-indexed
     async fn collection_exists(&self, name: &str) -> Result<bool> {
-        Self::map_milvus_error(self.client.has_collection(name).await, "check collection")
+        let exists =
+            Self::map_milvus_error(self.client.has_collection(name).await, "check collection")?;
+        if !exists || self.has_current_schema(name).await? {
+            return Ok(exists);
+        }
+
+        tracing::warn!(
+            "Milvus collection '{}' predates chunk-id keyed rows; dropping it to be re-indexed",
+            name
+        );
+        Self::map_milvus_error(
+            self.client.drop_collection(name).await,
+            "drop outdated collection",
+        )?;
+        Ok(false)
     }
 
     async fn get_stats(&self, collection: &str) -> Result<HashMap<String, serde_json::Value>> {
@@ -152,10 +465,11 @@ impl VectorStoreAdmin for MilvusVectorStoreProvider {
 impl VectorStoreProvider for MilvusVectorStoreProvider {
     async fn create_collection(&self, name: &str, dimensions: usize) -> Result<()> {
         let schema = CollectionSchemaBuilder::new(name, &format!("Collection for {}", name))
-            .add_field(FieldSchema::new_primary_int64(
-                "id",
-                "primary key field",
-                true, // Use auto_id
+            .add_field(FieldSchema::new_primary_varchar(
+                "chunk_id",
+                "stable chunk ID",
+                false, // Rows are keyed by chunk id, upserts replace them
+                MILVUS_FIELD_VARCHAR_MAX_LENGTH,
             ))
             .add_field(FieldSchema::new_float_vector(
                 "vector",
@@ -168,11 +482,27 @@ impl VectorStoreProvider for MilvusVectorStoreProvider {
                 MILVUS_FIELD_VARCHAR_MAX_LENGTH,
             ))
             .add_field(FieldSchema::new_int64("start_line", "start line"))
+            .add_field(FieldSchema::new_int64("end_line", "end line"))
+            .add_field(FieldSchema::new_varchar(
+                "language",
+                "language",
+                MILVUS_FIELD_VARCHAR_MAX_LENGTH,
+            ))
+            .add_field(FieldSchema::new_varchar(
+                "node_type",
+                "AST node type",
+                MILVUS_FIELD_VARCHAR_MAX_LENGTH,
+            ))
             .add_field(FieldSchema::new_varchar(
                 "content",
                 "content",
                 MILVUS_METADATA_VARCHAR_MAX_LENGTH,
             ))
+            .add_field(FieldSchema::new_varchar(
+                "symbol",
+                "code symbol as JSON",
+                MILVUS_METADATA_VARCHAR_MAX_LENGTH,
+            ))
             .build()
             .map_err(|e| Error::vector_db(format!("Failed to create schema: {}", e)))?;
 
@@ -248,111 +578,39 @@ impl VectorStoreProvider for MilvusVectorStoreProvider {
         vectors: &[Embedding],
         metadata: Vec<HashMap<String, serde_json::Value>>,
     ) -> Result<Vec<String>> {
-        if vectors.is_empty() {
-            return Err(Error::vector_db(
-                "No vectors provided for insertion".to_string(),
-            ));
-        }
-
-        if vectors.len() != metadata.len() {
-            return Err(Error::vector_db(format!(
-                "Vectors ({}) and metadata ({}) arrays must have the same length",
-                vectors.len(),
-                metadata.len()
-            )));
-        }
-
-        // Validate all vectors have the same dimensions
-        let expected_dims = vectors[0].dimensions;
-        for (i, vector) in vectors.iter().enumerate() {
-            if vector.dimensions != expected_dims {
-                return Err(Error::vector_db(format!(
-                    "Vector at index {} has dimensions {} but expected {}",
-                    i, vector.dimensions, expected_dims
-                )));
-            }
-        }
-
-        // Prepare data for insertion
-        let mut vectors_flat = Vec::new();
-        let mut file_paths = Vec::new();
-        let mut start_lines = Vec::new();
-        let mut contents = Vec::new();
-
-        for (embedding, meta) in vectors.iter().zip(metadata.iter()) {
-            vectors_flat.extend_from_slice(&embedding.vector);
-
-            let file_path = meta.string_or("file_path", "unknown");
-            let start_line = meta
-                .opt_i64("start_line")
-                .or_else(|| meta.opt_i64("line_number"))
-                .unwrap_or(0);
-            let content = meta.string_or("content", "");
-
-            file_paths.push(file_path);
-            start_lines.push(start_line);
-            contents.push(content);
-        }
-
-        // With auto_id: true, we don't provide the "id" column
-        let vector_column = FieldColumn {
-            name: "vector".to_string(),
-            dtype: DataType::FloatVector,
-            value: ValueVec::Float(vectors_flat),
-            dim: expected_dims as i64,
-            max_length: 0,
-            is_dynamic: false,
-        };
-        let file_path_column = FieldColumn {
-            name: "file_path".to_string(),
-            dtype: DataType::VarChar,
-            value: ValueVec::String(file_paths),
-            dim: 1,
-            max_length: MILVUS_FIELD_VARCHAR_MAX_LENGTH,
-            is_dynamic: false,
-        };
-        let start_line_column = FieldColumn {
-            name: "start_line".to_string(),
-            dtype: DataType::Int64,
-            value: ValueVec::Long(start_lines),
-            dim: 1,
-            max_length: 0,
-            is_dynamic: false,
-        };
-        let content_column = FieldColumn {
-            name: "content".to_string(),
-            dtype: DataType::VarChar,
-            value: ValueVec::String(contents),
-            dim: 1,
-            max_length: MILVUS_METADATA_VARCHAR_MAX_LENGTH,
-            is_dynamic: false,
-        };
-
-        let columns = vec![
-            vector_column,
-            file_path_column,
-            start_line_column,
-            content_column,
-        ];
-
-        let res = Self::map_milvus_error(
+        // Chunks without an id get a fresh one, as rows need a primary key
+        let chunk_ids: Vec<String> = metadata
+            .iter()
+            .map(|meta| match meta.string_or("id", "") {
+                id if id.is_empty() => uuid::Uuid::new_v4().to_string(),
+                id => id,
+            })
+            .collect();
+        let columns = Self::row_columns(chunk_ids.clone(), vectors, &metadata)?;
+        Self::map_milvus_error(
             self.client.insert(collection, columns, None).await,
             "insert vectors",
         )?;
+        Ok(chunk_ids)
+    }
 
-        // Return IDs as strings from the result
-        let ids = match res.i_ds {
-            Some(ids) => match ids.id_field {
-                Some(milvus::proto::schema::i_ds::IdField::IntId(int_ids)) => {
-                    int_ids.data.iter().map(|id| id.to_string()).collect()
-                }
-                Some(milvus::proto::schema::i_ds::IdField::StrId(str_ids)) => str_ids.data.clone(),
-                None => Vec::new(),
-            },
-            None => Vec::new(),
-        };
+    async fn upsert_vectors(
+        &self,
+        collection: &str,
+        ids: &[String],
+        vectors: &[Embedding],
+        metadata: Vec<HashMap<String, serde_json::Value>>,
+    ) -> Result<()> {
+        if ids.is_empty() && vectors.is_empty() {
+            return Ok(());
+        }
 
-        Ok(ids)
+        let columns = Self::row_columns(ids.to_vec(), vectors, &metadata)?;
+        Self::map_milvus_error(
+            self.client.upsert(collection, columns, None).await,
+            "upsert vectors",
+        )?;
+        Ok(())
     }
 
     async fn search_similar(
@@ -360,7 +618,7 @@ impl VectorStoreProvider for MilvusVectorStoreProvider {
         collection: &str,
         query_vector: &[f32],
         limit: usize,
-        _filter: Option<&str>,
+        filter: Option<&MetadataFilter>,
     ) -> Result<Vec<SearchResult>> {
         if query_vector.is_empty() {
             return Err(Error::vector_db("Query vector cannot be empty".to_string()));
@@ -391,15 +649,27 @@ impl VectorStoreProvider for MilvusVectorStoreProvider {
 
         use milvus::query::SearchOptions;
 
-        let search_options = SearchOptions::new()
-            .limit(limit)
+        // Widened glob predicates may drop hits locally, so over-fetch when filtering
+        let fetch_limit = if filter.is_some() {
+            limit.saturating_mul(MILVUS_FILTER_OVERFETCH_FACTOR)
+        } else {
+            limit
+        };
+        let mut search_options = SearchOptions::new()
+            .limit(fetch_limit)
             .output_fields(vec![
-                "id".to_string(),
                 "file_path".to_string(),
                 "start_line".to_string(),
+                "end_line".to_string(),
+                "language".to_string(),
+                "node_type".to_string(),
                 "content".to_string(),
+                "symbol".to_string(),
             ])
             .add_param("metric_type", "L2");
+        if let Some(filter) = filter {
+            search_options = search_options.expr(milvus_filter_expr(filter));
+        }
 
         let search_results = match self
             .client
@@ -433,12 +703,12 @@ impl VectorStoreProvider for MilvusVectorStoreProvider {
                 columns_map.insert(column.name.as_str(), column);
             }
 
+            // The primary key is the chunk id
             for (i, id_val) in ids.iter().enumerate() {
                 let distance = scores.get(i).copied().unwrap_or(0.0);
                 let score = (-distance).exp();
 
                 let id_str = match id_val {
-                    Value::Long(id) => id.to_string(),
                     Value::String(id) => id.to_string(),
                     _ => "unknown".to_string(),
                 };
@@ -471,32 +741,66 @@ impl VectorStoreProvider for MilvusVectorStoreProvider {
                     })
                     .unwrap_or_default();
 
+                let string_column = |name: &str| {
+                    columns_map
+                        .get(name)
+                        .and_then(|col| col.get(i))
+                        .and_then(|v| match v {
+                            Value::String(s) => Some(s.to_string()),
+                            _ => None,
+                        })
+                };
+                let language = string_column("language").unwrap_or_else(|| "unknown".to_string());
+
+                // Glob predicates are widened when translated to `like`, so
+                // re-check the filter against the returned fields
+                if let Some(filter) = filter {
+                    let end_line = columns_map
+                        .get("end_line")
+                        .and_then(|col| col.get(i))
+                        .map(|v| match v {
+                            Value::Long(n) => n as u32,
+                            _ => start_line,
+                        })
+                        .unwrap_or(start_line);
+                    let mut row = HashMap::from([
+                        ("file_path".to_string(), serde_json::json!(file_path)),
+                        ("language".to_string(), serde_json::json!(language)),
+                        ("start_line".to_string(), serde_json::json!(start_line)),
+                        ("end_line".to_string(), serde_json::json!(end_line)),
+                    ]);
+                    if let Some(node_type) = string_column("node_type") {
+                        row.insert("node_type".to_string(), serde_json::json!(node_type));
+                    }
+                    if !filter.matches(&row) {
+                        continue;
+                    }
+                }
+
                 results.push(SearchResult {
                     id: id_str,
                     file_path,
                     start_line,
                     content,
                     score: score as f64,
-                    language: "unknown".to_string(),
+                    language,
+                    symbol: symbol_at(&columns_map, i),
                 });
             }
         }
 
+        results.truncate(limit);
         Ok(results)
     }
 
     async fn delete_vectors(&self, collection: &str, ids: &[String]) -> Result<()> {
         use milvus::mutate::DeleteOptions;
-        use milvus::value::ValueVec;
 
-        // Convert string IDs to i64 for Milvus
-        let id_numbers: Vec<i64> = ids.iter().filter_map(|id| id.parse::<i64>().ok()).collect();
-
-        if id_numbers.is_empty() {
+        if ids.is_empty() {
             return Ok(()); // Nothing to delete
         }
 
-        let options = DeleteOptions::with_ids(ValueVec::Long(id_numbers));
+        let options = DeleteOptions::with_filter(milvus_ids_expr(ids));
 
         Self::map_milvus_error(
             self.client.delete(collection, &options).await,
@@ -506,6 +810,33 @@ impl VectorStoreProvider for MilvusVectorStoreProvider {
         Ok(())
     }
 
+    async fn delete_by_file_path(&self, collection: &str, file_path: &str) -> Result<()> {
+        use milvus::mutate::DeleteOptions;
+
+        let options =
+            DeleteOptions::with_filter(format!("file_path == {}", milvus_quote(file_path)));
+        Self::map_milvus_error(
+            self.client.delete(collection, &options).await,
+            "delete file vectors",
+        )?;
+        Ok(())
+    }
+
+    async fn list_by_file_path(
+        &self,
+        collection: &str,
+        file_path: &str,
+    ) -> Result<Vec<SearchResult>> {
+        let expr = format!("file_path == {}", milvus_quote(file_path));
+        self.query_rows(
+            collection,
+            &expr,
+            MILVUS_QUERY_MAX_LIMIT,
+            "list file vectors",
+        )
+        .await
+    }
+
     async fn get_vectors_by_ids(
         &self,
         collection: &str,
@@ -515,192 +846,155 @@ impl VectorStoreProvider for MilvusVectorStoreProvider {
             return Ok(Vec::new());
         }
 
-        // Ensure collection is loaded
-        self.client
-            .load_collection(collection, None)
+        self.query_rows(collection, &milvus_ids_expr(ids), ids.len(), "query by IDs")
             .await
-            .map_err(|e| {
-                Error::vector_db(format!("Failed to load collection '{}': {}", collection, e))
-            })?;
-
-        // Construct expression for query
-        let expr = format!("id in [{}]", ids.join(","));
-
-        use milvus::query::QueryOptions;
-        let mut query_options = QueryOptions::new();
-        query_options = query_options.output_fields(vec![
-            "id".to_string(),
-            "file_path".to_string(),
-            "start_line".to_string(),
-            "content".to_string(),
-        ]);
-
-        let query_results = Self::map_milvus_error(
-            self.client.query(collection, &expr, &query_options).await,
-            "query by IDs",
-        )?;
-
-        // Convert results to our format
-        let mut results = Vec::new();
-
-        // Map columns by name
-        let mut columns_map = HashMap::new();
-        for column in &query_results {
-            columns_map.insert(column.name.as_str(), column);
-        }
-
-        let row_count = if let Some(col) = query_results.first() {
-            col.len()
-        } else {
-            0
-        };
-
-        for i in 0..row_count {
-            let id_str = columns_map
-                .get("id")
-                .and_then(|col| col.get(i))
-                .map(|v| match v {
-                    Value::Long(id) => id.to_string(),
-                    Value::String(id) => id.to_string(),
-                    _ => "unknown".to_string(),
-                })
-                .unwrap_or_else(|| "unknown".to_string());
-
-            let file_path = columns_map
-                .get("file_path")
-                .and_then(|col| col.get(i))
-                .map(|v| match v {
-                    Value::String(s) => s.to_string(),
-                    _ => "unknown".to_string(),
-                })
-                .unwrap_or_else(|| "unknown".to_string());
-
-            let start_line = columns_map
-                .get("start_line")
-                .or_else(|| columns_map.get("line_number"))
-                .and_then(|col| col.get(i))
-                .map(|v| match v {
-                    Value::Long(n) => n as u32,
-                    _ => 0,
-                })
-                .unwrap_or(0);
-
-            let content = columns_map
-                .get("content")
-                .and_then(|col| col.get(i))
-                .map(|v| match v {
-                    Value::String(s) => s.to_string(),
-                    _ => "".to_string(),
-                })
-                .unwrap_or_default();
-
-            results.push(SearchResult {
-                id: id_str,
-                file_path,
-                start_line,
-                content,
-                score: 1.0,
-                language: "unknown".to_string(),
-            });
-        }
-
-        Ok(results)
     }
 
     async fn list_vectors(&self, collection: &str, limit: usize) -> Result<Vec<SearchResult>> {
         if limit == 0 {
             return Ok(Vec::new());
         }
-
-        // Ensure collection is loaded
-        self.client
-            .load_collection(collection, None)
+        self.query_rows(collection, MILVUS_EXPR_ALL, limit, "list vectors")
             .await
-            .map_err(|e| {
-                Error::vector_db(format!("Failed to load collection '{}': {}", collection, e))
-            })?;
+    }
+}
 
-        let expr = "id >= 0".to_string();
-        use milvus::query::QueryOptions;
-        let mut query_options = QueryOptions::new();
-        query_options = query_options.limit(limit as i64).output_fields(vec![
-            "id".to_string(),
-            "file_path".to_string(),
-            "start_line".to_string(),
-            "content".to_string(),
-        ]);
+/// Parse the JSON `symbol` column of row `row`, if the chunk has a symbol
+fn symbol_at(columns: &HashMap<&str, &FieldColumn>, row: usize) -> Option<CodeSymbol> {
+    match columns.get("symbol").and_then(|col| col.get(row)) {
+        Some(Value::String(json)) if !json.is_empty() => serde_json::from_str(&json).ok(),
+        _ => None,
+    }
+}
 
-        let query_results = Self::map_milvus_error(
-            self.client.query(collection, &expr, &query_options).await,
-            "list vectors",
-        )?;
+// ============================================================================
+// Filter translation
+// ============================================================================
 
-        // Convert results to our format
-        let mut results = Vec::new();
+/// Translate a metadata filter into a Milvus boolean expression
+///
+/// Glob patterns have no exact Milvus equivalent and are widened to `like`
+/// patterns, so the expression may select a superset of the matching rows;
+/// `search_similar` re-checks each hit with [`MetadataFilter::matches`].
+pub(crate) fn milvus_filter_expr(filter: &MetadataFilter) -> String {
+    milvus_expr(filter, true)
+}
 
-        // Map columns by name
-        let mut columns_map = HashMap::new();
-        for column in &query_results {
-            columns_map.insert(column.name.as_str(), column);
+/// Build the expression for `filter`; `positive` is false under an odd number
+/// of negations, where widening a predicate would narrow the overall result.
+fn milvus_expr(filter: &MetadataFilter, positive: bool) -> String {
+    match filter {
+        MetadataFilter::Eq { field, value } => {
+            format!("{} == {}", field.key(), milvus_quote(value))
         }
+        MetadataFilter::In { field, values } => {
+            let values: Vec<String> = values.iter().map(|v| milvus_quote(v)).collect();
+            format!("{} in [{}]", field.key(), values.join(", "))
+        }
+        MetadataFilter::Prefix { field, prefix } => format!(
+            "{} like {}",
+            field.key(),
+            milvus_quote(&format!("{}%", escape_like(prefix)))
+        ),
+        MetadataFilter::Glob { field, pattern } => {
+            if positive {
+                format!(
+                    "{} like {}",
+                    field.key(),
+                    milvus_quote(&glob_to_like(pattern))
+                )
+            } else {
+                // Under negation, select nothing here and let the local check decide
+                MILVUS_EXPR_NONE.to_string()
+            }
+        }
+        MetadataFilter::LineRange { start, end } => {
+            let mut parts = Vec::new();
+            if let Some(start) = start {
+                parts.push(format!("end_line >= {}", start));
+            }
+            if let Some(end) = end {
+                parts.push(format!("start_line <= {}", end));
+            }
+            if parts.is_empty() {
+                MILVUS_EXPR_ALL.to_string()
+            } else {
+                parts.join(" and ")
+            }
+        }
+        MetadataFilter::And(filters) if filters.is_empty() => MILVUS_EXPR_ALL.to_string(),
+        MetadataFilter::And(filters) => join_exprs(filters, " and ", positive),
+        MetadataFilter::Or(filters) if filters.is_empty() => MILVUS_EXPR_NONE.to_string(),
+        MetadataFilter::Or(filters) => join_exprs(filters, " or ", positive),
+        MetadataFilter::Not(inner) => format!("not ({})", milvus_expr(inner, !positive)),
+    }
+}
 
-        let row_count = if let Some(col) = query_results.first() {
-            col.len()
-        } else {
-            0
-        };
+fn join_exprs(filters: &[MetadataFilter], separator: &str, positive: bool) -> String {
+    filters
+        .iter()
+        .map(|f| format!("({})", milvus_expr(f, positive)))
+        .collect::<Vec<_>>()
+        .join(separator)
+}
 
-        for i in 0..row_count {
-            let id_str = columns_map
-                .get("id")
-                .and_then(|col| col.get(i))
-                .map(|v| match v {
-                    Value::Long(id) => id.to_string(),
-                    Value::String(id) => id.to_string(),
-                    _ => "unknown".to_string(),
-                })
-                .unwrap_or_else(|| "unknown".to_string());
+/// Expression selecting the rows of the given chunk ids
+pub(crate) fn milvus_ids_expr(ids: &[String]) -> String {
+    let quoted: Vec<String> = ids.iter().map(|id| milvus_quote(id)).collect();
+    format!("chunk_id in [{}]", quoted.join(", "))
+}
 
-            let file_path = columns_map
-                .get("file_path")
-                .and_then(|col| col.get(i))
-                .map(|v| match v {
-                    Value::String(s) => s.to_string(),
-                    _ => "unknown".to_string(),
-                })
-                .unwrap_or_else(|| "unknown".to_string());
+/// Check whether a collection with these fields can be written
+///
+/// Rows used to be keyed by an auto-generated `id` field, with fewer
+/// metadata fields; such collections must be recreated.
+pub(crate) fn milvus_schema_is_current<'a>(fields: impl IntoIterator<Item = &'a str>) -> bool {
+    let fields: Vec<&str> = fields.into_iter().collect();
+    !fields.contains(&"id") && MILVUS_SCHEMA_FIELDS.iter().all(|f| fields.contains(f))
+}
 
-            let start_line = columns_map
-                .get("start_line")
-                .or_else(|| columns_map.get("line_number"))
-                .and_then(|col| col.get(i))
-                .map(|v| match v {
-                    Value::Long(n) => n as u32,
-                    _ => 0,
-                })
-                .unwrap_or(0);
+/// Quote a string literal for a Milvus expression
+fn milvus_quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
 
-            let content = columns_map
-                .get("content")
-                .and_then(|col| col.get(i))
-                .map(|v| match v {
-                    Value::String(s) => s.to_string(),
-                    _ => "".to_string(),
-                })
-                .unwrap_or_default();
+/// Escape `like` wildcards in a literal
+fn escape_like(value: &str) -> String {
+    value.replace('%', "\\%").replace('_', "\\_")
+}
 
-            results.push(SearchResult {
-                id: id_str,
-                file_path,
-                start_line,
-                content,
-                score: 1.0,
-                language: "unknown".to_string(),
-            });
+/// Widen a glob pattern to a `like` pattern (`*`, `**`, `**/` -> `%`, `?` -> `_`)
+///
+/// `**/` matches no directory at all too, so it becomes a bare `%`: `**/*.rs`
+/// must select a root-level `main.rs` as well as `src/main.rs`.
+pub(crate) fn glob_to_like(pattern: &str) -> String {
+    let mut like = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' => {
+                let mut stars = 1;
+                while chars.peek() == Some(&'*') {
+                    chars.next();
+                    stars += 1;
+                }
+                if stars > 1 && chars.peek() == Some(&'/') {
+                    chars.next();
+                }
+                // `%%` selects the same rows as `%`
+                if !like.ends_with('%') || like.ends_with("\\%") {
+                    like.push('%');
+                }
+            }
+            '?' => like.push('_'),
+            '%' | '_' => {
+                like.push('\\');
+                like.push(c);
+            }
+            _ => like.push(c),
         }
-
-        Ok(results)
     }
+    like
 }
 
 // ============================================================================
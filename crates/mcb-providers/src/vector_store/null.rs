@@ -7,7 +7,7 @@ use async_trait::async_trait;
 use dashmap::DashMap;
 use mcb_domain::error::{Error, Result};
 use mcb_domain::ports::providers::{VectorStoreAdmin, VectorStoreProvider};
-use mcb_domain::value_objects::{Embedding, SearchResult};
+use mcb_domain::value_objects::{Embedding, MetadataFilter, SearchResult};
 use serde_json::Value;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -98,12 +98,22 @@ impl VectorStoreProvider for NullVectorStoreProvider {
         Ok(vec!["".to_string(); vectors.len()])
     }
 
+    async fn upsert_vectors(
+        &self,
+        _collection: &str,
+        _ids: &[String],
+        _vectors: &[Embedding],
+        _metadata: Vec<HashMap<String, Value>>,
+    ) -> Result<()> {
+        Ok(())
+    }
+
     async fn search_similar(
         &self,
         _collection: &str,
         _query_vector: &[f32],
         _limit: usize,
-        _filter: Option<&str>,
+        _filter: Option<&MetadataFilter>,
     ) -> Result<Vec<SearchResult>> {
         // Null provider always returns empty results
         Ok(Vec::new())
@@ -113,6 +123,18 @@ impl VectorStoreProvider for NullVectorStoreProvider {
         Ok(())
     }
 
+    async fn delete_by_file_path(&self, _collection: &str, _file_path: &str) -> Result<()> {
+        Ok(())
+    }
+
+    async fn list_by_file_path(
+        &self,
+        _collection: &str,
+        _file_path: &str,
+    ) -> Result<Vec<SearchResult>> {
+        Ok(Vec::new())
+    }
+
     async fn get_vectors_by_ids(
         &self,
         _collection: &str,
@@ -6,6 +6,7 @@
 
 use mcb_application::ports::admin::{IndexingOperationsInterface, PerformanceMetricsInterface};
 use mcb_application::ports::infrastructure::EventBusProvider;
+use mcb_application::ports::services::{ContextServiceInterface, IndexingServiceInterface};
 use mcb_infrastructure::config::watcher::ConfigWatcher;
 use rocket::config::{Config as RocketConfig, LogLevel};
 use std::net::IpAddr;
@@ -78,6 +79,8 @@ impl AdminApi {
             state: AdminState {
                 metrics,
                 indexing,
+                indexing_service: None,
+                context_service: None,
                 config_watcher: None,
                 config_path: None,
                 shutdown_coordinator: None,
@@ -103,6 +106,8 @@ impl AdminApi {
             state: AdminState {
                 metrics,
                 indexing,
+                indexing_service: None,
+                context_service: None,
                 config_watcher: None,
                 config_path: None,
                 shutdown_coordinator: None,
@@ -130,6 +135,8 @@ impl AdminApi {
             state: AdminState {
                 metrics,
                 indexing,
+                indexing_service: None,
+                context_service: None,
                 config_watcher: Some(config_watcher),
                 config_path: Some(config_path),
                 shutdown_coordinator: None,
@@ -142,6 +149,24 @@ impl AdminApi {
         }
     }
 
+    /// Let the admin API cancel indexing operations through `indexing_service`
+    pub fn with_indexing_service(
+        mut self,
+        indexing_service: Arc<dyn IndexingServiceInterface>,
+    ) -> Self {
+        self.state.indexing_service = Some(indexing_service);
+        self
+    }
+
+    /// Let the admin API report embedding cache stats of `context_service`
+    pub fn with_context_service(
+        mut self,
+        context_service: Arc<dyn ContextServiceInterface>,
+    ) -> Self {
+        self.state.context_service = Some(context_service);
+        self
+    }
+
     /// Start the admin API server
     ///
     /// Returns a handle that can be used to gracefully shutdown the server.
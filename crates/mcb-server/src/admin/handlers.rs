@@ -1,7 +1,8 @@
 //! Admin request handlers
 //!
 //! HTTP handlers for admin API endpoints including health checks,
-//! performance metrics, indexing status, and runtime configuration management.
+//! performance metrics, indexing status and cancellation, and runtime
+//! configuration management.
 //!
 //! Migrated from Axum to Rocket in v0.1.2 (ADR-026).
 //! Authentication guards added in v0.1.2.
@@ -13,6 +14,10 @@ use mcb_application::ports::admin::{
 };
 use mcb_application::ports::infrastructure::EventBusProvider;
 use mcb_application::ports::providers::CacheProvider;
+use mcb_application::ports::services::{
+    ContextServiceInterface, EmbeddingCacheStats, IndexingServiceInterface,
+};
+use mcb_domain::error::Error;
 use mcb_infrastructure::config::watcher::ConfigWatcher;
 use mcb_infrastructure::infrastructure::ServiceManager;
 use rocket::http::Status;
@@ -33,6 +38,10 @@ pub struct AdminState {
     pub metrics: Arc<dyn PerformanceMetricsInterface>,
     /// Indexing operations tracker
     pub indexing: Arc<dyn IndexingOperationsInterface>,
+    /// Indexing service for cancelling operations
+    pub indexing_service: Option<Arc<dyn IndexingServiceInterface>>,
+    /// Context service for embedding cache stats
+    pub context_service: Option<Arc<dyn ContextServiceInterface>>,
     /// Configuration watcher for hot-reload support
     pub config_watcher: Option<Arc<ConfigWatcher>>,
     /// Configuration file path (for updates)
@@ -140,6 +149,86 @@ pub fn get_indexing_status(state: &State<AdminState>) -> Json<IndexingStatusResp
     })
 }
 
+/// Indexing cancellation response
+#[derive(Serialize)]
+pub struct CancelIndexingResponse {
+    /// Whether cancellation was requested
+    pub cancelled: bool,
+    /// Id of the indexing operation
+    pub operation_id: String,
+    /// Message describing the outcome
+    pub message: String,
+}
+
+impl CancelIndexingResponse {
+    fn new(cancelled: bool, operation_id: &str, message: impl Into<String>) -> Self {
+        Self {
+            cancelled,
+            operation_id: operation_id.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+/// Cancel a running indexing operation (protected)
+///
+/// The operation stops before its next file. Files committed so far are
+/// checkpointed, so indexing the collection again resumes after them.
+///
+/// # Authentication
+///
+/// Requires valid admin API key via `X-Admin-Key` header.
+#[post("/indexing/<operation_id>/cancel")]
+pub async fn cancel_indexing(
+    _auth: AdminAuth,
+    state: &State<AdminState>,
+    operation_id: &str,
+) -> (Status, Json<CancelIndexingResponse>) {
+    let Some(indexing_service) = &state.indexing_service else {
+        return (
+            Status::ServiceUnavailable,
+            Json(CancelIndexingResponse::new(
+                false,
+                operation_id,
+                "Indexing service not available",
+            )),
+        );
+    };
+
+    match indexing_service.cancel_indexing(operation_id).await {
+        Ok(()) => {
+            info!(
+                operation_id = operation_id,
+                "Indexing cancellation requested"
+            );
+            (
+                Status::Ok,
+                Json(CancelIndexingResponse::new(
+                    true,
+                    operation_id,
+                    "Cancellation requested",
+                )),
+            )
+        }
+        Err(e @ Error::NotFound { .. }) => (
+            Status::NotFound,
+            Json(CancelIndexingResponse::new(
+                false,
+                operation_id,
+                e.to_string(),
+            )),
+        ),
+        Err(e) => (
+            Status::InternalServerError,
+            Json(CancelIndexingResponse::new(
+                false,
+                operation_id,
+                e.to_string(),
+            )),
+        ),
+    }
+}
+
 /// Readiness response
 #[derive(Serialize)]
 pub struct ReadinessResponse {
@@ -464,3 +553,28 @@ pub async fn get_cache_stats(
         )),
     }
 }
+
+/// Get embedding cache statistics (protected)
+///
+/// Returns how many texts were served from the embedding cache instead of
+/// being sent to the embedding provider.
+///
+/// # Authentication
+///
+/// Requires valid admin API key via `X-Admin-Key` header.
+#[get("/cache/embeddings/stats")]
+pub async fn get_embedding_cache_stats(
+    _auth: AdminAuth,
+    state: &State<AdminState>,
+) -> Result<Json<EmbeddingCacheStats>, (Status, Json<CacheErrorResponse>)> {
+    let Some(context_service) = &state.context_service else {
+        return Err((
+            Status::ServiceUnavailable,
+            Json(CacheErrorResponse {
+                error: "Context service not available".to_string(),
+            }),
+        ));
+    };
+
+    Ok(Json(context_service.embedding_cache_stats()))
+}
@@ -20,12 +20,15 @@
 //! | `/health/extended` | GET | Extended health with dependency status |
 //! | `/metrics` | GET | Performance metrics |
 //! | `/indexing` | GET | Indexing operations status |
+//! | `/indexing/:id/cancel` | POST | Cancel a running indexing operation |
 //! | `/ready` | GET | Kubernetes readiness probe |
 //! | `/live` | GET | Kubernetes liveness probe |
 //! | `/shutdown` | POST | Initiate graceful server shutdown |
 //! | `/config` | GET | Current configuration (sanitized) |
 //! | `/config/reload` | POST | Reload configuration from file |
 //! | `/config/:section` | PATCH | Update a configuration section |
+//! | `/cache/stats` | GET | Cache statistics |
+//! | `/cache/embeddings/stats` | GET | Embedding cache hit and miss counts |
 
 pub mod api;
 pub mod auth;
@@ -11,8 +11,8 @@ use std::sync::Arc;
 use super::auth::AdminAuthConfig;
 use super::config_handlers::{get_config, reload_config, update_config_section};
 use super::handlers::{
-    AdminState, extended_health_check, get_cache_stats, get_indexing_status, get_metrics,
-    health_check, liveness_check, readiness_check, shutdown,
+    AdminState, cancel_indexing, extended_health_check, get_cache_stats, get_embedding_cache_stats,
+    get_indexing_status, get_metrics, health_check, liveness_check, readiness_check, shutdown,
 };
 use super::lifecycle_handlers::{
     list_services, restart_service, services_health, start_service, stop_service,
@@ -26,6 +26,7 @@ use super::sse::events_stream;
 /// - GET /health/extended - Extended health check with dependency status
 /// - GET /metrics - Performance metrics
 /// - GET /indexing - Indexing operations status
+/// - POST /indexing/:id/cancel - Cancel a running indexing operation (protected)
 /// - GET /ready - Kubernetes readiness probe (public)
 /// - GET /live - Kubernetes liveness probe (public)
 /// - POST /shutdown - Initiate graceful server shutdown (protected)
@@ -39,6 +40,7 @@ use super::sse::events_stream;
 /// - POST /services/:name/stop - Stop a service (protected)
 /// - POST /services/:name/restart - Restart a service (protected)
 /// - GET /cache/stats - Cache statistics (protected)
+/// - GET /cache/embeddings/stats - Embedding cache statistics (protected)
 ///
 /// # Authentication
 ///
@@ -53,6 +55,7 @@ pub fn admin_rocket(state: AdminState, auth_config: Arc<AdminAuthConfig>) -> Roc
             extended_health_check,
             get_metrics,
             get_indexing_status,
+            cancel_indexing,
             readiness_check,
             liveness_check,
             // Service control
@@ -71,6 +74,7 @@ pub fn admin_rocket(state: AdminState, auth_config: Arc<AdminAuthConfig>) -> Roc
             restart_service,
             // Cache management
             get_cache_stats,
+            get_embedding_cache_stats,
         ],
     )
 }
@@ -3,6 +3,7 @@
 //! This module contains all the argument types used by the MCP tools.
 //! These are extracted to improve code organization and maintainability.
 
+use mcb_domain::value_objects::FusionStrategy;
 use schemars::JsonSchema;
 use serde::Deserialize;
 use validator::Validate;
@@ -33,6 +34,11 @@ pub struct IndexCodebaseArgs {
     /// Whether to follow symbolic links
     #[schemars(description = "Follow symbolic links during indexing")]
     pub follow_symlinks: Option<bool>,
+    /// Whether to wait for indexing to finish instead of running it in the background
+    #[schemars(
+        description = "Wait for indexing to finish instead of returning an operation id immediately"
+    )]
+    pub wait: Option<bool>,
     /// Optional JWT token for authentication
     #[schemars(description = "JWT token for authenticated requests")]
     pub token: Option<String>,
@@ -88,8 +94,18 @@ pub struct SearchCodeArgs {
     #[schemars(description = "Only search in files with these extensions")]
     pub extensions: Option<Vec<String>>,
     /// Optional search filters
+    #[validate(nested)]
     #[schemars(description = "Optional filters to narrow down search results")]
     pub filters: Option<SearchFilters>,
+    /// How keyword and semantic rankings are fused (default: server config)
+    #[validate(custom(
+        function = "validate_fusion_strategy",
+        message = "Invalid fusion strategy"
+    ))]
+    #[schemars(
+        description = "Score fusion of keyword and semantic matches: 'linear', 'rrf' (better for exact identifiers) or 'distribution'"
+    )]
+    pub fusion: Option<String>,
     /// Optional JWT token for authentication
     #[schemars(description = "JWT token for authenticated requests")]
     pub token: Option<String>,
@@ -114,6 +130,20 @@ pub struct GetIndexingStatusArgs {
     pub collection: String,
 }
 
+/// Arguments for cancelling an indexing operation
+#[derive(Debug, Deserialize, JsonSchema, Validate)]
+#[schemars(description = "Parameters for cancelling an indexing operation")]
+pub struct CancelIndexingArgs {
+    /// Id of the running indexing operation
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "Operation id must be between 1 and 100 characters"
+    ))]
+    #[schemars(description = "Id of the indexing operation, as returned by index_codebase")]
+    pub operation_id: String,
+}
+
 /// Arguments for clearing an index
 #[derive(Debug, Deserialize, JsonSchema, Validate)]
 #[schemars(description = "Parameters for clearing an index")]
@@ -193,6 +223,13 @@ fn validate_search_query(query: &str) -> Result<(), validator::ValidationError>
     Ok(())
 }
 
+fn validate_fusion_strategy(fusion: &str) -> Result<(), validator::ValidationError> {
+    fusion
+        .parse::<FusionStrategy>()
+        .map(|_| ())
+        .map_err(|_| validator::ValidationError::new("Unknown fusion strategy"))
+}
+
 fn validate_collection_name(name: &str) -> Result<(), validator::ValidationError> {
     if name.is_empty() {
         return Err(validator::ValidationError::new(
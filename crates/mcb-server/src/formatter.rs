@@ -11,6 +11,7 @@ use std::time::Duration;
 
 use mcb_application::domain_services::search::{IndexingResult, IndexingStatus};
 use mcb_domain::SearchResult;
+use mcb_domain::ports::providers::HybridSearchResult;
 
 /// Response formatter for MCP server tools
 pub struct ResponseFormatter;
@@ -19,7 +20,7 @@ impl ResponseFormatter {
     /// Format search response for display
     pub fn format_search_response(
         query: &str,
-        results: &[SearchResult],
+        results: &[HybridSearchResult],
         duration: Duration,
         limit: usize,
     ) -> Result<CallToolResult, McpError> {
@@ -51,7 +52,7 @@ impl ResponseFormatter {
 
     fn format_search_results(
         message: &mut String,
-        results: &[SearchResult],
+        results: &[HybridSearchResult],
         limit: usize,
         duration: Duration,
     ) {
@@ -129,12 +130,39 @@ impl ResponseFormatter {
         CallToolResult::error(vec![Content::text(message)])
     }
 
+    /// Format response for an indexing operation started in the background
+    pub fn format_indexing_started(
+        operation_id: &str,
+        collection: &str,
+        path: &Path,
+    ) -> CallToolResult {
+        let message = format!(
+            "🚀 **Indexing Started**\n\n\
+             • Operation: `{}`\n\
+             • Collection: `{}`\n\
+             • Source directory: `{}`\n\n\
+             Indexing runs in the background. Use `get_indexing_status` with \
+             collection `{}` to follow its progress, or `cancel_indexing` to stop it.\n",
+            operation_id,
+            collection,
+            path.display(),
+            collection
+        );
+        tracing::info!(
+            "Indexing operation {} started for {}",
+            operation_id,
+            path.display()
+        );
+        CallToolResult::success(vec![Content::text(message)])
+    }
+
     /// Format indexing status response
     pub fn format_indexing_status(status: &IndexingStatus) -> CallToolResult {
         let mut message = String::new();
 
         if status.is_indexing {
             message.push_str("🔄 **Indexing Status: In Progress**\n");
+            format_operation_impl(&mut message, status);
             message.push_str(&format!("Progress: {:.1}%\n", status.progress * 100.0));
             if let Some(current_file) = &status.current_file {
                 message.push_str(&format!("Current file: `{}`\n", current_file));
@@ -143,18 +171,50 @@ impl ResponseFormatter {
                 "Files processed: {}/{}\n",
                 status.processed_files, status.total_files
             ));
+            if let Some(eta_secs) = status.eta_secs {
+                message.push_str(&format!("Estimated time remaining: {}s\n", eta_secs));
+            }
+        } else if status.cancelled {
+            message.push_str("⏹️ **Indexing Status: Cancelled**\n");
+            format_operation_impl(&mut message, status);
+            message.push_str(&format!(
+                "Stopped after {}/{} files. Index the collection again to resume.\n",
+                status.processed_files, status.total_files
+            ));
         } else {
             message.push_str("📋 **Indexing Status: Idle**\n");
+            format_operation_impl(&mut message, status);
             if status.total_files > 0 {
                 message.push_str(&format!(
                     "Last run processed {}/{} files\n",
                     status.processed_files, status.total_files
                 ));
+            } else if status.operation_id.is_some() && status.errors.is_empty() {
+                message.push_str("Last run found no changed files.\n");
             } else {
                 message.push_str("No indexing operation is currently running.\n");
             }
         }
 
+        if !status.errors.is_empty() {
+            message.push_str(&format!("\n⚠️ **Errors:** {}\n", status.errors.len()));
+            for error in &status.errors {
+                message.push_str(&format!("• {}\n", error));
+            }
+        }
+
+        CallToolResult::success(vec![Content::text(message)])
+    }
+
+    /// Format response for a cancelled indexing operation
+    pub fn format_indexing_cancelled(operation_id: &str) -> CallToolResult {
+        let message = format!(
+            "⏹️ **Indexing Cancelled**\n\n\
+             Operation `{}` stops before its next file. Files indexed so far are kept, \
+             and indexing the collection again resumes where it stopped.",
+            operation_id
+        );
+        tracing::info!("Indexing operation {} cancelled", operation_id);
         CallToolResult::success(vec![Content::text(message)])
     }
 
@@ -170,6 +230,15 @@ impl ResponseFormatter {
 
 // Helper functions extracted to reduce impl block size
 
+fn format_operation_impl(message: &mut String, status: &IndexingStatus) {
+    if let Some(collection) = &status.collection {
+        message.push_str(&format!("Collection: `{}`\n", collection));
+    }
+    if let Some(operation_id) = &status.operation_id {
+        message.push_str(&format!("Operation: `{}`\n", operation_id));
+    }
+}
+
 fn format_empty_search_response_impl(message: &mut String) {
     message.push_str("❌ **No Results Found**\n\n");
     message.push_str("**Possible Reasons:**\n");
@@ -185,22 +254,38 @@ fn format_empty_search_response_impl(message: &mut String) {
 
 fn format_search_results_impl(
     message: &mut String,
-    results: &[SearchResult],
+    results: &[HybridSearchResult],
     limit: usize,
     duration: Duration,
 ) {
     message.push_str("📊 **Search Results:**\n\n");
 
-    for (i, result) in results.iter().enumerate() {
+    for (i, hit) in results.iter().enumerate() {
+        let result = &hit.result;
         message.push_str(&format!(
             "**{}.** 📁 `{}` (line {})\n",
             i + 1,
             result.file_path,
             result.start_line
         ));
+        if let Some(symbol) = &result.symbol {
+            message.push_str(&format!("🔣 {}\n", symbol));
+            if let Some(doc) = symbol.doc.as_deref().and_then(|doc| doc.lines().next()) {
+                message.push_str(&format!("📝 {}\n", doc));
+            }
+        }
 
         format_code_preview_impl(message, result);
-        message.push_str(&format!("🎯 **Relevance Score:** {:.3}\n\n", result.score));
+        match hit.rerank_score {
+            Some(rerank_score) => message.push_str(&format!(
+                "🎯 **Relevance Score:** {:.3} (reranked; fused {:.3}, semantic {:.3}, BM25 {:.3})\n\n",
+                rerank_score, hit.hybrid_score, hit.semantic_score, hit.bm25_score
+            )),
+            None => message.push_str(&format!(
+                "🎯 **Relevance Score:** {:.3} (semantic {:.3}, BM25 {:.3})\n\n",
+                hit.hybrid_score, hit.semantic_score, hit.bm25_score
+            )),
+        }
     }
 
     if results.len() == limit {
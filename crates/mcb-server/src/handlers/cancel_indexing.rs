@@ -0,0 +1,50 @@
+//! Cancel Indexing Tool Handler
+//!
+//! Handles the cancel_indexing MCP tool call using the domain indexing service.
+
+use rmcp::ErrorData as McpError;
+use rmcp::handler::server::wrapper::Parameters;
+use rmcp::model::CallToolResult;
+use std::sync::Arc;
+use validator::Validate;
+
+use mcb_application::domain_services::search::IndexingServiceInterface;
+
+use crate::args::CancelIndexingArgs;
+use crate::formatter::ResponseFormatter;
+
+/// Handler for indexing cancellation operations
+pub struct CancelIndexingHandler {
+    indexing_service: Arc<dyn IndexingServiceInterface>,
+}
+
+impl CancelIndexingHandler {
+    /// Create a new cancel_indexing handler
+    pub fn new(indexing_service: Arc<dyn IndexingServiceInterface>) -> Self {
+        Self { indexing_service }
+    }
+
+    /// Handle the cancel_indexing tool request
+    pub async fn handle(
+        &self,
+        Parameters(args): Parameters<CancelIndexingArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        if let Err(e) = args.validate() {
+            return Err(McpError::invalid_params(
+                format!("Invalid arguments: {}", e),
+                None,
+            ));
+        }
+
+        self.indexing_service
+            .cancel_indexing(&args.operation_id)
+            .await
+            .map_err(|e| {
+                McpError::invalid_params(format!("Failed to cancel indexing: {}", e), None)
+            })?;
+
+        Ok(ResponseFormatter::format_indexing_cancelled(
+            &args.operation_id,
+        ))
+    }
+}
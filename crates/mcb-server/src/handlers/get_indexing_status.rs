@@ -36,7 +36,9 @@ impl GetIndexingStatusHandler {
             ));
         }
 
-        let status = self.indexing_service.get_status();
+        let status = self
+            .indexing_service
+            .get_collection_status(&args.collection);
         Ok(ResponseFormatter::format_indexing_status(&status))
     }
 }
@@ -10,7 +10,7 @@ use std::sync::Arc;
 use std::time::Instant;
 use validator::Validate;
 
-use mcb_application::domain_services::search::IndexingServiceInterface;
+use mcb_application::domain_services::search::{IndexingOptions, IndexingServiceInterface};
 
 use crate::args::IndexCodebaseArgs;
 use crate::formatter::ResponseFormatter;
@@ -54,9 +54,33 @@ impl IndexCodebaseHandler {
         }
 
         let collection = args.collection.as_deref().unwrap_or("default");
+        let options = build_indexing_options(&args);
+
+        if !args.wait.unwrap_or(false) {
+            return match self
+                .indexing_service
+                .start_indexing(path, collection, &options)
+                .await
+            {
+                Ok(operation_id) => Ok(ResponseFormatter::format_indexing_started(
+                    &operation_id,
+                    collection,
+                    path,
+                )),
+                Err(e) => Ok(ResponseFormatter::format_indexing_error(
+                    &e.to_string(),
+                    path,
+                )),
+            };
+        }
+
         let timer = Instant::now();
 
-        match self.indexing_service.index_codebase(path, collection).await {
+        match self
+            .indexing_service
+            .index_codebase(path, collection, &options)
+            .await
+        {
             Ok(result) => Ok(ResponseFormatter::format_indexing_success(
                 &result,
                 path,
@@ -69,3 +93,19 @@ impl IndexCodebaseHandler {
         }
     }
 }
+
+/// Translate tool arguments into indexing options
+fn build_indexing_options(args: &IndexCodebaseArgs) -> IndexingOptions {
+    let mut options =
+        IndexingOptions::default().with_follow_symlinks(args.follow_symlinks.unwrap_or(false));
+    if let Some(extensions) = &args.extensions {
+        options = options.with_extensions(extensions);
+    }
+    if let Some(patterns) = &args.ignore_patterns {
+        options = options.with_ignore_patterns(patterns.iter().cloned());
+    }
+    if let Some(max_file_size) = args.max_file_size {
+        options = options.with_max_file_size(max_file_size);
+    }
+    options
+}
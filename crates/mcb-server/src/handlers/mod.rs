@@ -3,12 +3,14 @@
 //! Implementations of MCP tool calls using domain services.
 //! Each handler translates MCP protocol requests into domain service calls.
 
+pub mod cancel_indexing;
 pub mod clear_index;
 pub mod get_indexing_status;
 pub mod index_codebase;
 pub mod search_code;
 
 // Re-export handlers for convenience
+pub use cancel_indexing::CancelIndexingHandler;
 pub use clear_index::ClearIndexHandler;
 pub use get_indexing_status::GetIndexingStatusHandler;
 pub use index_codebase::IndexCodebaseHandler;
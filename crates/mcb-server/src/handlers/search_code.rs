@@ -10,6 +10,7 @@ use std::time::Instant;
 use validator::Validate;
 
 use mcb_application::domain_services::search::SearchServiceInterface;
+use mcb_domain::value_objects::SearchQuery;
 
 use crate::args::SearchCodeArgs;
 use crate::formatter::ResponseFormatter;
@@ -39,11 +40,12 @@ impl SearchCodeHandler {
         }
 
         let collection = args.collection.as_deref().unwrap_or("default");
+        let query = build_search_query(&args);
         let timer = Instant::now();
 
         let results = self
             .search_service
-            .search(collection, &args.query, args.limit)
+            .search(collection, &query)
             .await
             .map_err(|e| McpError::internal_error(format!("Search failed: {}", e), None))?;
 
@@ -55,3 +57,36 @@ impl SearchCodeHandler {
         )
     }
 }
+
+/// Translate tool arguments into a search query
+///
+/// `extensions` and `filters.file_extensions` are merged into a single
+/// allow-list; every other filter narrows the result set further.
+fn build_search_query(args: &SearchCodeArgs) -> SearchQuery {
+    let mut query = SearchQuery::new(args.query.as_str(), args.limit);
+    let filters = args.filters.as_ref();
+
+    let extensions: Vec<&String> = args
+        .extensions
+        .iter()
+        .chain(filters.and_then(|f| f.file_extensions.as_ref()))
+        .flatten()
+        .collect();
+    query = query.with_extensions(extensions);
+
+    if let Some(filters) = filters {
+        if let Some(languages) = &filters.languages {
+            query = query.with_languages(languages);
+        }
+        if let Some(patterns) = &filters.exclude_patterns {
+            query = query.excluding(patterns);
+        }
+        if let Some(min_score) = filters.min_score {
+            query = query.with_min_score(f64::from(min_score));
+        }
+    }
+    if let Some(fusion) = args.fusion.as_deref().and_then(|f| f.parse().ok()) {
+        query = query.with_fusion(fusion);
+    }
+    query
+}
@@ -33,6 +33,7 @@ use std::sync::Arc;
 use mcb_infrastructure::cache::provider::SharedCacheProvider;
 use mcb_infrastructure::config::TransportMode;
 use mcb_infrastructure::crypto::CryptoService;
+use mcb_infrastructure::infrastructure::ServiceManager;
 use tracing::{error, info};
 
 use crate::McpServer;
@@ -67,10 +68,19 @@ pub async fn run_server(config_path: Option<&Path>) -> Result<(), Box<dyn std::e
     let http_host = config.server.network.host.clone();
     let http_port = config.server.network.port;
 
-    let server = create_mcp_server(config).await?;
+    let (server, service_manager) = create_mcp_server(config).await?;
     info!("MCP server initialized successfully");
 
-    start_transport(server, transport_mode, &http_host, http_port).await
+    let result = start_transport(server, transport_mode, &http_host, http_port).await;
+
+    // Stop background services once the transports are done
+    for (name, stop_result) in service_manager.stop_all().await {
+        if let Err(e) = stop_result {
+            error!(service = %name, error = %e, "Failed to stop background service");
+        }
+    }
+
+    result
 }
 
 /// Load configuration from optional path
@@ -85,9 +95,12 @@ fn load_config(
 }
 
 /// Create and configure the MCP server with all services
+///
+/// Returns the server along with the manager of the background services it
+/// started, which the caller stops on shutdown.
 async fn create_mcp_server(
     config: mcb_infrastructure::config::AppConfig,
-) -> Result<McpServer, Box<dyn std::error::Error>> {
+) -> Result<(McpServer, ServiceManager), Box<dyn std::error::Error>> {
     // Create AppContext with resolved providers
     let app_context = mcb_infrastructure::di::bootstrap::init_app(config.clone()).await?;
 
@@ -96,6 +109,8 @@ async fn create_mcp_server(
     let vector_store_provider = app_context.vector_store_handle().get();
     let cache_provider = app_context.cache_handle().get();
     let language_chunker = app_context.language_handle().get();
+    let hybrid_search_provider = app_context.hybrid_search_handle().get();
+    let reranker_provider = app_context.reranker_handle().get();
 
     // Create shared cache provider (conversion for domain services factory)
     let shared_cache = SharedCacheProvider::from_arc(cache_provider);
@@ -109,6 +124,12 @@ async fn create_mcp_server(
         embedding_provider,
         vector_store_provider,
         language_chunker,
+        hybrid_search_provider,
+        reranker_provider,
+        snapshot_provider: app_context.snapshot(),
+        state_store: app_context.state_store(),
+        event_bus: app_context.event_bus(),
+        indexing_operations: app_context.indexing(),
     };
     let services =
         mcb_infrastructure::di::modules::domain_services::DomainServicesFactory::create_services(
@@ -116,12 +137,25 @@ async fn create_mcp_server(
         )
         .await?;
 
-    McpServerBuilder::new()
+    let server = McpServerBuilder::new()
         .with_indexing_service(services.indexing_service)
         .with_context_service(services.context_service)
         .with_search_service(services.search_service)
         .try_build()
-        .map_err(|e| -> Box<dyn std::error::Error> { Box::new(e) })
+        .map_err(|e| -> Box<dyn std::error::Error> { Box::new(e) })?;
+
+    // Start background services (file watcher keeping indexed codebases in sync)
+    let service_manager = ServiceManager::new(app_context.event_bus());
+    if let Some(file_watcher) = services.file_watcher {
+        service_manager.register(file_watcher);
+    }
+    for (name, result) in service_manager.start_all().await {
+        if let Err(e) = result {
+            error!(service = %name, error = %e, "Failed to start background service");
+        }
+    }
+
+    Ok((server, service_manager))
 }
 
 /// Start the appropriate transport based on configuration
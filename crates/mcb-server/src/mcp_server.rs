@@ -15,7 +15,8 @@ use rmcp::model::{
 use mcb_application::{ContextServiceInterface, IndexingServiceInterface, SearchServiceInterface};
 
 use crate::handlers::{
-    ClearIndexHandler, GetIndexingStatusHandler, IndexCodebaseHandler, SearchCodeHandler,
+    CancelIndexingHandler, ClearIndexHandler, GetIndexingStatusHandler, IndexCodebaseHandler,
+    SearchCodeHandler,
 };
 use crate::tools::{ToolHandlers, create_tool_list, route_tool_call};
 
@@ -38,6 +39,8 @@ pub struct McpServer {
     search_code_handler: Arc<SearchCodeHandler>,
     /// Handler for indexing status operations
     get_indexing_status_handler: Arc<GetIndexingStatusHandler>,
+    /// Handler for indexing cancellation operations
+    cancel_indexing_handler: Arc<CancelIndexingHandler>,
     /// Handler for index clearing operations
     clear_index_handler: Arc<ClearIndexHandler>,
 }
@@ -53,6 +56,8 @@ impl McpServer {
         let search_code_handler = Arc::new(SearchCodeHandler::new(search_service.clone()));
         let get_indexing_status_handler =
             Arc::new(GetIndexingStatusHandler::new(indexing_service.clone()));
+        let cancel_indexing_handler =
+            Arc::new(CancelIndexingHandler::new(indexing_service.clone()));
         let clear_index_handler = Arc::new(ClearIndexHandler::new(indexing_service.clone()));
 
         Self {
@@ -62,6 +67,7 @@ impl McpServer {
             index_codebase_handler,
             search_code_handler,
             get_indexing_status_handler,
+            cancel_indexing_handler,
             clear_index_handler,
         }
     }
@@ -96,6 +102,11 @@ impl McpServer {
         Arc::clone(&self.get_indexing_status_handler)
     }
 
+    /// Access to cancel indexing handler (for HTTP transport)
+    pub fn cancel_indexing_handler(&self) -> Arc<CancelIndexingHandler> {
+        Arc::clone(&self.cancel_indexing_handler)
+    }
+
     /// Access to clear index handler (for HTTP transport)
     pub fn clear_index_handler(&self) -> Arc<ClearIndexHandler> {
         Arc::clone(&self.clear_index_handler)
@@ -120,6 +131,7 @@ impl ServerHandler for McpServer {
                  - index_codebase: Build a semantic index for a directory\n\
                  - search_code: Query indexed code using natural language\n\
                  - get_indexing_status: Inspect indexing progress\n\
+                 - cancel_indexing: Stop a running indexing operation\n\
                  - clear_index: Clear a collection before re-indexing\n"
                     .to_string(),
             ),
@@ -150,6 +162,7 @@ impl ServerHandler for McpServer {
             index_codebase: Arc::clone(&self.index_codebase_handler),
             search_code: Arc::clone(&self.search_code_handler),
             get_indexing_status: Arc::clone(&self.get_indexing_status_handler),
+            cancel_indexing: Arc::clone(&self.cancel_indexing_handler),
             clear_index: Arc::clone(&self.clear_index_handler),
         };
         route_tool_call(request, &handlers).await
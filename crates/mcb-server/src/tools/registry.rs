@@ -8,7 +8,9 @@ use rmcp::model::Tool;
 use std::borrow::Cow;
 use std::sync::Arc;
 
-use crate::args::{ClearIndexArgs, GetIndexingStatusArgs, IndexCodebaseArgs, SearchCodeArgs};
+use crate::args::{
+    CancelIndexingArgs, ClearIndexArgs, GetIndexingStatusArgs, IndexCodebaseArgs, SearchCodeArgs,
+};
 
 /// Tool definitions for MCP protocol
 pub struct ToolDefinitions;
@@ -18,7 +20,8 @@ impl ToolDefinitions {
     pub fn index_codebase() -> Result<Tool, McpError> {
         Self::create_tool(
             "index_codebase",
-            "Index a codebase directory for semantic search using vector embeddings",
+            "Index a codebase directory for semantic search using vector embeddings. \
+             Runs in the background and returns an operation id; track it with get_indexing_status",
             schemars::schema_for!(IndexCodebaseArgs),
         )
     }
@@ -36,11 +39,21 @@ impl ToolDefinitions {
     pub fn get_indexing_status() -> Result<Tool, McpError> {
         Self::create_tool(
             "get_indexing_status",
-            "Get the current indexing status and statistics",
+            "Get the indexing progress, ETA and errors of a collection",
             schemars::schema_for!(GetIndexingStatusArgs),
         )
     }
 
+    /// Get the cancel_indexing tool definition
+    pub fn cancel_indexing() -> Result<Tool, McpError> {
+        Self::create_tool(
+            "cancel_indexing",
+            "Cancel a running indexing operation by id. \
+             Files indexed so far are kept and the next index_codebase run resumes after them",
+            schemars::schema_for!(CancelIndexingArgs),
+        )
+    }
+
     /// Get the clear_index tool definition
     pub fn clear_index() -> Result<Tool, McpError> {
         Self::create_tool(
@@ -87,6 +100,7 @@ pub fn create_tool_list() -> Result<Vec<Tool>, McpError> {
         ToolDefinitions::index_codebase()?,
         ToolDefinitions::search_code()?,
         ToolDefinitions::get_indexing_status()?,
+        ToolDefinitions::cancel_indexing()?,
         ToolDefinitions::clear_index()?,
     ])
 }
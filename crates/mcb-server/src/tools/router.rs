@@ -8,9 +8,12 @@ use rmcp::handler::server::wrapper::Parameters;
 use rmcp::model::{CallToolRequestParam, CallToolResult};
 use std::sync::Arc;
 
-use crate::args::{ClearIndexArgs, GetIndexingStatusArgs, IndexCodebaseArgs, SearchCodeArgs};
+use crate::args::{
+    CancelIndexingArgs, ClearIndexArgs, GetIndexingStatusArgs, IndexCodebaseArgs, SearchCodeArgs,
+};
 use crate::handlers::{
-    ClearIndexHandler, GetIndexingStatusHandler, IndexCodebaseHandler, SearchCodeHandler,
+    CancelIndexingHandler, ClearIndexHandler, GetIndexingStatusHandler, IndexCodebaseHandler,
+    SearchCodeHandler,
 };
 
 /// Handler references for tool routing
@@ -21,6 +24,8 @@ pub struct ToolHandlers {
     pub search_code: Arc<SearchCodeHandler>,
     /// Handler for indexing status operations
     pub get_indexing_status: Arc<GetIndexingStatusHandler>,
+    /// Handler for indexing cancellation operations
+    pub cancel_indexing: Arc<CancelIndexingHandler>,
     /// Handler for index clearing operations
     pub clear_index: Arc<ClearIndexHandler>,
 }
@@ -45,6 +50,10 @@ pub async fn route_tool_call(
             let args = parse_args::<GetIndexingStatusArgs>(&request)?;
             handlers.get_indexing_status.handle(Parameters(args)).await
         }
+        "cancel_indexing" => {
+            let args = parse_args::<CancelIndexingArgs>(&request)?;
+            handlers.cancel_indexing.handle(Parameters(args)).await
+        }
         "clear_index" => {
             let args = parse_args::<ClearIndexArgs>(&request)?;
             handlers.clear_index.handle(Parameters(args)).await
@@ -327,6 +327,7 @@ async fn handle_tools_call(state: &HttpTransportState, request: &McpRequest) ->
         index_codebase: state.server.index_codebase_handler(),
         search_code: state.server.search_code_handler(),
         get_indexing_status: state.server.get_indexing_status_handler(),
+        cancel_indexing: state.server.cancel_indexing_handler(),
         clear_index: state.server.clear_index_handler(),
     };
 
@@ -5,7 +5,9 @@
 //! Migrated from Axum to Rocket in v0.1.2 (ADR-026).
 
 use async_trait::async_trait;
+use mcb_application::ports::admin::IndexingOperationsInterface;
 use mcb_application::ports::infrastructure::{DomainEventStream, EventBusProvider};
+use mcb_application::ports::services::EmbeddingCacheStats;
 use mcb_domain::error::Result;
 use mcb_domain::events::DomainEvent;
 use mcb_providers::admin::{AtomicPerformanceMetrics, DefaultIndexingOperations};
@@ -14,6 +16,8 @@ use rocket::http::Status;
 use rocket::local::asynchronous::Client;
 use std::sync::Arc;
 
+use crate::test_utils::mock_services::{MockContextService, MockIndexingService};
+
 /// Null EventBus for testing
 struct TestEventBus;
 
@@ -45,6 +49,8 @@ fn create_test_state() -> AdminState {
     AdminState {
         metrics: Arc::new(AtomicPerformanceMetrics::new()),
         indexing: Arc::new(DefaultIndexingOperations::new()),
+        indexing_service: None,
+        context_service: None,
         config_watcher: None,
         config_path: None,
         shutdown_coordinator: None,
@@ -126,6 +132,8 @@ async fn test_indexing_endpoint_with_operations() {
     let state = AdminState {
         metrics: Arc::new(AtomicPerformanceMetrics::new()),
         indexing: indexing.clone(),
+        indexing_service: None,
+        context_service: None,
         config_watcher: None,
         config_path: None,
         shutdown_coordinator: None,
@@ -164,6 +172,86 @@ async fn test_indexing_endpoint_with_operations() {
     assert_eq!(op["progress_percent"], 20.0);
 }
 
+#[rocket::async_test]
+async fn test_cancel_indexing_endpoint() {
+    let state = AdminState {
+        indexing_service: Some(Arc::new(MockIndexingService::new())),
+        ..create_test_state()
+    };
+    let client = Client::tracked(admin_rocket(state, Arc::new(AdminAuthConfig::default())))
+        .await
+        .expect("valid rocket instance");
+
+    let response = client
+        .post("/indexing/mock-operation/cancel")
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::Ok);
+    let body = response.into_string().await.expect("response body");
+    let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(json["cancelled"], true);
+    assert_eq!(json["operation_id"], "mock-operation");
+
+    let response = client.post("/indexing/missing/cancel").dispatch().await;
+    assert_eq!(response.status(), Status::NotFound);
+}
+
+#[rocket::async_test]
+async fn test_cancel_indexing_endpoint_without_service() {
+    let client = Client::tracked(admin_rocket(
+        create_test_state(),
+        Arc::new(AdminAuthConfig::default()),
+    ))
+    .await
+    .expect("valid rocket instance");
+
+    let response = client.post("/indexing/op-1/cancel").dispatch().await;
+
+    assert_eq!(response.status(), Status::ServiceUnavailable);
+}
+
+#[rocket::async_test]
+async fn test_embedding_cache_stats_endpoint() {
+    let context_service =
+        MockContextService::new().with_embedding_cache_stats(EmbeddingCacheStats {
+            enabled: true,
+            hits: 3,
+            misses: 1,
+            hit_rate: 0.75,
+        });
+    let state = AdminState {
+        context_service: Some(Arc::new(context_service)),
+        ..create_test_state()
+    };
+    let client = Client::tracked(admin_rocket(state, Arc::new(AdminAuthConfig::default())))
+        .await
+        .expect("valid rocket instance");
+
+    let response = client.get("/cache/embeddings/stats").dispatch().await;
+
+    assert_eq!(response.status(), Status::Ok);
+    let body = response.into_string().await.expect("response body");
+    let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(json["enabled"], true);
+    assert_eq!(json["hits"], 3);
+    assert_eq!(json["misses"], 1);
+    assert_eq!(json["hit_rate"], 0.75);
+}
+
+#[rocket::async_test]
+async fn test_embedding_cache_stats_without_context_service() {
+    let client = Client::tracked(admin_rocket(
+        create_test_state(),
+        Arc::new(AdminAuthConfig::default()),
+    ))
+    .await
+    .expect("valid rocket instance");
+
+    let response = client.get("/cache/embeddings/stats").dispatch().await;
+
+    assert_eq!(response.status(), Status::ServiceUnavailable);
+}
+
 #[rocket::async_test]
 async fn test_readiness_probe_not_ready() {
     // Create a fresh state - uptime will be < 1 second
@@ -235,6 +323,8 @@ async fn test_health_with_active_operations() {
     let state = AdminState {
         metrics: Arc::new(AtomicPerformanceMetrics::new()),
         indexing: indexing.clone(),
+        indexing_service: None,
+        context_service: None,
         config_watcher: None,
         config_path: None,
         shutdown_coordinator: None,
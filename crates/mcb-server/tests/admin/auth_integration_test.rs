@@ -11,6 +11,7 @@
 //! This ensures the AdminAuth guard works correctly in production.
 
 use async_trait::async_trait;
+use mcb_application::ports::admin::IndexingOperationsInterface;
 use mcb_application::ports::infrastructure::{DomainEventStream, EventBusProvider};
 use mcb_domain::error::Result;
 use mcb_domain::events::DomainEvent;
@@ -57,6 +58,8 @@ fn create_test_state() -> AdminState {
     AdminState {
         metrics: Arc::new(AtomicPerformanceMetrics::new()),
         indexing: Arc::new(DefaultIndexingOperations::new()),
+        indexing_service: None,
+        context_service: None,
         config_watcher: None,
         config_path: None,
         shutdown_coordinator: None,
@@ -441,6 +444,8 @@ async fn test_indexing_public_no_auth_required() {
     let state = AdminState {
         metrics: Arc::new(AtomicPerformanceMetrics::new()),
         indexing: indexing.clone(),
+        indexing_service: None,
+        context_service: None,
         config_watcher: None,
         config_path: None,
         shutdown_coordinator: None,
@@ -6,7 +6,7 @@
 //! Migrated from Axum to Rocket in v0.1.2 (ADR-026).
 
 use async_trait::async_trait;
-use mcb_application::ports::admin::PerformanceMetricsInterface;
+use mcb_application::ports::admin::{IndexingOperationsInterface, PerformanceMetricsInterface};
 use mcb_application::ports::infrastructure::{DomainEventStream, EventBusProvider};
 use mcb_domain::error::Result;
 use mcb_domain::events::DomainEvent;
@@ -53,6 +53,8 @@ fn create_shared_test_state() -> (
     let state = AdminState {
         metrics: metrics.clone(),
         indexing: indexing.clone(),
+        indexing_service: None,
+        context_service: None,
         config_watcher: None,
         config_path: None,
         shutdown_coordinator: None,
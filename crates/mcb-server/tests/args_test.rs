@@ -11,6 +11,7 @@ fn test_search_args_valid() {
         collection: Some("test".to_string()),
         extensions: None,
         filters: None,
+        fusion: None,
         token: None,
     };
 
@@ -25,6 +26,7 @@ fn test_search_args_empty_query() {
         collection: None,
         extensions: None,
         filters: None,
+        fusion: None,
         token: None,
     };
 
@@ -39,6 +41,7 @@ fn test_search_args_query_too_long() {
         collection: None,
         extensions: None,
         filters: None,
+        fusion: None,
         token: None,
     };
 
@@ -53,6 +56,7 @@ fn test_search_args_limit_zero() {
         collection: None,
         extensions: None,
         filters: None,
+        fusion: None,
         token: None,
     };
 
@@ -67,6 +71,7 @@ fn test_search_args_limit_too_high() {
         collection: None,
         extensions: None,
         filters: None,
+        fusion: None,
         token: None,
     };
 
@@ -81,12 +86,30 @@ fn test_search_args_dangerous_content() {
         collection: None,
         extensions: None,
         filters: None,
+        fusion: None,
         token: None,
     };
 
     assert!(args.validate().is_err());
 }
 
+#[test]
+fn test_search_args_fusion_strategy() {
+    let args = |fusion: &str| SearchCodeArgs {
+        query: "TokenBucketLimiter".to_string(),
+        limit: 10,
+        collection: None,
+        extensions: None,
+        filters: None,
+        fusion: Some(fusion.to_string()),
+        token: None,
+    };
+
+    assert!(args("rrf").validate().is_ok());
+    assert!(args("distribution_based").validate().is_ok());
+    assert!(args("best").validate().is_err());
+}
+
 #[test]
 fn test_index_args_valid() {
     let args = IndexCodebaseArgs {
@@ -96,6 +119,7 @@ fn test_index_args_valid() {
         ignore_patterns: None,
         max_file_size: None,
         follow_symlinks: None,
+        wait: None,
         token: None,
     };
 
@@ -111,6 +135,7 @@ fn test_index_args_empty_path() {
         ignore_patterns: None,
         max_file_size: None,
         follow_symlinks: None,
+        wait: None,
         token: None,
     };
 
@@ -126,6 +151,7 @@ fn test_index_args_path_traversal() {
         ignore_patterns: None,
         max_file_size: None,
         follow_symlinks: None,
+        wait: None,
         token: None,
     };
 
@@ -23,7 +23,7 @@ fn test_format_search_response_with_results() {
 
 #[test]
 fn test_format_search_response_no_results() {
-    let results: Vec<mcb_domain::SearchResult> = vec![];
+    let results: Vec<mcb_domain::ports::providers::HybridSearchResult> = vec![];
     let duration = Duration::from_millis(50);
 
     let response = ResponseFormatter::format_search_response("test query", &results, duration, 10);
@@ -127,6 +127,7 @@ fn test_format_indexing_status_idle() {
         current_file: None,
         total_files: 0,
         processed_files: 0,
+        ..Default::default()
     };
 
     let response = ResponseFormatter::format_indexing_status(&status);
@@ -142,6 +143,7 @@ fn test_format_indexing_status_in_progress() {
         current_file: Some("src/main.rs".to_string()),
         total_files: 100,
         processed_files: 50,
+        ..Default::default()
     };
 
     let response = ResponseFormatter::format_indexing_status(&status);
@@ -157,6 +159,7 @@ fn test_format_indexing_status_completed() {
         current_file: None,
         total_files: 100,
         processed_files: 100,
+        ..Default::default()
     };
 
     let response = ResponseFormatter::format_indexing_status(&status);
@@ -25,6 +25,7 @@ async fn test_index_codebase_valid_path() {
         ignore_patterns: None,
         max_file_size: None,
         follow_symlinks: None,
+        wait: None,
         token: None,
     };
 
@@ -47,6 +48,7 @@ async fn test_index_codebase_nonexistent_path() {
         ignore_patterns: None,
         max_file_size: None,
         follow_symlinks: None,
+        wait: None,
         token: None,
     };
 
@@ -71,6 +73,7 @@ async fn test_index_codebase_empty_path() {
         ignore_patterns: None,
         max_file_size: None,
         follow_symlinks: None,
+        wait: None,
         token: None,
     };
 
@@ -95,6 +98,7 @@ async fn test_index_codebase_default_collection() {
         ignore_patterns: None,
         max_file_size: None,
         follow_symlinks: None,
+        wait: None,
         token: None,
     };
 
@@ -117,6 +121,7 @@ async fn test_index_codebase_service_error() {
         ignore_patterns: None,
         max_file_size: None,
         follow_symlinks: None,
+        wait: None,
         token: None,
     };
 
@@ -148,6 +153,7 @@ async fn test_index_codebase_with_errors_in_result() {
         ignore_patterns: None,
         max_file_size: None,
         follow_symlinks: None,
+        wait: None,
         token: None,
     };
 
@@ -172,6 +178,7 @@ async fn test_index_codebase_file_instead_of_directory() {
         ignore_patterns: None,
         max_file_size: None,
         follow_symlinks: None,
+        wait: None,
         token: None,
     };
 
@@ -196,6 +203,7 @@ async fn test_index_codebase_with_extensions() {
         ignore_patterns: None,
         max_file_size: None,
         follow_symlinks: None,
+        wait: None,
         token: None,
     };
 
@@ -203,3 +211,38 @@ async fn test_index_codebase_with_extensions() {
 
     assert!(result.is_ok());
 }
+
+#[tokio::test]
+async fn test_index_codebase_forwards_options() {
+    let (_temp_dir, codebase_path) = create_temp_codebase();
+    let mock_service = MockIndexingService::new();
+    let last_options = mock_service.last_options();
+    let handler = IndexCodebaseHandler::new(Arc::new(mock_service));
+
+    let args = IndexCodebaseArgs {
+        path: codebase_path.to_string_lossy().to_string(),
+        collection: None,
+        extensions: Some(vec![".RS".to_string(), "py".to_string()]),
+        ignore_patterns: Some(vec!["vendor/".to_string()]),
+        max_file_size: Some(1024),
+        follow_symlinks: Some(true),
+        wait: None,
+        token: None,
+    };
+
+    let result = handler.handle(Parameters(args)).await;
+    assert!(result.is_ok());
+
+    let options = last_options
+        .lock()
+        .expect("Lock poisoned")
+        .clone()
+        .expect("indexing service should receive options");
+    assert_eq!(
+        options.extensions,
+        Some(vec!["rs".to_string(), "py".to_string()])
+    );
+    assert_eq!(options.ignore_patterns, vec!["vendor/".to_string()]);
+    assert_eq!(options.max_file_size, Some(1024));
+    assert!(options.follow_symlinks);
+}
@@ -1,6 +1,7 @@
 //! Tests for SearchCodeHandler
 
-use mcb_server::args::SearchCodeArgs;
+use mcb_domain::value_objects::FusionStrategy;
+use mcb_server::args::{SearchCodeArgs, SearchFilters};
 use mcb_server::handlers::SearchCodeHandler;
 use rmcp::handler::server::wrapper::Parameters;
 use std::sync::Arc;
@@ -20,6 +21,7 @@ async fn test_search_code_valid_query() {
         collection: Some("test".to_string()),
         extensions: None,
         filters: None,
+        fusion: None,
         token: None,
     };
 
@@ -41,6 +43,7 @@ async fn test_search_code_empty_query() {
         collection: None,
         extensions: None,
         filters: None,
+        fusion: None,
         token: None,
     };
 
@@ -61,6 +64,7 @@ async fn test_search_code_whitespace_only_query() {
         collection: None,
         extensions: None,
         filters: None,
+        fusion: None,
         token: None,
     };
 
@@ -82,6 +86,7 @@ async fn test_search_code_default_limit() {
         collection: None,
         extensions: None,
         filters: None,
+        fusion: None,
         token: None,
     };
 
@@ -102,6 +107,7 @@ async fn test_search_code_custom_limit() {
         collection: None,
         extensions: None,
         filters: None,
+        fusion: None,
         token: None,
     };
 
@@ -121,6 +127,7 @@ async fn test_search_code_service_error() {
         collection: None,
         extensions: None,
         filters: None,
+        fusion: None,
         token: None,
     };
 
@@ -141,6 +148,7 @@ async fn test_search_code_no_results() {
         collection: None,
         extensions: None,
         filters: None,
+        fusion: None,
         token: None,
     };
 
@@ -162,6 +170,7 @@ async fn test_search_code_with_collection() {
         collection: Some("my-project".to_string()),
         extensions: None,
         filters: None,
+        fusion: None,
         token: None,
     };
 
@@ -169,3 +178,73 @@ async fn test_search_code_with_collection() {
 
     assert!(result.is_ok());
 }
+
+#[tokio::test]
+async fn test_search_code_forwards_filters() {
+    let mock_service = MockSearchService::new().with_results(create_test_search_results(3));
+    let last_query = mock_service.last_query();
+    let handler = SearchCodeHandler::new(Arc::new(mock_service));
+
+    let args = SearchCodeArgs {
+        query: "auth middleware".to_string(),
+        limit: 5,
+        collection: None,
+        extensions: Some(vec!["rs".to_string()]),
+        filters: Some(SearchFilters {
+            file_extensions: Some(vec![".py".to_string()]),
+            languages: Some(vec!["rust".to_string(), "python".to_string()]),
+            exclude_patterns: Some(vec!["tests/".to_string()]),
+            min_score: Some(0.3),
+        }),
+        fusion: Some("rrf".to_string()),
+        token: None,
+    };
+
+    let result = handler.handle(Parameters(args)).await;
+    assert!(result.is_ok());
+
+    let query = last_query
+        .lock()
+        .expect("Lock poisoned")
+        .clone()
+        .expect("search service should receive a query");
+    assert_eq!(query.limit, 5);
+    assert!((query.min_score.expect("min score") - 0.3).abs() < 1e-6);
+    assert_eq!(query.fusion, Some(FusionStrategy::ReciprocalRank));
+
+    let filter = query.filter.expect("filters should be forwarded");
+    let metadata = |path: &str, language: &str| {
+        std::collections::HashMap::from([
+            ("file_path".to_string(), serde_json::json!(path)),
+            ("language".to_string(), serde_json::json!(language)),
+        ])
+    };
+    assert!(filter.matches(&metadata("/repo/src/auth.rs", "rust")));
+    assert!(filter.matches(&metadata("/repo/tools/auth.py", "python")));
+    assert!(!filter.matches(&metadata("/repo/tests/auth.rs", "rust")));
+    assert!(!filter.matches(&metadata("/repo/src/auth.go", "go")));
+}
+
+#[tokio::test]
+async fn test_search_code_rejects_invalid_min_score() {
+    let handler = SearchCodeHandler::new(Arc::new(MockSearchService::new()));
+
+    let args = SearchCodeArgs {
+        query: "test query".to_string(),
+        limit: 10,
+        collection: None,
+        extensions: None,
+        filters: Some(SearchFilters {
+            file_extensions: None,
+            languages: None,
+            exclude_patterns: None,
+            min_score: Some(1.5),
+        }),
+        fusion: None,
+        token: None,
+    };
+
+    let result = handler.handle(Parameters(args)).await;
+
+    assert!(result.is_err());
+}
@@ -6,4 +6,5 @@
 //! - tools: Tool registry and router tests
 
 mod admin;
+mod test_utils;
 mod tools;
@@ -377,7 +377,7 @@ fn test_format_search_response_contains_relevance_scores() {
 
 #[test]
 fn test_format_search_response_empty_contains_tips() {
-    let results: Vec<mcb_domain::SearchResult> = vec![];
+    let results: Vec<mcb_domain::ports::providers::HybridSearchResult> = vec![];
     let duration = Duration::from_millis(50);
 
     let response = ResponseFormatter::format_search_response("nonexistent", &results, duration, 10)
@@ -426,6 +426,7 @@ fn test_format_indexing_status_idle_has_is_error_false() {
         current_file: None,
         total_files: 0,
         processed_files: 0,
+        ..Default::default()
     };
 
     let response = ResponseFormatter::format_indexing_status(&status);
@@ -444,6 +445,7 @@ fn test_format_indexing_status_idle_contains_idle_indicator() {
         current_file: None,
         total_files: 0,
         processed_files: 0,
+        ..Default::default()
     };
 
     let response = ResponseFormatter::format_indexing_status(&status);
@@ -464,6 +466,7 @@ fn test_format_indexing_status_in_progress_contains_progress() {
         current_file: Some("src/main.rs".to_string()),
         total_files: 100,
         processed_files: 65,
+        ..Default::default()
     };
 
     let response = ResponseFormatter::format_indexing_status(&status);
@@ -485,6 +488,7 @@ fn test_format_indexing_status_in_progress_contains_current_file() {
         current_file: Some("src/lib.rs".to_string()),
         total_files: 50,
         processed_files: 25,
+        ..Default::default()
     };
 
     let response = ResponseFormatter::format_indexing_status(&status);
@@ -505,6 +509,7 @@ fn test_format_indexing_status_in_progress_contains_file_counts() {
         current_file: Some("test.rs".to_string()),
         total_files: 200,
         processed_files: 60,
+        ..Default::default()
     };
 
     let response = ResponseFormatter::format_indexing_status(&status);
@@ -518,6 +523,91 @@ fn test_format_indexing_status_in_progress_contains_file_counts() {
     );
 }
 
+#[test]
+fn test_format_indexing_status_in_progress_contains_operation_and_eta() {
+    let status = IndexingStatus {
+        is_indexing: true,
+        progress: 0.25,
+        current_file: Some("src/lib.rs".to_string()),
+        total_files: 40,
+        processed_files: 10,
+        operation_id: Some("op-42".to_string()),
+        collection: Some("project".to_string()),
+        eta_secs: Some(90),
+        errors: vec!["Failed to read src/bad.rs: denied".to_string()],
+        cancelled: false,
+    };
+
+    let response = ResponseFormatter::format_indexing_status(&status);
+    let text = extract_text_content(&response.content);
+
+    assert!(!response.is_error.unwrap_or(false));
+    assert!(
+        text.contains("op-42"),
+        "Status MUST show operation id. Got: {}",
+        text
+    );
+    assert!(
+        text.contains("project"),
+        "Status MUST show collection. Got: {}",
+        text
+    );
+    assert!(text.contains("90s"), "Status MUST show ETA. Got: {}", text);
+    assert!(
+        text.contains("src/bad.rs"),
+        "Status MUST list errors. Got: {}",
+        text
+    );
+}
+
+#[test]
+fn test_format_indexing_started_contains_operation_id() {
+    let response =
+        ResponseFormatter::format_indexing_started("op-7", "project", Path::new("/tmp/project"));
+    let text = extract_text_content(&response.content);
+
+    assert!(!response.is_error.unwrap_or(false));
+    assert!(
+        text.contains("op-7") && text.contains("get_indexing_status"),
+        "Started response MUST show the operation id and how to track it. Got: {}",
+        text
+    );
+}
+
+#[test]
+fn test_format_indexing_status_cancelled_contains_resume_hint() {
+    let status = IndexingStatus {
+        cancelled: true,
+        total_files: 40,
+        processed_files: 12,
+        operation_id: Some("op-3".to_string()),
+        ..Default::default()
+    };
+
+    let response = ResponseFormatter::format_indexing_status(&status);
+    let text = extract_text_content(&response.content);
+
+    assert!(!response.is_error.unwrap_or(false));
+    assert!(
+        text.contains("Cancelled") && text.contains("12/40") && text.contains("resume"),
+        "Cancelled status MUST show progress and how to resume. Got: {}",
+        text
+    );
+}
+
+#[test]
+fn test_format_indexing_cancelled_contains_operation_id() {
+    let response = ResponseFormatter::format_indexing_cancelled("op-3");
+    let text = extract_text_content(&response.content);
+
+    assert!(!response.is_error.unwrap_or(false));
+    assert!(
+        text.contains("op-3"),
+        "Cancel response MUST show the operation id. Got: {}",
+        text
+    );
+}
+
 // =============================================================================
 // CLEAR INDEX TESTS
 // =============================================================================
@@ -583,8 +673,8 @@ fn extract_text_content(content: &[rmcp::model::Content]) -> String {
 
 mod handler_error_tests {
     use super::*;
-    use mcb_server::args::IndexCodebaseArgs;
-    use mcb_server::handlers::IndexCodebaseHandler;
+    use mcb_server::args::{CancelIndexingArgs, IndexCodebaseArgs};
+    use mcb_server::handlers::{CancelIndexingHandler, IndexCodebaseHandler};
     use rmcp::handler::server::wrapper::Parameters;
     use std::sync::Arc;
 
@@ -604,6 +694,7 @@ mod handler_error_tests {
             ignore_patterns: None,
             max_file_size: None,
             follow_symlinks: None,
+            wait: None,
             token: None,
         };
 
@@ -638,6 +729,7 @@ mod handler_error_tests {
             ignore_patterns: None,
             max_file_size: None,
             follow_symlinks: None,
+            wait: None,
             token: None,
         };
 
@@ -665,4 +757,24 @@ mod handler_error_tests {
             }
         }
     }
+
+    #[tokio::test]
+    async fn test_cancel_handler_cancels_only_running_operations() {
+        let handler = CancelIndexingHandler::new(Arc::new(MockIndexingService::new()));
+
+        let cancelled = handler
+            .handle(Parameters(CancelIndexingArgs {
+                operation_id: "mock-operation".to_string(),
+            }))
+            .await
+            .expect("running operation should be cancelled");
+        assert!(!cancelled.is_error.unwrap_or(false));
+
+        let unknown = handler
+            .handle(Parameters(CancelIndexingArgs {
+                operation_id: "missing".to_string(),
+            }))
+            .await;
+        assert!(unknown.is_err(), "unknown operation ids are invalid params");
+    }
 }
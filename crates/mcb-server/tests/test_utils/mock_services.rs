@@ -3,13 +3,14 @@
 
 use async_trait::async_trait;
 use mcb_application::domain_services::search::{
-    ContextServiceInterface, IndexingResult, IndexingServiceInterface, IndexingStatus,
-    SearchServiceInterface,
+    ContextServiceInterface, EmbeddingCacheStats, IndexingOptions, IndexingResult,
+    IndexingServiceInterface, IndexingStatus, SearchServiceInterface,
 };
 use mcb_domain::entities::CodeChunk;
 use mcb_domain::error::Result;
-use mcb_domain::value_objects::{Embedding, SearchResult};
-use std::path::Path;
+use mcb_domain::ports::providers::HybridSearchResult;
+use mcb_domain::value_objects::{Embedding, SearchQuery, SearchResult};
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 
@@ -20,11 +21,13 @@ use std::sync::{Arc, Mutex};
 /// Mock implementation of SearchServiceInterface for testing
 pub struct MockSearchService {
     /// Pre-configured results to return
-    results: Arc<Mutex<Vec<SearchResult>>>,
+    results: Arc<Mutex<Vec<HybridSearchResult>>>,
     /// Whether the next call should fail
     should_fail: Arc<AtomicBool>,
     /// Error message to return on failure
     error_message: Arc<Mutex<String>>,
+    /// Query received by the most recent search call
+    last_query: Arc<Mutex<Option<SearchQuery>>>,
 }
 
 impl MockSearchService {
@@ -34,11 +37,17 @@ impl MockSearchService {
             results: Arc::new(Mutex::new(Vec::new())),
             should_fail: Arc::new(AtomicBool::new(false)),
             error_message: Arc::new(Mutex::new("Simulated search failure".to_string())),
+            last_query: Arc::new(Mutex::new(None)),
         }
     }
 
+    /// Get a handle to the query received by the most recent search call
+    pub fn last_query(&self) -> Arc<Mutex<Option<SearchQuery>>> {
+        Arc::clone(&self.last_query)
+    }
+
     /// Configure the mock to return specific results
-    pub fn with_results(self, results: Vec<SearchResult>) -> Self {
+    pub fn with_results(self, results: Vec<HybridSearchResult>) -> Self {
         *self.results.lock().expect("Lock poisoned") = results;
         self
     }
@@ -62,16 +71,16 @@ impl SearchServiceInterface for MockSearchService {
     async fn search(
         &self,
         _collection: &str,
-        _query: &str,
-        limit: usize,
-    ) -> Result<Vec<SearchResult>> {
+        query: &SearchQuery,
+    ) -> Result<Vec<HybridSearchResult>> {
+        *self.last_query.lock().expect("Lock poisoned") = Some(query.clone());
         if self.should_fail.load(Ordering::SeqCst) {
             let msg = self.error_message.lock().expect("Lock poisoned").clone();
             return Err(mcb_domain::error::Error::internal(msg));
         }
 
         let results = self.results.lock().expect("Lock poisoned");
-        Ok(results.iter().take(limit).cloned().collect())
+        Ok(results.iter().take(query.limit).cloned().collect())
     }
 }
 
@@ -89,6 +98,8 @@ pub struct MockIndexingService {
     should_fail: Arc<AtomicBool>,
     /// Error message to return on failure
     error_message: Arc<Mutex<String>>,
+    /// Options received by the most recent indexing call
+    last_options: Arc<Mutex<Option<IndexingOptions>>>,
 }
 
 impl MockIndexingService {
@@ -104,9 +115,15 @@ impl MockIndexingService {
             status: Arc::new(Mutex::new(IndexingStatus::default())),
             should_fail: Arc::new(AtomicBool::new(false)),
             error_message: Arc::new(Mutex::new("Simulated indexing failure".to_string())),
+            last_options: Arc::new(Mutex::new(None)),
         }
     }
 
+    /// Get a handle to the options received by the most recent indexing call
+    pub fn last_options(&self) -> Arc<Mutex<Option<IndexingOptions>>> {
+        Arc::clone(&self.last_options)
+    }
+
     /// Configure the mock to return specific indexing result
     pub fn with_result(self, result: IndexingResult) -> Self {
         *self.indexing_result.lock().expect("Lock poisoned") = Some(result);
@@ -135,7 +152,13 @@ impl Default for MockIndexingService {
 
 #[async_trait]
 impl IndexingServiceInterface for MockIndexingService {
-    async fn index_codebase(&self, _path: &Path, _collection: &str) -> Result<IndexingResult> {
+    async fn index_codebase(
+        &self,
+        _path: &Path,
+        _collection: &str,
+        options: &IndexingOptions,
+    ) -> Result<IndexingResult> {
+        *self.last_options.lock().expect("Lock poisoned") = Some(options.clone());
         if self.should_fail.load(Ordering::SeqCst) {
             let msg = self.error_message.lock().expect("Lock poisoned").clone();
             return Err(mcb_domain::error::Error::internal(msg));
@@ -150,10 +173,48 @@ impl IndexingServiceInterface for MockIndexingService {
         }))
     }
 
+    async fn reindex_files(
+        &self,
+        path: &Path,
+        collection: &str,
+        _files: &[PathBuf],
+        options: &IndexingOptions,
+    ) -> Result<IndexingResult> {
+        self.index_codebase(path, collection, options).await
+    }
+
+    async fn start_indexing(
+        &self,
+        _path: &Path,
+        _collection: &str,
+        options: &IndexingOptions,
+    ) -> Result<String> {
+        *self.last_options.lock().expect("Lock poisoned") = Some(options.clone());
+        if self.should_fail.load(Ordering::SeqCst) {
+            let msg = self.error_message.lock().expect("Lock poisoned").clone();
+            return Err(mcb_domain::error::Error::internal(msg));
+        }
+        Ok("mock-operation".to_string())
+    }
+
+    async fn cancel_indexing(&self, operation_id: &str) -> Result<()> {
+        if operation_id != "mock-operation" {
+            return Err(mcb_domain::error::Error::not_found(format!(
+                "Running indexing operation '{}'",
+                operation_id
+            )));
+        }
+        Ok(())
+    }
+
     fn get_status(&self) -> IndexingStatus {
         self.status.lock().expect("Lock poisoned").clone()
     }
 
+    fn get_collection_status(&self, _collection: &str) -> IndexingStatus {
+        self.get_status()
+    }
+
     async fn clear_collection(&self, _collection: &str) -> Result<()> {
         if self.should_fail.load(Ordering::SeqCst) {
             let msg = self.error_message.lock().expect("Lock poisoned").clone();
@@ -177,6 +238,8 @@ pub struct MockContextService {
     should_fail: Arc<AtomicBool>,
     /// Error message to return on failure
     error_message: Arc<Mutex<String>>,
+    /// Reported embedding cache statistics
+    embedding_cache_stats: EmbeddingCacheStats,
 }
 
 impl MockContextService {
@@ -187,9 +250,16 @@ impl MockContextService {
             dimensions: 384,
             should_fail: Arc::new(AtomicBool::new(false)),
             error_message: Arc::new(Mutex::new("Simulated context failure".to_string())),
+            embedding_cache_stats: EmbeddingCacheStats::default(),
         }
     }
 
+    /// Configure the embedding cache statistics to report
+    pub fn with_embedding_cache_stats(mut self, stats: EmbeddingCacheStats) -> Self {
+        self.embedding_cache_stats = stats;
+        self
+    }
+
     /// Configure the mock to return specific search results
     pub fn with_search_results(self, results: Vec<SearchResult>) -> Self {
         *self.search_results.lock().expect("Lock poisoned") = results;
@@ -226,6 +296,14 @@ impl ContextServiceInterface for MockContextService {
         Ok(())
     }
 
+    async fn collection_exists(&self, _collection: &str) -> Result<bool> {
+        if self.should_fail.load(Ordering::SeqCst) {
+            let msg = self.error_message.lock().expect("Lock poisoned").clone();
+            return Err(mcb_domain::error::Error::internal(msg));
+        }
+        Ok(true)
+    }
+
     async fn store_chunks(&self, _collection: &str, _chunks: &[CodeChunk]) -> Result<()> {
         if self.should_fail.load(Ordering::SeqCst) {
             let msg = self.error_message.lock().expect("Lock poisoned").clone();
@@ -237,8 +315,7 @@ impl ContextServiceInterface for MockContextService {
     async fn search_similar(
         &self,
         _collection: &str,
-        _query: &str,
-        limit: usize,
+        query: &SearchQuery,
     ) -> Result<Vec<SearchResult>> {
         if self.should_fail.load(Ordering::SeqCst) {
             let msg = self.error_message.lock().expect("Lock poisoned").clone();
@@ -246,7 +323,7 @@ impl ContextServiceInterface for MockContextService {
         }
 
         let results = self.search_results.lock().expect("Lock poisoned");
-        Ok(results.iter().take(limit).cloned().collect())
+        Ok(results.iter().take(query.limit).cloned().collect())
     }
 
     async fn embed_text(&self, _text: &str) -> Result<Embedding> {
@@ -262,6 +339,14 @@ impl ContextServiceInterface for MockContextService {
         })
     }
 
+    async fn delete_file_chunks(&self, _collection: &str, _file_paths: &[String]) -> Result<()> {
+        if self.should_fail.load(Ordering::SeqCst) {
+            let msg = self.error_message.lock().expect("Lock poisoned").clone();
+            return Err(mcb_domain::error::Error::internal(msg));
+        }
+        Ok(())
+    }
+
     async fn clear_collection(&self, _collection: &str) -> Result<()> {
         if self.should_fail.load(Ordering::SeqCst) {
             let msg = self.error_message.lock().expect("Lock poisoned").clone();
@@ -283,4 +368,12 @@ impl ContextServiceInterface for MockContextService {
     fn embedding_dimensions(&self) -> usize {
         self.dimensions
     }
+
+    fn embedding_space(&self) -> String {
+        format!("mock/mock/{}", self.dimensions)
+    }
+
+    fn embedding_cache_stats(&self) -> EmbeddingCacheStats {
+        self.embedding_cache_stats.clone()
+    }
 }
@@ -5,6 +5,7 @@
 #![allow(dead_code)]
 
 use mcb_domain::SearchResult;
+use mcb_domain::ports::providers::HybridSearchResult;
 
 /// Create a single test search result
 pub fn create_test_search_result(
@@ -12,19 +13,20 @@ pub fn create_test_search_result(
     content: &str,
     score: f64,
     start_line: u32,
-) -> SearchResult {
-    SearchResult {
+) -> HybridSearchResult {
+    HybridSearchResult::semantic_only(SearchResult {
         id: format!("test-result-{}", start_line),
         file_path: file_path.to_string(),
         start_line,
         content: content.to_string(),
         score,
         language: "rust".to_string(),
-    }
+        symbol: None,
+    })
 }
 
 /// Create multiple test search results
-pub fn create_test_search_results(count: usize) -> Vec<SearchResult> {
+pub fn create_test_search_results(count: usize) -> Vec<HybridSearchResult> {
     (0..count)
         .map(|i| {
             create_test_search_result(
@@ -5,12 +5,13 @@ use mcb_server::tools::registry::create_tool_list;
 #[test]
 fn test_tool_definitions_create_valid_tools() {
     let tools = create_tool_list().expect("should create tool list");
-    assert_eq!(tools.len(), 4);
+    assert_eq!(tools.len(), 5);
 
     let names: Vec<_> = tools.iter().map(|t| t.name.as_ref()).collect();
     assert!(names.contains(&"index_codebase"));
     assert!(names.contains(&"search_code"));
     assert!(names.contains(&"get_indexing_status"));
+    assert!(names.contains(&"cancel_indexing"));
     assert!(names.contains(&"clear_index"));
 }
 